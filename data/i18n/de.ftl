@@ -0,0 +1,668 @@
+copied = { $name } in die Zwischenablage kopiert.
+copy-failed = Kopieren in die Zwischenablage fehlgeschlagen.
+copy-unsupported = Kopieren in die Zwischenablage ist nicht möglich, da das Betriebssystem es nicht unterstützt.
+clearing = Die Zwischenablage wird in { $seconds } Sekunden geleert.
+written = { $name } gespeichert in { $destination }.
+no-password-picked = Kein Passwort ausgewählt.
+no-passphrase-picked = Keine Passphrase ausgewählt.
+suggestions-range = Die Anzahl der Vorschläge muss zwischen 1 und 1000 liegen.
+otp-valid = Noch { $seconds } Sekunden gültig.
+saved-private-key = Privater Schlüssel gespeichert in „{ $path }“.
+saved-public-key = Öffentlicher Schlüssel gespeichert in „{ $path }“.
+unknown-language = Unbekannte Sprache „{ $language }“, verfügbar sind { $languages }.
+entropy = Entropie: { $bits } Bit ({ $rating }).
+rating-weak = schwach
+rating-fair = mittel
+rating-strong = stark
+rating-very-strong = sehr stark
+porcelain-unsupported = Dieser Befehl unterstützt --porcelain nicht.
+again-itself = Der letzte Befehl ist `again` selbst, zuerst muss etwas Neues erzeugt werden.
+again-nothing = `again` kann nur einen Befehl wiederholen, der etwas erzeugt hat.
+again-hidden = Mit --copy wird das letzte Geheimnis kopiert, mit -i angezeigt.
+
+noun-api-key = API-Schlüssel
+noun-card-number = Kartennummer
+noun-codename = Codename
+noun-database-password = Datenbankpasswort
+noun-email = E-Mail-Adresse
+noun-excluded-characters = Ausgeschlossene Zeichen
+noun-hash = Hash
+noun-ip-address = IP-Adresse
+noun-license-key = Lizenzschlüssel
+noun-mac-address = MAC-Adresse
+noun-name = Name
+noun-nano-id = Nano ID
+noun-otp-code = OTP-Code
+noun-otp-secret = OTP-Geheimnis
+noun-pass-phrase = Passphrase
+noun-passphrase = Passphrase
+noun-password = Passwort
+noun-petname = Petname
+noun-public-key = Öffentlicher Schlüssel
+noun-salt = Salt
+noun-secret = Geheimnis
+noun-share = Anteil
+noun-slug = Slug
+noun-token = Token
+noun-username = Benutzername
+
+## accounts
+count-range = Die Anzahl muss zwischen 1 und { $max } liegen.
+accounts-too-few = Nur { $count } verschiedene Benutzernamen gefunden, eine kleinere Anzahl versuchen.
+
+## alias
+alias-unterminated-quote = Im Konfigurationswert „{ $key }“ fehlt ein schließendes Anführungszeichen.
+alias-empty = Der Konfigurationswert „{ $key }“ ist leer.
+
+## apikey
+apikey-unknown-profile = Kein API-Schlüssel-Profil namens „{ $profile }“ in der Konfigurationsdatei.
+apikey-negative-bytes = Der Konfigurationswert „{ $table }.bytes“ darf nicht negativ sein.
+apikey-checksum-choice = Der Konfigurationswert „{ $table }.checksum“ muss „none“ oder „crc32“ sein.
+apikey-bytes-range = Die Anzahl der Bytes muss zwischen 8 und 1024 liegen.
+apikey-prefix-whitespace = Das Präfix darf keine Leerzeichen enthalten.
+
+## bench
+bench-single = { $name } { $rate }/s mit 1 Thread
+bench-multi = { $name } { $rate }/s mit 1 Thread, { $multi-rate }/s mit { $threads } Threads
+bench-rng = { $name } { $rate } MB/s mit 1 Thread
+
+## breach
+breach-info = { $count } geleakte Passwörter in { $size } KB.
+unable-to-read = „{ $path }“ kann nicht gelesen werden: { $error }
+breach-no-passwords = „{ $path }“ enthält keine Passwörter.
+unable-to-create = „{ $path }“ kann nicht angelegt werden: { $error }
+unable-to-write = „{ $path }“ kann nicht geschrieben werden: { $error }
+breach-imported = { $count } geleakte Passwörter importiert.
+breach-found = Dieses Passwort steht im Leak-Filter, es sollte nicht verwendet werden.
+breach-not-found = Dieses Passwort steht nicht im Leak-Filter.
+breach-dead-end = Alle { $attempts } Versuche standen im Leak-Filter, mehr Zeichen oder eine größere Länge erlauben.
+no-config-directory = Das Konfigurationsverzeichnis wurde nicht gefunden.
+breach-not-imported = Noch kein Leak-Filter importiert, siehe `pwgen breach import --help`.
+breach-invalid = „{ $path }“ ist kein gültiger Leak-Filter, bitte erneut importieren.
+
+## codename
+unable-to-open = „{ $path }“ kann nicht geöffnet werden: { $error }
+codename-exhausted = Es wurde kein Codename gefunden, der noch nicht vergeben ist!
+
+## config
+config-missing-bracket = Zeile { $line }: ']' fehlt
+config-text-after-table = Zeile { $line }: unerwarteter Text nach dem Tabellennamen
+config-invalid-table = Zeile { $line }: ungültiger Tabellenname
+config-expected-key-value = Zeile { $line }: „Schlüssel = Wert“ erwartet
+config-invalid-key = Zeile { $line }: ungültiger Schlüssel
+config-invalid-value = Zeile { $line }: ungültiger Wert
+config-text-after-value = Zeile { $line }: unerwarteter Text nach dem Wert
+config-set-twice = Zeile { $line }: „{ $key }“ ist doppelt gesetzt
+invalid-config-file = Ungültige Konfigurationsdatei „{ $path }“: { $error }
+config-not-string = Der Konfigurationswert „{ $key }“ muss eine Zeichenkette sein.
+config-not-boolean = Der Konfigurationswert „{ $key }“ muss true oder false sein.
+config-not-integer = Der Konfigurationswert „{ $key }“ muss eine ganze Zahl sein.
+config-set-not-integer = „{ $key }“ muss eine ganze Zahl sein.
+config-set-not-boolean = „{ $key }“ muss true oder false sein.
+config-set = { $key } = { $value } gesetzt
+config-not-set = „{ $key }“ ist nicht gesetzt.
+config-nothing-set = Noch nichts gesetzt, `pwgen config list --all` zeigt, was gesetzt werden kann.
+config-editor-unstartable = Der Editor „{ $editor }“ kann nicht gestartet werden: { $error }
+config-editor-failed = Der Editor „{ $editor }“ ist fehlgeschlagen.
+config-warning = Warnung: { $message }
+config-imported = { $count } Werte importiert.
+
+## coupons
+length-range = Die Länge muss zwischen 1 und { $max } liegen.
+coupons-too-few = Es gibt nur { $possible } verschiedene Codes der Länge { $length }, für { $count } Codes eine größere Länge verwenden.
+
+## daemon
+daemon-no-runtime-dir = XDG_RUNTIME_DIR ist nicht gesetzt, mit --socket einen Socket wählen.
+daemon-already-listening = Ein anderer Daemon lauscht bereits auf „{ $path }“.
+unable-to-remove = „{ $path }“ kann nicht entfernt werden: { $error }
+daemon-unable-to-listen = Lauschen auf „{ $path }“ nicht möglich: { $error }
+daemon-unable-to-restrict = „{ $path }“ kann nicht eingeschränkt werden: { $error }
+daemon-listening = Lausche auf { $path }
+daemon-unsupported = Der Daemon braucht Unix-Sockets, die diese Plattform nicht hat.
+
+## defaults
+defaults-unknown-key-suggestion = Unbekannter Konfigurationsschlüssel „{ $key }“, war „{ $closest }“ gemeint?
+defaults-unknown-key = Unbekannter Konfigurationsschlüssel „{ $key }“, `pwgen config list --all` zeigt alle Schlüssel.
+defaults-unknown-command = „{ $command }“ ist weder ein Befehl noch ein Alias.
+defaults-length-range = Der Konfigurationswert „password.length“ muss zwischen 1 und { $max } liegen.
+defaults-out-of-range = Der Konfigurationswert „{ $key }“ liegt außerhalb des gültigen Bereichs.
+defaults-not-character = Der Konfigurationswert „{ $key }“ muss ein einzelnes Zeichen sein.
+defaults-not-strings = Der Konfigurationswert „{ $key }“ darf nur Zeichenketten enthalten.
+defaults-not-array = Der Konfigurationswert „{ $key }“ muss eine Liste von Namen sein.
+defaults-unknown-choice = Der Konfigurationswert „{ $key }“ hat die unbekannte Auswahl „{ $value }“, erwartet wird eine von { $choices }.
+
+## derive
+derive-empty-site = Die Website darf nicht leer sein.
+derive-touch-yubikey = Den YubiKey berühren, falls er blinkt.
+derive-yubikey-response = Der YubiKey hat unerwartet geantwortet, ist der Slot für HMAC-SHA1-Challenge-Response eingerichtet?
+derive-no-fido2 = Kein FIDO2-Sicherheitsschlüssel gefunden, ist er eingesteckt?
+derive-touch-security-key = Den Sicherheitsschlüssel berühren, wenn er blinkt.
+derive-no-hmac-secret = Der Sicherheitsschlüssel hat kein hmac-secret geliefert, unterstützt er die Erweiterung?
+derive-making-credential = Lege ein Credential für pwgen auf dem Sicherheitsschlüssel an, ihn berühren, wenn er blinkt.
+derive-no-credential = Der Sicherheitsschlüssel hat kein Credential geliefert.
+
+## doctor
+doctor-ok = ok
+doctor-warn = warn
+doctor-fail = fehl
+doctor-check = { $check }: { $detail }
+doctor-failed = { $count ->
+        [one] 1 Prüfung ist fehlgeschlagen.
+       *[other] { $count } Prüfungen sind fehlgeschlagen.
+    }
+doctor-random-source = Zufallsquelle
+doctor-display = Anzeige
+doctor-clipboard = Zwischenablage
+doctor-keyring = Schlüsselbund
+doctor-config = Konfiguration
+doctor-terminal = Terminal
+doctor-random-ok = hat den Gesundheitstest bestanden
+doctor-display-xwayland = Wayland, mit X11 über XWayland
+doctor-display-wayland = Wayland ohne XWayland, die Zwischenablage braucht ein gesetztes DISPLAY
+doctor-display-x11 = X11
+doctor-display-none = weder WAYLAND_DISPLAY noch DISPLAY ist gesetzt, es gibt keine Zwischenablage zum Kopieren
+doctor-clipboard-ok = Geheimnisse können kopiert werden
+doctor-clipboard-unreadable = verbunden, aber das Lesen ist fehlgeschlagen: { $error }
+doctor-clipboard-unavailable = nicht verfügbar, --no-copy verwenden: { $error }
+doctor-osc52-tmux = tmux gibt es mit `set -g set-clipboard on` weiter
+doctor-osc52-supported = { $terminal } unterstützt es
+doctor-osc52-no-terminal = kein Terminal erkannt
+doctor-osc52-unknown = unbekannt, ob „{ $terminal }“ es unterstützt
+doctor-osc52-remote = { $detail }, über SSH ist die entfernte Zwischenablage nicht die eigene
+doctor-keyring-ok = verfügbar, { $count } Geheimnisse von pwgen gespeichert
+doctor-keyring-unavailable = nicht verfügbar, --keyring und git-credential funktionieren nicht: { $error }
+doctor-config-no-directory = kein Konfigurationsverzeichnis gefunden
+doctor-config-none = noch keine unter „{ $path }“
+doctor-config-valid = „{ $path }“ ist gültig
+doctor-terminal-ok = Geheimnisse werden ohne Echo abgefragt
+doctor-terminal-stdin = stdin ist kein Terminal, Geheimnisse werden zeilenweise daraus gelesen
+doctor-terminal-stdout = stdout ist kein Terminal, angezeigte Geheimnisse landen, wo immer es hinführt
+doctor-tool-installed = installiert, { $option } funktioniert
+doctor-tool-missing = nicht installiert, nötig für { $option }
+
+## email
+email-invalid-domain = „{ $domain }“ ist keine gültige Domain!
+email-invalid-tag = „{ $tag }“ ist kein gültiges Tag! Nur Buchstaben, Ziffern, '.', '-' und '_' sind erlaubt.
+email-invalid-mailbox = „{ $mailbox }“ ist kein gültiger Postfachname!
+
+## external
+external-not-installed = { $program } kann nicht ausgeführt werden, ist es installiert? { $error }
+external-unable-to-run = { $program } kann nicht ausgeführt werden: { $error }
+external-failed = { $program } ist fehlgeschlagen: { $error }
+
+## git_credential
+git-credential-generated = Neues Passwort für { $name } erzeugt.
+git-credential-unreadable = Lesen von git nicht möglich: { $error }
+
+## hardening
+hardening-core-dumps = Core-Dumps können nicht abgeschaltet werden: { $error }
+hardening-dumpable = Der Prozess kann nicht vor Dumps geschützt werden: { $error }
+hardening-lock = Der Speicher kann nicht gesperrt werden, ist `ulimit -l` zu niedrig? { $error }
+hardening-working-set = Das Working Set zum Sperren des Speichers kann nicht vergrößert werden: { $error }
+hardening-lock-unsupported = Speicher sperren wird auf dieser Plattform nicht unterstützt.
+
+## hash
+hash-cost-bcrypt-only = --cost gilt nur für bcrypt.
+hash-iterations-range = Die Iterationen müssen zwischen 1 und 1000 liegen.
+hash-parallelism-range = Die Parallelität muss zwischen 1 und 255 liegen.
+hash-memory-range = Der Speicher muss zwischen { $min } KiB und 4 GiB liegen.
+hash-argon2-salt = Das Salt muss mindestens 8 Bytes Base64 lang sein.
+hash-argon2-only = --memory, --iterations und --parallelism gelten nur für argon2id.
+hash-cost-range = Die Kosten müssen zwischen 4 und 31 liegen.
+hash-bcrypt-salt = Das Salt muss 22 Zeichen im Base64 von bcrypt lang sein.
+hash-invalid-parameters = Ungültige argon2id-Parameter: { $error }
+hash-failed = Das Passwort kann nicht gehasht werden: { $error }
+hash-bcrypt-too-long = bcrypt ignoriert alles nach { $bytes } Bytes, für längere Passwörter argon2id verwenden.
+
+## health
+health-unavailable = Die Zufallsquelle des Systems ist nicht verfügbar: { $error }
+health-failed = Die Zufallsquelle des Systems hat ihren Gesundheitstest nicht bestanden, { $reason }. Damit werden keine Geheimnisse erzeugt.
+health-repetition = ein Byte wiederholt sich zu oft hintereinander
+health-proportion = ein Byte kommt weit häufiger vor als die anderen
+
+## history
+history-given-secret = Dem letzten Befehl wurde ein Geheimnis übergeben, daher wird er nicht zum Wiederholen aufbewahrt.
+history-disabled = Der Verlauf ist nicht aktiviert, mit `pwgen config set history.enabled true` einschalten.
+history-nothing-since-enabled = Seit der Verlauf aktiviert ist, wurde nichts erzeugt.
+history-no-config-directory = Kein Konfigurationsverzeichnis, um den letzten Befehl aufzubewahren.
+history-nothing-yet = Es wurde noch nichts erzeugt.
+history-damaged = „{ $path }“ ist beschädigt, etwas Neues erzeugen, um es zu ersetzen.
+
+## honeywords
+honeywords-empty-reference = Die Referenz darf nicht leer sein.
+honeywords-nothing-to-vary = Die Referenz braucht mindestens einen Buchstaben, eine Ziffer oder ein Symbol zum Variieren.
+honeywords-too-few = Nur { $count } verschiedene Köder haben diese Form, eine längere Referenz oder eine kleinere Anzahl versuchen.
+
+## id
+id-too-many-ulids = Zu viele ULIDs innerhalb einer Millisekunde!
+id-too-many-ksuids = Zu viele KSUIDs innerhalb einer Sekunde!
+
+## identity
+identity-age-order = Das Mindestalter darf nicht größer als das Höchstalter sein.
+identity-age-max = Das Höchstalter darf nicht größer als 150 sein.
+
+## invented
+invented-too-long = Kein erfundener Name passt in { $length } Zeichen!
+
+## ip
+ip-exhausted = In diesem Subnetz wurde keine Adresse gefunden, die nicht reserviert ist!
+ip-invalid-cidr = „{ $cidr }“ ist kein gültiger CIDR-Block! Beispiel: „10.0.0.0/8“.
+ip-invalid-prefix = „{ $prefix }“ ist keine gültige Präfixlänge!
+ip-v4-prefix = Die IPv4-Präfixlänge darf nicht größer als 32 sein.
+ip-v6-prefix = Die IPv6-Präfixlänge darf nicht größer als 128 sein.
+ip-invalid-address = „{ $address }“ ist keine gültige IP-Adresse!
+
+## json
+json-invalid = Ungültiges JSON bei Byte { $position }: { $reason }
+json-text-after-value = unerwarteter Text nach dem Wert
+json-expected = '{ $character }' erwartet
+json-unknown-value = unbekannter Wert
+json-unexpected-end = unerwartetes Ende
+json-nested-too-deeply = zu tief verschachtelt
+json-expected-comma-bracket = ',' oder ']' erwartet
+json-expected-field-name = Feldname erwartet
+json-expected-comma-brace = ',' oder '{"}"}' erwartet
+json-unterminated-string = nicht abgeschlossene Zeichenkette
+json-invalid-escape = ungültige Escape-Sequenz
+json-control-character = Steuerzeichen in der Zeichenkette
+json-invalid-utf8 = ungültiges UTF-8
+json-unpaired-surrogate = ungepaartes Surrogat
+json-invalid-number = ungültige Zahl
+
+## keyring
+keyring-invalid-name = Ungültiger Name „{ $name }“ für ein Geheimnis!
+keyring-not-found = Kein Geheimnis namens „{ $name }“ im Schlüsselbund.
+keyring-not-utf8 = Das Geheimnis „{ $name }“ ist kein gültiges UTF-8.
+keyring-not-in-keychain = Kein Geheimnis namens „{ $name }“ im Schlüsselbund von macOS.
+keyring-unable-to-save = „{ $name }“ kann nicht gespeichert werden: { $error }
+keyring-not-in-credential-manager = Kein Geheimnis namens „{ $name }“ in der Anmeldeinformationsverwaltung.
+keyring-unsupported = Auf dieser Plattform gibt es keinen unterstützten Geheimnisspeicher.
+
+## license
+license-no-x = Das Muster braucht mindestens ein 'X'.
+license-too-few-x = Das Muster braucht mehr als { $checksum } 'X' für eine Prüfsumme.
+license-invalid = Der Lizenzschlüssel ist ungültig! { $problem }
+license-valid = Der Lizenzschlüssel ist gültig.
+license-pattern-mismatch = Er passt nicht zum Muster.
+license-too-short = Das Muster ist zu kurz für eine Prüfsumme.
+license-checksum-mismatch = Die Prüfsumme stimmt nicht.
+license-invalid-character = '{ $character }' ist kein gültiges Zeichen.
+
+## nanoid
+nanoid-size-range = Die Größe muss zwischen 1 und 65536 liegen.
+nanoid-alphabet-range = Das Alphabet muss zwischen 2 und 256 Zeichen enthalten.
+nanoid-duplicate = Das Alphabet enthält '{ $character }' mehrfach, was es wahrscheinlicher machen würde.
+
+## native_host
+native-host-too-long = Die Nachricht mit { $length } Bytes ist zu lang, Verbindung wird geschlossen.
+native-host-unreadable = Vom Browser kann keine Nachricht gelesen werden: { $error }
+native-host-unanswerable = Dem Browser kann nicht geantwortet werden: { $error }
+
+## otp
+otp-digits-range = Die Ziffern müssen zwischen 6 und 10 liegen.
+otp-period-range = Der Zeitraum muss mindestens 1 Sekunde betragen.
+otp-invalid-secret = Das Geheimnis ist kein gültiges Base32.
+otp-clock-before-1970 = Die Systemuhr steht vor 1970.
+otp-bytes-range = Die Bytes müssen zwischen 10 und 64 liegen.
+otp-colon = Aussteller und Konto dürfen kein ':' enthalten.
+otp-empty = Aussteller und Konto dürfen nicht leer sein.
+
+## output
+output-file = „{ $path }“
+output-vault = Vault unter { $target }
+output-bitwarden = Bitwarden als „{ $name }“
+output-1password = 1Password als „{ $title }“
+output-kdbx = „{ $database }“ als „{ $entry }“
+output-keyring = den Schlüsselbund als „{ $name }“
+output-docker = das { $engine }-Secret „{ $name }“
+output-vault-password-file-only = --vault-password-file gilt nur für --output ansible-vault.
+output-dotenv-invalid-name = „{ $key }“ ist kein gültiger Variablenname für dotenv.
+output-dotenv-invalid-secret = Das Geheimnis enthält ein ' oder ein Steuerzeichen, das ein dotenv-Wert in einfachen Anführungszeichen nicht aufnehmen kann. Mit --exclude weglassen.
+output-needs-vault-password-file = --output ansible-vault braucht eine --vault-password-file.
+output-no-vault-password = „{ $path }“ enthält kein Vault-Passwort.
+output-not-appendable = Nur an Ausgaben in plain, dotenv, CSV, YAML und TOML kann angehängt werden.
+output-exists = „{ $path }“ existiert bereits, mit --append wird das Geheimnis angehängt.
+output-unable-to-append = An „{ $path }“ kann nicht angehängt werden: { $problem }
+output-toml-table = das Geheimnis würde in der letzten Tabelle der TOML-Datei landen.
+output-key-exists = es gibt dort schon „{ $key }“, einen anderen --key wählen.
+output-vault-target = --vault-write erwartet PFAD#FELD, etwa secret/data/myapp#password.
+output-no-vault-addr = VAULT_ADDR muss auf die Adresse des Vault-Servers gesetzt sein.
+output-bw-locked = Zuerst den Tresor mit `bw unlock` entsperren und BW_SESSION exportieren.
+output-kdbx-missing = „{ $path }“ existiert nicht.
+
+## passphrase
+passphrase-no-words = Die Passphrase braucht mindestens ein Wort.
+passphrase-too-many-words = Zu viele Wörter! Es dürfen nicht mehr als 1024 sein.
+
+## password
+password-hybrid-conflict = --style hybrid lässt sich nicht mit --mobile-friendly, --alternate-hands oder --no-patterns kombinieren.
+wordlist-empty = Die Wortliste ist leer!
+password-policy-kept-failing = die Regeln von { $policy } schlugen immer wieder fehl, zuletzt bei „{ $problem }“. Eine größere Länge oder mehr Versuche probieren
+password-patterns-kept-failing = --no-patterns fand immer wieder { $pattern }. Eine kleinere Länge oder mehr Zeichensätze probieren
+password-tried = in { $attempts } Versuchen
+password-dead-end = Kein Passwort hat die Regeln { $tried } befolgt, { $reason }.
+explain-sets = Zeichensätze: { $sets }.
+explain-excluded = { $count } Zeichen ausgeschlossen: { $characters }
+explain-pool = Vorrat von { $count } Zeichen, { $bits } Bit pro Zeichen.
+explain-weight = { $set } { $factor }-mal
+explain-weights = { $weights } so oft gewählt, { $bits } Bit pro Zeichen.
+explain-hybrid-word = ein Wort aus { $count }
+random-characters = { $count ->
+        [one] 1 zufälliges Zeichen
+       *[other] { $count } zufällige Zeichen
+    }
+explain-hybrid = Hybrid aus { $parts }.
+explain-length = Länge von { $length } Zeichen.
+explain-mobile = Buchstaben, Ziffern und ein Symbol jeweils in einem Block, in zufälliger Reihenfolge.
+explain-hands = Abwechselnd { $left } Zeichen der linken Hand und { $right } der rechten.
+trade-off-hybrid = Das Hybridpasswort hat { $bits } Bit Entropie, das Wort zählt nur als die eine Wahl, die es ist.
+trade-off = Das sind { $lost } Bit weniger als die { $bits } Bit, wenn jedes Zeichen aus dem ganzen Vorrat gewählt wird.
+password-hands = --alternate-hands braucht Zeichen für beide Hände.
+password-no-characters = Keine Zeichen erlaubt! Mehr Zeichensätze hinzufügen oder weniger Zeichen ausschließen.
+password-too-long = Passwort zu lang! Es darf nicht länger als { $max } sein.
+pattern-dictionary-word = ein Wörterbuchwort
+pattern-keyboard-walk = eine Tastaturfolge
+pattern-date = ein Datum
+password-tried-within = innerhalb { $seconds ->
+        [one] 1 Sekunde
+       *[other] { $seconds } Sekunden
+    } ({ $attempts } Versuche)
+explain-hybrid-digit = eine Ziffer aus { $count }
+explain-hybrid-symbol = ein Symbol aus { $count }
+
+## petname
+petname-words-range = Die Anzahl der Wörter muss zwischen 1 und 16 liegen.
+petname-invalid-separator = Das Trennzeichen darf für DNS-Namen nur Kleinbuchstaben, Ziffern und '-' enthalten.
+invalid-dns-label = „{ $label }“ ist kein gültiges DNS-Label!
+
+## picker
+picker-candidates-range = Die Anzahl der Kandidaten muss zwischen 1 und 100 liegen.
+picker-unknown-choice = Die Auswahl hat etwas zurückgegeben, das keiner der Kandidaten ist.
+
+## platform
+platform-too-short = kürzer als { $min } Zeichen
+platform-too-long = länger als { $max } Zeichen
+platform-invalid-character = enthält '{ $character }'
+platform-no-letter-start = beginnt nicht mit einem Buchstaben
+platform-symbol-edge = beginnt oder endet mit einem Symbol
+platform-double-symbol = enthält zwei Symbole hintereinander
+
+## policy
+policy-invalid = Ungültige Richtlinie „{ $path }“: { $error }
+policy-unknown-rule = unbekannte Regel „{ $rule }“, erwartet wird eine von { $keys } oder ein Satz in [{ $tables }].
+policy-length-range = „{ $key }“ muss zwischen 1 und 65536 liegen.
+policy-max-below-min = „max_length“ ist kürzer als „min_length“.
+policy-length-outside = „length“ liegt außerhalb von „min_length“ und „max_length“.
+policy-max-consecutive = „max_consecutive“ muss mindestens 1 sein.
+policy-unknown-set = „{ $key }“ ist kein Zeichensatz.
+policy-count-range = „{ $key }“ muss zwischen 0 und 65536 liegen.
+policy-no-characters = es sind keine Zeichen erlaubt.
+policy-required-unavailable = „{ $set }“ ist vorgeschrieben, aber keines seiner Zeichen ist erlaubt.
+policy-max-counts = „{ $set }“ braucht mehr Zeichen, als „max_counts“ erlaubt.
+policy-ends-unavailable = keines der Zeichen von „{ $key }“ ist erlaubt.
+policy-max-length-required = „max_length“ ist zu kurz für alle vorgeschriebenen Zeichensätze.
+policy-needs-length-between = { $policy } braucht eine Länge zwischen { $min } und { $max }.
+policy-needs-length = { $policy } braucht eine Länge von mindestens { $min }.
+policy-requires-set = { $policy } schreibt „{ $set }“ vor, was die gewählten Zeichensätze nicht haben.
+policy-requires-sets = { $policy } schreibt { $count } Zeichensätze vor, die nicht in { $length } Zeichen passen.
+policy-impossible = { $policy } hat Regeln, die kein Passwort mit { $length } Zeichen befolgen kann.
+policy-max-consecutive-impossible = { $policy } erlaubt höchstens { $max } gleiche Zeichen hintereinander, aber es ist nur ein Zeichen übrig.
+policy-entropy-impossible = { $policy } verlangt { $bits } Bit Entropie, { $length } Zeichen schaffen etwa { $best }.
+check-min-length = Länge von { $length }, mindestens { $min }.
+check-max-length = Länge von { $length }, höchstens { $max }.
+check-allowed = Nur Zeichen der erlaubten Sätze.
+check-outside = Zeichen außerhalb der erlaubten Sätze: { $characters }
+check-not-forbidden = Keine verbotenen Zeichen.
+check-forbidden = Verbotene Zeichen: { $characters }
+check-contains = Enthält „{ $set }“.
+check-contains-no = Enthält nichts aus „{ $set }“.
+check-min-count = Mindestens { $min } aus „{ $set }“, hat { $count }.
+check-max-count = Höchstens { $max } aus „{ $set }“, hat { $count }.
+check-consecutive = Höchstens { $max } gleiche Zeichen hintereinander, hat { $longest }.
+check-entropy = Entropie von etwa { $bits } Bit, mindestens { $min }.
+conform-removed = { $count } nicht erlaubte Zeichen entfernt.
+conform-appended-set = Ein zufälliges Zeichen der Art { $set } angehängt.
+conform-padded = { $characters } angehängt, um die Mindestlänge von { $min } zu erreichen.
+conform-trimmed = { $count } Zeichen am Ende entfernt, um in die Höchstlänge von { $max } zu passen.
+conform-entropy = { $characters } angehängt, um { $bits } Bit Entropie zu erreichen.
+conform-runs = { $characters } ersetzt, um Folgen gleicher Zeichen aufzubrechen.
+conform-max-count = { $characters } ersetzt, um höchstens { $max } aus „{ $set }“ zu haben.
+conform-failed = Das Passwort lässt sich nicht an die Richtlinie anpassen, es verletzt noch eine Regel: { $rule }
+validate-broken = Das Passwort verletzt { $failed } von { $total } Regeln.
+validate-followed = Das Passwort befolgt die Richtlinie.
+conform-nothing = Das Passwort befolgt die Richtlinie bereits.
+conform-entropy-change = Entropie von etwa { $bits } Bit, vorher { $before }.
+compare-same = Beide Richtlinien erzeugen gleich starke Passwörter.
+compare-stronger = { $stronger } erzeugt Passwörter, die { $bits } Bit stärker sind als bei { $weaker }, das { $guesses }-fache an Rateversuchen.
+compare-nothing = nichts
+compare-length-range = { $length } ({ $min } bis { $max })
+compare-length-open = { $length } ({ $min } oder mehr)
+compare-met = { $bits } Bit, erreicht
+compare-missed = { $bits } Bit, verfehlt
+compare-none = keins
+conform-made-uppercase = Zeichen { $position } großgeschrieben.
+conform-made-lowercase = Zeichen { $position } kleingeschrieben.
+conform-replaced-first = Das erste Zeichen durch ein zufälliges Zeichen der Art { $set } ersetzt.
+conform-replaced-last = Das letzte Zeichen durch ein zufälliges Zeichen der Art { $set } ersetzt.
+conform-added-first = Ein zufälliges Zeichen der Art { $set } als erstes Zeichen hinzugefügt.
+conform-added-last = Ein zufälliges Zeichen der Art { $set } als letztes Zeichen hinzugefügt.
+check-starts = Beginnt mit { $sets }.
+check-ends = Endet mit { $sets }.
+or = oder
+set-lower = Kleinbuchstabe
+set-upper = Großbuchstabe
+set-digit = Ziffer
+set-symbol = Symbol
+set-rare-symbol = seltenes Symbol
+set-latin-1 = Latin-1-Zeichen
+set-greek = griechischer Buchstabe
+set-emoji = Emoji
+check-pass = gut
+check-fail = fehl
+compare-policy = Richtlinie
+compare-length = Länge
+compare-characters = Zeichen
+compare-required = Vorgeschrieben
+compare-keyspace = Schlüsselraum
+compare-entropy = Entropie
+compare-lost = Durch Regeln verloren
+compare-target = Entropieziel
+compare-shortest = Kürzeste Länge
+bits = { $bits } Bit
+
+## prompt
+prompt-number-range = Bitte eine Zahl zwischen 1 und { $max } eingeben.
+prompt-yes-no = Bitte mit ja oder nein antworten.
+prompt-pick = Eins zum Kopieren wählen (1-{ $count }, leer zum Abbrechen):
+prompt-unreadable-stdin = stdin kann nicht gelesen werden: { $error }
+prompt-unreadable = Die Eingabe kann nicht gelesen werden: { $error }
+prompt-repeat = Zur Bestätigung wiederholen:
+prompt-mismatch = Die beiden Eingaben stimmen nicht überein.
+
+## request
+request-not-object = Die Anfrage muss ein JSON-Objekt sein.
+request-char-sets = „char_sets“ muss eine Liste von Namen sein.
+request-unknown-set = Unbekannter Zeichensatz { $set }.
+request-unknown-password-option = Unbekannte Passwort-Option „{ $key }“.
+request-site-and-policy = „site“ und „policy“ lassen sich nicht kombinieren.
+request-separator = „separator“ muss ein einzelnes Zeichen sein.
+request-unknown-passphrase-option = Unbekannte Passphrase-Option „{ $key }“.
+request-unknown-command = Unbekannter Befehl „{ $command }“, „password“ oder „passphrase“ verwenden.
+request-no-command = Die Anfrage braucht ein „command“.
+request-not-positive = „{ $key }“ muss eine positive ganze Zahl sein.
+request-not-string = „{ $key }“ muss eine Zeichenkette sein.
+
+## salt
+salt-bytes-range = Die Bytes müssen zwischen 1 und 65536 liegen.
+
+## sandbox
+sandbox-failed = pwgen kann nicht in eine Sandbox gesetzt werden: { $error }
+sandbox-unveil-path = unveil von „{ $path }“ nicht möglich: { $error }
+sandbox-unveil = unveil nicht möglich: { $error }
+sandbox-pledge = pledge nicht möglich: { $error }
+
+## setup
+setup-welcome = Willkommen bei pwgen! Es sieht so aus, als würde es zum ersten Mal verwendet.
+setup-ask = Jetzt die Standardwerte einrichten?
+setup-skipped = Übersprungen, `pwgen setup` holt es später nach.
+setup-length = Passwortlänge? [16]
+setup-length-range = Bitte eine Länge zwischen 4 und 65536 eingeben.
+setup-symbols = Sind Symbole in Passwörtern in Ordnung?
+setup-copy = Erzeugte Geheimnisse in die Zwischenablage kopieren?
+setup-clear = Die Zwischenablage nach wie vielen Sekunden leeren? 0 leert sie nie. [0]
+setup-clear-range = Bitte eine Anzahl Sekunden bis 86400 eingeben.
+setup-saved = Standardwerte in { $path } gespeichert.
+
+## shamir
+shamir-different-secrets = Die Anteile gehören zu verschiedenen Geheimnissen.
+shamir-different-lengths = Die Anteile sind unterschiedlich lang.
+shamir-too-few = { $threshold } verschiedene Anteile werden gebraucht, nur { $given } wurden angegeben.
+shamir-invalid-secret = Die Anteile ergeben kein gültiges Geheimnis.
+shamir-invalid-share = „{ $share }“ ist kein gültiger Anteil.
+shamir-typo = „{ $share }“ hat einen Tippfehler, die Prüfsumme stimmt nicht.
+
+## sites
+sites-invalid = Ungültige Website-Regeln „{ $path }“: { $error }
+sites-invalid-rules = Ungültige Passwortregeln für { $site }: { $error }
+sites-unknown = Keine Passwortregeln für { $site } bekannt, `--site` in der Readme zeigt, wie sie hinzugefügt werden.
+sites-expected-property = „Name: Wert“ erwartet in „{ $property }“
+sites-not-number = „{ $key }“ muss eine Zahl sein
+sites-max-below-min = „maxlength“ ist kürzer als „minlength“
+sites-unknown-class = unbekannte Zeichenklasse „{ $class }“
+
+## skey
+skey-invalid-seed = Der Seed muss aus 1 bis 16 Buchstaben und Ziffern bestehen.
+skey-passphrase-range = Die Passphrase muss zwischen 10 und 63 Zeichen lang sein.
+skey-server-setup = Servereinrichtung: { $algorithm } { $count } { $seed } { $key }
+
+## sshkey
+sshkey-exists = „{ $path }“ existiert bereits, mit --force wird es überschrieben.
+sshkey-derive-failed = Der Schlüssel der Passphrase kann nicht abgeleitet werden: { $error }
+sshkey-no-home = Das Home-Verzeichnis wurde nicht gefunden, mit --out wählen, wo der Schlüssel gespeichert wird.
+sshkey-passphrase = Passphrase: { $passphrase }
+
+## stats
+stats-online = Online-Angriff
+stats-offline-slow = Offline, langsamer Hash
+stats-offline-fast = Offline, schneller Hash
+stats-long-term = Langfristige Geheimnisse
+stats-no-command = Einen Befehl für den Bericht nennen, etwa „password“.
+stats-word-dependent = Die Entropie, die --leet und --capitalize hinzufügen und --max-length wegnimmt, hängt von den gewählten Wörtern ab und ist nicht enthalten.
+stats-separator-set = Die Entropie, die --separator-set hinzufügt, ist nicht enthalten, --show-strength der Passphrase zählt sie mit.
+stats-unsupported = Statistiken gibt es nur für password, passphrase und token.
+stats-pool-character = Vorrat von { $pool } Zeichen, { $bits } Bit pro Zeichen.
+stats-pool-word = Vorrat von { $pool } Wörtern, { $bits } Bit pro Wort.
+stats-length-character = Länge von { $amount } Zeichen.
+stats-length-word = Länge von { $amount } Wörtern.
+stats-keyspace = Schlüsselraum von { $keyspace } (2^{ $bits }).
+stats-heading-character = Zeichen
+stats-heading-word = Wörter
+stats-target = Ziel
+stats-bits = Bit
+stats-met = erreicht
+
+## stdout_only
+stdout-only-conflict = --stdout-only lässt sich nicht verwenden mit { $conflict }.
+stdout-only-unique = --unique, das den Benutzernamen in eine Verlaufsdatei schreibt
+stdout-only-history = --history, das den Codenamen in eine Datei schreibt
+stdout-only-sshkey = sshkey, das den Schlüssel in Dateien schreibt
+stdout-only-keyring = Befehlen, die den Schlüsselbund verwenden
+stdout-only-daemon = daemon, das Geheimnisse über einen Socket ausliefert
+stdout-only-stored = Optionen, die das Geheimnis anderswo speichern
+stdout-only-program = --systemd-cred und --gpg-recipient, die das Geheimnis an ein anderes Programm geben
+stdout-only-picker = --picker, das das Geheimnis in einem anderen Programm anzeigt
+
+## strength
+boost-leet = +{ $bits } Bit durch Leet-Ersetzungen.
+boost-capitalization = +{ $bits } Bit durch zufällige Großschreibung.
+boost-separators = +{ $bits } Bit durch zufällige Trennzeichen.
+
+## testcard
+testcard-brand = Marke: { $brand } (TESTKARTE, nicht für Zahlungen verwendbar)
+testcard-number = Nummer: { $number }
+testcard-expiry = Ablauf: { $expiry }
+testcard-cvc = CVC: { $cvc }
+
+## token
+token-invalid = Das Token ist ungültig! Vermutlich wurde es falsch eingegeben.
+token-valid = Das Token ist gültig.
+
+## username
+username-no-config-directory = Kein Konfigurationsverzeichnis, um die vergebenen Benutzernamen aufzubewahren, mit --history eine Datei wählen.
+username-alliteration-style = Alliteration funktioniert nur mit dem Stil words.
+username-platform-length = Benutzernamen für { $platform } brauchen mindestens { $min } Zeichen.
+username-empty-search = Der zu suchende Text darf nicht leer sein.
+username-issued = schon vergeben
+username-blocked = gesperrt, weil er „{ $word }“ enthält
+username-embed-failed = „{ $text }“ lässt sich nicht in einen gültigen Benutzernamen einbauen, das Ergebnis war immer wieder { $problem }.
+username-from-style = --from funktioniert nur mit dem Stil words.
+username-from-empty = Der Text, aus dem der Benutzername abgeleitet wird, darf nicht leer sein.
+username-from-numbers = --from unterstützt bis zu 19 Ziffern.
+username-derive-failed = Es lässt sich kein Benutzername ableiten, der zu den Optionen passt!
+username-keyspace = Mögliche Benutzernamen: { $keyspace } ({ $bits } Bit).
+username-duplicate-chance = Wahrscheinlichkeit eines Duplikats unter { $users } Benutzern: { $chance }, unter { $threshold } % bis zu { $unique } Benutzern.
+username-chance-tiny = weniger als 0,01 %
+username-none-fit = Kein Benutzername passt zu den Optionen!
+username-too-many-numbers = Zu viele Ziffern! Es dürfen nicht mehr als 65536 sein.
+username-no-number = Es lässt sich keine Zahl wählen, die nicht wie ein Jahr aussieht!
+username-no-adjectives = Keine Adjektive passen zu den Wortlängen und gesperrten Wörtern! Die Grenzen erweitern.
+username-no-objects = Keine Objekte passen zu den Wortlängen und gesperrten Wörtern! Die Grenzen erweitern.
+username-invalid-for-platform = Es lässt sich kein gültiger Benutzername für { $platform } erzeugen, das Ergebnis war immer wieder { $problem }.
+username-invalid = Es lässt sich kein gültiger Benutzername erzeugen, das Ergebnis war immer wieder { $problem }.
+username-character-not-allowed = '{ $character }' ist in Benutzernamen für { $platform } nicht erlaubt.
+
+## vaults
+vaults-deleted = „{ $name }“ gelöscht.
+vaults-empty = Noch keine Geheimnisse gespeichert, mit --keyring <NAME> eins speichern.
+
+## wordlist
+wordlist-invalid-name = Ungültiger Wortlistenname „{ $name }“! Nur Buchstaben, Ziffern, '-' und '_' sind erlaubt.
+wordlist-built-in-replace = „{ $name }“ ist eine eingebaute Wortliste und kann nicht ersetzt werden.
+wordlist-too-small = Die Wortliste braucht mindestens 2 verschiedene gültige Wörter.
+wordlist-exists = Eine Wortliste namens „{ $name }“ gibt es schon. Mit --force wird sie ersetzt.
+wordlist-imported = { $count } Wörter als „{ $name }“ importiert.
+wordlist-duplicates = { $count } doppelte Wörter übersprungen.
+wordlist-rejected = { $count } Wörter mit Leer- oder Steuerzeichen abgelehnt (Zeilen { $lines }).
+wordlist-entropy = Entropie pro Wort: { $bits } Bit.
+wordlist-listed-built-in = { $name } (eingebaut, { $count } Wörter)
+wordlist-listed = { $name } ({ $count } Wörter)
+wordlist-info-name = Name: { $name }
+wordlist-info-words = Wörter: { $count }
+wordlist-info-length = Wortlänge: { $min } bis { $max } Zeichen
+wordlist-info-entropy = Entropie pro Wort: { $bits } Bit
+wordlist-built-in-remove = „{ $name }“ ist eine eingebaute Wortliste und kann nicht entfernt werden.
+wordlist-not-imported = Keine importierte Wortliste namens „{ $name }“.
+wordlist-removed = Wortliste „{ $name }“ entfernt.
+wordlist-checked = { $count } Wörter in „{ $name }“ geprüft.
+wordlist-no-problems = Keine Probleme gefunden.
+wordlist-unnormalized = { $count } Wörter sind großgeschrieben, doppelt oder enthalten Leerzeichen: { $examples }
+wordlist-prefixes = { $count } Wörter sind der Anfang eines anderen Worts, was zusammengesetzte Wörter mehrdeutig machen kann: { $examples }
+wordlist-confusable = { $count } Gruppen von Wörtern sehen aufgeschrieben gleich aus: { $examples }
+wordlist-more = und { $count } weitere
+wordlist-unknown = Keine Wortliste namens „{ $name }“.
+wordlist-unknown-hint = Keine Wortliste namens „{ $name }“. „pwgen wordlist list“ zeigt alle Wortlisten.
+wordlist-too-long = Passt nicht in { $max } Zeichen! Das kürzestmögliche Ergebnis hat { $shortest } Zeichen.
+wordlist-alliteration-too-long = Kein alliterierendes Ergebnis passt in { $max } Zeichen!
+wordlist-no-alliteration = Keine Wörter der Wortlisten beginnen mit demselben Buchstaben!
+wordlist-info-built-in = Quelle: eingebaut
+wordlist-info-imported = Quelle: importiert
+
+## xkpasswd
+xkpasswd-negative = „{ $key }“ darf nicht negativ sein.
+xkpasswd-unknown-case = Unbekannte case_transform „{ $value }“.
+xkpasswd-unknown-padding = Unbekannter padding_type „{ $value }“.
+xkpasswd-unknown-setting = Unbekannte xkpasswd-Einstellung „{ $key }“.
+xkpasswd-words-range = num_words muss zwischen 1 und 100 liegen.
+xkpasswd-word-length = word_length_min muss mindestens 1 und höchstens word_length_max sein.
+xkpasswd-separator-alphabet = Ein zufälliges separator_character braucht ein separator_alphabet.
+xkpasswd-symbol-alphabet = Ein zufälliges padding_character braucht ein symbol_alphabet.
+xkpasswd-no-separator = padding_character kann nicht das Trennzeichen sein, wenn es keins gibt.
+xkpasswd-pad-to-length = Adaptives Auffüllen braucht ein pad_to_length.
+xkpasswd-character = „{ $key }“ muss ein Zeichen, „RANDOM“ oder „NONE“ sein.
+xkpasswd-single-characters = „{ $key }“ darf nur einzelne Zeichen enthalten.
+xkpasswd-character-list = „{ $key }“ muss eine Liste von Zeichen sein.
+xkpasswd-not-object = „{ $path }“ muss ein JSON-Objekt mit Einstellungen enthalten.
+xkpasswd-unsupported-value = Die Einstellung „{ $key }“ hat einen nicht unterstützten Wert.
+xkpasswd-unknown-scheme = Kein xkpasswd-Schema namens „{ $scheme }“ in der Konfigurationsdatei.
+xkpasswd-invalid = Ungültige Einstellungen „{ $path }“: { $error }
+xkpasswd-no-words = Die Wortliste hat keine Wörter mit { $min } bis { $max } Buchstaben.
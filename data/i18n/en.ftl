@@ -0,0 +1,669 @@
+# Messages shown by pwgen. Variables are written as { $name }.
+copied = { $name } copied to clipboard.
+copy-failed = Unable to copy to clipboard.
+copy-unsupported = Unable to copy to clipboard because it is not supported by OS.
+clearing = Clearing it in { $seconds } seconds.
+written = { $name } written to { $destination }.
+no-password-picked = No password picked.
+no-passphrase-picked = No passphrase picked.
+suggestions-range = Amount of suggestions must be between 1 and 1000.
+otp-valid = Valid for { $seconds } more seconds.
+saved-private-key = Saved private key to "{ $path }".
+saved-public-key = Saved public key to "{ $path }".
+unknown-language = Unknown language "{ $language }", available are { $languages }.
+entropy = Entropy: { $bits } bits ({ $rating }).
+rating-weak = weak
+rating-fair = fair
+rating-strong = strong
+rating-very-strong = very strong
+porcelain-unsupported = This command doesn't support --porcelain.
+again-itself = The last command is `again` itself, generate something to replace it.
+again-nothing = `again` can only repeat a command that generated something.
+again-hidden = Add --copy to copy the last secret or -i to show it.
+
+noun-api-key = API key
+noun-card-number = Card number
+noun-codename = Codename
+noun-database-password = Database password
+noun-email = Email
+noun-excluded-characters = Excluded characters
+noun-hash = Hash
+noun-ip-address = IP address
+noun-license-key = License key
+noun-mac-address = MAC address
+noun-name = Name
+noun-nano-id = Nano ID
+noun-otp-code = OTP code
+noun-otp-secret = OTP secret
+noun-pass-phrase = Pass phrase
+noun-passphrase = Passphrase
+noun-password = Password
+noun-petname = Petname
+noun-public-key = Public key
+noun-salt = Salt
+noun-secret = Secret
+noun-share = Share
+noun-slug = Slug
+noun-token = Token
+noun-username = Username
+
+## accounts
+count-range = Count must be between 1 and { $max }.
+accounts-too-few = Only found { $count } different usernames, try a lower count.
+
+## alias
+alias-unterminated-quote = Config value "{ $key }" has an unterminated quote.
+alias-empty = Config value "{ $key }" is empty.
+
+## apikey
+apikey-unknown-profile = No API key profile named "{ $profile }" in the config file.
+apikey-negative-bytes = Config value "{ $table }.bytes" cannot be negative.
+apikey-checksum-choice = Config value "{ $table }.checksum" must be "none" or "crc32".
+apikey-bytes-range = Amount of bytes must be between 8 and 1024.
+apikey-prefix-whitespace = Prefix cannot contain whitespace.
+
+## bench
+bench-single = { $name } { $rate }/s on 1 thread
+bench-multi = { $name } { $rate }/s on 1 thread, { $multi-rate }/s on { $threads } threads
+bench-rng = { $name } { $rate } MB/s on 1 thread
+
+## breach
+breach-info = { $count } breached passwords in { $size } KB.
+unable-to-read = Unable to read "{ $path }": { $error }
+breach-no-passwords = "{ $path }" doesn't contain any passwords.
+unable-to-create = Unable to create "{ $path }": { $error }
+unable-to-write = Unable to write "{ $path }": { $error }
+breach-imported = Imported { $count } breached passwords.
+breach-found = This password is in the breach filter, don't use it.
+breach-not-found = This password isn't in the breach filter.
+breach-dead-end = Every one of { $attempts } attempts was in the breach filter, allow more characters or a longer length.
+no-config-directory = Unable to locate the config directory.
+breach-not-imported = No breach filter imported yet, see `pwgen breach import --help`.
+breach-invalid = "{ $path }" isn't a valid breach filter, import it again.
+
+## codename
+unable-to-open = Unable to open "{ $path }": { $error }
+codename-exhausted = Unable to find a codename that hasn't been issued yet!
+
+## config
+config-missing-bracket = line { $line }: missing ']'
+config-text-after-table = line { $line }: unexpected text after table name
+config-invalid-table = line { $line }: invalid table name
+config-expected-key-value = line { $line }: expected "key = value"
+config-invalid-key = line { $line }: invalid key
+config-invalid-value = line { $line }: invalid value
+config-text-after-value = line { $line }: unexpected text after value
+config-set-twice = line { $line }: "{ $key }" is set twice
+invalid-config-file = Invalid config file "{ $path }": { $error }
+config-not-string = Config value "{ $key }" must be a string.
+config-not-boolean = Config value "{ $key }" must be true or false.
+config-not-integer = Config value "{ $key }" must be an integer.
+config-set-not-integer = "{ $key }" must be a whole number.
+config-set-not-boolean = "{ $key }" must be true or false.
+config-set = Set { $key } = { $value }
+config-not-set = "{ $key }" isn't set.
+config-nothing-set = Nothing is set yet, see `pwgen config list --all` for what can be.
+config-editor-unstartable = Unable to start the editor "{ $editor }": { $error }
+config-editor-failed = The editor "{ $editor }" failed.
+config-warning = Warning: { $message }
+config-imported = Imported { $count } values.
+
+## coupons
+length-range = Length must be between 1 and { $max }.
+coupons-too-few = Only { $possible } different codes of length { $length } exist, use a longer length for { $count } codes.
+
+## daemon
+daemon-no-runtime-dir = XDG_RUNTIME_DIR isn't set, choose a socket with --socket.
+daemon-already-listening = Another daemon is already listening on "{ $path }".
+unable-to-remove = Unable to remove "{ $path }": { $error }
+daemon-unable-to-listen = Unable to listen on "{ $path }": { $error }
+daemon-unable-to-restrict = Unable to restrict "{ $path }": { $error }
+daemon-listening = Listening on { $path }
+daemon-unsupported = The daemon needs Unix sockets, which this platform doesn't have.
+
+## defaults
+defaults-unknown-key-suggestion = Unknown config key "{ $key }", did you mean "{ $closest }"?
+defaults-unknown-key = Unknown config key "{ $key }", see `pwgen config list --all` for all keys.
+defaults-unknown-command = "{ $command }" is neither a command nor an alias.
+defaults-length-range = Config value "password.length" must be between 1 and { $max }.
+defaults-out-of-range = Config value "{ $key }" is out of range.
+defaults-not-character = Config value "{ $key }" must be a single character.
+defaults-not-strings = Config value "{ $key }" must only contain strings.
+defaults-not-array = Config value "{ $key }" must be an array of names.
+defaults-unknown-choice = Config value "{ $key }" has unknown choice "{ $value }", expected one of { $choices }.
+
+## derive
+derive-empty-site = The site can't be empty.
+derive-touch-yubikey = Touch your YubiKey if it blinks.
+derive-yubikey-response = The YubiKey gave an unexpected response, is the slot set up for HMAC-SHA1 challenge-response?
+derive-no-fido2 = No FIDO2 security key found, is it plugged in?
+derive-touch-security-key = Touch your security key when it blinks.
+derive-no-hmac-secret = The security key didn't return an hmac-secret, does it support the extension?
+derive-making-credential = Making a credential for pwgen on the security key, touch it when it blinks.
+derive-no-credential = The security key didn't return a credential.
+
+## doctor
+doctor-ok = ok
+doctor-warn = warn
+doctor-fail = fail
+doctor-check = { $check }: { $detail }
+doctor-failed = { $count ->
+    [one] 1 check failed.
+   *[other] { $count } checks failed.
+    }
+doctor-random-source = Random source
+doctor-display = Display
+doctor-clipboard = Clipboard
+doctor-keyring = Keyring
+doctor-config = Config
+doctor-terminal = Terminal
+doctor-random-ok = passed its health test
+doctor-display-xwayland = Wayland, with X11 through XWayland
+doctor-display-wayland = Wayland without XWayland, the clipboard needs DISPLAY to be set
+doctor-display-x11 = X11
+doctor-display-none = neither WAYLAND_DISPLAY nor DISPLAY is set, there is no clipboard to copy to
+doctor-clipboard-ok = secrets can be copied
+doctor-clipboard-unreadable = connected, but reading it failed: { $error }
+doctor-clipboard-unavailable = unavailable, use --no-copy: { $error }
+doctor-osc52-tmux = tmux passes it on with `set -g set-clipboard on`
+doctor-osc52-supported = { $terminal } supports it
+doctor-osc52-no-terminal = no terminal detected
+doctor-osc52-unknown = unknown whether "{ $terminal }" supports it
+doctor-osc52-remote = { $detail }, over SSH the remote clipboard isn't yours
+doctor-keyring-ok = available, { $count } secrets saved by pwgen
+doctor-keyring-unavailable = unavailable, --keyring and git-credential won't work: { $error }
+doctor-config-no-directory = no config directory could be found
+doctor-config-none = none yet at "{ $path }"
+doctor-config-valid = "{ $path }" is valid
+doctor-terminal-ok = secrets are prompted for without echoing them
+doctor-terminal-stdin = stdin isn't a terminal, secrets are read from it as lines
+doctor-terminal-stdout = stdout isn't a terminal, shown secrets end up wherever it goes
+doctor-tool-installed = installed, { $option } works
+doctor-tool-missing = not installed, needed for { $option }
+
+## email
+email-invalid-domain = "{ $domain }" is not a valid domain!
+email-invalid-tag = "{ $tag }" is not a valid tag! Only letters, digits, '.', '-' and '_' are allowed.
+email-invalid-mailbox = "{ $mailbox }" is not a valid mailbox name!
+
+## external
+external-not-installed = Unable to run { $program }, is it installed? { $error }
+external-unable-to-run = Unable to run { $program }: { $error }
+external-failed = { $program } failed: { $error }
+
+## git_credential
+git-credential-generated = Generated a new password for { $name }.
+git-credential-unreadable = Unable to read from git: { $error }
+
+## hardening
+hardening-core-dumps = Unable to turn off core dumps: { $error }
+hardening-dumpable = Unable to make the process non-dumpable: { $error }
+hardening-lock = Unable to lock the memory, is `ulimit -l` too low? { $error }
+hardening-working-set = Unable to raise the working set for locking memory: { $error }
+hardening-lock-unsupported = Locking memory isn't supported on this platform.
+
+## hash
+hash-cost-bcrypt-only = --cost only applies to bcrypt.
+hash-iterations-range = Iterations must be between 1 and 1000.
+hash-parallelism-range = Parallelism must be between 1 and 255.
+hash-memory-range = Memory must be between { $min } KiB and 4 GiB.
+hash-argon2-salt = Salt must be at least 8 bytes of base64.
+hash-argon2-only = --memory, --iterations and --parallelism only apply to argon2id.
+hash-cost-range = Cost must be between 4 and 31.
+hash-bcrypt-salt = Salt must be 22 characters of bcrypt's base64.
+hash-invalid-parameters = Invalid argon2id parameters: { $error }
+hash-failed = Unable to hash the password: { $error }
+hash-bcrypt-too-long = bcrypt ignores everything after { $bytes } bytes, use argon2id for longer passwords.
+
+## health
+health-unavailable = The system's random source is unavailable: { $error }
+health-failed = The system's random source failed its health test, { $reason }. Refusing to generate secrets with it.
+health-repetition = a byte repeats too often in a row
+health-proportion = a byte is far more common than the others
+
+## history
+history-given-secret = The last command was given a secret, so it isn't kept to be repeated.
+history-disabled = The history isn't enabled, turn it on with `pwgen config set history.enabled true`.
+history-nothing-since-enabled = Nothing was generated since the history was enabled.
+history-no-config-directory = No config directory to keep the last command in.
+history-nothing-yet = Nothing was generated yet.
+history-damaged = "{ $path }" is damaged, generate something to replace it.
+
+## honeywords
+honeywords-empty-reference = The reference cannot be empty.
+honeywords-nothing-to-vary = The reference needs at least one letter, digit or symbol to vary.
+honeywords-too-few = Only { $count } different decoys share this shape, try a longer reference or a lower count.
+
+## id
+id-too-many-ulids = Too many ULIDs within one millisecond!
+id-too-many-ksuids = Too many KSUIDs within one second!
+
+## identity
+identity-age-order = Minimum age cannot be larger than the maximum age.
+identity-age-max = Maximum age cannot be larger than 150.
+
+## invented
+invented-too-long = Cannot fit an invented name within { $length } characters!
+
+## ip
+ip-exhausted = Unable to find an address that isn't reserved in this subnet!
+ip-invalid-cidr = "{ $cidr }" is not a valid CIDR block! Example: "10.0.0.0/8".
+ip-invalid-prefix = "{ $prefix }" is not a valid prefix length!
+ip-v4-prefix = IPv4 prefix length cannot be larger than 32.
+ip-v6-prefix = IPv6 prefix length cannot be larger than 128.
+ip-invalid-address = "{ $address }" is not a valid IP address!
+
+## json
+json-invalid = Invalid JSON at byte { $position }: { $reason }
+json-text-after-value = unexpected text after the value
+json-expected = expected '{ $character }'
+json-unknown-value = unknown value
+json-unexpected-end = unexpected end
+json-nested-too-deeply = nested too deeply
+json-expected-comma-bracket = expected ',' or ']'
+json-expected-field-name = expected a field name
+json-expected-comma-brace = expected ',' or '{"}"}'
+json-unterminated-string = unterminated string
+json-invalid-escape = invalid escape
+json-control-character = control character in string
+json-invalid-utf8 = invalid UTF-8
+json-unpaired-surrogate = unpaired surrogate
+json-invalid-number = invalid number
+
+## keyring
+keyring-invalid-name = Invalid secret name "{ $name }"!
+keyring-not-found = No secret named "{ $name }" in the keyring.
+keyring-not-utf8 = The secret "{ $name }" isn't valid UTF-8.
+keyring-not-in-keychain = No secret named "{ $name }" in the keychain.
+keyring-unable-to-save = Unable to save "{ $name }": { $error }
+keyring-not-in-credential-manager = No secret named "{ $name }" in the Credential Manager.
+keyring-unsupported = There is no supported secret store on this platform.
+
+## license
+license-no-x = Pattern needs at least one 'X'.
+license-too-few-x = Pattern needs more than { $checksum } 'X' to fit a checksum.
+license-invalid = License key is invalid! { $problem }
+license-valid = License key is valid.
+license-pattern-mismatch = It doesn't match the pattern.
+license-too-short = The pattern is too short to contain a checksum.
+license-checksum-mismatch = The checksum doesn't match.
+license-invalid-character = '{ $character }' is not a valid character.
+
+## nanoid
+nanoid-size-range = Size must be between 1 and 65536.
+nanoid-alphabet-range = Alphabet must contain between 2 and 256 characters.
+nanoid-duplicate = Alphabet contains '{ $character }' more than once, which would make it more likely.
+
+## native_host
+native-host-too-long = Message of { $length } bytes is too long, closing.
+native-host-unreadable = Unable to read a message from the browser: { $error }
+native-host-unanswerable = Unable to answer the browser: { $error }
+
+## otp
+otp-digits-range = Digits must be between 6 and 10.
+otp-period-range = Period must be at least 1 second.
+otp-invalid-secret = Secret is not valid base32.
+otp-clock-before-1970 = System clock is set before 1970.
+otp-bytes-range = Bytes must be between 10 and 64.
+otp-colon = Issuer and account cannot contain ':'.
+otp-empty = Issuer and account cannot be empty.
+
+## output
+output-file = "{ $path }"
+output-vault = Vault at { $target }
+output-bitwarden = Bitwarden as "{ $name }"
+output-1password = 1Password as "{ $title }"
+output-kdbx = "{ $database }" as "{ $entry }"
+output-keyring = the keyring as "{ $name }"
+output-docker = the { $engine } secret "{ $name }"
+output-vault-password-file-only = --vault-password-file only applies to --output ansible-vault.
+output-dotenv-invalid-name = "{ $key }" isn't a valid variable name for dotenv.
+output-dotenv-invalid-secret = The secret has a ' or a control character, which a dotenv value in single quotes can't hold. Leave them out with --exclude.
+output-needs-vault-password-file = --output ansible-vault needs a --vault-password-file.
+output-no-vault-password = "{ $path }" doesn't contain a vault password.
+output-not-appendable = Only plain, dotenv, CSV, YAML and TOML output can be appended to.
+output-exists = "{ $path }" already exists, add --append to add the secret to it.
+output-unable-to-append = Unable to append to "{ $path }": { $problem }
+output-toml-table = the secret would end up in the last table of the TOML file.
+output-key-exists = it already has "{ $key }", pick another --key.
+output-vault-target = --vault-write expects PATH#FIELD, like secret/data/myapp#password.
+output-no-vault-addr = VAULT_ADDR must be set to the address of the Vault server.
+output-bw-locked = Unlock the vault with `bw unlock` and export BW_SESSION first.
+output-kdbx-missing = "{ $path }" doesn't exist.
+
+## passphrase
+passphrase-no-words = Passphrase needs at least one word.
+passphrase-too-many-words = Too many words! Cannot be more than 1024.
+
+## password
+password-hybrid-conflict = --style hybrid can't be combined with --mobile-friendly, --alternate-hands or --no-patterns.
+wordlist-empty = Wordlist is empty!
+password-policy-kept-failing = the rules of { $policy } kept failing, last on "{ $problem }". Try a longer length or more attempts
+password-patterns-kept-failing = --no-patterns kept finding { $pattern }. Try a shorter length or more character sets
+password-tried = in { $attempts } attempts
+password-dead-end = No password followed the rules { $tried }, { $reason }.
+explain-sets = Character sets: { $sets }.
+explain-excluded = Excluded { $count } characters: { $characters }
+explain-pool = Pool of { $count } characters, { $bits } bits per character.
+explain-weight = { $set } { $factor } times
+explain-weights = Picking { $weights } as often, { $bits } bits per character.
+explain-hybrid-word = a word out of { $count }
+random-characters = { $count ->
+        [one] 1 random character
+       *[other] { $count } random characters
+    }
+explain-hybrid = Hybrid of { $parts }.
+explain-length = Length of { $length } characters.
+explain-mobile = Letters, digits and one symbol each in a block, in a random order.
+explain-hands = Alternating between { $left } characters of the left hand and { $right } of the right.
+trade-off-hybrid = The hybrid password has { $bits } bits of entropy, the word only counts as the one pick it is.
+trade-off = That's { $lost } bits less than the { $bits } bits of picking every character from the whole pool.
+password-hands = --alternate-hands needs characters for both hands.
+password-no-characters = No characters are allowed! Try to add more character sets or exclude less characters.
+password-too-long = Password too long! Cannot be longer than { $max }.
+pattern-dictionary-word = a dictionary word
+pattern-keyboard-walk = a keyboard walk
+pattern-date = a date
+password-tried-within = within { $seconds ->
+        [one] 1 second
+       *[other] { $seconds } seconds
+    } ({ $attempts } attempts)
+explain-hybrid-digit = a digit out of { $count }
+explain-hybrid-symbol = a symbol out of { $count }
+
+## petname
+petname-words-range = Amount of words must be between 1 and 16.
+petname-invalid-separator = Separator must only contain lowercase letters, digits and '-' for DNS names.
+invalid-dns-label = "{ $label }" is not a valid DNS label!
+
+## picker
+picker-candidates-range = Amount of candidates must be between 1 and 100.
+picker-unknown-choice = The picker returned something that isn't one of the candidates.
+
+## platform
+platform-too-short = shorter than { $min } characters
+platform-too-long = longer than { $max } characters
+platform-invalid-character = contains '{ $character }'
+platform-no-letter-start = does not start with a letter
+platform-symbol-edge = starts or ends with a symbol
+platform-double-symbol = contains two symbols in a row
+
+## policy
+policy-invalid = Invalid policy "{ $path }": { $error }
+policy-unknown-rule = unknown rule "{ $rule }", expected one of { $keys } or a set in [{ $tables }].
+policy-length-range = "{ $key }" must be between 1 and 65536.
+policy-max-below-min = "max_length" is shorter than "min_length".
+policy-length-outside = "length" is outside of "min_length" and "max_length".
+policy-max-consecutive = "max_consecutive" must be at least 1.
+policy-unknown-set = "{ $key }" isn't a character set.
+policy-count-range = "{ $key }" must be between 0 and 65536.
+policy-no-characters = no characters are allowed.
+policy-required-unavailable = "{ $set }" is required but none of its characters are allowed.
+policy-max-counts = "{ $set }" needs more characters than "max_counts" allows.
+policy-ends-unavailable = none of the "{ $key }" characters are allowed.
+policy-max-length-required = "max_length" is too short for every required character set.
+policy-needs-length-between = { $policy } needs a length between { $min } and { $max }.
+policy-needs-length = { $policy } needs a length of at least { $min }.
+policy-requires-set = { $policy } requires { $set }, which the chosen character sets don't have.
+policy-requires-sets = { $policy } requires { $count } character sets, which don't fit in { $length } characters.
+policy-impossible = { $policy } has rules no password of { $length } characters can follow.
+policy-max-consecutive-impossible = { $policy } allows at most { $max } of the same character in a row, but only one character is left.
+policy-entropy-impossible = { $policy } requires { $bits } bits of entropy, { $length } characters can have about { $best }.
+check-min-length = Length of { $length }, at least { $min }.
+check-max-length = Length of { $length }, at most { $max }.
+check-allowed = Only characters of the allowed sets.
+check-outside = Characters outside the allowed sets: { $characters }
+check-not-forbidden = No forbidden characters.
+check-forbidden = Forbidden characters: { $characters }
+check-contains = Contains { $set }.
+check-contains-no = Contains no { $set }.
+check-min-count = At least { $min } { $set }, has { $count }.
+check-max-count = At most { $max } { $set }, has { $count }.
+check-consecutive = At most { $max } of the same character in a row, has { $longest }.
+check-entropy = Entropy of about { $bits } bits, at least { $min }.
+conform-removed = Removed { $count } characters that aren't allowed.
+conform-appended-set = Appended a random { $set }.
+conform-padded = Appended { $characters } to reach the minimum length of { $min }.
+conform-trimmed = Removed { $count } characters from the end to fit the maximum length of { $max }.
+conform-entropy = Appended { $characters } to reach { $bits } bits of entropy.
+conform-runs = Replaced { $characters } to break up runs of the same character.
+conform-max-count = Replaced { $characters } to have at most { $max } { $set }.
+conform-failed = Unable to make the password follow the policy, it still breaks a rule: { $rule }
+validate-broken = The password breaks { $failed } of { $total } rules.
+validate-followed = The password follows the policy.
+conform-nothing = The password already follows the policy.
+conform-entropy-change = Entropy of about { $bits } bits, was { $before }.
+compare-same = Both policies generate passwords of the same strength.
+compare-stronger = { $stronger } generates passwords { $bits } bits stronger than { $weaker }, { $guesses } times the guesses.
+compare-nothing = nothing
+compare-length-range = { $length } ({ $min } to { $max })
+compare-length-open = { $length } ({ $min } or more)
+compare-met = { $bits } bits, met
+compare-missed = { $bits } bits, missed
+compare-none = none
+conform-made-uppercase = Made character { $position } uppercase.
+conform-made-lowercase = Made character { $position } lowercase.
+conform-replaced-first = Replaced the first character with a random { $set }.
+conform-replaced-last = Replaced the last character with a random { $set }.
+conform-added-first = Added a random { $set } as the first character.
+conform-added-last = Added a random { $set } as the last character.
+check-starts = Starts with { $sets }.
+check-ends = Ends with { $sets }.
+or = or
+set-lower = lowercase letter
+set-upper = uppercase letter
+set-digit = digit
+set-symbol = symbol
+set-rare-symbol = rare symbol
+set-latin-1 = Latin-1 character
+set-greek = Greek letter
+set-emoji = emoji
+check-pass = pass
+check-fail = fail
+compare-policy = Policy
+compare-length = Length
+compare-characters = Characters
+compare-required = Required
+compare-keyspace = Keyspace
+compare-entropy = Entropy
+compare-lost = Lost to rules
+compare-target = Entropy target
+compare-shortest = Shortest length
+bits = { $bits } bits
+
+## prompt
+prompt-number-range = Please enter a number between 1 and { $max }.
+prompt-yes-no = Please answer yes or no.
+prompt-pick = Pick one to copy (1-{ $count }, empty to cancel):
+prompt-unreadable-stdin = Unable to read stdin: { $error }
+prompt-unreadable = Unable to read the input: { $error }
+prompt-repeat = Repeat to confirm:
+prompt-mismatch = The two entries don't match.
+
+## request
+request-not-object = The request must be a JSON object.
+request-char-sets = "char_sets" must be an array of names.
+request-unknown-set = Unknown character set { $set }.
+request-unknown-password-option = Unknown password option "{ $key }".
+request-site-and-policy = "site" and "policy" can't be combined.
+request-separator = "separator" must be a single character.
+request-unknown-passphrase-option = Unknown passphrase option "{ $key }".
+request-unknown-command = Unknown command "{ $command }", use "password" or "passphrase".
+request-no-command = The request needs a "command".
+request-not-positive = "{ $key }" must be a positive whole number.
+request-not-string = "{ $key }" must be a string.
+
+## salt
+salt-bytes-range = Bytes must be between 1 and 65536.
+
+## sandbox
+sandbox-failed = Unable to sandbox pwgen: { $error }
+sandbox-unveil-path = Unable to unveil "{ $path }": { $error }
+sandbox-unveil = Unable to unveil: { $error }
+sandbox-pledge = Unable to pledge: { $error }
+
+## setup
+setup-welcome = Welcome to pwgen! It looks like this is the first time you're using it.
+setup-ask = Set up your defaults now?
+setup-skipped = Skipped, run `pwgen setup` to do it later.
+setup-length = Password length? [16]
+setup-length-range = Please enter a length between 4 and 65536.
+setup-symbols = Are symbols OK in passwords?
+setup-copy = Copy generated secrets to the clipboard?
+setup-clear = Clear the clipboard after how many seconds? 0 never clears it. [0]
+setup-clear-range = Please enter a number of seconds up to 86400.
+setup-saved = Saved your defaults to { $path }.
+
+## shamir
+shamir-different-secrets = The shares belong to different secrets.
+shamir-different-lengths = The shares have different lengths.
+shamir-too-few = { $threshold } different shares are needed, only { $given } were given.
+shamir-invalid-secret = The shares don't combine into a valid secret.
+shamir-invalid-share = "{ $share }" is not a valid share.
+shamir-typo = "{ $share }" has a typo, its checksum doesn't match.
+
+## sites
+sites-invalid = Invalid site rules "{ $path }": { $error }
+sites-invalid-rules = Invalid password rules for { $site }: { $error }
+sites-unknown = No password rules known for { $site }, see `--site` in the readme to add them.
+sites-expected-property = expected "name: value" in "{ $property }"
+sites-not-number = "{ $key }" must be a number
+sites-max-below-min = "maxlength" is shorter than "minlength"
+sites-unknown-class = unknown character class "{ $class }"
+
+## skey
+skey-invalid-seed = Seed must be 1 to 16 letters and digits.
+skey-passphrase-range = Pass phrase must be between 10 and 63 characters.
+skey-server-setup = Server setup: { $algorithm } { $count } { $seed } { $key }
+
+## sshkey
+sshkey-exists = "{ $path }" already exists, use --force to overwrite it.
+sshkey-derive-failed = Unable to derive the key of the passphrase: { $error }
+sshkey-no-home = Unable to find the home directory, use --out to choose where to save the key.
+sshkey-passphrase = Passphrase: { $passphrase }
+
+## stats
+stats-online = Online attack
+stats-offline-slow = Offline, slow hash
+stats-offline-fast = Offline, fast hash
+stats-long-term = Long-term secrets
+stats-no-command = Name a command to report on, like "password".
+stats-word-dependent = The entropy that --leet and --capitalize add and --max-length takes away depends on the words picked, it's left out.
+stats-separator-set = The entropy --separator-set adds is left out, --show-strength of the passphrase includes it.
+stats-unsupported = Statistics are only available for password, passphrase and token.
+stats-pool-character = Pool of { $pool } characters, { $bits } bits per character.
+stats-pool-word = Pool of { $pool } words, { $bits } bits per word.
+stats-length-character = Length of { $amount } characters.
+stats-length-word = Length of { $amount } words.
+stats-keyspace = Keyspace of { $keyspace } (2^{ $bits }).
+stats-heading-character = Characters
+stats-heading-word = Words
+stats-target = Target
+stats-bits = Bits
+stats-met = met
+
+## stdout_only
+stdout-only-conflict = --stdout-only can't be used with { $conflict }.
+stdout-only-unique = --unique, which writes the username to a history file
+stdout-only-history = --history, which writes the codename to a file
+stdout-only-sshkey = sshkey, which writes the key to files
+stdout-only-keyring = commands using the keyring
+stdout-only-daemon = daemon, which serves secrets over a socket
+stdout-only-stored = options saving the secret elsewhere
+stdout-only-program = --systemd-cred and --gpg-recipient, which hand the secret to another program
+stdout-only-picker = --picker, which shows the secret in another program
+
+## strength
+boost-leet = +{ $bits } bits from leet substitutions.
+boost-capitalization = +{ $bits } bits from random capitalization.
+boost-separators = +{ $bits } bits from random separators.
+
+## testcard
+testcard-brand = Brand: { $brand } (TEST CARD, not usable for payments)
+testcard-number = Number: { $number }
+testcard-expiry = Expiry: { $expiry }
+testcard-cvc = CVC: { $cvc }
+
+## token
+token-invalid = Token is invalid! It was probably mistyped.
+token-valid = Token is valid.
+
+## username
+username-no-config-directory = No config directory to keep the issued usernames in, pick a file with --history.
+username-alliteration-style = Alliteration only works with the words style.
+username-platform-length = { $platform } usernames need at least { $min } characters.
+username-empty-search = Text to search for cannot be empty.
+username-issued = issued before
+username-blocked = blocked for containing "{ $word }"
+username-embed-failed = Unable to embed "{ $text }" in a valid username, the result kept being { $problem }.
+username-from-style = --from only works with the words style.
+username-from-empty = Text to derive the username from cannot be empty.
+username-from-numbers = --from supports up to 19 numbers.
+username-derive-failed = Unable to derive a username that fits the options!
+username-keyspace = Possible usernames: { $keyspace } ({ $bits } bits).
+username-duplicate-chance = Chance of a duplicate among { $users } users: { $chance }, below { $threshold }% up to { $unique } users.
+username-chance-tiny = less than 0.01%
+username-none-fit = No usernames fit the options!
+username-too-many-numbers = Too many numbers! Cannot be more than 65536.
+username-no-number = Unable to pick a number that doesn't look like a year!
+username-no-adjectives = No adjectives fit the word length bounds and blocked words! Try to widen them.
+username-no-objects = No objects fit the word length bounds and blocked words! Try to widen them.
+username-invalid-for-platform = Unable to generate a valid { $platform } username, the result kept being { $problem }.
+username-invalid = Unable to generate a valid username, the result kept being { $problem }.
+username-character-not-allowed = '{ $character }' is not allowed in { $platform } usernames.
+
+## vaults
+vaults-deleted = Deleted "{ $name }".
+vaults-empty = No secrets saved yet, save one with --keyring <NAME>.
+
+## wordlist
+wordlist-invalid-name = Invalid wordlist name "{ $name }"! Only letters, digits, '-' and '_' are allowed.
+wordlist-built-in-replace = "{ $name }" is a built-in wordlist and cannot be replaced.
+wordlist-too-small = Wordlist needs at least 2 distinct valid words.
+wordlist-exists = A wordlist named "{ $name }" already exists. Use --force to replace it.
+wordlist-imported = Imported { $count } words as "{ $name }".
+wordlist-duplicates = Skipped { $count } duplicate words.
+wordlist-rejected = Rejected { $count } words containing whitespace or control characters (lines { $lines }).
+wordlist-entropy = Entropy per word: { $bits } bits.
+wordlist-listed-built-in = { $name } (built-in, { $count } words)
+wordlist-listed = { $name } ({ $count } words)
+wordlist-info-name = Name: { $name }
+wordlist-info-words = Words: { $count }
+wordlist-info-length = Word length: { $min } to { $max } characters
+wordlist-info-entropy = Entropy per word: { $bits } bits
+wordlist-built-in-remove = "{ $name }" is a built-in wordlist and cannot be removed.
+wordlist-not-imported = No imported wordlist named "{ $name }".
+wordlist-removed = Removed wordlist "{ $name }".
+wordlist-checked = Checked { $count } words in "{ $name }".
+wordlist-no-problems = No problems found.
+wordlist-unnormalized = { $count } words are uppercase, duplicated or contain whitespace: { $examples }
+wordlist-prefixes = { $count } words are a prefix of another word, which can make joined words ambiguous: { $examples }
+wordlist-confusable = { $count } groups of words look alike when written down: { $examples }
+wordlist-more = and { $count } more
+wordlist-unknown = No wordlist named "{ $name }".
+wordlist-unknown-hint = No wordlist named "{ $name }". Use "pwgen wordlist list" to see all wordlists.
+wordlist-too-long = Cannot fit within { $max } characters! The shortest possible result is { $shortest } characters.
+wordlist-alliteration-too-long = Cannot fit an alliterating result within { $max } characters!
+wordlist-no-alliteration = No words in the wordlists start with the same letter!
+wordlist-info-built-in = Source: built-in
+wordlist-info-imported = Source: imported
+
+## xkpasswd
+xkpasswd-negative = "{ $key }" cannot be negative.
+xkpasswd-unknown-case = Unknown case_transform "{ $value }".
+xkpasswd-unknown-padding = Unknown padding_type "{ $value }".
+xkpasswd-unknown-setting = Unknown xkpasswd setting "{ $key }".
+xkpasswd-words-range = num_words must be between 1 and 100.
+xkpasswd-word-length = word_length_min must be at least 1 and at most word_length_max.
+xkpasswd-separator-alphabet = A random separator_character needs a separator_alphabet.
+xkpasswd-symbol-alphabet = A random padding_character needs a symbol_alphabet.
+xkpasswd-no-separator = padding_character can't be the separator when there is none.
+xkpasswd-pad-to-length = Adaptive padding needs a pad_to_length.
+xkpasswd-character = "{ $key }" must be a character, "RANDOM" or "NONE".
+xkpasswd-single-characters = "{ $key }" must only contain single characters.
+xkpasswd-character-list = "{ $key }" must be a list of characters.
+xkpasswd-not-object = "{ $path }" must hold a JSON object of settings.
+xkpasswd-unsupported-value = Setting "{ $key }" has an unsupported value.
+xkpasswd-unknown-scheme = No xkpasswd scheme named "{ $scheme }" in the config file.
+xkpasswd-invalid = Invalid settings "{ $path }": { $error }
+xkpasswd-no-words = The wordlist has no words of { $min } to { $max } letters.
@@ -0,0 +1,668 @@
+copied = { $name } copiado al portapapeles.
+copy-failed = No se pudo copiar al portapapeles.
+copy-unsupported = No se pudo copiar al portapapeles porque el sistema no lo admite.
+clearing = El portapapeles se vaciará en { $seconds } segundos.
+written = { $name } guardado en { $destination }.
+no-password-picked = No se eligió ninguna contraseña.
+no-passphrase-picked = No se eligió ninguna frase de contraseña.
+suggestions-range = La cantidad de sugerencias debe estar entre 1 y 1000.
+otp-valid = Válido durante { $seconds } segundos más.
+saved-private-key = Clave privada guardada en "{ $path }".
+saved-public-key = Clave pública guardada en "{ $path }".
+unknown-language = Idioma desconocido "{ $language }", disponibles: { $languages }.
+entropy = Entropía: { $bits } bits ({ $rating }).
+rating-weak = débil
+rating-fair = aceptable
+rating-strong = fuerte
+rating-very-strong = muy fuerte
+porcelain-unsupported = Este comando no admite --porcelain.
+again-itself = El último comando es el propio `again`, primero hay que generar algo nuevo.
+again-nothing = `again` solo puede repetir un comando que haya generado algo.
+again-hidden = Con --copy se copia el último secreto y con -i se muestra.
+
+noun-api-key = Clave de API
+noun-card-number = Número de tarjeta
+noun-codename = Nombre en clave
+noun-database-password = Contraseña de la base de datos
+noun-email = Correo electrónico
+noun-excluded-characters = Caracteres excluidos
+noun-hash = Hash
+noun-ip-address = Dirección IP
+noun-license-key = Clave de licencia
+noun-mac-address = Dirección MAC
+noun-name = Nombre
+noun-nano-id = Nano ID
+noun-otp-code = Código OTP
+noun-otp-secret = Secreto OTP
+noun-pass-phrase = Frase de contraseña
+noun-passphrase = Frase de contraseña
+noun-password = Contraseña
+noun-petname = Petname
+noun-public-key = Clave pública
+noun-salt = Sal
+noun-secret = Secreto
+noun-share = Parte
+noun-slug = Slug
+noun-token = Token
+noun-username = Nombre de usuario
+
+## accounts
+count-range = La cantidad debe estar entre 1 y { $max }.
+accounts-too-few = Solo se encontraron { $count } nombres de usuario distintos, prueba con una cantidad menor.
+
+## alias
+alias-unterminated-quote = Al valor de configuración "{ $key }" le falta cerrar una comilla.
+alias-empty = El valor de configuración "{ $key }" está vacío.
+
+## apikey
+apikey-unknown-profile = No hay ningún perfil de clave de API llamado "{ $profile }" en el archivo de configuración.
+apikey-negative-bytes = El valor de configuración "{ $table }.bytes" no puede ser negativo.
+apikey-checksum-choice = El valor de configuración "{ $table }.checksum" debe ser "none" o "crc32".
+apikey-bytes-range = La cantidad de bytes debe estar entre 8 y 1024.
+apikey-prefix-whitespace = El prefijo no puede contener espacios.
+
+## bench
+bench-single = { $name } { $rate }/s con 1 hilo
+bench-multi = { $name } { $rate }/s con 1 hilo, { $multi-rate }/s con { $threads } hilos
+bench-rng = { $name } { $rate } MB/s con 1 hilo
+
+## breach
+breach-info = { $count } contraseñas filtradas en { $size } KB.
+unable-to-read = No se pudo leer "{ $path }": { $error }
+breach-no-passwords = "{ $path }" no contiene ninguna contraseña.
+unable-to-create = No se pudo crear "{ $path }": { $error }
+unable-to-write = No se pudo escribir "{ $path }": { $error }
+breach-imported = Se importaron { $count } contraseñas filtradas.
+breach-found = Esta contraseña está en el filtro de filtraciones, no la uses.
+breach-not-found = Esta contraseña no está en el filtro de filtraciones.
+breach-dead-end = Los { $attempts } intentos estaban en el filtro de filtraciones, permite más caracteres o una longitud mayor.
+no-config-directory = No se encontró el directorio de configuración.
+breach-not-imported = Todavía no se ha importado ningún filtro de filtraciones, consulta `pwgen breach import --help`.
+breach-invalid = "{ $path }" no es un filtro de filtraciones válido, vuelve a importarlo.
+
+## codename
+unable-to-open = No se pudo abrir "{ $path }": { $error }
+codename-exhausted = ¡No se encontró ningún nombre en clave que no se haya asignado ya!
+
+## config
+config-missing-bracket = línea { $line }: falta ']'
+config-text-after-table = línea { $line }: texto inesperado tras el nombre de la tabla
+config-invalid-table = línea { $line }: nombre de tabla no válido
+config-expected-key-value = línea { $line }: se esperaba "clave = valor"
+config-invalid-key = línea { $line }: clave no válida
+config-invalid-value = línea { $line }: valor no válido
+config-text-after-value = línea { $line }: texto inesperado tras el valor
+config-set-twice = línea { $line }: "{ $key }" está definido dos veces
+invalid-config-file = Archivo de configuración no válido "{ $path }": { $error }
+config-not-string = El valor de configuración "{ $key }" debe ser una cadena.
+config-not-boolean = El valor de configuración "{ $key }" debe ser true o false.
+config-not-integer = El valor de configuración "{ $key }" debe ser un número entero.
+config-set-not-integer = "{ $key }" debe ser un número entero.
+config-set-not-boolean = "{ $key }" debe ser true o false.
+config-set = Definido { $key } = { $value }
+config-not-set = "{ $key }" no está definido.
+config-nothing-set = Todavía no hay nada definido, consulta `pwgen config list --all` para ver qué se puede definir.
+config-editor-unstartable = No se pudo iniciar el editor "{ $editor }": { $error }
+config-editor-failed = El editor "{ $editor }" falló.
+config-warning = Aviso: { $message }
+config-imported = Se importaron { $count } valores.
+
+## coupons
+length-range = La longitud debe estar entre 1 y { $max }.
+coupons-too-few = Solo existen { $possible } códigos distintos de longitud { $length }, usa una longitud mayor para { $count } códigos.
+
+## daemon
+daemon-no-runtime-dir = XDG_RUNTIME_DIR no está definido, elige un socket con --socket.
+daemon-already-listening = Ya hay otro daemon escuchando en "{ $path }".
+unable-to-remove = No se pudo eliminar "{ $path }": { $error }
+daemon-unable-to-listen = No se pudo escuchar en "{ $path }": { $error }
+daemon-unable-to-restrict = No se pudo restringir "{ $path }": { $error }
+daemon-listening = Escuchando en { $path }
+daemon-unsupported = El daemon necesita sockets de Unix, que esta plataforma no tiene.
+
+## defaults
+defaults-unknown-key-suggestion = Clave de configuración desconocida "{ $key }", ¿querías decir "{ $closest }"?
+defaults-unknown-key = Clave de configuración desconocida "{ $key }", consulta `pwgen config list --all` para ver todas las claves.
+defaults-unknown-command = "{ $command }" no es ni un comando ni un alias.
+defaults-length-range = El valor de configuración "password.length" debe estar entre 1 y { $max }.
+defaults-out-of-range = El valor de configuración "{ $key }" está fuera de rango.
+defaults-not-character = El valor de configuración "{ $key }" debe ser un solo carácter.
+defaults-not-strings = El valor de configuración "{ $key }" solo puede contener cadenas.
+defaults-not-array = El valor de configuración "{ $key }" debe ser una lista de nombres.
+defaults-unknown-choice = El valor de configuración "{ $key }" tiene la opción desconocida "{ $value }", se esperaba una de { $choices }.
+
+## derive
+derive-empty-site = El sitio no puede estar vacío.
+derive-touch-yubikey = Toca tu YubiKey si parpadea.
+derive-yubikey-response = La YubiKey dio una respuesta inesperada, ¿está la ranura configurada para desafío-respuesta HMAC-SHA1?
+derive-no-fido2 = No se encontró ninguna llave de seguridad FIDO2, ¿está conectada?
+derive-touch-security-key = Toca tu llave de seguridad cuando parpadee.
+derive-no-hmac-secret = La llave de seguridad no devolvió un hmac-secret, ¿admite la extensión?
+derive-making-credential = Creando una credencial para pwgen en la llave de seguridad, tócala cuando parpadee.
+derive-no-credential = La llave de seguridad no devolvió ninguna credencial.
+
+## doctor
+doctor-ok = ok
+doctor-warn = aviso
+doctor-fail = fallo
+doctor-check = { $check }: { $detail }
+doctor-failed = { $count ->
+        [one] Falló 1 comprobación.
+       *[other] Fallaron { $count } comprobaciones.
+    }
+doctor-random-source = Fuente aleatoria
+doctor-display = Pantalla
+doctor-clipboard = Portapapeles
+doctor-keyring = Llavero
+doctor-config = Configuración
+doctor-terminal = Terminal
+doctor-random-ok = superó su prueba de salud
+doctor-display-xwayland = Wayland, con X11 mediante XWayland
+doctor-display-wayland = Wayland sin XWayland, el portapapeles necesita que DISPLAY esté definido
+doctor-display-x11 = X11
+doctor-display-none = ni WAYLAND_DISPLAY ni DISPLAY están definidos, no hay portapapeles al que copiar
+doctor-clipboard-ok = se pueden copiar los secretos
+doctor-clipboard-unreadable = conectado, pero no se pudo leer: { $error }
+doctor-clipboard-unavailable = no disponible, usa --no-copy: { $error }
+doctor-osc52-tmux = tmux lo transmite con `set -g set-clipboard on`
+doctor-osc52-supported = { $terminal } lo admite
+doctor-osc52-no-terminal = no se detectó ningún terminal
+doctor-osc52-unknown = se desconoce si "{ $terminal }" lo admite
+doctor-osc52-remote = { $detail }, por SSH el portapapeles remoto no es el tuyo
+doctor-keyring-ok = disponible, { $count } secretos guardados por pwgen
+doctor-keyring-unavailable = no disponible, --keyring y git-credential no funcionarán: { $error }
+doctor-config-no-directory = no se encontró ningún directorio de configuración
+doctor-config-none = todavía no hay ninguna en "{ $path }"
+doctor-config-valid = "{ $path }" es válida
+doctor-terminal-ok = los secretos se piden sin mostrarlos
+doctor-terminal-stdin = stdin no es un terminal, los secretos se leen de él como líneas
+doctor-terminal-stdout = stdout no es un terminal, los secretos mostrados acaban allá donde vaya
+doctor-tool-installed = instalado, { $option } funciona
+doctor-tool-missing = no instalado, necesario para { $option }
+
+## email
+email-invalid-domain = ¡"{ $domain }" no es un dominio válido!
+email-invalid-tag = ¡"{ $tag }" no es una etiqueta válida! Solo se permiten letras, dígitos, '.', '-' y '_'.
+email-invalid-mailbox = ¡"{ $mailbox }" no es un nombre de buzón válido!
+
+## external
+external-not-installed = No se pudo ejecutar { $program }, ¿está instalado? { $error }
+external-unable-to-run = No se pudo ejecutar { $program }: { $error }
+external-failed = { $program } falló: { $error }
+
+## git_credential
+git-credential-generated = Se generó una contraseña nueva para { $name }.
+git-credential-unreadable = No se pudo leer de git: { $error }
+
+## hardening
+hardening-core-dumps = No se pudieron desactivar los volcados de memoria: { $error }
+hardening-dumpable = No se pudo impedir el volcado del proceso: { $error }
+hardening-lock = No se pudo bloquear la memoria, ¿es `ulimit -l` demasiado bajo? { $error }
+hardening-working-set = No se pudo ampliar el conjunto de trabajo para bloquear memoria: { $error }
+hardening-lock-unsupported = Bloquear la memoria no es posible en esta plataforma.
+
+## hash
+hash-cost-bcrypt-only = --cost solo se aplica a bcrypt.
+hash-iterations-range = Las iteraciones deben estar entre 1 y 1000.
+hash-parallelism-range = El paralelismo debe estar entre 1 y 255.
+hash-memory-range = La memoria debe estar entre { $min } KiB y 4 GiB.
+hash-argon2-salt = La sal debe tener al menos 8 bytes en base64.
+hash-argon2-only = --memory, --iterations y --parallelism solo se aplican a argon2id.
+hash-cost-range = El coste debe estar entre 4 y 31.
+hash-bcrypt-salt = La sal debe tener 22 caracteres del base64 de bcrypt.
+hash-invalid-parameters = Parámetros de argon2id no válidos: { $error }
+hash-failed = No se pudo calcular el hash de la contraseña: { $error }
+hash-bcrypt-too-long = bcrypt ignora todo lo que hay tras { $bytes } bytes, usa argon2id para contraseñas más largas.
+
+## health
+health-unavailable = La fuente aleatoria del sistema no está disponible: { $error }
+health-failed = La fuente aleatoria del sistema no superó su prueba de salud, { $reason }. No se generarán secretos con ella.
+health-repetition = un byte se repite demasiadas veces seguidas
+health-proportion = un byte es mucho más frecuente que los demás
+
+## history
+history-given-secret = Al último comando se le dio un secreto, así que no se guarda para repetirlo.
+history-disabled = El historial no está activado, actívalo con `pwgen config set history.enabled true`.
+history-nothing-since-enabled = No se ha generado nada desde que se activó el historial.
+history-no-config-directory = No hay directorio de configuración donde guardar el último comando.
+history-nothing-yet = Todavía no se ha generado nada.
+history-damaged = "{ $path }" está dañado, genera algo para reemplazarlo.
+
+## honeywords
+honeywords-empty-reference = La referencia no puede estar vacía.
+honeywords-nothing-to-vary = La referencia necesita al menos una letra, un dígito o un símbolo que variar.
+honeywords-too-few = Solo { $count } señuelos distintos comparten esta forma, prueba con una referencia más larga o una cantidad menor.
+
+## id
+id-too-many-ulids = ¡Demasiados ULID en un milisegundo!
+id-too-many-ksuids = ¡Demasiados KSUID en un segundo!
+
+## identity
+identity-age-order = La edad mínima no puede ser mayor que la máxima.
+identity-age-max = La edad máxima no puede ser mayor que 150.
+
+## invented
+invented-too-long = ¡No cabe ningún nombre inventado en { $length } caracteres!
+
+## ip
+ip-exhausted = ¡No se encontró ninguna dirección no reservada en esta subred!
+ip-invalid-cidr = ¡"{ $cidr }" no es un bloque CIDR válido! Ejemplo: "10.0.0.0/8".
+ip-invalid-prefix = ¡"{ $prefix }" no es una longitud de prefijo válida!
+ip-v4-prefix = La longitud de prefijo IPv4 no puede ser mayor que 32.
+ip-v6-prefix = La longitud de prefijo IPv6 no puede ser mayor que 128.
+ip-invalid-address = ¡"{ $address }" no es una dirección IP válida!
+
+## json
+json-invalid = JSON no válido en el byte { $position }: { $reason }
+json-text-after-value = texto inesperado tras el valor
+json-expected = se esperaba '{ $character }'
+json-unknown-value = valor desconocido
+json-unexpected-end = final inesperado
+json-nested-too-deeply = anidado demasiado profundo
+json-expected-comma-bracket = se esperaba ',' o ']'
+json-expected-field-name = se esperaba un nombre de campo
+json-expected-comma-brace = se esperaba ',' o '{"}"}'
+json-unterminated-string = cadena sin cerrar
+json-invalid-escape = secuencia de escape no válida
+json-control-character = carácter de control en la cadena
+json-invalid-utf8 = UTF-8 no válido
+json-unpaired-surrogate = sustituto sin pareja
+json-invalid-number = número no válido
+
+## keyring
+keyring-invalid-name = ¡Nombre de secreto no válido "{ $name }"!
+keyring-not-found = No hay ningún secreto llamado "{ $name }" en el llavero.
+keyring-not-utf8 = El secreto "{ $name }" no es UTF-8 válido.
+keyring-not-in-keychain = No hay ningún secreto llamado "{ $name }" en el llavero de macOS.
+keyring-unable-to-save = No se pudo guardar "{ $name }": { $error }
+keyring-not-in-credential-manager = No hay ningún secreto llamado "{ $name }" en el Administrador de credenciales.
+keyring-unsupported = No hay ningún almacén de secretos compatible en esta plataforma.
+
+## license
+license-no-x = El patrón necesita al menos una 'X'.
+license-too-few-x = El patrón necesita más de { $checksum } 'X' para que quepa una suma de comprobación.
+license-invalid = ¡La clave de licencia no es válida! { $problem }
+license-valid = La clave de licencia es válida.
+license-pattern-mismatch = No coincide con el patrón.
+license-too-short = El patrón es demasiado corto para contener una suma de comprobación.
+license-checksum-mismatch = La suma de comprobación no coincide.
+license-invalid-character = '{ $character }' no es un carácter válido.
+
+## nanoid
+nanoid-size-range = El tamaño debe estar entre 1 y 65536.
+nanoid-alphabet-range = El alfabeto debe contener entre 2 y 256 caracteres.
+nanoid-duplicate = El alfabeto contiene '{ $character }' más de una vez, lo que lo haría más probable.
+
+## native_host
+native-host-too-long = El mensaje de { $length } bytes es demasiado largo, se cierra la conexión.
+native-host-unreadable = No se pudo leer un mensaje del navegador: { $error }
+native-host-unanswerable = No se pudo responder al navegador: { $error }
+
+## otp
+otp-digits-range = Los dígitos deben estar entre 6 y 10.
+otp-period-range = El periodo debe ser de al menos 1 segundo.
+otp-invalid-secret = El secreto no es base32 válido.
+otp-clock-before-1970 = El reloj del sistema está antes de 1970.
+otp-bytes-range = Los bytes deben estar entre 10 y 64.
+otp-colon = El emisor y la cuenta no pueden contener ':'.
+otp-empty = El emisor y la cuenta no pueden estar vacíos.
+
+## output
+output-file = "{ $path }"
+output-vault = Vault en { $target }
+output-bitwarden = Bitwarden como "{ $name }"
+output-1password = 1Password como "{ $title }"
+output-kdbx = "{ $database }" como "{ $entry }"
+output-keyring = el llavero como "{ $name }"
+output-docker = el secreto de { $engine } "{ $name }"
+output-vault-password-file-only = --vault-password-file solo se aplica a --output ansible-vault.
+output-dotenv-invalid-name = "{ $key }" no es un nombre de variable válido para dotenv.
+output-dotenv-invalid-secret = El secreto tiene una ' o un carácter de control, que un valor de dotenv entre comillas simples no puede contener. Exclúyelos con --exclude.
+output-needs-vault-password-file = --output ansible-vault necesita un --vault-password-file.
+output-no-vault-password = "{ $path }" no contiene ninguna contraseña de vault.
+output-not-appendable = Solo se puede añadir a salidas plain, dotenv, CSV, YAML y TOML.
+output-exists = "{ $path }" ya existe, añade --append para añadirle el secreto.
+output-unable-to-append = No se pudo añadir a "{ $path }": { $problem }
+output-toml-table = el secreto acabaría en la última tabla del archivo TOML.
+output-key-exists = ya tiene "{ $key }", elige otro --key.
+output-vault-target = --vault-write espera RUTA#CAMPO, como secret/data/myapp#password.
+output-no-vault-addr = VAULT_ADDR debe contener la dirección del servidor de Vault.
+output-bw-locked = Desbloquea primero la bóveda con `bw unlock` y exporta BW_SESSION.
+output-kdbx-missing = "{ $path }" no existe.
+
+## passphrase
+passphrase-no-words = La frase de contraseña necesita al menos una palabra.
+passphrase-too-many-words = ¡Demasiadas palabras! No pueden ser más de 1024.
+
+## password
+password-hybrid-conflict = --style hybrid no se puede combinar con --mobile-friendly, --alternate-hands ni --no-patterns.
+wordlist-empty = ¡La lista de palabras está vacía!
+password-policy-kept-failing = las reglas de { $policy } siguieron fallando, la última vez en "{ $problem }". Prueba con una longitud mayor o más intentos
+password-patterns-kept-failing = --no-patterns siguió encontrando { $pattern }. Prueba con una longitud menor o más conjuntos de caracteres
+password-tried = en { $attempts } intentos
+password-dead-end = Ninguna contraseña siguió las reglas { $tried }, { $reason }.
+explain-sets = Conjuntos de caracteres: { $sets }.
+explain-excluded = Se excluyeron { $count } caracteres: { $characters }
+explain-pool = Reserva de { $count } caracteres, { $bits } bits por carácter.
+explain-weight = { $set } { $factor } veces
+explain-weights = Eligiendo { $weights } más a menudo, { $bits } bits por carácter.
+explain-hybrid-word = una palabra de { $count }
+random-characters = { $count ->
+        [one] 1 carácter aleatorio
+       *[other] { $count } caracteres aleatorios
+    }
+explain-hybrid = Híbrido de { $parts }.
+explain-length = Longitud de { $length } caracteres.
+explain-mobile = Letras, dígitos y un símbolo, cada uno en un bloque, en orden aleatorio.
+explain-hands = Alternando entre { $left } caracteres de la mano izquierda y { $right } de la derecha.
+trade-off-hybrid = La contraseña híbrida tiene { $bits } bits de entropía, la palabra solo cuenta como la única elección que es.
+trade-off = Son { $lost } bits menos que los { $bits } bits de elegir cada carácter de toda la reserva.
+password-hands = --alternate-hands necesita caracteres para ambas manos.
+password-no-characters = ¡No se permite ningún carácter! Añade más conjuntos de caracteres o excluye menos caracteres.
+password-too-long = ¡Contraseña demasiado larga! No puede tener más de { $max }.
+pattern-dictionary-word = una palabra del diccionario
+pattern-keyboard-walk = una secuencia de teclado
+pattern-date = una fecha
+password-tried-within = en { $seconds ->
+        [one] 1 segundo
+       *[other] { $seconds } segundos
+    } ({ $attempts } intentos)
+explain-hybrid-digit = un dígito de { $count }
+explain-hybrid-symbol = un símbolo de { $count }
+
+## petname
+petname-words-range = La cantidad de palabras debe estar entre 1 y 16.
+petname-invalid-separator = El separador solo puede contener minúsculas, dígitos y '-' para nombres DNS.
+invalid-dns-label = ¡"{ $label }" no es una etiqueta DNS válida!
+
+## picker
+picker-candidates-range = La cantidad de candidatos debe estar entre 1 y 100.
+picker-unknown-choice = El selector devolvió algo que no es ninguno de los candidatos.
+
+## platform
+platform-too-short = más corto que { $min } caracteres
+platform-too-long = más largo que { $max } caracteres
+platform-invalid-character = contiene '{ $character }'
+platform-no-letter-start = no empieza por una letra
+platform-symbol-edge = empieza o termina con un símbolo
+platform-double-symbol = contiene dos símbolos seguidos
+
+## policy
+policy-invalid = Política no válida "{ $path }": { $error }
+policy-unknown-rule = regla desconocida "{ $rule }", se esperaba una de { $keys } o un conjunto en [{ $tables }].
+policy-length-range = "{ $key }" debe estar entre 1 y 65536.
+policy-max-below-min = "max_length" es más corto que "min_length".
+policy-length-outside = "length" está fuera de "min_length" y "max_length".
+policy-max-consecutive = "max_consecutive" debe ser al menos 1.
+policy-unknown-set = "{ $key }" no es un conjunto de caracteres.
+policy-count-range = "{ $key }" debe estar entre 0 y 65536.
+policy-no-characters = no se permite ningún carácter.
+policy-required-unavailable = "{ $set }" es obligatorio, pero ninguno de sus caracteres está permitido.
+policy-max-counts = "{ $set }" necesita más caracteres de los que permite "max_counts".
+policy-ends-unavailable = ninguno de los caracteres de "{ $key }" está permitido.
+policy-max-length-required = "max_length" es demasiado corto para todos los conjuntos de caracteres obligatorios.
+policy-needs-length-between = { $policy } necesita una longitud entre { $min } y { $max }.
+policy-needs-length = { $policy } necesita una longitud de al menos { $min }.
+policy-requires-set = { $policy } exige "{ $set }", que los conjuntos de caracteres elegidos no tienen.
+policy-requires-sets = { $policy } exige { $count } conjuntos de caracteres, que no caben en { $length } caracteres.
+policy-impossible = { $policy } tiene reglas que ninguna contraseña de { $length } caracteres puede seguir.
+policy-max-consecutive-impossible = { $policy } permite como mucho { $max } caracteres iguales seguidos, pero solo queda un carácter.
+policy-entropy-impossible = { $policy } exige { $bits } bits de entropía, { $length } caracteres pueden tener unos { $best }.
+check-min-length = Longitud de { $length }, al menos { $min }.
+check-max-length = Longitud de { $length }, como mucho { $max }.
+check-allowed = Solo caracteres de los conjuntos permitidos.
+check-outside = Caracteres fuera de los conjuntos permitidos: { $characters }
+check-not-forbidden = Ningún carácter prohibido.
+check-forbidden = Caracteres prohibidos: { $characters }
+check-contains = Contiene "{ $set }".
+check-contains-no = No contiene nada de "{ $set }".
+check-min-count = Al menos { $min } de "{ $set }", tiene { $count }.
+check-max-count = Como mucho { $max } de "{ $set }", tiene { $count }.
+check-consecutive = Como mucho { $max } caracteres iguales seguidos, tiene { $longest }.
+check-entropy = Entropía de unos { $bits } bits, al menos { $min }.
+conform-removed = Se eliminaron { $count } caracteres no permitidos.
+conform-appended-set = Se añadió un carácter aleatorio de tipo { $set }.
+conform-padded = Se añadieron { $characters } para llegar a la longitud mínima de { $min }.
+conform-trimmed = Se eliminaron { $count } caracteres del final para no pasar de la longitud máxima de { $max }.
+conform-entropy = Se añadieron { $characters } para llegar a { $bits } bits de entropía.
+conform-runs = Se reemplazaron { $characters } para romper secuencias del mismo carácter.
+conform-max-count = Se reemplazaron { $characters } para tener como mucho { $max } de "{ $set }".
+conform-failed = No se pudo hacer que la contraseña siga la política, todavía incumple una regla: { $rule }
+validate-broken = La contraseña incumple { $failed } de { $total } reglas.
+validate-followed = La contraseña sigue la política.
+conform-nothing = La contraseña ya sigue la política.
+conform-entropy-change = Entropía de unos { $bits } bits, antes { $before }.
+compare-same = Ambas políticas generan contraseñas de la misma fortaleza.
+compare-stronger = { $stronger } genera contraseñas { $bits } bits más fuertes que { $weaker }, { $guesses } veces los intentos.
+compare-nothing = nada
+compare-length-range = { $length } (de { $min } a { $max })
+compare-length-open = { $length } ({ $min } o más)
+compare-met = { $bits } bits, alcanzado
+compare-missed = { $bits } bits, no alcanzado
+compare-none = ninguno
+conform-made-uppercase = Se puso en mayúscula el carácter { $position }.
+conform-made-lowercase = Se puso en minúscula el carácter { $position }.
+conform-replaced-first = Se reemplazó el primer carácter por un carácter aleatorio de tipo { $set }.
+conform-replaced-last = Se reemplazó el último carácter por un carácter aleatorio de tipo { $set }.
+conform-added-first = Se añadió un carácter aleatorio de tipo { $set } como primer carácter.
+conform-added-last = Se añadió un carácter aleatorio de tipo { $set } como último carácter.
+check-starts = Empieza por { $sets }.
+check-ends = Termina con { $sets }.
+or = o
+set-lower = minúscula
+set-upper = mayúscula
+set-digit = dígito
+set-symbol = símbolo
+set-rare-symbol = símbolo raro
+set-latin-1 = Latin-1
+set-greek = letra griega
+set-emoji = emoji
+check-pass = bien
+check-fail = mal
+compare-policy = Política
+compare-length = Longitud
+compare-characters = Caracteres
+compare-required = Obligatorios
+compare-keyspace = Espacio de claves
+compare-entropy = Entropía
+compare-lost = Perdido por reglas
+compare-target = Objetivo de entropía
+compare-shortest = Longitud mínima
+bits = { $bits } bits
+
+## prompt
+prompt-number-range = Introduce un número entre 1 y { $max }.
+prompt-yes-no = Responde sí o no.
+prompt-pick = Elige uno para copiar (1-{ $count }, vacío para cancelar):
+prompt-unreadable-stdin = No se pudo leer stdin: { $error }
+prompt-unreadable = No se pudo leer la entrada: { $error }
+prompt-repeat = Repite para confirmar:
+prompt-mismatch = Las dos entradas no coinciden.
+
+## request
+request-not-object = La petición debe ser un objeto JSON.
+request-char-sets = "char_sets" debe ser una lista de nombres.
+request-unknown-set = Conjunto de caracteres desconocido { $set }.
+request-unknown-password-option = Opción de contraseña desconocida "{ $key }".
+request-site-and-policy = "site" y "policy" no se pueden combinar.
+request-separator = "separator" debe ser un solo carácter.
+request-unknown-passphrase-option = Opción de frase de contraseña desconocida "{ $key }".
+request-unknown-command = Comando desconocido "{ $command }", usa "password" o "passphrase".
+request-no-command = La petición necesita un "command".
+request-not-positive = "{ $key }" debe ser un número entero positivo.
+request-not-string = "{ $key }" debe ser una cadena.
+
+## salt
+salt-bytes-range = Los bytes deben estar entre 1 y 65536.
+
+## sandbox
+sandbox-failed = No se pudo aislar pwgen en un sandbox: { $error }
+sandbox-unveil-path = No se pudo aplicar unveil a "{ $path }": { $error }
+sandbox-unveil = No se pudo aplicar unveil: { $error }
+sandbox-pledge = No se pudo aplicar pledge: { $error }
+
+## setup
+setup-welcome = ¡Bienvenido a pwgen! Parece que es la primera vez que lo usas.
+setup-ask = ¿Configurar ahora tus valores predeterminados?
+setup-skipped = Omitido, ejecuta `pwgen setup` para hacerlo más tarde.
+setup-length = ¿Longitud de la contraseña? [16]
+setup-length-range = Introduce una longitud entre 4 y 65536.
+setup-symbols = ¿Se permiten símbolos en las contraseñas?
+setup-copy = ¿Copiar los secretos generados al portapapeles?
+setup-clear = ¿Vaciar el portapapeles tras cuántos segundos? 0 no lo vacía nunca. [0]
+setup-clear-range = Introduce un número de segundos hasta 86400.
+setup-saved = Tus valores predeterminados se guardaron en { $path }.
+
+## shamir
+shamir-different-secrets = Las partes pertenecen a secretos distintos.
+shamir-different-lengths = Las partes tienen longitudes distintas.
+shamir-too-few = Se necesitan { $threshold } partes distintas, solo se dieron { $given }.
+shamir-invalid-secret = Las partes no se combinan en un secreto válido.
+shamir-invalid-share = "{ $share }" no es una parte válida.
+shamir-typo = "{ $share }" tiene una errata, su suma de comprobación no coincide.
+
+## sites
+sites-invalid = Reglas de sitios no válidas "{ $path }": { $error }
+sites-invalid-rules = Reglas de contraseña no válidas para { $site }: { $error }
+sites-unknown = No se conocen reglas de contraseña para { $site }, consulta `--site` en el readme para añadirlas.
+sites-expected-property = se esperaba "nombre: valor" en "{ $property }"
+sites-not-number = "{ $key }" debe ser un número
+sites-max-below-min = "maxlength" es más corto que "minlength"
+sites-unknown-class = clase de caracteres desconocida "{ $class }"
+
+## skey
+skey-invalid-seed = La semilla debe tener de 1 a 16 letras y dígitos.
+skey-passphrase-range = La frase de contraseña debe tener entre 10 y 63 caracteres.
+skey-server-setup = Configuración del servidor: { $algorithm } { $count } { $seed } { $key }
+
+## sshkey
+sshkey-exists = "{ $path }" ya existe, usa --force para sobrescribirlo.
+sshkey-derive-failed = No se pudo derivar la clave de la frase de contraseña: { $error }
+sshkey-no-home = No se encontró el directorio personal, usa --out para elegir dónde guardar la clave.
+sshkey-passphrase = Frase de contraseña: { $passphrase }
+
+## stats
+stats-online = Ataque en línea
+stats-offline-slow = Sin conexión, hash lento
+stats-offline-fast = Sin conexión, hash rápido
+stats-long-term = Secretos a largo plazo
+stats-no-command = Indica un comando sobre el que informar, como "password".
+stats-word-dependent = La entropía que añaden --leet y --capitalize y quita --max-length depende de las palabras elegidas, no se incluye.
+stats-separator-set = La entropía que añade --separator-set no se incluye, --show-strength de la frase de contraseña sí la incluye.
+stats-unsupported = Las estadísticas solo están disponibles para password, passphrase y token.
+stats-pool-character = Reserva de { $pool } caracteres, { $bits } bits por carácter.
+stats-pool-word = Reserva de { $pool } palabras, { $bits } bits por palabra.
+stats-length-character = Longitud de { $amount } caracteres.
+stats-length-word = Longitud de { $amount } palabras.
+stats-keyspace = Espacio de claves de { $keyspace } (2^{ $bits }).
+stats-heading-character = Caracteres
+stats-heading-word = Palabras
+stats-target = Objetivo
+stats-bits = Bits
+stats-met = alcanzado
+
+## stdout_only
+stdout-only-conflict = --stdout-only no se puede usar con { $conflict }.
+stdout-only-unique = --unique, que escribe el nombre de usuario en un archivo de historial
+stdout-only-history = --history, que escribe el nombre en clave en un archivo
+stdout-only-sshkey = sshkey, que escribe la clave en archivos
+stdout-only-keyring = comandos que usan el llavero
+stdout-only-daemon = daemon, que sirve secretos por un socket
+stdout-only-stored = opciones que guardan el secreto en otro sitio
+stdout-only-program = --systemd-cred y --gpg-recipient, que pasan el secreto a otro programa
+stdout-only-picker = --picker, que muestra el secreto en otro programa
+
+## strength
+boost-leet = +{ $bits } bits por sustituciones leet.
+boost-capitalization = +{ $bits } bits por mayúsculas aleatorias.
+boost-separators = +{ $bits } bits por separadores aleatorios.
+
+## testcard
+testcard-brand = Marca: { $brand } (TARJETA DE PRUEBA, no sirve para pagos)
+testcard-number = Número: { $number }
+testcard-expiry = Caducidad: { $expiry }
+testcard-cvc = CVC: { $cvc }
+
+## token
+token-invalid = ¡El token no es válido! Probablemente se escribió mal.
+token-valid = El token es válido.
+
+## username
+username-no-config-directory = No hay directorio de configuración donde guardar los nombres de usuario asignados, elige un archivo con --history.
+username-alliteration-style = La aliteración solo funciona con el estilo words.
+username-platform-length = Los nombres de usuario de { $platform } necesitan al menos { $min } caracteres.
+username-empty-search = El texto a buscar no puede estar vacío.
+username-issued = ya asignado
+username-blocked = bloqueado por contener "{ $word }"
+username-embed-failed = No se pudo incluir "{ $text }" en un nombre de usuario válido, el resultado siguió siendo { $problem }.
+username-from-style = --from solo funciona con el estilo words.
+username-from-empty = El texto del que derivar el nombre de usuario no puede estar vacío.
+username-from-numbers = --from admite hasta 19 cifras.
+username-derive-failed = ¡No se pudo derivar un nombre de usuario que se ajuste a las opciones!
+username-keyspace = Nombres de usuario posibles: { $keyspace } ({ $bits } bits).
+username-duplicate-chance = Probabilidad de un duplicado entre { $users } usuarios: { $chance }, por debajo del { $threshold } % hasta { $unique } usuarios.
+username-chance-tiny = menos del 0,01 %
+username-none-fit = ¡Ningún nombre de usuario se ajusta a las opciones!
+username-too-many-numbers = ¡Demasiadas cifras! No pueden ser más de 65536.
+username-no-number = ¡No se pudo elegir un número que no parezca un año!
+username-no-adjectives = ¡Ningún adjetivo se ajusta a las longitudes de palabra y las palabras bloqueadas! Prueba a ampliarlas.
+username-no-objects = ¡Ningún objeto se ajusta a las longitudes de palabra y las palabras bloqueadas! Prueba a ampliarlas.
+username-invalid-for-platform = No se pudo generar un nombre de usuario válido para { $platform }, el resultado siguió siendo { $problem }.
+username-invalid = No se pudo generar un nombre de usuario válido, el resultado siguió siendo { $problem }.
+username-character-not-allowed = '{ $character }' no está permitido en los nombres de usuario de { $platform }.
+
+## vaults
+vaults-deleted = Se eliminó "{ $name }".
+vaults-empty = Todavía no hay secretos guardados, guarda uno con --keyring <NOMBRE>.
+
+## wordlist
+wordlist-invalid-name = ¡Nombre de lista de palabras no válido "{ $name }"! Solo se permiten letras, dígitos, '-' y '_'.
+wordlist-built-in-replace = "{ $name }" es una lista de palabras integrada y no se puede reemplazar.
+wordlist-too-small = La lista de palabras necesita al menos 2 palabras válidas distintas.
+wordlist-exists = Ya existe una lista de palabras llamada "{ $name }". Usa --force para reemplazarla.
+wordlist-imported = Se importaron { $count } palabras como "{ $name }".
+wordlist-duplicates = Se omitieron { $count } palabras duplicadas.
+wordlist-rejected = Se rechazaron { $count } palabras con espacios o caracteres de control (líneas { $lines }).
+wordlist-entropy = Entropía por palabra: { $bits } bits.
+wordlist-listed-built-in = { $name } (integrada, { $count } palabras)
+wordlist-listed = { $name } ({ $count } palabras)
+wordlist-info-name = Nombre: { $name }
+wordlist-info-words = Palabras: { $count }
+wordlist-info-length = Longitud de palabra: de { $min } a { $max } caracteres
+wordlist-info-entropy = Entropía por palabra: { $bits } bits
+wordlist-built-in-remove = "{ $name }" es una lista de palabras integrada y no se puede eliminar.
+wordlist-not-imported = No hay ninguna lista de palabras importada llamada "{ $name }".
+wordlist-removed = Se eliminó la lista de palabras "{ $name }".
+wordlist-checked = Se comprobaron { $count } palabras en "{ $name }".
+wordlist-no-problems = No se encontraron problemas.
+wordlist-unnormalized = { $count } palabras están en mayúsculas, duplicadas o contienen espacios: { $examples }
+wordlist-prefixes = { $count } palabras son el comienzo de otra palabra, lo que puede hacer ambiguas las palabras unidas: { $examples }
+wordlist-confusable = { $count } grupos de palabras se parecen al escribirlas: { $examples }
+wordlist-more = y { $count } más
+wordlist-unknown = No hay ninguna lista de palabras llamada "{ $name }".
+wordlist-unknown-hint = No hay ninguna lista de palabras llamada "{ $name }". Usa "pwgen wordlist list" para ver todas.
+wordlist-too-long = ¡No cabe en { $max } caracteres! El resultado más corto posible tiene { $shortest } caracteres.
+wordlist-alliteration-too-long = ¡No cabe ningún resultado con aliteración en { $max } caracteres!
+wordlist-no-alliteration = ¡Ninguna palabra de las listas empieza por la misma letra!
+wordlist-info-built-in = Origen: integrada
+wordlist-info-imported = Origen: importada
+
+## xkpasswd
+xkpasswd-negative = "{ $key }" no puede ser negativo.
+xkpasswd-unknown-case = case_transform desconocido "{ $value }".
+xkpasswd-unknown-padding = padding_type desconocido "{ $value }".
+xkpasswd-unknown-setting = Ajuste de xkpasswd desconocido "{ $key }".
+xkpasswd-words-range = num_words debe estar entre 1 y 100.
+xkpasswd-word-length = word_length_min debe ser al menos 1 y como mucho word_length_max.
+xkpasswd-separator-alphabet = Un separator_character aleatorio necesita un separator_alphabet.
+xkpasswd-symbol-alphabet = Un padding_character aleatorio necesita un symbol_alphabet.
+xkpasswd-no-separator = padding_character no puede ser el separador cuando no hay ninguno.
+xkpasswd-pad-to-length = El relleno adaptativo necesita un pad_to_length.
+xkpasswd-character = "{ $key }" debe ser un carácter, "RANDOM" o "NONE".
+xkpasswd-single-characters = "{ $key }" solo puede contener caracteres sueltos.
+xkpasswd-character-list = "{ $key }" debe ser una lista de caracteres.
+xkpasswd-not-object = "{ $path }" debe contener un objeto JSON de ajustes.
+xkpasswd-unsupported-value = El ajuste "{ $key }" tiene un valor no admitido.
+xkpasswd-unknown-scheme = No hay ningún esquema de xkpasswd llamado "{ $scheme }" en el archivo de configuración.
+xkpasswd-invalid = Ajustes no válidos "{ $path }": { $error }
+xkpasswd-no-words = La lista de palabras no tiene palabras de { $min } a { $max } letras.
@@ -0,0 +1,668 @@
+copied = { $name } copié dans le presse-papiers.
+copy-failed = Impossible de copier dans le presse-papiers.
+copy-unsupported = Impossible de copier dans le presse-papiers car le système ne le prend pas en charge.
+clearing = Le presse-papiers sera vidé dans { $seconds } secondes.
+written = { $name } enregistré dans { $destination }.
+no-password-picked = Aucun mot de passe choisi.
+no-passphrase-picked = Aucune phrase de passe choisie.
+suggestions-range = Le nombre de suggestions doit être compris entre 1 et 1000.
+otp-valid = Valide encore { $seconds } secondes.
+saved-private-key = Clé privée enregistrée dans « { $path } ».
+saved-public-key = Clé publique enregistrée dans « { $path } ».
+unknown-language = Langue inconnue « { $language } », disponibles : { $languages }.
+entropy = Entropie : { $bits } bits ({ $rating }).
+rating-weak = faible
+rating-fair = moyenne
+rating-strong = forte
+rating-very-strong = très forte
+porcelain-unsupported = Cette commande ne prend pas en charge --porcelain.
+again-itself = La dernière commande est `again` elle-même, il faut d'abord générer quelque chose de nouveau.
+again-nothing = `again` ne peut répéter qu'une commande qui a généré quelque chose.
+again-hidden = Avec --copy, le dernier secret est copié, avec -i il est affiché.
+
+noun-api-key = Clé d'API
+noun-card-number = Numéro de carte
+noun-codename = Nom de code
+noun-database-password = Mot de passe de la base de données
+noun-email = Adresse e-mail
+noun-excluded-characters = Caractères exclus
+noun-hash = Hash
+noun-ip-address = Adresse IP
+noun-license-key = Clé de licence
+noun-mac-address = Adresse MAC
+noun-name = Nom
+noun-nano-id = Nano ID
+noun-otp-code = Code OTP
+noun-otp-secret = Secret OTP
+noun-pass-phrase = Phrase de passe
+noun-passphrase = Phrase de passe
+noun-password = Mot de passe
+noun-petname = Petname
+noun-public-key = Clé publique
+noun-salt = Sel
+noun-secret = Secret
+noun-share = Part
+noun-slug = Slug
+noun-token = Jeton
+noun-username = Nom d'utilisateur
+
+## accounts
+count-range = Le nombre doit être compris entre 1 et { $max }.
+accounts-too-few = Seulement { $count } noms d'utilisateur différents trouvés, essayez un nombre plus petit.
+
+## alias
+alias-unterminated-quote = La valeur de configuration « { $key } » contient un guillemet non fermé.
+alias-empty = La valeur de configuration « { $key } » est vide.
+
+## apikey
+apikey-unknown-profile = Aucun profil de clé d'API nommé « { $profile } » dans le fichier de configuration.
+apikey-negative-bytes = La valeur de configuration « { $table }.bytes » ne peut pas être négative.
+apikey-checksum-choice = La valeur de configuration « { $table }.checksum » doit être « none » ou « crc32 ».
+apikey-bytes-range = Le nombre d'octets doit être compris entre 8 et 1024.
+apikey-prefix-whitespace = Le préfixe ne peut pas contenir d'espaces.
+
+## bench
+bench-single = { $name } { $rate }/s sur 1 thread
+bench-multi = { $name } { $rate }/s sur 1 thread, { $multi-rate }/s sur { $threads } threads
+bench-rng = { $name } { $rate } Mo/s sur 1 thread
+
+## breach
+breach-info = { $count } mots de passe divulgués en { $size } Ko.
+unable-to-read = Impossible de lire « { $path } » : { $error }
+breach-no-passwords = « { $path } » ne contient aucun mot de passe.
+unable-to-create = Impossible de créer « { $path } » : { $error }
+unable-to-write = Impossible d'écrire « { $path } » : { $error }
+breach-imported = { $count } mots de passe divulgués importés.
+breach-found = Ce mot de passe figure dans le filtre de fuites, ne l'utilisez pas.
+breach-not-found = Ce mot de passe ne figure pas dans le filtre de fuites.
+breach-dead-end = Les { $attempts } tentatives figuraient toutes dans le filtre de fuites, autorisez plus de caractères ou une longueur plus grande.
+no-config-directory = Impossible de trouver le répertoire de configuration.
+breach-not-imported = Aucun filtre de fuites importé pour l'instant, voir `pwgen breach import --help`.
+breach-invalid = « { $path } » n'est pas un filtre de fuites valide, importez-le à nouveau.
+
+## codename
+unable-to-open = Impossible d'ouvrir « { $path } » : { $error }
+codename-exhausted = Impossible de trouver un nom de code qui n'a pas encore été attribué !
+
+## config
+config-missing-bracket = ligne { $line } : ']' manquant
+config-text-after-table = ligne { $line } : texte inattendu après le nom de table
+config-invalid-table = ligne { $line } : nom de table invalide
+config-expected-key-value = ligne { $line } : « clé = valeur » attendu
+config-invalid-key = ligne { $line } : clé invalide
+config-invalid-value = ligne { $line } : valeur invalide
+config-text-after-value = ligne { $line } : texte inattendu après la valeur
+config-set-twice = ligne { $line } : « { $key } » est défini deux fois
+invalid-config-file = Fichier de configuration invalide « { $path } » : { $error }
+config-not-string = La valeur de configuration « { $key } » doit être une chaîne.
+config-not-boolean = La valeur de configuration « { $key } » doit être true ou false.
+config-not-integer = La valeur de configuration « { $key } » doit être un entier.
+config-set-not-integer = « { $key } » doit être un nombre entier.
+config-set-not-boolean = « { $key } » doit être true ou false.
+config-set = { $key } = { $value } défini
+config-not-set = « { $key } » n'est pas défini.
+config-nothing-set = Rien n'est encore défini, voir `pwgen config list --all` pour ce qui peut l'être.
+config-editor-unstartable = Impossible de lancer l'éditeur « { $editor } » : { $error }
+config-editor-failed = L'éditeur « { $editor } » a échoué.
+config-warning = Avertissement : { $message }
+config-imported = { $count } valeurs importées.
+
+## coupons
+length-range = La longueur doit être comprise entre 1 et { $max }.
+coupons-too-few = Il n'existe que { $possible } codes différents de longueur { $length }, utilisez une longueur plus grande pour { $count } codes.
+
+## daemon
+daemon-no-runtime-dir = XDG_RUNTIME_DIR n'est pas défini, choisissez un socket avec --socket.
+daemon-already-listening = Un autre daemon écoute déjà sur « { $path } ».
+unable-to-remove = Impossible de supprimer « { $path } » : { $error }
+daemon-unable-to-listen = Impossible d'écouter sur « { $path } » : { $error }
+daemon-unable-to-restrict = Impossible de restreindre « { $path } » : { $error }
+daemon-listening = En écoute sur { $path }
+daemon-unsupported = Le daemon a besoin de sockets Unix, que cette plateforme n'a pas.
+
+## defaults
+defaults-unknown-key-suggestion = Clé de configuration inconnue « { $key } », vouliez-vous dire « { $closest } » ?
+defaults-unknown-key = Clé de configuration inconnue « { $key } », voir `pwgen config list --all` pour toutes les clés.
+defaults-unknown-command = « { $command } » n'est ni une commande ni un alias.
+defaults-length-range = La valeur de configuration « password.length » doit être comprise entre 1 et { $max }.
+defaults-out-of-range = La valeur de configuration « { $key } » est hors limites.
+defaults-not-character = La valeur de configuration « { $key } » doit être un seul caractère.
+defaults-not-strings = La valeur de configuration « { $key } » ne doit contenir que des chaînes.
+defaults-not-array = La valeur de configuration « { $key } » doit être une liste de noms.
+defaults-unknown-choice = La valeur de configuration « { $key } » a le choix inconnu « { $value } », attendu l'un de { $choices }.
+
+## derive
+derive-empty-site = Le site ne peut pas être vide.
+derive-touch-yubikey = Touchez votre YubiKey si elle clignote.
+derive-yubikey-response = La YubiKey a donné une réponse inattendue, l'emplacement est-il configuré pour le défi-réponse HMAC-SHA1 ?
+derive-no-fido2 = Aucune clé de sécurité FIDO2 trouvée, est-elle branchée ?
+derive-touch-security-key = Touchez votre clé de sécurité quand elle clignote.
+derive-no-hmac-secret = La clé de sécurité n'a pas renvoyé de hmac-secret, prend-elle en charge l'extension ?
+derive-making-credential = Création d'un identifiant pour pwgen sur la clé de sécurité, touchez-la quand elle clignote.
+derive-no-credential = La clé de sécurité n'a renvoyé aucun identifiant.
+
+## doctor
+doctor-ok = ok
+doctor-warn = avert
+doctor-fail = échec
+doctor-check = { $check } : { $detail }
+doctor-failed = { $count ->
+        [one] { $count } vérification a échoué.
+       *[other] { $count } vérifications ont échoué.
+    }
+doctor-random-source = Source aléatoire
+doctor-display = Affichage
+doctor-clipboard = Presse-papiers
+doctor-keyring = Trousseau
+doctor-config = Configuration
+doctor-terminal = Terminal
+doctor-random-ok = a réussi son test de santé
+doctor-display-xwayland = Wayland, avec X11 via XWayland
+doctor-display-wayland = Wayland sans XWayland, le presse-papiers a besoin que DISPLAY soit défini
+doctor-display-x11 = X11
+doctor-display-none = ni WAYLAND_DISPLAY ni DISPLAY n'est défini, il n'y a pas de presse-papiers où copier
+doctor-clipboard-ok = les secrets peuvent être copiés
+doctor-clipboard-unreadable = connecté, mais la lecture a échoué : { $error }
+doctor-clipboard-unavailable = indisponible, utilisez --no-copy : { $error }
+doctor-osc52-tmux = tmux le transmet avec `set -g set-clipboard on`
+doctor-osc52-supported = { $terminal } le prend en charge
+doctor-osc52-no-terminal = aucun terminal détecté
+doctor-osc52-unknown = on ne sait pas si « { $terminal } » le prend en charge
+doctor-osc52-remote = { $detail }, via SSH le presse-papiers distant n'est pas le vôtre
+doctor-keyring-ok = disponible, { $count } secrets enregistrés par pwgen
+doctor-keyring-unavailable = indisponible, --keyring et git-credential ne fonctionneront pas : { $error }
+doctor-config-no-directory = aucun répertoire de configuration trouvé
+doctor-config-none = aucune pour l'instant dans « { $path } »
+doctor-config-valid = « { $path } » est valide
+doctor-terminal-ok = les secrets sont demandés sans être affichés
+doctor-terminal-stdin = stdin n'est pas un terminal, les secrets y sont lus ligne par ligne
+doctor-terminal-stdout = stdout n'est pas un terminal, les secrets affichés finissent là où il mène
+doctor-tool-installed = installé, { $option } fonctionne
+doctor-tool-missing = non installé, nécessaire pour { $option }
+
+## email
+email-invalid-domain = « { $domain } » n'est pas un domaine valide !
+email-invalid-tag = « { $tag } » n'est pas une étiquette valide ! Seuls les lettres, les chiffres, '.', '-' et '_' sont autorisés.
+email-invalid-mailbox = « { $mailbox } » n'est pas un nom de boîte aux lettres valide !
+
+## external
+external-not-installed = Impossible d'exécuter { $program }, est-il installé ? { $error }
+external-unable-to-run = Impossible d'exécuter { $program } : { $error }
+external-failed = { $program } a échoué : { $error }
+
+## git_credential
+git-credential-generated = Nouveau mot de passe généré pour { $name }.
+git-credential-unreadable = Impossible de lire depuis git : { $error }
+
+## hardening
+hardening-core-dumps = Impossible de désactiver les core dumps : { $error }
+hardening-dumpable = Impossible de protéger le processus contre les dumps : { $error }
+hardening-lock = Impossible de verrouiller la mémoire, `ulimit -l` est-il trop bas ? { $error }
+hardening-working-set = Impossible d'agrandir le working set pour verrouiller la mémoire : { $error }
+hardening-lock-unsupported = Le verrouillage de la mémoire n'est pas pris en charge sur cette plateforme.
+
+## hash
+hash-cost-bcrypt-only = --cost ne s'applique qu'à bcrypt.
+hash-iterations-range = Les itérations doivent être comprises entre 1 et 1000.
+hash-parallelism-range = Le parallélisme doit être compris entre 1 et 255.
+hash-memory-range = La mémoire doit être comprise entre { $min } Kio et 4 Gio.
+hash-argon2-salt = Le sel doit faire au moins 8 octets en base64.
+hash-argon2-only = --memory, --iterations et --parallelism ne s'appliquent qu'à argon2id.
+hash-cost-range = Le coût doit être compris entre 4 et 31.
+hash-bcrypt-salt = Le sel doit faire 22 caractères du base64 de bcrypt.
+hash-invalid-parameters = Paramètres argon2id invalides : { $error }
+hash-failed = Impossible de hacher le mot de passe : { $error }
+hash-bcrypt-too-long = bcrypt ignore tout ce qui suit { $bytes } octets, utilisez argon2id pour des mots de passe plus longs.
+
+## health
+health-unavailable = La source aléatoire du système est indisponible : { $error }
+health-failed = La source aléatoire du système a échoué à son test de santé, { $reason }. Aucun secret ne sera généré avec elle.
+health-repetition = un octet se répète trop souvent à la suite
+health-proportion = un octet est bien plus fréquent que les autres
+
+## history
+history-given-secret = Un secret a été donné à la dernière commande, elle n'est donc pas conservée pour être répétée.
+history-disabled = L'historique n'est pas activé, activez-le avec `pwgen config set history.enabled true`.
+history-nothing-since-enabled = Rien n'a été généré depuis l'activation de l'historique.
+history-no-config-directory = Aucun répertoire de configuration pour conserver la dernière commande.
+history-nothing-yet = Rien n'a encore été généré.
+history-damaged = « { $path } » est endommagé, générez quelque chose pour le remplacer.
+
+## honeywords
+honeywords-empty-reference = La référence ne peut pas être vide.
+honeywords-nothing-to-vary = La référence a besoin d'au moins une lettre, un chiffre ou un symbole à faire varier.
+honeywords-too-few = Seulement { $count } leurres différents ont cette forme, essayez une référence plus longue ou un nombre plus petit.
+
+## id
+id-too-many-ulids = Trop d'ULID en une milliseconde !
+id-too-many-ksuids = Trop de KSUID en une seconde !
+
+## identity
+identity-age-order = L'âge minimum ne peut pas dépasser l'âge maximum.
+identity-age-max = L'âge maximum ne peut pas dépasser 150.
+
+## invented
+invented-too-long = Impossible de faire tenir un nom inventé en { $length } caractères !
+
+## ip
+ip-exhausted = Impossible de trouver une adresse non réservée dans ce sous-réseau !
+ip-invalid-cidr = « { $cidr } » n'est pas un bloc CIDR valide ! Exemple : « 10.0.0.0/8 ».
+ip-invalid-prefix = « { $prefix } » n'est pas une longueur de préfixe valide !
+ip-v4-prefix = La longueur de préfixe IPv4 ne peut pas dépasser 32.
+ip-v6-prefix = La longueur de préfixe IPv6 ne peut pas dépasser 128.
+ip-invalid-address = « { $address } » n'est pas une adresse IP valide !
+
+## json
+json-invalid = JSON invalide à l'octet { $position } : { $reason }
+json-text-after-value = texte inattendu après la valeur
+json-expected = '{ $character }' attendu
+json-unknown-value = valeur inconnue
+json-unexpected-end = fin inattendue
+json-nested-too-deeply = imbrication trop profonde
+json-expected-comma-bracket = ',' ou ']' attendu
+json-expected-field-name = nom de champ attendu
+json-expected-comma-brace = ',' ou '{"}"}' attendu
+json-unterminated-string = chaîne non terminée
+json-invalid-escape = échappement invalide
+json-control-character = caractère de contrôle dans la chaîne
+json-invalid-utf8 = UTF-8 invalide
+json-unpaired-surrogate = substitut non apparié
+json-invalid-number = nombre invalide
+
+## keyring
+keyring-invalid-name = Nom de secret invalide « { $name } » !
+keyring-not-found = Aucun secret nommé « { $name } » dans le trousseau.
+keyring-not-utf8 = Le secret « { $name } » n'est pas de l'UTF-8 valide.
+keyring-not-in-keychain = Aucun secret nommé « { $name } » dans le trousseau de macOS.
+keyring-unable-to-save = Impossible d'enregistrer « { $name } » : { $error }
+keyring-not-in-credential-manager = Aucun secret nommé « { $name } » dans le Gestionnaire d'identification.
+keyring-unsupported = Il n'y a aucun magasin de secrets pris en charge sur cette plateforme.
+
+## license
+license-no-x = Le motif a besoin d'au moins un 'X'.
+license-too-few-x = Le motif a besoin de plus de { $checksum } 'X' pour contenir une somme de contrôle.
+license-invalid = La clé de licence est invalide ! { $problem }
+license-valid = La clé de licence est valide.
+license-pattern-mismatch = Elle ne correspond pas au motif.
+license-too-short = Le motif est trop court pour contenir une somme de contrôle.
+license-checksum-mismatch = La somme de contrôle ne correspond pas.
+license-invalid-character = '{ $character }' n'est pas un caractère valide.
+
+## nanoid
+nanoid-size-range = La taille doit être comprise entre 1 et 65536.
+nanoid-alphabet-range = L'alphabet doit contenir entre 2 et 256 caractères.
+nanoid-duplicate = L'alphabet contient '{ $character }' plus d'une fois, ce qui le rendrait plus probable.
+
+## native_host
+native-host-too-long = Le message de { $length } octets est trop long, fermeture.
+native-host-unreadable = Impossible de lire un message du navigateur : { $error }
+native-host-unanswerable = Impossible de répondre au navigateur : { $error }
+
+## otp
+otp-digits-range = Les chiffres doivent être compris entre 6 et 10.
+otp-period-range = La période doit être d'au moins 1 seconde.
+otp-invalid-secret = Le secret n'est pas du base32 valide.
+otp-clock-before-1970 = L'horloge du système est réglée avant 1970.
+otp-bytes-range = Les octets doivent être compris entre 10 et 64.
+otp-colon = L'émetteur et le compte ne peuvent pas contenir ':'.
+otp-empty = L'émetteur et le compte ne peuvent pas être vides.
+
+## output
+output-file = « { $path } »
+output-vault = Vault dans { $target }
+output-bitwarden = Bitwarden sous « { $name } »
+output-1password = 1Password sous « { $title } »
+output-kdbx = « { $database } » sous « { $entry } »
+output-keyring = le trousseau sous « { $name } »
+output-docker = le secret { $engine } « { $name } »
+output-vault-password-file-only = --vault-password-file ne s'applique qu'à --output ansible-vault.
+output-dotenv-invalid-name = « { $key } » n'est pas un nom de variable valide pour dotenv.
+output-dotenv-invalid-secret = Le secret contient un ' ou un caractère de contrôle, qu'une valeur dotenv entre guillemets simples ne peut pas contenir. Excluez-les avec --exclude.
+output-needs-vault-password-file = --output ansible-vault a besoin d'un --vault-password-file.
+output-no-vault-password = « { $path } » ne contient pas de mot de passe vault.
+output-not-appendable = Seules les sorties plain, dotenv, CSV, YAML et TOML peuvent être complétées.
+output-exists = « { $path } » existe déjà, ajoutez --append pour y ajouter le secret.
+output-unable-to-append = Impossible d'ajouter à « { $path } » : { $problem }
+output-toml-table = le secret se retrouverait dans la dernière table du fichier TOML.
+output-key-exists = il contient déjà « { $key } », choisissez un autre --key.
+output-vault-target = --vault-write attend CHEMIN#CHAMP, comme secret/data/myapp#password.
+output-no-vault-addr = VAULT_ADDR doit contenir l'adresse du serveur Vault.
+output-bw-locked = Déverrouillez d'abord le coffre avec `bw unlock` et exportez BW_SESSION.
+output-kdbx-missing = « { $path } » n'existe pas.
+
+## passphrase
+passphrase-no-words = La phrase de passe a besoin d'au moins un mot.
+passphrase-too-many-words = Trop de mots ! Il ne peut pas y en avoir plus de 1024.
+
+## password
+password-hybrid-conflict = --style hybrid ne peut pas être combiné avec --mobile-friendly, --alternate-hands ou --no-patterns.
+wordlist-empty = La liste de mots est vide !
+password-policy-kept-failing = les règles de { $policy } ont continué à échouer, en dernier sur « { $problem } ». Essayez une longueur plus grande ou plus de tentatives
+password-patterns-kept-failing = --no-patterns a continué à trouver { $pattern }. Essayez une longueur plus petite ou plus de jeux de caractères
+password-tried = en { $attempts } tentatives
+password-dead-end = Aucun mot de passe n'a suivi les règles { $tried }, { $reason }.
+explain-sets = Jeux de caractères : { $sets }.
+explain-excluded = { $count } caractères exclus : { $characters }
+explain-pool = Réserve de { $count } caractères, { $bits } bits par caractère.
+explain-weight = { $set } { $factor } fois
+explain-weights = Choix de { $weights } plus souvent, { $bits } bits par caractère.
+explain-hybrid-word = un mot parmi { $count }
+random-characters = { $count ->
+        [one] { $count } caractère aléatoire
+       *[other] { $count } caractères aléatoires
+    }
+explain-hybrid = Hybride de { $parts }.
+explain-length = Longueur de { $length } caractères.
+explain-mobile = Lettres, chiffres et un symbole chacun dans un bloc, dans un ordre aléatoire.
+explain-hands = Alternance entre { $left } caractères de la main gauche et { $right } de la main droite.
+trade-off-hybrid = Le mot de passe hybride a { $bits } bits d'entropie, le mot ne compte que comme le choix unique qu'il est.
+trade-off = C'est { $lost } bits de moins que les { $bits } bits obtenus en choisissant chaque caractère dans toute la réserve.
+password-hands = --alternate-hands a besoin de caractères pour les deux mains.
+password-no-characters = Aucun caractère n'est autorisé ! Ajoutez des jeux de caractères ou excluez moins de caractères.
+password-too-long = Mot de passe trop long ! Il ne peut pas dépasser { $max }.
+pattern-dictionary-word = un mot du dictionnaire
+pattern-keyboard-walk = une suite de touches du clavier
+pattern-date = une date
+password-tried-within = en { $seconds ->
+        [one] { $seconds } seconde
+       *[other] { $seconds } secondes
+    } ({ $attempts } tentatives)
+explain-hybrid-digit = un chiffre parmi { $count }
+explain-hybrid-symbol = un symbole parmi { $count }
+
+## petname
+petname-words-range = Le nombre de mots doit être compris entre 1 et 16.
+petname-invalid-separator = Le séparateur ne doit contenir que des minuscules, des chiffres et '-' pour les noms DNS.
+invalid-dns-label = « { $label } » n'est pas une étiquette DNS valide !
+
+## picker
+picker-candidates-range = Le nombre de candidats doit être compris entre 1 et 100.
+picker-unknown-choice = Le sélecteur a renvoyé quelque chose qui ne fait pas partie des candidats.
+
+## platform
+platform-too-short = plus court que { $min } caractères
+platform-too-long = plus long que { $max } caractères
+platform-invalid-character = contient '{ $character }'
+platform-no-letter-start = ne commence pas par une lettre
+platform-symbol-edge = commence ou se termine par un symbole
+platform-double-symbol = contient deux symboles à la suite
+
+## policy
+policy-invalid = Politique invalide « { $path } » : { $error }
+policy-unknown-rule = règle inconnue « { $rule } », attendu l'une de { $keys } ou un jeu dans [{ $tables }].
+policy-length-range = « { $key } » doit être compris entre 1 et 65536.
+policy-max-below-min = « max_length » est plus court que « min_length ».
+policy-length-outside = « length » est en dehors de « min_length » et « max_length ».
+policy-max-consecutive = « max_consecutive » doit valoir au moins 1.
+policy-unknown-set = « { $key } » n'est pas un jeu de caractères.
+policy-count-range = « { $key } » doit être compris entre 0 et 65536.
+policy-no-characters = aucun caractère n'est autorisé.
+policy-required-unavailable = « { $set } » est exigé, mais aucun de ses caractères n'est autorisé.
+policy-max-counts = « { $set } » a besoin de plus de caractères que « max_counts » n'en autorise.
+policy-ends-unavailable = aucun des caractères de « { $key } » n'est autorisé.
+policy-max-length-required = « max_length » est trop court pour tous les jeux de caractères exigés.
+policy-needs-length-between = { $policy } a besoin d'une longueur comprise entre { $min } et { $max }.
+policy-needs-length = { $policy } a besoin d'une longueur d'au moins { $min }.
+policy-requires-set = { $policy } exige « { $set } », que les jeux de caractères choisis n'ont pas.
+policy-requires-sets = { $policy } exige { $count } jeux de caractères, qui ne tiennent pas en { $length } caractères.
+policy-impossible = { $policy } a des règles qu'aucun mot de passe de { $length } caractères ne peut suivre.
+policy-max-consecutive-impossible = { $policy } autorise au plus { $max } fois le même caractère à la suite, mais il ne reste qu'un caractère.
+policy-entropy-impossible = { $policy } exige { $bits } bits d'entropie, { $length } caractères peuvent en avoir environ { $best }.
+check-min-length = Longueur de { $length }, au moins { $min }.
+check-max-length = Longueur de { $length }, au plus { $max }.
+check-allowed = Uniquement des caractères des jeux autorisés.
+check-outside = Caractères hors des jeux autorisés : { $characters }
+check-not-forbidden = Aucun caractère interdit.
+check-forbidden = Caractères interdits : { $characters }
+check-contains = Contient « { $set } ».
+check-contains-no = Ne contient rien de « { $set } ».
+check-min-count = Au moins { $min } de « { $set } », en a { $count }.
+check-max-count = Au plus { $max } de « { $set } », en a { $count }.
+check-consecutive = Au plus { $max } fois le même caractère à la suite, en a { $longest }.
+check-entropy = Entropie d'environ { $bits } bits, au moins { $min }.
+conform-removed = { $count } caractères non autorisés supprimés.
+conform-appended-set = Ajout d'un caractère aléatoire de type { $set }.
+conform-padded = Ajout de { $characters } pour atteindre la longueur minimale de { $min }.
+conform-trimmed = { $count } caractères supprimés à la fin pour tenir dans la longueur maximale de { $max }.
+conform-entropy = Ajout de { $characters } pour atteindre { $bits } bits d'entropie.
+conform-runs = Remplacement de { $characters } pour casser les suites du même caractère.
+conform-max-count = Remplacement de { $characters } pour avoir au plus { $max } de « { $set } ».
+conform-failed = Impossible de faire suivre la politique au mot de passe, il enfreint encore une règle : { $rule }
+validate-broken = Le mot de passe enfreint { $failed } règles sur { $total }.
+validate-followed = Le mot de passe suit la politique.
+conform-nothing = Le mot de passe suit déjà la politique.
+conform-entropy-change = Entropie d'environ { $bits } bits, contre { $before } avant.
+compare-same = Les deux politiques génèrent des mots de passe de même force.
+compare-stronger = { $stronger } génère des mots de passe plus forts de { $bits } bits que { $weaker }, { $guesses } fois plus de tentatives.
+compare-nothing = rien
+compare-length-range = { $length } (de { $min } à { $max })
+compare-length-open = { $length } ({ $min } ou plus)
+compare-met = { $bits } bits, atteint
+compare-missed = { $bits } bits, manqué
+compare-none = aucun
+conform-made-uppercase = Le caractère { $position } mis en majuscule.
+conform-made-lowercase = Le caractère { $position } mis en minuscule.
+conform-replaced-first = Le premier caractère remplacé par un caractère aléatoire de type { $set }.
+conform-replaced-last = Le dernier caractère remplacé par un caractère aléatoire de type { $set }.
+conform-added-first = Ajout d'un caractère aléatoire de type { $set } en premier caractère.
+conform-added-last = Ajout d'un caractère aléatoire de type { $set } en dernier caractère.
+check-starts = Commence par { $sets }.
+check-ends = Se termine par { $sets }.
+or = ou
+set-lower = minuscule
+set-upper = majuscule
+set-digit = chiffre
+set-symbol = symbole
+set-rare-symbol = symbole rare
+set-latin-1 = Latin-1
+set-greek = lettre grecque
+set-emoji = emoji
+check-pass = ok
+check-fail = échec
+compare-policy = Politique
+compare-length = Longueur
+compare-characters = Caractères
+compare-required = Exigés
+compare-keyspace = Espace de clés
+compare-entropy = Entropie
+compare-lost = Perdu par les règles
+compare-target = Objectif d'entropie
+compare-shortest = Longueur minimale
+bits = { $bits } bits
+
+## prompt
+prompt-number-range = Saisissez un nombre entre 1 et { $max }.
+prompt-yes-no = Répondez par oui ou non.
+prompt-pick = Choisissez-en un à copier (1-{ $count }, vide pour annuler) :
+prompt-unreadable-stdin = Impossible de lire stdin : { $error }
+prompt-unreadable = Impossible de lire la saisie : { $error }
+prompt-repeat = Répétez pour confirmer :
+prompt-mismatch = Les deux saisies ne correspondent pas.
+
+## request
+request-not-object = La requête doit être un objet JSON.
+request-char-sets = « char_sets » doit être une liste de noms.
+request-unknown-set = Jeu de caractères inconnu { $set }.
+request-unknown-password-option = Option de mot de passe inconnue « { $key } ».
+request-site-and-policy = « site » et « policy » ne peuvent pas être combinés.
+request-separator = « separator » doit être un seul caractère.
+request-unknown-passphrase-option = Option de phrase de passe inconnue « { $key } ».
+request-unknown-command = Commande inconnue « { $command } », utilisez « password » ou « passphrase ».
+request-no-command = La requête a besoin d'un « command ».
+request-not-positive = « { $key } » doit être un nombre entier positif.
+request-not-string = « { $key } » doit être une chaîne.
+
+## salt
+salt-bytes-range = Les octets doivent être compris entre 1 et 65536.
+
+## sandbox
+sandbox-failed = Impossible de placer pwgen dans un bac à sable : { $error }
+sandbox-unveil-path = Impossible d'appliquer unveil à « { $path } » : { $error }
+sandbox-unveil = Impossible d'appliquer unveil : { $error }
+sandbox-pledge = Impossible d'appliquer pledge : { $error }
+
+## setup
+setup-welcome = Bienvenue dans pwgen ! On dirait que c'est la première fois que vous l'utilisez.
+setup-ask = Configurer vos valeurs par défaut maintenant ?
+setup-skipped = Ignoré, lancez `pwgen setup` pour le faire plus tard.
+setup-length = Longueur des mots de passe ? [16]
+setup-length-range = Saisissez une longueur entre 4 et 65536.
+setup-symbols = Les symboles sont-ils acceptables dans les mots de passe ?
+setup-copy = Copier les secrets générés dans le presse-papiers ?
+setup-clear = Vider le presse-papiers après combien de secondes ? 0 ne le vide jamais. [0]
+setup-clear-range = Saisissez un nombre de secondes jusqu'à 86400.
+setup-saved = Vos valeurs par défaut ont été enregistrées dans { $path }.
+
+## shamir
+shamir-different-secrets = Les parts appartiennent à des secrets différents.
+shamir-different-lengths = Les parts ont des longueurs différentes.
+shamir-too-few = { $threshold } parts différentes sont nécessaires, seulement { $given } ont été données.
+shamir-invalid-secret = Les parts ne se combinent pas en un secret valide.
+shamir-invalid-share = « { $share } » n'est pas une part valide.
+shamir-typo = « { $share } » contient une faute de frappe, sa somme de contrôle ne correspond pas.
+
+## sites
+sites-invalid = Règles de sites invalides « { $path } » : { $error }
+sites-invalid-rules = Règles de mot de passe invalides pour { $site } : { $error }
+sites-unknown = Aucune règle de mot de passe connue pour { $site }, voir `--site` dans le readme pour en ajouter.
+sites-expected-property = « nom: valeur » attendu dans « { $property } »
+sites-not-number = « { $key } » doit être un nombre
+sites-max-below-min = « maxlength » est plus court que « minlength »
+sites-unknown-class = classe de caractères inconnue « { $class } »
+
+## skey
+skey-invalid-seed = La graine doit comporter de 1 à 16 lettres et chiffres.
+skey-passphrase-range = La phrase de passe doit comporter entre 10 et 63 caractères.
+skey-server-setup = Configuration du serveur : { $algorithm } { $count } { $seed } { $key }
+
+## sshkey
+sshkey-exists = « { $path } » existe déjà, utilisez --force pour l'écraser.
+sshkey-derive-failed = Impossible de dériver la clé de la phrase de passe : { $error }
+sshkey-no-home = Impossible de trouver le répertoire personnel, utilisez --out pour choisir où enregistrer la clé.
+sshkey-passphrase = Phrase de passe : { $passphrase }
+
+## stats
+stats-online = Attaque en ligne
+stats-offline-slow = Hors ligne, hachage lent
+stats-offline-fast = Hors ligne, hachage rapide
+stats-long-term = Secrets à long terme
+stats-no-command = Indiquez une commande sur laquelle faire le rapport, comme « password ».
+stats-word-dependent = L'entropie qu'ajoutent --leet et --capitalize et qu'enlève --max-length dépend des mots choisis, elle n'est pas comptée.
+stats-separator-set = L'entropie qu'ajoute --separator-set n'est pas comptée, --show-strength de la phrase de passe l'inclut.
+stats-unsupported = Les statistiques ne sont disponibles que pour password, passphrase et token.
+stats-pool-character = Réserve de { $pool } caractères, { $bits } bits par caractère.
+stats-pool-word = Réserve de { $pool } mots, { $bits } bits par mot.
+stats-length-character = Longueur de { $amount } caractères.
+stats-length-word = Longueur de { $amount } mots.
+stats-keyspace = Espace de clés de { $keyspace } (2^{ $bits }).
+stats-heading-character = Caractères
+stats-heading-word = Mots
+stats-target = Cible
+stats-bits = Bits
+stats-met = atteint
+
+## stdout_only
+stdout-only-conflict = --stdout-only ne peut pas être utilisé avec { $conflict }.
+stdout-only-unique = --unique, qui écrit le nom d'utilisateur dans un fichier d'historique
+stdout-only-history = --history, qui écrit le nom de code dans un fichier
+stdout-only-sshkey = sshkey, qui écrit la clé dans des fichiers
+stdout-only-keyring = les commandes qui utilisent le trousseau
+stdout-only-daemon = daemon, qui sert les secrets sur un socket
+stdout-only-stored = les options qui enregistrent le secret ailleurs
+stdout-only-program = --systemd-cred et --gpg-recipient, qui confient le secret à un autre programme
+stdout-only-picker = --picker, qui affiche le secret dans un autre programme
+
+## strength
+boost-leet = +{ $bits } bits grâce aux substitutions leet.
+boost-capitalization = +{ $bits } bits grâce aux majuscules aléatoires.
+boost-separators = +{ $bits } bits grâce aux séparateurs aléatoires.
+
+## testcard
+testcard-brand = Marque : { $brand } (CARTE DE TEST, inutilisable pour des paiements)
+testcard-number = Numéro : { $number }
+testcard-expiry = Expiration : { $expiry }
+testcard-cvc = CVC : { $cvc }
+
+## token
+token-invalid = Le jeton est invalide ! Il a probablement été mal saisi.
+token-valid = Le jeton est valide.
+
+## username
+username-no-config-directory = Aucun répertoire de configuration pour conserver les noms d'utilisateur attribués, choisissez un fichier avec --history.
+username-alliteration-style = L'allitération ne fonctionne qu'avec le style words.
+username-platform-length = Les noms d'utilisateur { $platform } ont besoin d'au moins { $min } caractères.
+username-empty-search = Le texte à rechercher ne peut pas être vide.
+username-issued = déjà attribué
+username-blocked = bloqué car il contient « { $word } »
+username-embed-failed = Impossible d'intégrer « { $text } » dans un nom d'utilisateur valide, le résultat restait { $problem }.
+username-from-style = --from ne fonctionne qu'avec le style words.
+username-from-empty = Le texte dont dériver le nom d'utilisateur ne peut pas être vide.
+username-from-numbers = --from prend en charge jusqu'à 19 chiffres.
+username-derive-failed = Impossible de dériver un nom d'utilisateur qui respecte les options !
+username-keyspace = Noms d'utilisateur possibles : { $keyspace } ({ $bits } bits).
+username-duplicate-chance = Probabilité d'un doublon parmi { $users } utilisateurs : { $chance }, sous { $threshold } % jusqu'à { $unique } utilisateurs.
+username-chance-tiny = moins de 0,01 %
+username-none-fit = Aucun nom d'utilisateur ne respecte les options !
+username-too-many-numbers = Trop de chiffres ! Il ne peut pas y en avoir plus de 65536.
+username-no-number = Impossible de choisir un nombre qui ne ressemble pas à une année !
+username-no-adjectives = Aucun adjectif ne respecte les longueurs de mots et les mots bloqués ! Essayez de les élargir.
+username-no-objects = Aucun objet ne respecte les longueurs de mots et les mots bloqués ! Essayez de les élargir.
+username-invalid-for-platform = Impossible de générer un nom d'utilisateur { $platform } valide, le résultat restait { $problem }.
+username-invalid = Impossible de générer un nom d'utilisateur valide, le résultat restait { $problem }.
+username-character-not-allowed = '{ $character }' n'est pas autorisé dans les noms d'utilisateur { $platform }.
+
+## vaults
+vaults-deleted = « { $name } » supprimé.
+vaults-empty = Aucun secret enregistré pour l'instant, enregistrez-en un avec --keyring <NOM>.
+
+## wordlist
+wordlist-invalid-name = Nom de liste de mots invalide « { $name } » ! Seuls les lettres, les chiffres, '-' et '_' sont autorisés.
+wordlist-built-in-replace = « { $name } » est une liste de mots intégrée et ne peut pas être remplacée.
+wordlist-too-small = La liste de mots a besoin d'au moins 2 mots valides distincts.
+wordlist-exists = Une liste de mots nommée « { $name } » existe déjà. Utilisez --force pour la remplacer.
+wordlist-imported = { $count } mots importés sous « { $name } ».
+wordlist-duplicates = { $count } mots en double ignorés.
+wordlist-rejected = { $count } mots contenant des espaces ou des caractères de contrôle rejetés (lignes { $lines }).
+wordlist-entropy = Entropie par mot : { $bits } bits.
+wordlist-listed-built-in = { $name } (intégrée, { $count } mots)
+wordlist-listed = { $name } ({ $count } mots)
+wordlist-info-name = Nom : { $name }
+wordlist-info-words = Mots : { $count }
+wordlist-info-length = Longueur des mots : de { $min } à { $max } caractères
+wordlist-info-entropy = Entropie par mot : { $bits } bits
+wordlist-built-in-remove = « { $name } » est une liste de mots intégrée et ne peut pas être supprimée.
+wordlist-not-imported = Aucune liste de mots importée nommée « { $name } ».
+wordlist-removed = Liste de mots « { $name } » supprimée.
+wordlist-checked = { $count } mots vérifiés dans « { $name } ».
+wordlist-no-problems = Aucun problème trouvé.
+wordlist-unnormalized = { $count } mots sont en majuscules, en double ou contiennent des espaces : { $examples }
+wordlist-prefixes = { $count } mots sont le début d'un autre mot, ce qui peut rendre les mots accolés ambigus : { $examples }
+wordlist-confusable = { $count } groupes de mots se ressemblent une fois écrits : { $examples }
+wordlist-more = et { $count } de plus
+wordlist-unknown = Aucune liste de mots nommée « { $name } ».
+wordlist-unknown-hint = Aucune liste de mots nommée « { $name } ». Utilisez « pwgen wordlist list » pour voir toutes les listes.
+wordlist-too-long = Impossible de tenir en { $max } caractères ! Le résultat le plus court possible fait { $shortest } caractères.
+wordlist-alliteration-too-long = Impossible de faire tenir un résultat allitératif en { $max } caractères !
+wordlist-no-alliteration = Aucun mot des listes ne commence par la même lettre !
+wordlist-info-built-in = Source : intégrée
+wordlist-info-imported = Source : importée
+
+## xkpasswd
+xkpasswd-negative = « { $key } » ne peut pas être négatif.
+xkpasswd-unknown-case = case_transform inconnu « { $value } ».
+xkpasswd-unknown-padding = padding_type inconnu « { $value } ».
+xkpasswd-unknown-setting = Paramètre xkpasswd inconnu « { $key } ».
+xkpasswd-words-range = num_words doit être compris entre 1 et 100.
+xkpasswd-word-length = word_length_min doit valoir au moins 1 et au plus word_length_max.
+xkpasswd-separator-alphabet = Un separator_character aléatoire a besoin d'un separator_alphabet.
+xkpasswd-symbol-alphabet = Un padding_character aléatoire a besoin d'un symbol_alphabet.
+xkpasswd-no-separator = padding_character ne peut pas être le séparateur quand il n'y en a pas.
+xkpasswd-pad-to-length = Le remplissage adaptatif a besoin d'un pad_to_length.
+xkpasswd-character = « { $key } » doit être un caractère, « RANDOM » ou « NONE ».
+xkpasswd-single-characters = « { $key } » ne doit contenir que des caractères seuls.
+xkpasswd-character-list = « { $key } » doit être une liste de caractères.
+xkpasswd-not-object = « { $path } » doit contenir un objet JSON de paramètres.
+xkpasswd-unsupported-value = Le paramètre « { $key } » a une valeur non prise en charge.
+xkpasswd-unknown-scheme = Aucun schéma xkpasswd nommé « { $scheme } » dans le fichier de configuration.
+xkpasswd-invalid = Paramètres invalides « { $path } » : { $error }
+xkpasswd-no-words = La liste de mots n'a pas de mots de { $min } à { $max } lettres.
@@ -0,0 +1,668 @@
+copied = { $name } gekopieerd naar het klembord.
+copy-failed = Kopiëren naar het klembord is mislukt.
+copy-unsupported = Kopiëren naar het klembord is niet mogelijk omdat het besturingssysteem dit niet ondersteunt.
+clearing = Het klembord wordt over { $seconds } seconden gewist.
+written = { $name } opgeslagen in { $destination }.
+no-password-picked = Geen wachtwoord gekozen.
+no-passphrase-picked = Geen wachtwoordzin gekozen.
+suggestions-range = Het aantal suggesties moet tussen 1 en 1000 liggen.
+otp-valid = Nog { $seconds } seconden geldig.
+saved-private-key = Privésleutel opgeslagen in "{ $path }".
+saved-public-key = Publieke sleutel opgeslagen in "{ $path }".
+unknown-language = Onbekende taal "{ $language }", beschikbaar zijn { $languages }.
+entropy = Entropie: { $bits } bits ({ $rating }).
+rating-weak = zwak
+rating-fair = redelijk
+rating-strong = sterk
+rating-very-strong = zeer sterk
+porcelain-unsupported = Deze opdracht ondersteunt --porcelain niet.
+again-itself = De laatste opdracht is `again` zelf, genereer eerst iets nieuws.
+again-nothing = `again` kan alleen een opdracht herhalen die iets heeft gegenereerd.
+again-hidden = Met --copy wordt het laatste geheim gekopieerd, met -i getoond.
+
+noun-api-key = API-sleutel
+noun-card-number = Kaartnummer
+noun-codename = Codenaam
+noun-database-password = Databasewachtwoord
+noun-email = E-mailadres
+noun-excluded-characters = Uitgesloten tekens
+noun-hash = Hash
+noun-ip-address = IP-adres
+noun-license-key = Licentiesleutel
+noun-mac-address = MAC-adres
+noun-name = Naam
+noun-nano-id = Nano ID
+noun-otp-code = OTP-code
+noun-otp-secret = OTP-geheim
+noun-pass-phrase = Wachtwoordzin
+noun-passphrase = Wachtwoordzin
+noun-password = Wachtwoord
+noun-petname = Petname
+noun-public-key = Publieke sleutel
+noun-salt = Salt
+noun-secret = Geheim
+noun-share = Deel
+noun-slug = Slug
+noun-token = Token
+noun-username = Gebruikersnaam
+
+## accounts
+count-range = Het aantal moet tussen 1 en { $max } liggen.
+accounts-too-few = Slechts { $count } verschillende gebruikersnamen gevonden, probeer een lager aantal.
+
+## alias
+alias-unterminated-quote = In configuratiewaarde "{ $key }" wordt een aanhalingsteken niet gesloten.
+alias-empty = Configuratiewaarde "{ $key }" is leeg.
+
+## apikey
+apikey-unknown-profile = Geen API-sleutelprofiel met de naam "{ $profile }" in het configuratiebestand.
+apikey-negative-bytes = Configuratiewaarde "{ $table }.bytes" mag niet negatief zijn.
+apikey-checksum-choice = Configuratiewaarde "{ $table }.checksum" moet "none" of "crc32" zijn.
+apikey-bytes-range = Het aantal bytes moet tussen 8 en 1024 liggen.
+apikey-prefix-whitespace = Het voorvoegsel mag geen witruimte bevatten.
+
+## bench
+bench-single = { $name } { $rate }/s op 1 thread
+bench-multi = { $name } { $rate }/s op 1 thread, { $multi-rate }/s op { $threads } threads
+bench-rng = { $name } { $rate } MB/s op 1 thread
+
+## breach
+breach-info = { $count } gelekte wachtwoorden in { $size } KB.
+unable-to-read = Kan "{ $path }" niet lezen: { $error }
+breach-no-passwords = "{ $path }" bevat geen wachtwoorden.
+unable-to-create = Kan "{ $path }" niet aanmaken: { $error }
+unable-to-write = Kan "{ $path }" niet schrijven: { $error }
+breach-imported = { $count } gelekte wachtwoorden geïmporteerd.
+breach-found = Dit wachtwoord staat in het lekfilter, gebruik het niet.
+breach-not-found = Dit wachtwoord staat niet in het lekfilter.
+breach-dead-end = Alle { $attempts } pogingen stonden in het lekfilter, sta meer tekens of een grotere lengte toe.
+no-config-directory = Kan de configuratiemap niet vinden.
+breach-not-imported = Nog geen lekfilter geïmporteerd, zie `pwgen breach import --help`.
+breach-invalid = "{ $path }" is geen geldig lekfilter, importeer het opnieuw.
+
+## codename
+unable-to-open = Kan "{ $path }" niet openen: { $error }
+codename-exhausted = Geen codenaam gevonden die nog niet is uitgegeven!
+
+## config
+config-missing-bracket = regel { $line }: ']' ontbreekt
+config-text-after-table = regel { $line }: onverwachte tekst na de tabelnaam
+config-invalid-table = regel { $line }: ongeldige tabelnaam
+config-expected-key-value = regel { $line }: "sleutel = waarde" verwacht
+config-invalid-key = regel { $line }: ongeldige sleutel
+config-invalid-value = regel { $line }: ongeldige waarde
+config-text-after-value = regel { $line }: onverwachte tekst na de waarde
+config-set-twice = regel { $line }: "{ $key }" is twee keer ingesteld
+invalid-config-file = Ongeldig configuratiebestand "{ $path }": { $error }
+config-not-string = Configuratiewaarde "{ $key }" moet een tekenreeks zijn.
+config-not-boolean = Configuratiewaarde "{ $key }" moet true of false zijn.
+config-not-integer = Configuratiewaarde "{ $key }" moet een geheel getal zijn.
+config-set-not-integer = "{ $key }" moet een geheel getal zijn.
+config-set-not-boolean = "{ $key }" moet true of false zijn.
+config-set = { $key } = { $value } ingesteld
+config-not-set = "{ $key }" is niet ingesteld.
+config-nothing-set = Er is nog niets ingesteld, zie `pwgen config list --all` voor wat kan.
+config-editor-unstartable = Kan de editor "{ $editor }" niet starten: { $error }
+config-editor-failed = De editor "{ $editor }" is mislukt.
+config-warning = Waarschuwing: { $message }
+config-imported = { $count } waarden geïmporteerd.
+
+## coupons
+length-range = De lengte moet tussen 1 en { $max } liggen.
+coupons-too-few = Er bestaan maar { $possible } verschillende codes van lengte { $length }, gebruik een grotere lengte voor { $count } codes.
+
+## daemon
+daemon-no-runtime-dir = XDG_RUNTIME_DIR is niet ingesteld, kies een socket met --socket.
+daemon-already-listening = Een andere daemon luistert al op "{ $path }".
+unable-to-remove = Kan "{ $path }" niet verwijderen: { $error }
+daemon-unable-to-listen = Kan niet luisteren op "{ $path }": { $error }
+daemon-unable-to-restrict = Kan "{ $path }" niet afschermen: { $error }
+daemon-listening = Luistert op { $path }
+daemon-unsupported = De daemon heeft Unix-sockets nodig, die dit platform niet heeft.
+
+## defaults
+defaults-unknown-key-suggestion = Onbekende configuratiesleutel "{ $key }", bedoel je "{ $closest }"?
+defaults-unknown-key = Onbekende configuratiesleutel "{ $key }", zie `pwgen config list --all` voor alle sleutels.
+defaults-unknown-command = "{ $command }" is geen opdracht en geen alias.
+defaults-length-range = Configuratiewaarde "password.length" moet tussen 1 en { $max } liggen.
+defaults-out-of-range = Configuratiewaarde "{ $key }" valt buiten het bereik.
+defaults-not-character = Configuratiewaarde "{ $key }" moet één teken zijn.
+defaults-not-strings = Configuratiewaarde "{ $key }" mag alleen tekenreeksen bevatten.
+defaults-not-array = Configuratiewaarde "{ $key }" moet een lijst met namen zijn.
+defaults-unknown-choice = Configuratiewaarde "{ $key }" heeft de onbekende keuze "{ $value }", verwacht een van { $choices }.
+
+## derive
+derive-empty-site = De site mag niet leeg zijn.
+derive-touch-yubikey = Raak je YubiKey aan als hij knippert.
+derive-yubikey-response = De YubiKey gaf een onverwacht antwoord, is het slot ingesteld voor HMAC-SHA1-challenge-response?
+derive-no-fido2 = Geen FIDO2-beveiligingssleutel gevonden, zit hij erin?
+derive-touch-security-key = Raak je beveiligingssleutel aan wanneer hij knippert.
+derive-no-hmac-secret = De beveiligingssleutel gaf geen hmac-secret terug, ondersteunt hij de extensie?
+derive-making-credential = Credential voor pwgen aanmaken op de beveiligingssleutel, raak hem aan wanneer hij knippert.
+derive-no-credential = De beveiligingssleutel gaf geen credential terug.
+
+## doctor
+doctor-ok = ok
+doctor-warn = let
+doctor-fail = fout
+doctor-check = { $check }: { $detail }
+doctor-failed = { $count ->
+        [one] 1 controle is mislukt.
+       *[other] { $count } controles zijn mislukt.
+    }
+doctor-random-source = Willekeurige bron
+doctor-display = Beeldscherm
+doctor-clipboard = Klembord
+doctor-keyring = Sleutelbos
+doctor-config = Configuratie
+doctor-terminal = Terminal
+doctor-random-ok = is geslaagd voor de gezondheidstest
+doctor-display-xwayland = Wayland, met X11 via XWayland
+doctor-display-wayland = Wayland zonder XWayland, het klembord heeft een ingestelde DISPLAY nodig
+doctor-display-x11 = X11
+doctor-display-none = WAYLAND_DISPLAY en DISPLAY zijn allebei niet ingesteld, er is geen klembord om naar te kopiëren
+doctor-clipboard-ok = geheimen kunnen worden gekopieerd
+doctor-clipboard-unreadable = verbonden, maar lezen is mislukt: { $error }
+doctor-clipboard-unavailable = niet beschikbaar, gebruik --no-copy: { $error }
+doctor-osc52-tmux = tmux geeft het door met `set -g set-clipboard on`
+doctor-osc52-supported = { $terminal } ondersteunt het
+doctor-osc52-no-terminal = geen terminal gevonden
+doctor-osc52-unknown = onbekend of "{ $terminal }" het ondersteunt
+doctor-osc52-remote = { $detail }, via SSH is het externe klembord niet het jouwe
+doctor-keyring-ok = beschikbaar, { $count } geheimen opgeslagen door pwgen
+doctor-keyring-unavailable = niet beschikbaar, --keyring en git-credential werken niet: { $error }
+doctor-config-no-directory = geen configuratiemap gevonden
+doctor-config-none = nog geen op "{ $path }"
+doctor-config-valid = "{ $path }" is geldig
+doctor-terminal-ok = geheimen worden gevraagd zonder ze te tonen
+doctor-terminal-stdin = stdin is geen terminal, geheimen worden er regel voor regel uit gelezen
+doctor-terminal-stdout = stdout is geen terminal, getoonde geheimen komen terecht waar het ook heen gaat
+doctor-tool-installed = geïnstalleerd, { $option } werkt
+doctor-tool-missing = niet geïnstalleerd, nodig voor { $option }
+
+## email
+email-invalid-domain = "{ $domain }" is geen geldig domein!
+email-invalid-tag = "{ $tag }" is geen geldige tag! Alleen letters, cijfers, '.', '-' en '_' zijn toegestaan.
+email-invalid-mailbox = "{ $mailbox }" is geen geldige mailboxnaam!
+
+## external
+external-not-installed = Kan { $program } niet uitvoeren, is het geïnstalleerd? { $error }
+external-unable-to-run = Kan { $program } niet uitvoeren: { $error }
+external-failed = { $program } is mislukt: { $error }
+
+## git_credential
+git-credential-generated = Een nieuw wachtwoord gegenereerd voor { $name }.
+git-credential-unreadable = Kan niet lezen van git: { $error }
+
+## hardening
+hardening-core-dumps = Kan core dumps niet uitschakelen: { $error }
+hardening-dumpable = Kan het proces niet beschermen tegen dumps: { $error }
+hardening-lock = Kan het geheugen niet vergrendelen, is `ulimit -l` te laag? { $error }
+hardening-working-set = Kan de working set voor het vergrendelen van geheugen niet vergroten: { $error }
+hardening-lock-unsupported = Geheugen vergrendelen wordt op dit platform niet ondersteund.
+
+## hash
+hash-cost-bcrypt-only = --cost geldt alleen voor bcrypt.
+hash-iterations-range = Het aantal iteraties moet tussen 1 en 1000 liggen.
+hash-parallelism-range = Het parallellisme moet tussen 1 en 255 liggen.
+hash-memory-range = Het geheugen moet tussen { $min } KiB en 4 GiB liggen.
+hash-argon2-salt = Het salt moet minstens 8 bytes base64 zijn.
+hash-argon2-only = --memory, --iterations en --parallelism gelden alleen voor argon2id.
+hash-cost-range = De kosten moeten tussen 4 en 31 liggen.
+hash-bcrypt-salt = Het salt moet 22 tekens in de base64 van bcrypt zijn.
+hash-invalid-parameters = Ongeldige argon2id-parameters: { $error }
+hash-failed = Kan het wachtwoord niet hashen: { $error }
+hash-bcrypt-too-long = bcrypt negeert alles na { $bytes } bytes, gebruik argon2id voor langere wachtwoorden.
+
+## health
+health-unavailable = De willekeurige bron van het systeem is niet beschikbaar: { $error }
+health-failed = De willekeurige bron van het systeem is gezakt voor de gezondheidstest, { $reason }. Er worden geen geheimen mee gegenereerd.
+health-repetition = een byte herhaalt zich te vaak achter elkaar
+health-proportion = een byte komt veel vaker voor dan de andere
+
+## history
+history-given-secret = De laatste opdracht kreeg een geheim mee, dus wordt hij niet bewaard om te herhalen.
+history-disabled = De geschiedenis staat niet aan, zet hem aan met `pwgen config set history.enabled true`.
+history-nothing-since-enabled = Er is niets gegenereerd sinds de geschiedenis aan staat.
+history-no-config-directory = Geen configuratiemap om de laatste opdracht in te bewaren.
+history-nothing-yet = Er is nog niets gegenereerd.
+history-damaged = "{ $path }" is beschadigd, genereer iets om het te vervangen.
+
+## honeywords
+honeywords-empty-reference = De referentie mag niet leeg zijn.
+honeywords-nothing-to-vary = De referentie heeft minstens één letter, cijfer of symbool nodig om te variëren.
+honeywords-too-few = Slechts { $count } verschillende lokwoorden hebben deze vorm, probeer een langere referentie of een lager aantal.
+
+## id
+id-too-many-ulids = Te veel ULID's binnen één milliseconde!
+id-too-many-ksuids = Te veel KSUID's binnen één seconde!
+
+## identity
+identity-age-order = De minimumleeftijd mag niet hoger zijn dan de maximumleeftijd.
+identity-age-max = De maximumleeftijd mag niet hoger zijn dan 150.
+
+## invented
+invented-too-long = Er past geen verzonnen naam in { $length } tekens!
+
+## ip
+ip-exhausted = Geen adres in dit subnet gevonden dat niet gereserveerd is!
+ip-invalid-cidr = "{ $cidr }" is geen geldig CIDR-blok! Voorbeeld: "10.0.0.0/8".
+ip-invalid-prefix = "{ $prefix }" is geen geldige prefixlengte!
+ip-v4-prefix = De IPv4-prefixlengte mag niet groter zijn dan 32.
+ip-v6-prefix = De IPv6-prefixlengte mag niet groter zijn dan 128.
+ip-invalid-address = "{ $address }" is geen geldig IP-adres!
+
+## json
+json-invalid = Ongeldige JSON bij byte { $position }: { $reason }
+json-text-after-value = onverwachte tekst na de waarde
+json-expected = '{ $character }' verwacht
+json-unknown-value = onbekende waarde
+json-unexpected-end = onverwacht einde
+json-nested-too-deeply = te diep genest
+json-expected-comma-bracket = ',' of ']' verwacht
+json-expected-field-name = veldnaam verwacht
+json-expected-comma-brace = ',' of '{"}"}' verwacht
+json-unterminated-string = niet-afgesloten tekenreeks
+json-invalid-escape = ongeldige escape
+json-control-character = besturingsteken in tekenreeks
+json-invalid-utf8 = ongeldige UTF-8
+json-unpaired-surrogate = ongepaard surrogaat
+json-invalid-number = ongeldig getal
+
+## keyring
+keyring-invalid-name = Ongeldige naam "{ $name }" voor een geheim!
+keyring-not-found = Geen geheim met de naam "{ $name }" in de sleutelbos.
+keyring-not-utf8 = Het geheim "{ $name }" is geen geldige UTF-8.
+keyring-not-in-keychain = Geen geheim met de naam "{ $name }" in de sleutelhanger.
+keyring-unable-to-save = Kan "{ $name }" niet opslaan: { $error }
+keyring-not-in-credential-manager = Geen geheim met de naam "{ $name }" in Referentiebeheer.
+keyring-unsupported = Er is geen ondersteunde opslag voor geheimen op dit platform.
+
+## license
+license-no-x = Het patroon heeft minstens één 'X' nodig.
+license-too-few-x = Het patroon heeft meer dan { $checksum } 'X' nodig om een controlesom te bevatten.
+license-invalid = Licentiesleutel is ongeldig! { $problem }
+license-valid = Licentiesleutel is geldig.
+license-pattern-mismatch = Hij komt niet overeen met het patroon.
+license-too-short = Het patroon is te kort om een controlesom te bevatten.
+license-checksum-mismatch = De controlesom klopt niet.
+license-invalid-character = '{ $character }' is geen geldig teken.
+
+## nanoid
+nanoid-size-range = De grootte moet tussen 1 en 65536 liggen.
+nanoid-alphabet-range = Het alfabet moet tussen 2 en 256 tekens bevatten.
+nanoid-duplicate = Het alfabet bevat '{ $character }' meer dan eens, waardoor het waarschijnlijker wordt.
+
+## native_host
+native-host-too-long = Bericht van { $length } bytes is te lang, verbinding wordt gesloten.
+native-host-unreadable = Kan geen bericht van de browser lezen: { $error }
+native-host-unanswerable = Kan de browser niet antwoorden: { $error }
+
+## otp
+otp-digits-range = Het aantal cijfers moet tussen 6 en 10 liggen.
+otp-period-range = De periode moet minstens 1 seconde zijn.
+otp-invalid-secret = Het geheim is geen geldige base32.
+otp-clock-before-1970 = De systeemklok staat voor 1970.
+otp-bytes-range = Het aantal bytes moet tussen 10 en 64 liggen.
+otp-colon = Uitgever en account mogen geen ':' bevatten.
+otp-empty = Uitgever en account mogen niet leeg zijn.
+
+## output
+output-file = "{ $path }"
+output-vault = Vault op { $target }
+output-bitwarden = Bitwarden als "{ $name }"
+output-1password = 1Password als "{ $title }"
+output-kdbx = "{ $database }" als "{ $entry }"
+output-keyring = de sleutelbos als "{ $name }"
+output-docker = het { $engine }-secret "{ $name }"
+output-vault-password-file-only = --vault-password-file geldt alleen voor --output ansible-vault.
+output-dotenv-invalid-name = "{ $key }" is geen geldige variabelenaam voor dotenv.
+output-dotenv-invalid-secret = Het geheim bevat een ' of een besturingsteken, dat een dotenv-waarde tussen enkele aanhalingstekens niet kan bevatten. Laat ze weg met --exclude.
+output-needs-vault-password-file = --output ansible-vault heeft een --vault-password-file nodig.
+output-no-vault-password = "{ $path }" bevat geen vault-wachtwoord.
+output-not-appendable = Alleen aan plain-, dotenv-, CSV-, YAML- en TOML-uitvoer kan worden toegevoegd.
+output-exists = "{ $path }" bestaat al, voeg --append toe om het geheim eraan toe te voegen.
+output-unable-to-append = Kan niet toevoegen aan "{ $path }": { $problem }
+output-toml-table = het geheim zou in de laatste tabel van het TOML-bestand belanden.
+output-key-exists = het heeft al "{ $key }", kies een andere --key.
+output-vault-target = --vault-write verwacht PAD#VELD, zoals secret/data/myapp#password.
+output-no-vault-addr = VAULT_ADDR moet op het adres van de Vault-server staan.
+output-bw-locked = Ontgrendel eerst de kluis met `bw unlock` en exporteer BW_SESSION.
+output-kdbx-missing = "{ $path }" bestaat niet.
+
+## passphrase
+passphrase-no-words = De wachtwoordzin heeft minstens één woord nodig.
+passphrase-too-many-words = Te veel woorden! Het mogen er niet meer dan 1024 zijn.
+
+## password
+password-hybrid-conflict = --style hybrid kan niet samen met --mobile-friendly, --alternate-hands of --no-patterns.
+wordlist-empty = De woordenlijst is leeg!
+password-policy-kept-failing = de regels van { $policy } bleven mislukken, als laatste op "{ $problem }". Probeer een grotere lengte of meer pogingen
+password-patterns-kept-failing = --no-patterns bleef { $pattern } vinden. Probeer een kleinere lengte of meer tekensets
+password-tried = in { $attempts } pogingen
+password-dead-end = Geen wachtwoord volgde de regels { $tried }, { $reason }.
+explain-sets = Tekensets: { $sets }.
+explain-excluded = { $count } tekens uitgesloten: { $characters }
+explain-pool = Voorraad van { $count } tekens, { $bits } bits per teken.
+explain-weight = { $set } { $factor } keer
+explain-weights = { $weights } zo vaak gekozen, { $bits } bits per teken.
+explain-hybrid-word = een woord uit { $count }
+random-characters = { $count ->
+        [one] 1 willekeurig teken
+       *[other] { $count } willekeurige tekens
+    }
+explain-hybrid = Hybride van { $parts }.
+explain-length = Lengte van { $length } tekens.
+explain-mobile = Letters, cijfers en één symbool elk in een blok, in willekeurige volgorde.
+explain-hands = Afwisselend { $left } tekens van de linkerhand en { $right } van de rechter.
+trade-off-hybrid = Het hybride wachtwoord heeft { $bits } bits entropie, het woord telt maar als de ene keuze die het is.
+trade-off = Dat is { $lost } bits minder dan de { $bits } bits van elk teken uit de hele voorraad kiezen.
+password-hands = --alternate-hands heeft tekens voor beide handen nodig.
+password-no-characters = Er zijn geen tekens toegestaan! Voeg meer tekensets toe of sluit minder tekens uit.
+password-too-long = Wachtwoord te lang! Het mag niet langer zijn dan { $max }.
+pattern-dictionary-word = een woord uit het woordenboek
+pattern-keyboard-walk = een toetsenbordreeks
+pattern-date = een datum
+password-tried-within = binnen { $seconds ->
+        [one] 1 seconde
+       *[other] { $seconds } seconden
+    } ({ $attempts } pogingen)
+explain-hybrid-digit = een cijfer uit { $count }
+explain-hybrid-symbol = een symbool uit { $count }
+
+## petname
+petname-words-range = Het aantal woorden moet tussen 1 en 16 liggen.
+petname-invalid-separator = Het scheidingsteken mag voor DNS-namen alleen kleine letters, cijfers en '-' bevatten.
+invalid-dns-label = "{ $label }" is geen geldig DNS-label!
+
+## picker
+picker-candidates-range = Het aantal kandidaten moet tussen 1 en 100 liggen.
+picker-unknown-choice = De kiezer gaf iets terug dat geen van de kandidaten is.
+
+## platform
+platform-too-short = korter dan { $min } tekens
+platform-too-long = langer dan { $max } tekens
+platform-invalid-character = bevat '{ $character }'
+platform-no-letter-start = begint niet met een letter
+platform-symbol-edge = begint of eindigt met een symbool
+platform-double-symbol = bevat twee symbolen op een rij
+
+## policy
+policy-invalid = Ongeldig beleid "{ $path }": { $error }
+policy-unknown-rule = onbekende regel "{ $rule }", verwacht een van { $keys } of een set in [{ $tables }].
+policy-length-range = "{ $key }" moet tussen 1 en 65536 liggen.
+policy-max-below-min = "max_length" is korter dan "min_length".
+policy-length-outside = "length" ligt buiten "min_length" en "max_length".
+policy-max-consecutive = "max_consecutive" moet minstens 1 zijn.
+policy-unknown-set = "{ $key }" is geen tekenset.
+policy-count-range = "{ $key }" moet tussen 0 en 65536 liggen.
+policy-no-characters = er zijn geen tekens toegestaan.
+policy-required-unavailable = "{ $set }" is verplicht, maar geen van de tekens ervan is toegestaan.
+policy-max-counts = "{ $set }" heeft meer tekens nodig dan "max_counts" toestaat.
+policy-ends-unavailable = geen van de tekens van "{ $key }" is toegestaan.
+policy-max-length-required = "max_length" is te kort voor alle verplichte tekensets.
+policy-needs-length-between = { $policy } heeft een lengte tussen { $min } en { $max } nodig.
+policy-needs-length = { $policy } heeft een lengte van minstens { $min } nodig.
+policy-requires-set = { $policy } vereist "{ $set }", die de gekozen tekensets niet hebben.
+policy-requires-sets = { $policy } vereist { $count } tekensets, die niet in { $length } tekens passen.
+policy-impossible = { $policy } heeft regels die geen wachtwoord van { $length } tekens kan volgen.
+policy-max-consecutive-impossible = { $policy } staat hoogstens { $max } dezelfde tekens op een rij toe, maar er is maar één teken over.
+policy-entropy-impossible = { $policy } vereist { $bits } bits entropie, { $length } tekens halen ongeveer { $best }.
+check-min-length = Lengte van { $length }, minstens { $min }.
+check-max-length = Lengte van { $length }, hoogstens { $max }.
+check-allowed = Alleen tekens uit de toegestane sets.
+check-outside = Tekens buiten de toegestane sets: { $characters }
+check-not-forbidden = Geen verboden tekens.
+check-forbidden = Verboden tekens: { $characters }
+check-contains = Bevat "{ $set }".
+check-contains-no = Bevat niets uit "{ $set }".
+check-min-count = Minstens { $min } uit "{ $set }", heeft er { $count }.
+check-max-count = Hoogstens { $max } uit "{ $set }", heeft er { $count }.
+check-consecutive = Hoogstens { $max } dezelfde tekens op een rij, heeft er { $longest }.
+check-entropy = Entropie van ongeveer { $bits } bits, minstens { $min }.
+conform-removed = { $count } niet-toegestane tekens verwijderd.
+conform-appended-set = Een willekeurige { $set } toegevoegd.
+conform-padded = { $characters } toegevoegd om de minimumlengte van { $min } te halen.
+conform-trimmed = { $count } tekens van het einde verwijderd om binnen de maximumlengte van { $max } te passen.
+conform-entropy = { $characters } toegevoegd om { $bits } bits entropie te halen.
+conform-runs = { $characters } vervangen om reeksen van hetzelfde teken te doorbreken.
+conform-max-count = { $characters } vervangen om hoogstens { $max } uit "{ $set }" te hebben.
+conform-failed = Kan het wachtwoord het beleid niet laten volgen, het breekt nog een regel: { $rule }
+validate-broken = Het wachtwoord breekt { $failed } van { $total } regels.
+validate-followed = Het wachtwoord volgt het beleid.
+conform-nothing = Het wachtwoord volgt het beleid al.
+conform-entropy-change = Entropie van ongeveer { $bits } bits, was { $before }.
+compare-same = Beide beleidsregels genereren even sterke wachtwoorden.
+compare-stronger = { $stronger } genereert wachtwoorden die { $bits } bits sterker zijn dan { $weaker }, { $guesses } keer zoveel gokken.
+compare-nothing = niets
+compare-length-range = { $length } ({ $min } tot { $max })
+compare-length-open = { $length } ({ $min } of meer)
+compare-met = { $bits } bits, gehaald
+compare-missed = { $bits } bits, niet gehaald
+compare-none = geen
+conform-made-uppercase = Teken { $position } een hoofdletter gemaakt.
+conform-made-lowercase = Teken { $position } een kleine letter gemaakt.
+conform-replaced-first = Het eerste teken vervangen door een willekeurige { $set }.
+conform-replaced-last = Het laatste teken vervangen door een willekeurige { $set }.
+conform-added-first = Een willekeurige { $set } als eerste teken toegevoegd.
+conform-added-last = Een willekeurige { $set } als laatste teken toegevoegd.
+check-starts = Begint met { $sets }.
+check-ends = Eindigt met { $sets }.
+or = of
+set-lower = kleine letter
+set-upper = hoofdletter
+set-digit = cijfer
+set-symbol = symbool
+set-rare-symbol = zeldzaam symbool
+set-latin-1 = Latin-1-teken
+set-greek = Griekse letter
+set-emoji = emoji
+check-pass = goed
+check-fail = fout
+compare-policy = Beleid
+compare-length = Lengte
+compare-characters = Tekens
+compare-required = Verplicht
+compare-keyspace = Sleutelruimte
+compare-entropy = Entropie
+compare-lost = Verloren aan regels
+compare-target = Entropiedoel
+compare-shortest = Kortste lengte
+bits = { $bits } bits
+
+## prompt
+prompt-number-range = Voer een getal tussen 1 en { $max } in.
+prompt-yes-no = Antwoord met ja of nee.
+prompt-pick = Kies er een om te kopiëren (1-{ $count }, leeg om te annuleren):
+prompt-unreadable-stdin = Kan stdin niet lezen: { $error }
+prompt-unreadable = Kan de invoer niet lezen: { $error }
+prompt-repeat = Herhaal ter bevestiging:
+prompt-mismatch = De twee invoeren komen niet overeen.
+
+## request
+request-not-object = Het verzoek moet een JSON-object zijn.
+request-char-sets = "char_sets" moet een lijst met namen zijn.
+request-unknown-set = Onbekende tekenset { $set }.
+request-unknown-password-option = Onbekende wachtwoordoptie "{ $key }".
+request-site-and-policy = "site" en "policy" kunnen niet samen.
+request-separator = "separator" moet één teken zijn.
+request-unknown-passphrase-option = Onbekende wachtwoordzinoptie "{ $key }".
+request-unknown-command = Onbekende opdracht "{ $command }", gebruik "password" of "passphrase".
+request-no-command = Het verzoek heeft een "command" nodig.
+request-not-positive = "{ $key }" moet een positief geheel getal zijn.
+request-not-string = "{ $key }" moet een tekenreeks zijn.
+
+## salt
+salt-bytes-range = Het aantal bytes moet tussen 1 en 65536 liggen.
+
+## sandbox
+sandbox-failed = Kan pwgen niet in een sandbox plaatsen: { $error }
+sandbox-unveil-path = Kan unveil niet toepassen op "{ $path }": { $error }
+sandbox-unveil = Kan unveil niet toepassen: { $error }
+sandbox-pledge = Kan pledge niet toepassen: { $error }
+
+## setup
+setup-welcome = Welkom bij pwgen! Het lijkt erop dat je het voor het eerst gebruikt.
+setup-ask = Je standaardwaarden nu instellen?
+setup-skipped = Overgeslagen, voer `pwgen setup` uit om het later te doen.
+setup-length = Wachtwoordlengte? [16]
+setup-length-range = Voer een lengte tussen 4 en 65536 in.
+setup-symbols = Zijn symbolen in wachtwoorden goed?
+setup-copy = Gegenereerde geheimen naar het klembord kopiëren?
+setup-clear = Het klembord na hoeveel seconden wissen? 0 wist het nooit. [0]
+setup-clear-range = Voer een aantal seconden tot 86400 in.
+setup-saved = Je standaardwaarden zijn opgeslagen in { $path }.
+
+## shamir
+shamir-different-secrets = De delen horen bij verschillende geheimen.
+shamir-different-lengths = De delen zijn verschillend lang.
+shamir-too-few = Er zijn { $threshold } verschillende delen nodig, er zijn er maar { $given } gegeven.
+shamir-invalid-secret = De delen vormen samen geen geldig geheim.
+shamir-invalid-share = "{ $share }" is geen geldig deel.
+shamir-typo = "{ $share }" bevat een typefout, de controlesom klopt niet.
+
+## sites
+sites-invalid = Ongeldige siteregels "{ $path }": { $error }
+sites-invalid-rules = Ongeldige wachtwoordregels voor { $site }: { $error }
+sites-unknown = Geen wachtwoordregels bekend voor { $site }, zie `--site` in de readme om ze toe te voegen.
+sites-expected-property = "naam: waarde" verwacht in "{ $property }"
+sites-not-number = "{ $key }" moet een getal zijn
+sites-max-below-min = "maxlength" is korter dan "minlength"
+sites-unknown-class = onbekende tekenklasse "{ $class }"
+
+## skey
+skey-invalid-seed = De seed moet uit 1 tot 16 letters en cijfers bestaan.
+skey-passphrase-range = De wachtwoordzin moet tussen 10 en 63 tekens lang zijn.
+skey-server-setup = Serverinstelling: { $algorithm } { $count } { $seed } { $key }
+
+## sshkey
+sshkey-exists = "{ $path }" bestaat al, gebruik --force om het te overschrijven.
+sshkey-derive-failed = Kan de sleutel van de wachtwoordzin niet afleiden: { $error }
+sshkey-no-home = Kan de thuismap niet vinden, kies met --out waar de sleutel wordt opgeslagen.
+sshkey-passphrase = Wachtwoordzin: { $passphrase }
+
+## stats
+stats-online = Online aanval
+stats-offline-slow = Offline, trage hash
+stats-offline-fast = Offline, snelle hash
+stats-long-term = Geheimen voor lange termijn
+stats-no-command = Noem een opdracht om over te rapporteren, zoals "password".
+stats-word-dependent = De entropie die --leet en --capitalize toevoegen en --max-length afneemt hangt af van de gekozen woorden en is weggelaten.
+stats-separator-set = De entropie die --separator-set toevoegt is weggelaten, --show-strength van de wachtwoordzin telt hem wel mee.
+stats-unsupported = Statistieken zijn alleen beschikbaar voor password, passphrase en token.
+stats-pool-character = Voorraad van { $pool } tekens, { $bits } bits per teken.
+stats-pool-word = Voorraad van { $pool } woorden, { $bits } bits per woord.
+stats-length-character = Lengte van { $amount } tekens.
+stats-length-word = Lengte van { $amount } woorden.
+stats-keyspace = Sleutelruimte van { $keyspace } (2^{ $bits }).
+stats-heading-character = Tekens
+stats-heading-word = Woorden
+stats-target = Doel
+stats-bits = Bits
+stats-met = gehaald
+
+## stdout_only
+stdout-only-conflict = --stdout-only kan niet samen met { $conflict }.
+stdout-only-unique = --unique, dat de gebruikersnaam naar een geschiedenisbestand schrijft
+stdout-only-history = --history, dat de codenaam naar een bestand schrijft
+stdout-only-sshkey = sshkey, dat de sleutel naar bestanden schrijft
+stdout-only-keyring = opdrachten die de sleutelbos gebruiken
+stdout-only-daemon = daemon, dat geheimen via een socket aanbiedt
+stdout-only-stored = opties die het geheim ergens anders opslaan
+stdout-only-program = --systemd-cred en --gpg-recipient, die het geheim aan een ander programma geven
+stdout-only-picker = --picker, dat het geheim in een ander programma toont
+
+## strength
+boost-leet = +{ $bits } bits door leet-vervangingen.
+boost-capitalization = +{ $bits } bits door willekeurige hoofdletters.
+boost-separators = +{ $bits } bits door willekeurige scheidingstekens.
+
+## testcard
+testcard-brand = Merk: { $brand } (TESTKAART, niet bruikbaar voor betalingen)
+testcard-number = Nummer: { $number }
+testcard-expiry = Vervaldatum: { $expiry }
+testcard-cvc = CVC: { $cvc }
+
+## token
+token-invalid = Token is ongeldig! Waarschijnlijk is het verkeerd getypt.
+token-valid = Token is geldig.
+
+## username
+username-no-config-directory = Geen configuratiemap om de uitgegeven gebruikersnamen in te bewaren, kies een bestand met --history.
+username-alliteration-style = Alliteratie werkt alleen met de stijl words.
+username-platform-length = Gebruikersnamen voor { $platform } hebben minstens { $min } tekens nodig.
+username-empty-search = De tekst om naar te zoeken mag niet leeg zijn.
+username-issued = al eerder uitgegeven
+username-blocked = geblokkeerd omdat hij "{ $word }" bevat
+username-embed-failed = Kan "{ $text }" niet in een geldige gebruikersnaam verwerken, het resultaat bleef { $problem }.
+username-from-style = --from werkt alleen met de stijl words.
+username-from-empty = De tekst om de gebruikersnaam van af te leiden mag niet leeg zijn.
+username-from-numbers = --from ondersteunt maximaal 19 cijfers.
+username-derive-failed = Kan geen gebruikersnaam afleiden die bij de opties past!
+username-keyspace = Mogelijke gebruikersnamen: { $keyspace } ({ $bits } bits).
+username-duplicate-chance = Kans op een dubbele onder { $users } gebruikers: { $chance }, onder { $threshold }% tot { $unique } gebruikers.
+username-chance-tiny = minder dan 0,01%
+username-none-fit = Geen gebruikersnamen passen bij de opties!
+username-too-many-numbers = Te veel cijfers! Het mogen er niet meer dan 65536 zijn.
+username-no-number = Kan geen getal kiezen dat niet op een jaartal lijkt!
+username-no-adjectives = Geen bijvoeglijke naamwoorden passen bij de woordlengtes en geblokkeerde woorden! Probeer ze te verruimen.
+username-no-objects = Geen objecten passen bij de woordlengtes en geblokkeerde woorden! Probeer ze te verruimen.
+username-invalid-for-platform = Kan geen geldige gebruikersnaam voor { $platform } genereren, het resultaat bleef { $problem }.
+username-invalid = Kan geen geldige gebruikersnaam genereren, het resultaat bleef { $problem }.
+username-character-not-allowed = '{ $character }' is niet toegestaan in gebruikersnamen voor { $platform }.
+
+## vaults
+vaults-deleted = "{ $name }" verwijderd.
+vaults-empty = Nog geen geheimen opgeslagen, sla er een op met --keyring <NAAM>.
+
+## wordlist
+wordlist-invalid-name = Ongeldige naam "{ $name }" voor een woordenlijst! Alleen letters, cijfers, '-' en '_' zijn toegestaan.
+wordlist-built-in-replace = "{ $name }" is een ingebouwde woordenlijst en kan niet worden vervangen.
+wordlist-too-small = De woordenlijst heeft minstens 2 verschillende geldige woorden nodig.
+wordlist-exists = Er bestaat al een woordenlijst met de naam "{ $name }". Gebruik --force om hem te vervangen.
+wordlist-imported = { $count } woorden geïmporteerd als "{ $name }".
+wordlist-duplicates = { $count } dubbele woorden overgeslagen.
+wordlist-rejected = { $count } woorden met witruimte of besturingstekens geweigerd (regels { $lines }).
+wordlist-entropy = Entropie per woord: { $bits } bits.
+wordlist-listed-built-in = { $name } (ingebouwd, { $count } woorden)
+wordlist-listed = { $name } ({ $count } woorden)
+wordlist-info-name = Naam: { $name }
+wordlist-info-words = Woorden: { $count }
+wordlist-info-length = Woordlengte: { $min } tot { $max } tekens
+wordlist-info-entropy = Entropie per woord: { $bits } bits
+wordlist-built-in-remove = "{ $name }" is een ingebouwde woordenlijst en kan niet worden verwijderd.
+wordlist-not-imported = Geen geïmporteerde woordenlijst met de naam "{ $name }".
+wordlist-removed = Woordenlijst "{ $name }" verwijderd.
+wordlist-checked = { $count } woorden in "{ $name }" gecontroleerd.
+wordlist-no-problems = Geen problemen gevonden.
+wordlist-unnormalized = { $count } woorden bevatten hoofdletters, komen dubbel voor of bevatten witruimte: { $examples }
+wordlist-prefixes = { $count } woorden zijn het begin van een ander woord, wat samengevoegde woorden dubbelzinnig kan maken: { $examples }
+wordlist-confusable = { $count } groepen woorden lijken op elkaar als ze zijn opgeschreven: { $examples }
+wordlist-more = en nog { $count }
+wordlist-unknown = Geen woordenlijst met de naam "{ $name }".
+wordlist-unknown-hint = Geen woordenlijst met de naam "{ $name }". Gebruik "pwgen wordlist list" om alle woordenlijsten te zien.
+wordlist-too-long = Past niet in { $max } tekens! Het kortst mogelijke resultaat is { $shortest } tekens.
+wordlist-alliteration-too-long = Er past geen alliterend resultaat in { $max } tekens!
+wordlist-no-alliteration = Geen woorden in de woordenlijsten beginnen met dezelfde letter!
+wordlist-info-built-in = Bron: ingebouwd
+wordlist-info-imported = Bron: geïmporteerd
+
+## xkpasswd
+xkpasswd-negative = "{ $key }" mag niet negatief zijn.
+xkpasswd-unknown-case = Onbekende case_transform "{ $value }".
+xkpasswd-unknown-padding = Onbekend padding_type "{ $value }".
+xkpasswd-unknown-setting = Onbekende xkpasswd-instelling "{ $key }".
+xkpasswd-words-range = num_words moet tussen 1 en 100 liggen.
+xkpasswd-word-length = word_length_min moet minstens 1 en hoogstens word_length_max zijn.
+xkpasswd-separator-alphabet = Een willekeurig separator_character heeft een separator_alphabet nodig.
+xkpasswd-symbol-alphabet = Een willekeurig padding_character heeft een symbol_alphabet nodig.
+xkpasswd-no-separator = padding_character kan niet het scheidingsteken zijn als er geen is.
+xkpasswd-pad-to-length = Adaptieve opvulling heeft een pad_to_length nodig.
+xkpasswd-character = "{ $key }" moet een teken, "RANDOM" of "NONE" zijn.
+xkpasswd-single-characters = "{ $key }" mag alleen losse tekens bevatten.
+xkpasswd-character-list = "{ $key }" moet een lijst met tekens zijn.
+xkpasswd-not-object = "{ $path }" moet een JSON-object met instellingen bevatten.
+xkpasswd-unsupported-value = Instelling "{ $key }" heeft een niet-ondersteunde waarde.
+xkpasswd-unknown-scheme = Geen xkpasswd-schema met de naam "{ $scheme }" in het configuratiebestand.
+xkpasswd-invalid = Ongeldige instellingen "{ $path }": { $error }
+xkpasswd-no-words = De woordenlijst heeft geen woorden van { $min } tot { $max } letters.
@@ -0,0 +1,87 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::platform::Platform;
+use crate::{crypt, i18n, password, username};
+use crate::{PasswordArgs, UsernameArgs};
+
+#[derive(Args)]
+pub struct AccountsArgs {
+    /// Amount of accounts, all with different usernames.
+    /// Defaults to 10.
+    #[arg(short = 'C', long = "count")]
+    count: Option<usize>,
+
+    /// Amount of characters in each password.
+    /// Defaults to 16.
+    #[arg(short = 'l', long = "length")]
+    length: Option<u32>,
+
+    /// Format the accounts are printed in.
+    /// Defaults to chpasswd.
+    #[arg(short = 'O', long = "output", value_enum)]
+    output: Option<AccountsFormat>,
+
+    /// Print SHA-512 crypt hashes instead of the passwords, for `chpasswd -e`.
+    #[arg(short = 'e', long = "encrypted")]
+    encrypted: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum AccountsFormat {
+    /// "username:password" lines, ready to pipe into chpasswd.
+    Chpasswd,
+}
+
+/// A generated login, with the password hashed when `--encrypted` is used.
+struct Account {
+    username: String,
+    password: String,
+}
+
+pub fn run(args: &AccountsArgs) -> Result<(), String> {
+    let accounts = generate(args, &mut rand::rng())?;
+    match args.output.unwrap_or(AccountsFormat::Chpasswd) {
+        AccountsFormat::Chpasswd => {
+            for account in accounts {
+                println!("{}:{}", account.username, account.password);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Generates accounts with lowercase usernames that useradd accepts and random passwords.
+fn generate<R: Rng + ?Sized>(args: &AccountsArgs, rng: &mut R) -> Result<Vec<Account>, String> {
+    let count = args.count.unwrap_or(10);
+    if count == 0 || count > 100_000 {
+        return Err(i18n::message("count-range", &[("max", &100000)]));
+    }
+
+    let username_args = UsernameArgs { platform: Some(Platform::Unix), ..Default::default() };
+    let password_args = PasswordArgs {
+        length: args.length,
+        // A colon would end the password field early, chpasswd has no way to escape it.
+        excluded_chars: Some(":".into()),
+        ..Default::default()
+    };
+    let sampler = password::Sampler::new(&password_args)?;
+
+    let mut seen = HashSet::with_capacity(count);
+    let mut accounts = Vec::with_capacity(count);
+    for _ in 0..count * 100 {
+        let username = username::generate(&username_args, rng)?.to_lowercase();
+        if !seen.insert(username.clone()) {
+            continue;
+        }
+
+        let password = password::generate_with(&password_args, &sampler, rng)?;
+        let password = if args.encrypted { crypt::sha512_crypt(&password, rng) } else { password };
+        accounts.push(Account { username, password });
+        if accounts.len() == count {
+            return Ok(accounts);
+        }
+    }
+    Err(i18n::message("accounts-too-few", &[("count", &accounts.len())]))
+}
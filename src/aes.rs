@@ -0,0 +1,42 @@
+//! AES-256 in CTR mode, as used for encrypting OpenSSH private keys and Ansible Vault secrets,
+//! from the `aes` and `ctr` crates.
+
+use ::aes::Aes256;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+
+/// Encrypts `data` in place with AES-256-CTR. The whole 128 bit IV is the big-endian counter.
+pub fn ctr_256(key: &[u8; 32], iv: &[u8; 16], data: &mut [u8]) {
+    ctr::Ctr128BE::<Aes256>::new(key.into(), iv.into()).apply_keystream(data);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    fn unhex(text: &str) -> Vec<u8> {
+        (0..text.len()).step_by(2).map(|index| u8::from_str_radix(&text[index..index + 2], 16).unwrap()).collect()
+    }
+
+    #[test]
+    fn encrypts_block_like_fips_197() {
+        use ::aes::cipher::{BlockEncrypt, KeyInit};
+
+        let key: [u8; 32] = std::array::from_fn(|index| index as u8);
+        let mut block: [u8; 16] = unhex("00112233445566778899aabbccddeeff").try_into().unwrap();
+        Aes256::new(&key.into()).encrypt_block((&mut block).into());
+        assert_eq!(hex(&block), "8ea2b7ca516745bfeafc49904b496089");
+    }
+
+    #[test]
+    fn ctr_matches_sp_800_38a() {
+        let key: [u8; 32] = unhex("603deb1015ca71be2b73aef0857d77811f352c073b6108d72d9810a30914dff4").try_into().unwrap();
+        let iv: [u8; 16] = unhex("f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff").try_into().unwrap();
+        let mut data = unhex("6bc1bee22e409f96e93d7e117393172aae2d8a571e03ac9c9eb76fac45af8e51");
+        ctr_256(&key, &iv, &mut data);
+        assert_eq!(hex(&data), "601ec313775789a5b7a7f504bbf3d228f443e3ca4d62b59aca84e990cacaf5c5");
+    }
+}
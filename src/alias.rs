@@ -0,0 +1,96 @@
+use clap::Command;
+use std::ffi::OsString;
+
+use crate::config::{Config, Value};
+use crate::i18n;
+
+/// Replaces an alias from the "[alias]" table of the config file with what it stands for,
+/// so `pwgen wifi` can mean `pwgen password --length 24 --no-copy`. Built-in commands
+/// always win and aliases don't expand any further. Options given after an alias replace
+/// the ones it sets.
+pub fn expand(command: &mut Command, mut args: Vec<OsString>) -> Result<Vec<OsString>, String> {
+    let Some(name) = args.get(1).and_then(|name| name.to_str()) else {
+        return Ok(args);
+    };
+    if name.starts_with('-') || command.find_subcommand(name).is_some() || name == "help" {
+        return Ok(args);
+    }
+
+    let key = format!("alias.{}", name);
+    let config = Config::load()?;
+    let Some(expansion) = config.get_string(&key)? else {
+        return Ok(args);
+    };
+    let words = split_words(expansion).ok_or_else(|| i18n::message("alias-unterminated-quote", &[("key", &key)]))?;
+    if words.is_empty() {
+        return Err(i18n::message("alias-empty", &[("key", &key)]));
+    }
+    let names: Vec<String> = command.get_subcommands().map(|subcommand| subcommand.get_name().to_string()).collect();
+    for name in names {
+        *command = std::mem::take(command).mut_subcommand(name, |subcommand| subcommand.args_override_self(true));
+    }
+    args.splice(1..2, words.into_iter().map(OsString::from));
+    Ok(args)
+}
+
+/// Lists the aliases for the help text, if there are any.
+pub fn help() -> Option<String> {
+    let config = Config::load().ok()?;
+    let aliases: Vec<String> = config.iter()
+        .filter_map(|(key, value)| match (key.strip_prefix("alias."), value) {
+            (Some(name), Value::String(expansion)) => Some(format!("  {:<12} {}", name, expansion)),
+            _ => None,
+        })
+        .collect();
+    (!aliases.is_empty()).then(|| format!("Aliases:\n{}", aliases.join("\n")))
+}
+
+/// Splits text into words like a shell would, with single and double quotes and
+/// backslash escapes. Returns `None` for an unterminated quote.
+fn split_words(text: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next()? {
+                        '\'' => break,
+                        c => word.push(c),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => word.push(chars.next()?),
+                        c => word.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                word.push(chars.next()?);
+            }
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Some(words)
+}
@@ -0,0 +1,94 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+
+use crate::config::Config;
+use crate::encoding;
+use crate::i18n;
+
+#[derive(Args)]
+pub struct ApiKeyArgs {
+    /// Text in front of the key, so leaked-key scanners can recognize it.
+    /// Example usage: "--prefix sk_live_".
+    #[arg(short = 'p', long = "prefix")]
+    prefix: Option<String>,
+
+    /// Amount of random bytes in the key.
+    /// Defaults to 24.
+    #[arg(short = 'b', long = "bytes")]
+    bytes: Option<usize>,
+
+    /// Checksum appended to the key, so mistyped or made up keys can be rejected offline.
+    /// Defaults to none.
+    #[arg(short = 'c', long = "checksum", value_enum)]
+    checksum: Option<Checksum>,
+
+    /// Take the defaults from an "[apikey.<PROFILE>]" table in the config file.
+    /// Example usage: "--profile live".
+    #[arg(short = 'P', long = "profile")]
+    profile: Option<String>,
+
+    /// Disables copying the API key to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Checksum {
+    None,
+    /// CRC32 of the prefix and random part, as 6 base62 characters.
+    Crc32,
+}
+
+/// Generates a key like "sk_live_" followed by base62 encoded random bytes and an optional checksum.
+pub fn generate<R: Rng + ?Sized>(args: &ApiKeyArgs, rng: &mut R) -> Result<String, String> {
+    let mut prefix = String::new();
+    let mut byte_amount = 24;
+    let mut checksum = Checksum::None;
+
+    if let Some(profile) = &args.profile {
+        let config = Config::load()?;
+        let table = format!("apikey.{}", profile);
+        if !config.has_table(&table) {
+            return Err(i18n::message("apikey-unknown-profile", &[("profile", &profile)]));
+        }
+        if let Some(profile_prefix) = config.get_string(&format!("{}.prefix", table))? {
+            prefix = profile_prefix.to_string();
+        }
+        if let Some(profile_bytes) = config.get_integer(&format!("{}.bytes", table))? {
+            byte_amount = usize::try_from(profile_bytes).map_err(|_| i18n::message("apikey-negative-bytes", &[("table", &table)]))?;
+        }
+        if let Some(profile_checksum) = config.get_string(&format!("{}.checksum", table))? {
+            checksum = Checksum::from_str(profile_checksum, true)
+                .map_err(|_| i18n::message("apikey-checksum-choice", &[("table", &table)]))?;
+        }
+    }
+
+    if let Some(user_prefix) = &args.prefix {
+        prefix = user_prefix.clone();
+    }
+    if let Some(user_bytes) = args.bytes {
+        byte_amount = user_bytes;
+    }
+    if let Some(user_checksum) = args.checksum {
+        checksum = user_checksum;
+    }
+
+    if !(8..=1024).contains(&byte_amount) {
+        return Err(i18n::message("apikey-bytes-range", &[]));
+    }
+    if prefix.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(i18n::message("apikey-prefix-whitespace", &[]));
+    }
+
+    let mut random_bytes = vec![0u8; byte_amount];
+    rng.fill(random_bytes.as_mut_slice());
+    // Pad to the length the largest possible value needs, so all keys are equally long.
+    let encoded_length = (byte_amount as f64 * 8.0 / 62f64.log2()).ceil() as usize;
+    let mut key = format!("{}{}", prefix, encoding::base62(&random_bytes, encoded_length));
+
+    if let Checksum::Crc32 = checksum {
+        let crc = crc32fast::hash(key.as_bytes());
+        key.push_str(&encoding::base62(&crc.to_be_bytes(), 6));
+    }
+    Ok(key)
+}
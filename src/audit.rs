@@ -0,0 +1,102 @@
+//! An opt-in log of what was generated, for accounting for credentials made on shared hosts.
+//! Every entry is a JSON line with the time, user, command, the options shaping the secret
+//! and where it went. The secret itself is never written.
+
+use std::env;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+
+use crate::config::Config;
+use crate::date::Date;
+use crate::json::Value;
+use crate::Commands;
+
+/// The audit log is kept when PWGEN_AUDIT_LOG or `audit.file` in the config names a file.
+pub fn audit_file() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("PWGEN_AUDIT_LOG").filter(|path| !path.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    let config = Config::load().ok()?;
+    config.get_string("audit.file").ok()?.filter(|path| !path.is_empty()).map(PathBuf::from)
+}
+
+/// Appends an entry for a generated secret, warning when the log can't be written.
+pub fn record(command_name: &str, command: &Commands, destinations: &[&str]) {
+    let Some(path) = audit_file() else {
+        return;
+    };
+    let user = env::var("USER").or_else(|_| env::var("USERNAME")).unwrap_or_default();
+    let mut fields = vec![
+        ("time".into(), Value::String(Date::timestamp_now())),
+        ("user".into(), Value::String(user)),
+    ];
+    // USER can be set to anything, the user id can't.
+    #[cfg(unix)]
+    // SAFETY: getuid has no preconditions and can't fail.
+    fields.push(("uid".into(), Value::Number(unsafe { libc::getuid() } as f64)));
+    fields.extend([
+        ("command".into(), Value::String(command_name.into())),
+        ("parameters".into(), Value::Object(parameters(command).into_iter()
+            .map(|(name, value)| (name.to_string(), Value::String(value)))
+            .collect())),
+        ("destination".into(), Value::Array(destinations.iter().map(|destination| Value::String(destination.to_string())).collect())),
+    ]);
+    if let Err(error) = append(&path, &Value::Object(fields).to_compact()) {
+        log::warn!("Unable to write the audit log \"{}\": {}", path.display(), error);
+    }
+}
+
+fn append(path: &PathBuf, line: &str) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.create(true).append(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    writeln!(file, "{}", line)
+}
+
+/// The options that decide what a secret looks like, none of which give the secret away.
+fn parameters(command: &Commands) -> Vec<(&'static str, String)> {
+    let mut parameters = Vec::new();
+    let mut add = |name, value: Option<String>| {
+        if let Some(value) = value {
+            parameters.push((name, value));
+        }
+    };
+    match command {
+        Commands::Password( args ) => {
+            add("length", args.length.map(|length| length.to_string()));
+            add("char_sets", args.character_sets.as_ref().map(|sets| sets.iter().map(choice).collect::<Vec<_>>().join(",")));
+            add("exclude", args.excluded_chars.clone());
+            add("split", args.split.map(|split| format!("{}-of-{}", split.threshold, split.shares)));
+        }
+        Commands::Passphrase( args ) => {
+            add("words", args.words.map(|words| words.to_string()));
+            add("word_char", args.word_char.map(String::from));
+            add("separator_set", args.separator_set.as_ref().map(|set| set.text.clone()));
+            add("wordlist", args.wordlist.clone());
+            add("max_length", args.max_length.map(|length| length.to_string()));
+            add("leet", args.leet.as_ref().map(choice));
+            add("capitalization", args.capitalization.as_ref().map(choice));
+            add("split", args.split.map(|split| format!("{}-of-{}", split.threshold, split.shares)));
+        }
+        Commands::Username( args ) => {
+            add("style", args.style.as_ref().map(choice));
+            add("numbers", args.numbers.map(|numbers| numbers.to_string()));
+            add("word_char", args.word_char.map(String::from));
+            add("separator_set", args.separator_set.as_ref().map(|set| set.text.clone()));
+            add("max_length", args.max_length.map(|length| length.to_string()));
+            add("leet", args.leet.as_ref().map(choice));
+            add("platform", args.platform.as_ref().map(choice));
+        }
+        _ => {}
+    }
+    parameters
+}
+
+fn choice<T: ValueEnum>(value: &T) -> String {
+    value.to_possible_value().map(|value| value.get_name().to_string()).unwrap_or_default()
+}
@@ -0,0 +1,113 @@
+//! `pwgen bench`, measuring how fast secrets are generated with the current settings,
+//! on one thread and on all of them, to spot slowdowns in the samplers or the RNG.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use clap::{Args, Parser};
+use rand::Rng;
+
+use crate::{Cli, Commands};
+use crate::{defaults, i18n, passphrase, password, token};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Seconds each measurement runs for.
+    /// Defaults to 1.
+    #[arg(short = 's', long = "seconds", value_parser = clap::value_parser!(u64).range(1..=60))]
+    seconds: Option<u64>,
+
+    /// Threads used for the multi-threaded measurements.
+    /// Defaults to the amount of CPU threads.
+    #[arg(short = 't', long = "threads", value_parser = clap::value_parser!(u32).range(1..=1024))]
+    threads: Option<u32>,
+}
+
+pub fn run(args: &BenchArgs) -> Result<(), String> {
+    bench(args)
+}
+
+fn bench(args: &BenchArgs) -> Result<(), String> {
+    let duration = Duration::from_secs(args.seconds.unwrap_or(1));
+    let threads = args.threads.map_or_else(
+        || thread::available_parallelism().map_or(1, |threads| threads.get()),
+        |threads| threads as usize,
+    );
+
+    for name in ["password", "passphrase", "token"] {
+        // Parsed like `pwgen <name>` and completed from the config, just like a real run.
+        let mut command = Cli::try_parse_from(["pwgen", name]).map_err(|error| error.to_string())?
+            .command.unwrap();
+        defaults::apply(&mut command)?;
+        // The pool of a password is built once like for a real run, so only generating is measured.
+        let sampler = match &command {
+            Commands::Password( args ) => Some(password::Sampler::new(args)?),
+            _ => None,
+        };
+        generate(&command, sampler.as_ref(), &mut rand::rng())?;
+
+        let single = measure(duration, 1, |rng| generate(&command, sampler.as_ref(), rng).map(|_| ()));
+        if threads == 1 {
+            println!("{}", i18n::message("bench-single", &[("name", &format!("{:<11}", name)), ("rate", &format!("{:>12}", group(single)))]));
+        } else {
+            let multi = measure(duration, threads, |rng| generate(&command, sampler.as_ref(), rng).map(|_| ()));
+            println!("{}", i18n::message("bench-multi", &[("name", &format!("{:<11}", name)), ("rate", &format!("{:>12}", group(single))), ("multi-rate", &format!("{:>12}", group(multi))), ("threads", &threads)]));
+        }
+    }
+
+    const BUFFER_SIZE: usize = 4096;
+    let single = measure(duration, 1, |rng| {
+        rng.fill(&mut [0u8; BUFFER_SIZE][..]);
+        Ok(())
+    });
+    println!("{}", i18n::message("bench-rng", &[("name", &format!("{:<11}", "rng")), ("rate", &format!("{:>10}", group(single * BUFFER_SIZE as u64 / 1_000_000)))]));
+    Ok(())
+}
+
+fn generate<R: Rng + ?Sized>(command: &Commands, sampler: Option<&password::Sampler>, rng: &mut R) -> Result<String, String> {
+    match (command, sampler) {
+        (Commands::Password( args ), Some(sampler)) => password::generate_with(args, sampler, rng),
+        (Commands::Passphrase( args ), _) => passphrase::generate(args, rng).map(|passphrase| passphrase.text),
+        (Commands::Token( args ), _) => token::generate(args, rng),
+        _ => unreachable!("only generating commands are benchmarked"),
+    }
+}
+
+/// Runs `work` over and over on `threads` threads for `duration`, returning how often it ran per second.
+fn measure<F>(duration: Duration, threads: usize, work: F) -> u64
+where
+    F: Fn(&mut rand::rngs::ThreadRng) -> Result<(), String> + Sync,
+{
+    let start = Instant::now();
+    let total: u64 = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads).map(|_| scope.spawn(|| {
+            let mut rng = rand::rng();
+            let mut count = 0u64;
+            while start.elapsed() < duration {
+                // Checking the clock on every round would be measured along with the work.
+                for _ in 0..64 {
+                    if work(&mut rng).is_err() {
+                        return count;
+                    }
+                    count += 1;
+                }
+            }
+            count
+        })).collect();
+        workers.into_iter().map(|worker| worker.join().unwrap_or(0)).sum()
+    });
+    (total as f64 / start.elapsed().as_secs_f64()) as u64
+}
+
+/// Formats a number with thousands separators, like "1,234,567".
+fn group(number: u64) -> String {
+    let digits = number.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
@@ -0,0 +1,45 @@
+use rand::Rng;
+
+/// How letters in word-based secrets are capitalized.
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Capitalization {
+    /// Capitalize the first letter of each word.
+    First,
+    /// Flip the case of a random subset of letters.
+    Random,
+}
+
+/// Applies `capitalization` to `word`, returning the result and the entropy
+/// in bits it added. Only random capitalization adds entropy, one bit per letter.
+pub fn apply<R: Rng + ?Sized>(word: &str, capitalization: Capitalization, rng: &mut R) -> (String, f64) {
+    match capitalization {
+        Capitalization::First => {
+            let mut chars = word.chars();
+            let capitalized = match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            };
+            (capitalized, 0.0)
+        }
+        Capitalization::Random => {
+            let mut added_bits = 0.0;
+            let mut flipped = String::with_capacity(word.len());
+            for c in word.chars() {
+                let has_case = c.is_lowercase() || c.is_uppercase();
+                if !has_case {
+                    flipped.push(c);
+                    continue;
+                }
+                added_bits += 1.0;
+                if !rng.random_bool(0.5) {
+                    flipped.push(c);
+                } else if c.is_lowercase() {
+                    flipped.extend(c.to_uppercase());
+                } else {
+                    flipped.extend(c.to_lowercase());
+                }
+            }
+            (flipped, added_bits)
+        }
+    }
+}
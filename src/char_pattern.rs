@@ -0,0 +1,148 @@
+//! The regular expressions of `--exclude-regex`. A pattern is matched against one
+//! character at a time, so only what matches a single character is supported:
+//! literals, ".", classes like "[a-f0-9]" and "[^...]", escapes like "\d", "\s" and
+//! "\p{P}", and alternatives of those separated by "|".
+
+use crate::unicode::Category;
+
+#[derive(Clone)]
+pub struct CharPattern {
+    alternatives: Vec<Item>,
+}
+
+#[derive(Clone)]
+enum Item {
+    Any,
+    Literal(char),
+    Range(char, char),
+    Category(Category, bool),
+    Class(Vec<Item>, bool),
+}
+
+impl Item {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Item::Any => c != '\n',
+            Item::Literal(literal) => c == *literal,
+            Item::Range(first, last) => (*first..=*last).contains(&c),
+            Item::Category(category, negated) => category.contains(c) != *negated,
+            Item::Class(items, negated) => items.iter().any(|item| item.matches(c)) != *negated,
+        }
+    }
+}
+
+impl CharPattern {
+    pub fn matches(&self, c: char) -> bool {
+        self.alternatives.iter().any(|item| item.matches(c))
+    }
+}
+
+/// Parses a pattern, as the value parser of `--exclude-regex`.
+pub fn parse(pattern: &str) -> Result<CharPattern, String> {
+    let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+    let pattern = pattern.strip_suffix('$').filter(|rest| !rest.ends_with('\\')).unwrap_or(pattern);
+    let mut chars = pattern.chars().peekable();
+    let mut alternatives = Vec::new();
+    loop {
+        let item = match chars.next() {
+            None => return Err("The pattern is empty.".into()),
+            Some('.') => Item::Any,
+            Some('[') => class(&mut chars)?,
+            Some('\\') => escape(&mut chars)?,
+            Some(c) if "()*+?{}|".contains(c) => return Err(unsupported()),
+            Some(c) => Item::Literal(c),
+        };
+        alternatives.push(item);
+        match chars.next() {
+            None => break,
+            Some('|') => {}
+            Some(_) => return Err(unsupported()),
+        }
+    }
+    Ok(CharPattern { alternatives })
+}
+
+fn unsupported() -> String {
+    "Only patterns matching a single character are supported, like \"[0O1lI]\" or \"\\p{P}\".".into()
+}
+
+fn class(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Item, String> {
+    let negated = chars.next_if_eq(&'^').is_some();
+    let mut items = Vec::new();
+    let mut first = true;
+    loop {
+        let item = match chars.next() {
+            None => return Err("A \"[\" isn't closed with \"]\".".into()),
+            Some(']') if !first => break,
+            Some('\\') => escape(chars)?,
+            Some(c) => Item::Literal(c),
+        };
+        first = false;
+        // A "-" between two characters makes a range, anywhere else it's literal.
+        if let Item::Literal(start) = item && chars.peek() == Some(&'-') {
+            let mut lookahead = chars.clone();
+            lookahead.next();
+            match lookahead.next() {
+                Some(']') | None => items.push(item),
+                Some(_) => {
+                    chars.next();
+                    let end = match chars.next() {
+                        Some('\\') => match escape(chars)? {
+                            Item::Literal(end) => end,
+                            _ => return Err("A range can't end in a character class.".into()),
+                        },
+                        Some(end) => end,
+                        None => unreachable!(),
+                    };
+                    if end < start {
+                        return Err(format!("The range \"{}-{}\" is backwards.", start, end));
+                    }
+                    items.push(Item::Range(start, end));
+                }
+            }
+            continue;
+        }
+        items.push(item);
+    }
+    Ok(Item::Class(items, negated))
+}
+
+fn escape(chars: &mut std::iter::Peekable<std::str::Chars>) -> Result<Item, String> {
+    let c = chars.next().ok_or("The pattern ends in a \"\\\".")?;
+    Ok(match c {
+        'd' => Item::Category(Category::Number, false),
+        'D' => Item::Category(Category::Number, true),
+        's' => Item::Category(Category::Space, false),
+        'S' => Item::Category(Category::Space, true),
+        'w' | 'W' => Item::Class(vec![
+            Item::Category(Category::Letter, false),
+            Item::Category(Category::Number, false),
+            Item::Literal('_'),
+        ], c == 'W'),
+        'p' | 'P' => {
+            let name: String = if chars.next_if_eq(&'{').is_some() {
+                let name: String = chars.by_ref().take_while(|&c| c != '}').collect();
+                name
+            } else {
+                chars.next().map(String::from).unwrap_or_default()
+            };
+            let category = Category::from_property(&name)
+                .ok_or_else(|| format!("Unknown property \"{}\", use L, Lu, Ll, N, P, S or Z.", name))?;
+            Item::Category(category, c == 'P')
+        }
+        'x' | 'u' => {
+            let hex: String = if chars.next_if_eq(&'{').is_some() {
+                chars.by_ref().take_while(|&c| c != '}').collect()
+            } else {
+                chars.by_ref().take(if c == 'x' { 2 } else { 4 }).collect()
+            };
+            let code = u32::from_str_radix(&hex, 16).ok().and_then(char::from_u32)
+                .ok_or_else(|| format!("\"{}\" isn't a valid character code.", hex))?;
+            Item::Literal(code)
+        }
+        'n' => Item::Literal('\n'),
+        't' => Item::Literal('\t'),
+        c if c.is_ascii_alphanumeric() => return Err(format!("Unknown escape \"\\{}\".", c)),
+        c => Item::Literal(c),
+    })
+}
@@ -0,0 +1,91 @@
+use clap::Args;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::collections::HashSet;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::i18n;
+use crate::wordlist::ADJECTIVE_LIST;
+
+const MOUNTAIN_LIST_RAW: &str = include_str!("../data/codenames/mountains.txt");
+const GEM_LIST_RAW: &str = include_str!("../data/codenames/gems.txt");
+const BIRD_LIST_RAW: &str = include_str!("../data/codenames/birds.txt");
+
+#[derive(Args)]
+pub struct CodenameArgs {
+    /// Theme of the second word.
+    /// By default all themes are mixed.
+    #[arg(short = 't', long = "theme", value_enum)]
+    theme: Option<Theme>,
+
+    /// File recording issued codenames, so none is issued twice.
+    /// Example usage: "--history .codenames".
+    #[arg(short = 'H', long = "history")]
+    pub history: Option<PathBuf>,
+
+    /// Disables copying the codename to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Theme {
+    Mountains,
+    Gems,
+    Birds,
+}
+
+fn theme_words(theme: Option<Theme>) -> Vec<&'static str> {
+    match theme {
+        Some(Theme::Mountains) => MOUNTAIN_LIST_RAW.lines().collect(),
+        Some(Theme::Gems) => GEM_LIST_RAW.lines().collect(),
+        Some(Theme::Birds) => BIRD_LIST_RAW.lines().collect(),
+        None => MOUNTAIN_LIST_RAW.lines().chain(GEM_LIST_RAW.lines()).chain(BIRD_LIST_RAW.lines()).collect(),
+    }
+}
+
+/// Generates a two-word codename like "Amber Kestrel", skipping any already in the history file.
+pub fn generate<R: Rng + ?Sized>(args: &CodenameArgs, rng: &mut R) -> Result<String, String> {
+    const MAX_ATTEMPTS: usize = 10000;
+
+    let nouns = theme_words(args.theme);
+    let issued: HashSet<String> = match &args.history {
+        Some(path) if path.exists() => fs::read_to_string(path)
+            .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?
+            .lines()
+            .map(|line| line.trim().to_lowercase())
+            .collect(),
+        _ => HashSet::new(),
+    };
+
+    for _ in 0..MAX_ATTEMPTS {
+        let adjective = ADJECTIVE_LIST.choose(rng).expect("Adjective word list empty. This is a build error!");
+        let noun = nouns.choose(rng).expect("Codename word list empty. This is a build error!");
+        let codename = format!("{} {}", title_case(adjective), noun);
+        if issued.contains(&codename.to_lowercase()) {
+            continue;
+        }
+
+        if let Some(path) = &args.history {
+            let mut history_file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .map_err(|error| i18n::message("unable-to-open", &[("path", &path.display()), ("error", &error)]))?;
+            writeln!(history_file, "{}", codename)
+                .map_err(|error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]))?;
+        }
+        return Ok(codename);
+    }
+    Err(i18n::message("codename-exhausted", &[]))
+}
+
+fn title_case(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,470 @@
+use clap::{Args, Subcommand};
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use crate::{defaults, i18n, paths, porcelain};
+
+#[derive(Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    command: ConfigCommands,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Set a default in the config file.
+    /// Example usage: "pwgen config set password.length 20".
+    Set(SetArgs),
+    /// Print a value from the config file.
+    Get(KeyArgs),
+    /// Print every value in the config file.
+    List(ListArgs),
+    /// Open the config file in $VISUAL or $EDITOR.
+    Edit,
+    /// Print the config file for sharing, leaving out tokens, keys and other secrets.
+    Export,
+    /// Add the values of an exported config file to this one.
+    Import(ImportArgs),
+}
+
+#[derive(Args)]
+struct SetArgs {
+    /// Key to set, like "password.length".
+    key: String,
+
+    /// New value. Lists are separated with commas.
+    value: String,
+}
+
+#[derive(Args)]
+struct KeyArgs {
+    /// Key to print, like "password.length".
+    key: String,
+}
+
+#[derive(Args)]
+struct ImportArgs {
+    /// File made with `pwgen config export`.
+    file: PathBuf,
+}
+
+#[derive(Args)]
+struct ListArgs {
+    /// Also list the keys that aren't set, with what they do.
+    #[arg(short = 'a', long = "all")]
+    all: bool,
+}
+
+/// A value in the config file. Only the parts of TOML pwgen needs are supported.
+#[derive(Clone, PartialEq)]
+pub enum Value {
+    String(String),
+    Integer(i64),
+    Boolean(bool),
+    Array(Vec<Value>),
+}
+
+/// The parsed `config.toml`, with table names folded into dotted keys
+/// like "apikey.live.prefix".
+#[derive(Default)]
+pub struct Config {
+    values: BTreeMap<String, Value>,
+}
+
+impl Value {
+    /// Formats the value the way it is written in the config file.
+    pub fn to_toml(&self) -> String {
+        match self {
+            Value::String(text) => {
+                let escaped = text.replace('\\', "\\\\").replace('"', "\\\"")
+                    .replace('\n', "\\n").replace('\t', "\\t").replace('\r', "\\r");
+                format!("\"{}\"", escaped)
+            }
+            Value::Integer(number) => number.to_string(),
+            Value::Boolean(boolean) => boolean.to_string(),
+            Value::Array(items) => {
+                let items: Vec<String> = items.iter().map(Value::to_toml).collect();
+                format!("[{}]", items.join(", "))
+            }
+        }
+    }
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    paths::config_file()
+}
+
+impl Config {
+    /// Loads the config file, which is treated as empty when it doesn't exist.
+    pub fn load() -> Result<Config, String> {
+        let Some(path) = config_path() else {
+            return Ok(Config::default());
+        };
+        if !path.exists() {
+            return Ok(Config::default());
+        }
+
+        log::debug!("Reading the config from \"{}\"", path.display());
+        let text = fs::read_to_string(&path)
+            .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+        Config::parse(&text).map_err(|error| i18n::message("invalid-config-file", &[("path", &path.display()), ("error", &error)]))
+    }
+
+    pub fn parse(text: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        let mut table = String::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let (name, rest) = header.split_once(']')
+                    .ok_or_else(|| i18n::message("config-missing-bracket", &[("line", &line_number)]))?;
+                if !is_comment_or_empty(rest) {
+                    return Err(i18n::message("config-text-after-table", &[("line", &line_number)]));
+                }
+                table = parse_key(name).ok_or_else(|| i18n::message("config-invalid-table", &[("line", &line_number)]))?;
+                continue;
+            }
+
+            let (key, raw_value) = line.split_once('=')
+                .ok_or_else(|| i18n::message("config-expected-key-value", &[("line", &line_number)]))?;
+            let key = parse_key(key).ok_or_else(|| i18n::message("config-invalid-key", &[("line", &line_number)]))?;
+            let (value, rest) = parse_value(raw_value.trim())
+                .ok_or_else(|| i18n::message("config-invalid-value", &[("line", &line_number)]))?;
+            if !is_comment_or_empty(rest) {
+                return Err(i18n::message("config-text-after-value", &[("line", &line_number)]));
+            }
+
+            let full_key = if table.is_empty() { key } else { format!("{}.{}", table, key) };
+            if config.values.insert(full_key.clone(), value).is_some() {
+                return Err(i18n::message("config-set-twice", &[("line", &line_number), ("key", &full_key)]));
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    pub fn set(&mut self, key: &str, value: Value) {
+        self.values.insert(key.to_string(), value);
+    }
+
+    /// All values with their dotted keys, sorted by key.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.values.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    /// Whether any value lives in `table` or one of its subtables.
+    pub fn has_table(&self, table: &str) -> bool {
+        let prefix = format!("{}.", table);
+        self.values.keys().any(|key| key.starts_with(&prefix))
+    }
+
+    pub fn get_string(&self, key: &str) -> Result<Option<&str>, String> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(Value::String(text)) => Ok(Some(text)),
+            Some(_) => Err(i18n::message("config-not-string", &[("key", &key)])),
+        }
+    }
+
+    pub fn get_boolean(&self, key: &str) -> Result<Option<bool>, String> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(Value::Boolean(boolean)) => Ok(Some(*boolean)),
+            Some(_) => Err(i18n::message("config-not-boolean", &[("key", &key)])),
+        }
+    }
+
+    pub fn get_integer(&self, key: &str) -> Result<Option<i64>, String> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(Value::Integer(number)) => Ok(Some(*number)),
+            Some(_) => Err(i18n::message("config-not-integer", &[("key", &key)])),
+        }
+    }
+}
+
+pub fn run(args: &ConfigArgs) -> Result<(), String> {
+    match &args.command {
+        ConfigCommands::Set(set_args) => set(set_args),
+        ConfigCommands::Get(key_args) => get(&key_args.key),
+        ConfigCommands::List(list_args) => list(list_args),
+        ConfigCommands::Edit => edit(),
+        ConfigCommands::Export => export(),
+        ConfigCommands::Import(import_args) => import(import_args),
+    }
+}
+
+fn set(args: &SetArgs) -> Result<(), String> {
+    let key = defaults::find_key(&args.key)?;
+    let value = match key.kind {
+        defaults::Kind::Integer => Value::Integer(args.value.replace('_', "").parse()
+            .map_err(|_| i18n::message("config-set-not-integer", &[("key", &args.key)]))?),
+        defaults::Kind::Boolean => Value::Boolean(args.value.parse()
+            .map_err(|_| i18n::message("config-set-not-boolean", &[("key", &args.key)]))?),
+        defaults::Kind::String | defaults::Kind::Character => Value::String(args.value.clone()),
+        defaults::Kind::List => Value::Array(args.value.split(',').map(|name| Value::String(name.trim().to_string())).collect()),
+    };
+    defaults::validate(&args.key, &value)?;
+
+    save_values(&[(&args.key, value.clone())])?;
+    porcelain::human(i18n::message("config-set", &[("key", &args.key), ("value", &value.to_toml())]));
+    Ok(())
+}
+
+fn get(key: &str) -> Result<(), String> {
+    defaults::find_key(key)?;
+    let config = Config::load()?;
+    match config.get(key) {
+        // Strings are printed without quotes, so they can be used in scripts.
+        Some(Value::String(text)) => println!("{}", text),
+        Some(value) => println!("{}", value.to_toml()),
+        None => porcelain::human(i18n::message("config-not-set", &[("key", &key)])),
+    }
+    Ok(())
+}
+
+fn list(args: &ListArgs) -> Result<(), String> {
+    let config = Config::load()?;
+    for (key, value) in config.iter() {
+        println!("{} = {}", key, value.to_toml());
+    }
+    if args.all {
+        for key in defaults::KEYS.iter().filter(|key| !key.name.contains('*') && config.get(key.name).is_none()) {
+            println!("# {}: {}", key.name, key.description);
+        }
+    } else if config.iter().next().is_none() {
+        porcelain::human(i18n::message("config-nothing-set", &[]));
+    }
+    Ok(())
+}
+
+fn edit() -> Result<(), String> {
+    let path = config_path().ok_or_else(|| i18n::message("no-config-directory", &[]))?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| i18n::message("unable-to-create", &[("path", &dir.display()), ("error", &error)]))?;
+    }
+
+    let default_editor = if cfg!(windows) { "notepad" } else { "vi" };
+    let editor = env::var("VISUAL").or_else(|_| env::var("EDITOR"))
+        .ok().filter(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| default_editor.to_string());
+    // Editors are often set with arguments, like "code --wait".
+    let mut parts = editor.split_whitespace();
+    let status = Command::new(parts.next().unwrap())
+        .args(parts)
+        .arg(&path)
+        .status()
+        .map_err(|error| i18n::message("config-editor-unstartable", &[("editor", &editor), ("error", &error)]))?;
+    if !status.success() {
+        return Err(i18n::message("config-editor-failed", &[("editor", &editor)]));
+    }
+
+    let config = Config::load()?;
+    for (key, value) in config.iter() {
+        if let Err(message) = defaults::find_key(key).and_then(|_| defaults::validate(key, value)) {
+            eprintln!("{}", i18n::message("config-warning", &[("message", &message)]));
+        }
+    }
+    Ok(())
+}
+
+/// Whether a key might hold a secret, judged by the last part of its name.
+fn is_sensitive(key: &str) -> bool {
+    let name = key.rsplit('.').next().unwrap_or(key).to_lowercase();
+    ["token", "secret", "password", "passphrase", "key"].iter()
+        .any(|word| name == *word || name.ends_with(&format!("_{}", word)))
+}
+
+fn export() -> Result<(), String> {
+    let config = Config::load()?;
+    println!("# Exported with `pwgen config export`, load it with `pwgen config import`.");
+
+    let values: Vec<(&str, &Value)> = config.iter().filter(|(key, _)| !is_sensitive(key)).collect();
+    // Keys outside of tables have to come first, they would end up in the last table otherwise.
+    for (key, value) in values.iter().filter(|(key, _)| !key.contains('.')) {
+        println!("{} = {}", key, value.to_toml());
+    }
+    let mut current_table = "";
+    for (key, value) in values.iter().filter(|(key, _)| key.contains('.')) {
+        let (table, field) = key.rsplit_once('.').unwrap();
+        if table != current_table {
+            println!();
+            println!("[{}]", table);
+            current_table = table;
+        }
+        println!("{} = {}", field, value.to_toml());
+    }
+    Ok(())
+}
+
+fn import(args: &ImportArgs) -> Result<(), String> {
+    let text = fs::read_to_string(&args.file)
+        .map_err(|error| i18n::message("unable-to-read", &[("path", &args.file.display()), ("error", &error)]))?;
+    let imported = Config::parse(&text).map_err(|error| i18n::message("invalid-config-file", &[("path", &args.file.display()), ("error", &error)]))?;
+
+    // Everything is checked before anything is written, so a bad file changes nothing.
+    let mut values = Vec::new();
+    for (key, value) in imported.iter() {
+        defaults::validate(key, value)?;
+        values.push((key, value.clone()));
+    }
+    save_values(&values)?;
+    porcelain::human(i18n::message("config-imported", &[("count", &values.len())]));
+    Ok(())
+}
+
+/// Writes values to the config file, which is created when it doesn't exist yet.
+pub fn save_values(values: &[(&str, Value)]) -> Result<(), String> {
+    let path = config_path().ok_or_else(|| i18n::message("no-config-directory", &[]))?;
+    let mut text = if path.exists() {
+        fs::read_to_string(&path).map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?
+    } else {
+        "# Defaults for pwgen, see `pwgen config list --all` for everything that can be set.\n".to_string()
+    };
+    // Refuse to touch a file that is already broken, `pwgen config edit` can fix it first.
+    Config::parse(&text).map_err(|error| i18n::message("invalid-config-file", &[("path", &path.display()), ("error", &error)]))?;
+
+    for (key, value) in values {
+        text = set_in_text(&text, key, value);
+    }
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| i18n::message("unable-to-create", &[("path", &dir.display()), ("error", &error)]))?;
+    }
+    fs::write(&path, text).map_err(|error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]))
+}
+
+/// Sets `key` in the config file text, keeping everything else including comments. An
+/// existing line is replaced, otherwise the key is added to its table, creating it if needed.
+fn set_in_text(text: &str, key: &str, value: &Value) -> String {
+    let (table, field) = key.rsplit_once('.').unwrap_or(("", key));
+    let new_line = format!("{} = {}", field, value.to_toml());
+    let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+
+    let mut current_table = String::new();
+    // Line after which the key is added when it isn't set yet.
+    let mut table_end = None;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        if let Some(header) = trimmed.strip_prefix('[') {
+            // Keys outside of tables have to come before the first one.
+            if table.is_empty() && current_table.is_empty() && table_end.is_none() {
+                let blank_lines = lines[..index].iter().rev().take_while(|line| line.trim().is_empty()).count();
+                table_end = Some(index - blank_lines);
+            }
+            current_table = header.split_once(']').and_then(|(name, _)| parse_key(name)).unwrap_or_default();
+            if current_table == table {
+                table_end = Some(index + 1);
+            }
+            continue;
+        }
+        let Some((line_key, _)) = trimmed.split_once('=').filter(|_| !trimmed.starts_with('#')) else {
+            continue;
+        };
+        let Some(line_key) = parse_key(line_key) else {
+            continue;
+        };
+        let full_key = if current_table.is_empty() { line_key } else { format!("{}.{}", current_table, line_key) };
+        if full_key == key {
+            lines[index] = new_line;
+            return lines.join("\n") + "\n";
+        }
+        if current_table == table {
+            table_end = Some(index + 1);
+        }
+    }
+
+    match table_end {
+        Some(index) => lines.insert(index, new_line),
+        None if table.is_empty() => lines.push(new_line),
+        None => {
+            if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push(format!("[{}]", table));
+            lines.push(new_line);
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+fn is_comment_or_empty(text: &str) -> bool {
+    let text = text.trim();
+    text.is_empty() || text.starts_with('#')
+}
+
+/// Parses a bare or dotted key such as `length` or `apikey.live`.
+fn parse_key(key: &str) -> Option<String> {
+    let parts: Vec<&str> = key.split('.').map(str::trim).collect();
+    let is_valid = parts.iter().all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    });
+    is_valid.then(|| parts.join("."))
+}
+
+/// Parses a value at the start of `text`, returning it with the unparsed rest.
+fn parse_value(text: &str) -> Option<(Value, &str)> {
+    if let Some(rest) = text.strip_prefix('"') {
+        let mut string = String::new();
+        let mut chars = rest.char_indices();
+        while let Some((index, c)) = chars.next() {
+            match c {
+                '"' => return Some((Value::String(string), &rest[index + 1..])),
+                '\\' => match chars.next()?.1 {
+                    'n' => string.push('\n'),
+                    't' => string.push('\t'),
+                    'r' => string.push('\r'),
+                    '"' => string.push('"'),
+                    '\\' => string.push('\\'),
+                    _ => return None,
+                },
+                c => string.push(c),
+            }
+        }
+        return None;
+    }
+
+    if let Some(rest) = text.strip_prefix('\'') {
+        let end = rest.find('\'')?;
+        return Some((Value::String(rest[..end].to_string()), &rest[end + 1..]));
+    }
+
+    if let Some(mut rest) = text.strip_prefix('[') {
+        let mut items = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            if let Some(after) = rest.strip_prefix(']') {
+                return Some((Value::Array(items), after));
+            }
+            let (item, after) = parse_value(rest)?;
+            items.push(item);
+            rest = after.trim_start();
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after;
+            } else if !rest.starts_with(']') {
+                return None;
+            }
+        }
+    }
+
+    let end = text.find(|c: char| c.is_whitespace() || c == ',' || c == ']' || c == '#').unwrap_or(text.len());
+    let (word, rest) = text.split_at(end);
+    let value = match word {
+        "true" => Value::Boolean(true),
+        "false" => Value::Boolean(false),
+        _ => Value::Integer(word.replace('_', "").parse().ok()?),
+    };
+    Some((value, rest))
+}
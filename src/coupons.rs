@@ -0,0 +1,111 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::collections::HashSet;
+
+use crate::encoding::CROCKFORD_ALPHABET;
+use crate::i18n;
+
+const MAX_COUNT: usize = 10_000_000;
+
+#[derive(Args)]
+pub struct CouponsArgs {
+    /// Amount of codes, all different from each other.
+    /// Defaults to 100.
+    #[arg(short = 'C', long = "count")]
+    count: Option<usize>,
+
+    /// Amount of random characters per code, not counting the prefix.
+    /// Defaults to 10.
+    #[arg(short = 'l', long = "length")]
+    length: Option<usize>,
+
+    /// Characters the codes are made of.
+    /// Defaults to crockford.
+    #[arg(short = 'c', long = "charset", value_enum)]
+    charset: Option<CouponCharset>,
+
+    /// Text put in front of every code.
+    /// Example usage: "SUMMER-".
+    #[arg(short = 'p', long = "prefix")]
+    prefix: Option<String>,
+
+    /// Prints the codes as CSV with a "code" header, ready for import into a shop system.
+    #[arg(long = "csv")]
+    csv: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum CouponCharset {
+    /// Uppercase letters and digits without I, L, O and U, so codes are easy to read out.
+    Crockford,
+    /// Uppercase letters and digits.
+    Alphanumeric,
+    /// Digits only.
+    Numeric,
+}
+
+impl CouponCharset {
+    fn characters(self) -> &'static [u8] {
+        match self {
+            CouponCharset::Crockford => CROCKFORD_ALPHABET,
+            CouponCharset::Alphanumeric => b"ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789",
+            CouponCharset::Numeric => b"0123456789",
+        }
+    }
+}
+
+pub fn run(args: &CouponsArgs) -> Result<(), String> {
+    let codes = generate(args, &mut rand::rng())?;
+    if args.csv {
+        println!("code");
+        for code in &codes {
+            println!("{}", csv_field(code));
+        }
+    } else {
+        for code in &codes {
+            println!("{}", code);
+        }
+    }
+    Ok(())
+}
+
+/// Generates a batch of distinct codes. Duplicates are thrown away and drawn again,
+/// so the batch is refused up front when it would use up too much of the code space.
+fn generate<R: Rng + ?Sized>(args: &CouponsArgs, rng: &mut R) -> Result<Vec<String>, String> {
+    let count = args.count.unwrap_or(100);
+    if count == 0 || count > MAX_COUNT {
+        return Err(i18n::message("count-range", &[("max", &MAX_COUNT)]));
+    }
+    let length = args.length.unwrap_or(10);
+    if length == 0 || length > 64 {
+        return Err(i18n::message("length-range", &[("max", &64)]));
+    }
+
+    let characters = args.charset.unwrap_or(CouponCharset::Crockford).characters();
+    let possible_codes = (characters.len() as f64).powi(length as i32);
+    // Beyond half of the code space codes get easy to guess and duplicates slow the batch down.
+    if count as f64 > possible_codes / 2.0 {
+        return Err(i18n::message("coupons-too-few", &[("possible", &possible_codes), ("length", &length), ("count", &count)]));
+    }
+
+    let prefix = args.prefix.as_deref().unwrap_or("");
+    let mut seen = HashSet::with_capacity(count);
+    let mut codes = Vec::with_capacity(count);
+    while codes.len() < count {
+        let code: String = (0..length).map(|_| *characters.choose(rng).unwrap() as char).collect();
+        if seen.insert(code.clone()) {
+            codes.push(format!("{}{}", prefix, code));
+        }
+    }
+    Ok(codes)
+}
+
+/// Quotes a CSV field when it contains characters that would otherwise break the row.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
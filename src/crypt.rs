@@ -0,0 +1,86 @@
+//! SHA-512 based crypt(3) hashes (`$6$`), as accepted by `chpasswd -e` and `/etc/shadow`.
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::digest;
+
+const CRYPT_ALPHABET: &[u8; 64] = b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Rounds used when a hash doesn't name any, so they're left out of the hash string.
+const DEFAULT_ROUNDS: u32 = 5000;
+
+/// The order the digest bytes are encoded in, three at a time, with byte 63 last on its own.
+const BYTE_ORDER: [[usize; 3]; 21] = [
+    [0, 21, 42], [22, 43, 1], [44, 2, 23], [3, 24, 45], [25, 46, 4], [47, 5, 26], [6, 27, 48],
+    [28, 49, 7], [50, 8, 29], [9, 30, 51], [31, 52, 10], [53, 11, 32], [12, 33, 54], [34, 55, 13],
+    [56, 14, 35], [15, 36, 57], [37, 58, 16], [59, 17, 38], [18, 39, 60], [40, 61, 19], [62, 20, 41],
+];
+
+/// Hashes the password with a random 16 character salt and the default rounds.
+pub fn sha512_crypt<R: Rng + ?Sized>(password: &str, rng: &mut R) -> String {
+    let salt: String = (0..16).map(|_| *CRYPT_ALPHABET.choose(rng).unwrap() as char).collect();
+    sha512_crypt_with(password.as_bytes(), salt.as_bytes(), DEFAULT_ROUNDS)
+}
+
+/// Ulrich Drepper's SHA-crypt algorithm with SHA-512.
+fn sha512_crypt_with(password: &[u8], salt: &[u8], rounds: u32) -> String {
+    let salt = &salt[..salt.len().min(16)];
+
+    let alternate = digest::sha512(&[password, salt, password].concat());
+    let mut input = [password, salt].concat();
+    input.extend(alternate.iter().cycle().take(password.len()));
+    let mut length = password.len();
+    while length > 0 {
+        if length & 1 == 1 {
+            input.extend_from_slice(&alternate);
+        } else {
+            input.extend_from_slice(password);
+        }
+        length >>= 1;
+    }
+    let mut result = digest::sha512(&input);
+
+    let password_digest = digest::sha512(&password.repeat(password.len()));
+    let password_sequence: Vec<u8> = password_digest.iter().cycle().take(password.len()).copied().collect();
+    let salt_digest = digest::sha512(&salt.repeat(16 + result[0] as usize));
+    let salt_sequence: Vec<u8> = salt_digest.iter().cycle().take(salt.len()).copied().collect();
+
+    for round in 0..rounds {
+        let mut input = Vec::with_capacity(64 + 2 * password.len() + salt.len());
+        if round % 2 == 1 {
+            input.extend_from_slice(&password_sequence);
+        } else {
+            input.extend_from_slice(&result);
+        }
+        if round % 3 != 0 {
+            input.extend_from_slice(&salt_sequence);
+        }
+        if round % 7 != 0 {
+            input.extend_from_slice(&password_sequence);
+        }
+        if round % 2 == 1 {
+            input.extend_from_slice(&result);
+        } else {
+            input.extend_from_slice(&password_sequence);
+        }
+        result = digest::sha512(&input);
+    }
+
+    let mut encoded = String::with_capacity(86);
+    for [first, second, third] in BYTE_ORDER {
+        push_base64(&mut encoded, (result[first] as u32) << 16 | (result[second] as u32) << 8 | result[third] as u32, 4);
+    }
+    push_base64(&mut encoded, result[63] as u32, 2);
+
+    let rounds_part = if rounds == DEFAULT_ROUNDS { String::new() } else { format!("rounds={}$", rounds) };
+    format!("$6${}{}${}", rounds_part, String::from_utf8_lossy(salt), encoded)
+}
+
+/// Appends the lowest `amount * 6` bits of `value`, least significant first.
+fn push_base64(encoded: &mut String, mut value: u32, amount: usize) {
+    for _ in 0..amount {
+        encoded.push(CRYPT_ALPHABET[(value & 63) as usize] as char);
+        value >>= 6;
+    }
+}
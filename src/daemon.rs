@@ -0,0 +1,99 @@
+use clap::Args;
+use std::env;
+use std::path::PathBuf;
+
+use crate::i18n;
+
+#[derive(Args)]
+pub struct DaemonArgs {
+    /// Path of the Unix socket to listen on.
+    /// Defaults to pwgen.sock in $XDG_RUNTIME_DIR.
+    #[arg(short = 's', long = "socket")]
+    socket: Option<PathBuf>,
+}
+
+/// Requests are a single short line, longer ones are cut off.
+#[cfg(unix)]
+const MAX_REQUEST_LENGTH: u64 = 64 * 1024;
+
+/// Serves generation requests on a Unix socket until killed. Every line a client sends
+/// is a JSON request as in `pwgen native-host`, answered with a line of JSON.
+pub fn run(args: &DaemonArgs) -> Result<(), String> {
+    let socket = match &args.socket {
+        Some(socket) => socket.clone(),
+        None => match env::var_os("XDG_RUNTIME_DIR").filter(|dir| !dir.is_empty()) {
+            Some(dir) => PathBuf::from(dir).join("pwgen.sock"),
+            None => return Err(i18n::message("daemon-no-runtime-dir", &[])),
+        },
+    };
+
+    serve(&socket)
+}
+
+#[cfg(unix)]
+fn serve(socket: &std::path::Path) -> Result<(), String> {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::os::unix::fs::PermissionsExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::thread;
+
+    if socket.exists() {
+        if UnixStream::connect(socket).is_ok() {
+            return Err(i18n::message("daemon-already-listening", &[("path", &socket.display())]));
+        }
+        // Left behind by a daemon that didn't shut down cleanly.
+        fs::remove_file(socket).map_err(|error| i18n::message("unable-to-remove", &[("path", &socket.display()), ("error", &error)]))?;
+    }
+
+    // Only the user running the daemon may ask it for secrets. The socket is created that way,
+    // so nobody can connect between binding and restricting it. No other thread runs yet
+    // that could create a file under the umask meanwhile.
+    // SAFETY: umask only swaps the file mode mask of the process.
+    let umask = unsafe { libc::umask(0o177) };
+    let bound = UnixListener::bind(socket);
+    // SAFETY: as above, restoring the mask from before.
+    unsafe { libc::umask(umask) };
+    let listener = bound.map_err(|error| i18n::message("daemon-unable-to-listen", &[("path", &socket.display()), ("error", &error)]))?;
+    fs::set_permissions(socket, fs::Permissions::from_mode(0o600))
+        .map_err(|error| i18n::message("daemon-unable-to-restrict", &[("path", &socket.display()), ("error", &error)]))?;
+    crate::porcelain::human(i18n::message("daemon-listening", &[("path", &socket.display())]));
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                log::warn!("Unable to accept a connection: {}", error);
+                continue;
+            }
+        };
+        log::debug!("Accepted a connection");
+        thread::spawn(move || {
+            let mut rng = rand::rng();
+            let Ok(mut writer) = stream.try_clone() else {
+                return;
+            };
+            let mut reader = BufReader::new(stream);
+            loop {
+                let mut line = String::new();
+                match (&mut reader).take(MAX_REQUEST_LENGTH).read_line(&mut line) {
+                    Ok(0) | Err(_) => return,
+                    Ok(_) => {}
+                }
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let response = crate::request::handle(&line, &mut rng).to_compact();
+                if writeln!(writer, "{}", response).is_err() {
+                    return;
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn serve(_socket: &std::path::Path) -> Result<(), String> {
+    Err(i18n::message("daemon-unsupported", &[]))
+}
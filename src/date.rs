@@ -0,0 +1,70 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A calendar date in the proleptic Gregorian calendar.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i64,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    /// Today's date in UTC.
+    pub fn today() -> Date {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+        Date::from_days((seconds / 86400) as i64)
+    }
+
+    /// The current time in UTC as an RFC 3339 timestamp, like "2024-05-01T13:37:00Z".
+    pub fn timestamp_now() -> String {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+        let time_of_day = seconds % 86400;
+        format!(
+            "{}T{:02}:{:02}:{:02}Z",
+            Date::from_days((seconds / 86400) as i64),
+            time_of_day / 3600,
+            time_of_day / 60 % 60,
+            time_of_day % 60,
+        )
+    }
+
+    /// Converts days since 1970-01-01 to a date, using Howard Hinnant's civil_from_days.
+    pub fn from_days(days: i64) -> Date {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let day_of_era = z.rem_euclid(146097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+        let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+        Date { year, month, day }
+    }
+
+    /// Days since 1970-01-01, the inverse of `from_days`.
+    pub fn to_days(self) -> i64 {
+        let year = if self.month <= 2 { self.year - 1 } else { self.year };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = if self.month > 2 { self.month - 3 } else { self.month + 9 } as i64;
+        let day_of_year = (153 * shifted_month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// The same day `years` earlier, with February 29 becoming February 28 in common years.
+    pub fn years_earlier(self, years: i64) -> Date {
+        let year = self.year - years;
+        let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+        let day = if self.month == 2 && self.day == 29 && !is_leap_year { 28 } else { self.day };
+        Date { year, month: self.month, day }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
@@ -0,0 +1,35 @@
+//! Generation giving up because every attempt broke a constraint, like the policy of `--site`
+//! or `--policy` or the breach filter of `--check-offline`. pwgen then exits with [`EXIT_CODE`]
+//! instead of 0, so jobs generating credentials can tell a dead end apart and alert on it.
+
+use std::sync::Mutex;
+
+use crate::porcelain;
+
+pub const EXIT_CODE: i32 = 3;
+
+static REACHED: Mutex<Option<DeadEnd>> = Mutex::new(None);
+
+struct DeadEnd {
+    constraint: &'static str,
+    attempts: usize,
+}
+
+/// Records that `attempts` secrets in a row broke `constraint`, passing on the error `message`.
+pub fn reached(constraint: &'static str, attempts: usize, message: String) -> String {
+    *REACHED.lock().unwrap() = Some(DeadEnd { constraint, attempts });
+    message
+}
+
+/// Prints a generation error, exiting with [`EXIT_CODE`] when it came from a dead end.
+/// Scripts get the constraint and the attempts made before the error.
+pub fn error(message: &str) {
+    let Some(dead_end) = REACHED.lock().unwrap().take() else {
+        porcelain::error(message);
+        return;
+    };
+    porcelain::field("dead-end", dead_end.constraint);
+    porcelain::field("attempts", &dead_end.attempts.to_string());
+    porcelain::error(message);
+    std::process::exit(EXIT_CODE);
+}
@@ -0,0 +1,272 @@
+use std::path::PathBuf;
+
+use clap::{CommandFactory, ValueEnum};
+
+use crate::{Commands, PassphraseArgs, PasswordArgs, i18n, password, sites};
+use crate::policy::Policy;
+use crate::config::{Config, Value};
+
+/// Kind of value a config key holds.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Kind {
+    Integer,
+    String,
+    Character,
+    Boolean,
+    /// An array of names, or a single string of comma separated names.
+    List,
+}
+
+/// A config key holding the default of a command line option.
+/// A '*' in the name stands for any single part, like the profile of "apikey.*.prefix".
+pub struct Key {
+    pub name: &'static str,
+    pub kind: Kind,
+    pub description: &'static str,
+}
+
+/// Every default that can be set in the config file. Options given on the command line
+/// or through their PWGEN_* environment variable take precedence over these.
+pub const KEYS: &[Key] = &[
+    Key { name: "default_command", kind: Kind::String, description: "Command or alias bare `pwgen` runs." },
+    Key { name: "password.length", kind: Kind::Integer, description: "Amount of characters." },
+    Key { name: "password.char_sets", kind: Kind::List, description: "Character sets used, like [\"lower\", \"digits\"]." },
+    Key { name: "password.exclude", kind: Kind::String, description: "Characters left out of the character sets." },
+    Key { name: "password.no_copy", kind: Kind::Boolean, description: "Don't copy the password to clipboard." },
+    Key { name: "password.no_hide", kind: Kind::Boolean, description: "Show the password generated." },
+    Key { name: "passphrase.words", kind: Kind::Integer, description: "Amount of words." },
+    Key { name: "passphrase.word_char", kind: Kind::Character, description: "Character in between the words." },
+    Key { name: "passphrase.wordlist", kind: Kind::String, description: "Name of the wordlist to pick words from." },
+    Key { name: "passphrase.max_length", kind: Kind::Integer, description: "Maximum total length including separators." },
+    Key { name: "passphrase.no_copy", kind: Kind::Boolean, description: "Don't copy the passphrase to clipboard." },
+    Key { name: "passphrase.no_hide", kind: Kind::Boolean, description: "Show the passphrase generated." },
+    Key { name: "username.style", kind: Kind::String, description: "How the name part is built." },
+    Key { name: "username.numbers", kind: Kind::Integer, description: "Amount of numbers after the username." },
+    Key { name: "username.word_char", kind: Kind::Character, description: "Character in between the words and numbers." },
+    Key { name: "username.max_length", kind: Kind::Integer, description: "Maximum total length including separators and numbers." },
+    Key { name: "username.block_words", kind: Kind::String, description: "File of words usernames must not contain." },
+    Key { name: "username.hide", kind: Kind::Boolean, description: "Hide the username generated." },
+    Key { name: "username.no_copy", kind: Kind::Boolean, description: "Don't copy the username to clipboard." },
+    Key { name: "clipboard.clear_after", kind: Kind::Integer, description: "Seconds after which a copied secret is cleared from the clipboard." },
+    Key { name: "history.enabled", kind: Kind::Boolean, description: "Keep the last secret in the platform secret store for `pwgen last`." },
+    Key { name: "audit.file", kind: Kind::String, description: "File an audit entry is appended to for every secret generated, without the secret." },
+    Key { name: "alias.*", kind: Kind::String, description: "Command line the alias stands for, like \"password --length 24\"." },
+    Key { name: "apikey.*.prefix", kind: Kind::String, description: "Text in front of keys of the profile." },
+    Key { name: "apikey.*.bytes", kind: Kind::Integer, description: "Amount of random bytes in keys of the profile." },
+    Key { name: "apikey.*.checksum", kind: Kind::String, description: "Checksum appended to keys of the profile." },
+    Key { name: "xkpasswd.*.num_words", kind: Kind::Integer, description: "Amount of words of the scheme." },
+    Key { name: "xkpasswd.*.word_length_min", kind: Kind::Integer, description: "Shortest words of the scheme." },
+    Key { name: "xkpasswd.*.word_length_max", kind: Kind::Integer, description: "Longest words of the scheme." },
+    Key { name: "xkpasswd.*.case_transform", kind: Kind::String, description: "Case of the words, like \"ALTERNATE\" or \"RANDOM\"." },
+    Key { name: "xkpasswd.*.separator_character", kind: Kind::String, description: "Character in between the words, \"RANDOM\" or \"NONE\"." },
+    Key { name: "xkpasswd.*.separator_alphabet", kind: Kind::List, description: "Characters a random separator is picked from." },
+    Key { name: "xkpasswd.*.padding_digits_before", kind: Kind::Integer, description: "Random digits before the words." },
+    Key { name: "xkpasswd.*.padding_digits_after", kind: Kind::Integer, description: "Random digits after the words." },
+    Key { name: "xkpasswd.*.padding_type", kind: Kind::String, description: "\"NONE\", \"FIXED\" or \"ADAPTIVE\" padding with symbols." },
+    Key { name: "xkpasswd.*.padding_character", kind: Kind::String, description: "Padding symbol, \"RANDOM\" or \"SEPARATOR\"." },
+    Key { name: "xkpasswd.*.padding_characters_before", kind: Kind::Integer, description: "Padding symbols before the password with fixed padding." },
+    Key { name: "xkpasswd.*.padding_characters_after", kind: Kind::Integer, description: "Padding symbols after the password with fixed padding." },
+    Key { name: "xkpasswd.*.pad_to_length", kind: Kind::Integer, description: "Length adaptive padding pads or cuts the password to." },
+    Key { name: "xkpasswd.*.symbol_alphabet", kind: Kind::List, description: "Characters a random padding symbol is picked from." },
+];
+
+/// Looks up a key, suggesting the closest known one for typos.
+pub fn find_key(name: &str) -> Result<&'static Key, String> {
+    let parts: Vec<&str> = name.split('.').collect();
+    let matches = |key: &Key| {
+        let key_parts: Vec<&str> = key.name.split('.').collect();
+        key_parts.len() == parts.len()
+            && key_parts.iter().zip(&parts).all(|(key_part, part)| *key_part == "*" || key_part == part)
+    };
+    if let Some(key) = KEYS.iter().find(|key| matches(key)) {
+        return Ok(key);
+    }
+
+    let closest = KEYS.iter()
+        .filter(|key| !key.name.contains('*'))
+        .map(|key| (edit_distance(name, key.name), key.name))
+        .min();
+    match closest {
+        Some((distance, closest)) if distance <= 3 => Err(i18n::message("defaults-unknown-key-suggestion", &[("key", &name), ("closest", &closest)])),
+        _ => Err(i18n::message("defaults-unknown-key", &[("key", &name)])),
+    }
+}
+
+/// Levenshtein distance between two keys.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Fills in the options that weren't given with the defaults from the config file.
+/// The config file is only read for the commands that have defaults in it.
+pub fn apply(command: &mut Commands) -> Result<(), String> {
+    if !matches!(command, Commands::Password(_) | Commands::Passphrase(_) | Commands::Username(_)) {
+        return Ok(());
+    }
+    let config = &Config::load()?;
+    match command {
+        Commands::Password(args) => apply_password(args, config)?,
+        Commands::Passphrase(args) => apply_passphrase(args, config)?,
+        Commands::Username(args) => {
+            args.style = args.style.or(choice(config, "username.style")?);
+            // An explicit number range replaces the amount of numbers.
+            if args.number_range.is_none() {
+                args.numbers = args.numbers.or(integer(config, "username.numbers")?);
+            }
+            args.word_char = args.word_char.or(character(config, "username.word_char")?);
+            args.max_length = args.max_length.or(integer(config, "username.max_length")?);
+            args.block_words = args.block_words.take().or(string(config, "username.block_words")?.map(PathBuf::from));
+            args.hide |= boolean(config, "username.hide")?;
+            args.copy_disabled |= boolean(config, "username.no_copy")?;
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Fills in the defaults of `pwgen password`, which requests of the daemon and the native
+/// host get too.
+pub fn apply_password(args: &mut PasswordArgs, config: &Config) -> Result<(), String> {
+    // Excluding more only narrows the rules of a site or policy down, so the excluded characters
+    // of the config file come first and the policy adds what it doesn't allow to them.
+    args.excluded_chars = args.excluded_chars.take().or(string(config, "password.exclude")?);
+    // The rules of the site or policy come before the rest of the config file, which may not follow them.
+    if let Some(site) = args.site.clone() {
+        sites::policy(&site)?.apply(args)?;
+    } else if let Some(path) = args.policy_file.clone() {
+        Policy::load(&path)?.apply(args)?;
+    }
+    args.length = args.length.or(password_length(config)?);
+    args.character_sets = args.character_sets.take().or(list(config, "password.char_sets")?);
+    args.copy_disabled |= boolean(config, "password.no_copy")?;
+    args.hide_disabled |= boolean(config, "password.no_hide")?;
+    Ok(())
+}
+
+/// Fills in the defaults of `pwgen passphrase`, which requests of the daemon and the native
+/// host get too.
+pub fn apply_passphrase(args: &mut PassphraseArgs, config: &Config) -> Result<(), String> {
+    args.words = args.words.or(integer(config, "passphrase.words")?);
+    args.word_char = args.word_char.or(character(config, "passphrase.word_char")?);
+    args.wordlist = args.wordlist.take().or(string(config, "passphrase.wordlist")?);
+    args.max_length = args.max_length.or(integer(config, "passphrase.max_length")?);
+    args.copy_disabled |= boolean(config, "passphrase.no_copy")?;
+    args.hide_disabled |= boolean(config, "passphrase.no_hide")?;
+    Ok(())
+}
+
+/// Command bare `pwgen` runs, which is `password` unless the config file says otherwise.
+pub fn default_command() -> Result<String, String> {
+    let config = Config::load()?;
+    Ok(config.get_string("default_command")?.unwrap_or("password").to_string())
+}
+
+/// Seconds to wait before clearing a copied secret from the clipboard, if set.
+pub fn clear_after() -> Option<u64> {
+    let config = Config::load().ok()?;
+    let seconds = config.get_integer("clipboard.clear_after").ok()??;
+    u64::try_from(seconds).ok().filter(|seconds| *seconds > 0)
+}
+
+/// Checks that a value has the kind of its key and, for keys holding choices, is one of them.
+pub fn validate(name: &str, value: &Value) -> Result<(), String> {
+    let key = find_key(name)?;
+    let mut config = Config::default();
+    config.set(name, value.clone());
+    match key.name {
+        "password.length" => password_length(&config).map(|_| ()),
+        "password.char_sets" => list::<crate::password::CharSet>(&config, name).map(|_| ()),
+        "username.style" => choice::<crate::username::UsernameStyle>(&config, name).map(|_| ()),
+        "apikey.*.checksum" => choice::<crate::apikey::Checksum>(&config, name).map(|_| ()),
+        "default_command" => {
+            let command = string(&config, name)?.unwrap_or_default();
+            let is_alias = Config::load()?.get(&format!("alias.{}", command)).is_some();
+            if crate::Cli::command().find_subcommand(&command).is_none() && !is_alias {
+                return Err(i18n::message("defaults-unknown-command", &[("command", &command)]));
+            }
+            Ok(())
+        }
+        _ => match key.kind {
+            Kind::Integer => integer::<u32>(&config, name).map(|_| ()),
+            Kind::String => string(&config, name).map(|_| ()),
+            Kind::Character => character(&config, name).map(|_| ()),
+            Kind::Boolean => config.get_boolean(name).map(|_| ()),
+            Kind::List => Ok(()),
+        },
+    }
+}
+
+/// The `password.length` of the config file, within what `--length` allows.
+fn password_length(config: &Config) -> Result<Option<u32>, String> {
+    let length = integer::<u32>(config, "password.length")?;
+    if length.is_some_and(|length| !(1..=password::MAX_LENGTH).contains(&length)) {
+        return Err(i18n::message("defaults-length-range", &[("max", &password::MAX_LENGTH)]));
+    }
+    Ok(length)
+}
+
+fn integer<T: TryFrom<i64>>(config: &Config, key: &str) -> Result<Option<T>, String> {
+    config.get_integer(key)?
+        .map(|number| T::try_from(number).map_err(|_| i18n::message("defaults-out-of-range", &[("key", &key)])))
+        .transpose()
+}
+
+fn string(config: &Config, key: &str) -> Result<Option<String>, String> {
+    Ok(config.get_string(key)?.map(str::to_string))
+}
+
+fn character(config: &Config, key: &str) -> Result<Option<char>, String> {
+    let Some(text) = config.get_string(key)? else {
+        return Ok(None);
+    };
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(Some(c)),
+        _ => Err(i18n::message("defaults-not-character", &[("key", &key)])),
+    }
+}
+
+fn boolean(config: &Config, key: &str) -> Result<bool, String> {
+    Ok(config.get_boolean(key)?.unwrap_or(false))
+}
+
+fn choice<T: ValueEnum>(config: &Config, key: &str) -> Result<Option<T>, String> {
+    let Some(text) = config.get_string(key)? else {
+        return Ok(None);
+    };
+    T::from_str(text, true).map(Some).map_err(|_| unknown_choice::<T>(key, text))
+}
+
+pub fn list<T: ValueEnum>(config: &Config, key: &str) -> Result<Option<Vec<T>>, String> {
+    let names: Vec<String> = match config.get(key) {
+        None => return Ok(None),
+        Some(Value::String(text)) => text.split(',').map(|name| name.trim().to_string()).collect(),
+        Some(Value::Array(items)) => items.iter().map(|item| match item {
+            Value::String(name) => Ok(name.clone()),
+            _ => Err(i18n::message("defaults-not-strings", &[("key", &key)])),
+        }).collect::<Result<_, _>>()?,
+        Some(_) => return Err(i18n::message("defaults-not-array", &[("key", &key)])),
+    };
+    names.iter()
+        .map(|name| T::from_str(name, true).map_err(|_| unknown_choice::<T>(key, name)))
+        .collect::<Result<Vec<T>, String>>()
+        .map(Some)
+}
+
+fn unknown_choice<T: ValueEnum>(key: &str, text: &str) -> String {
+    let choices: Vec<String> = T::value_variants().iter()
+        .filter_map(|variant| variant.to_possible_value())
+        .map(|value| value.get_name().to_string())
+        .collect();
+    i18n::message("defaults-unknown-choice", &[("key", &key), ("value", &text), ("choices", &choices.join(", "))])
+}
@@ -0,0 +1,238 @@
+//! Site passwords that are derived instead of stored, so the same one comes out every time
+//! for a site without keeping it anywhere. The secret they're derived from lives on a
+//! hardware token, which has to be plugged in to get a password.
+
+use std::fs;
+use std::path::PathBuf;
+
+use clap::{ArgGroup, Args};
+use rand::Rng;
+
+use crate::{PasswordArgs, digest, encoding, external, i18n, password, paths};
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("backend").required(true)))]
+pub struct DeriveArgs {
+    /// Site the password is for. Letter case and a leading "www." don't matter.
+    /// Example usage: "pwgen derive github.com --yubikey".
+    site: String,
+
+    /// Derive the password with the HMAC-SHA1 challenge-response of a YubiKey, using
+    /// `ykman` or `ykchalresp`. The site is the challenge.
+    #[arg(long = "yubikey", group = "backend")]
+    yubikey: bool,
+
+    /// Slot of the YubiKey set up for challenge-response.
+    /// Defaults to 2.
+    #[arg(long = "slot", value_parser = clap::value_parser!(u8).range(1..=2), conflicts_with = "fido2")]
+    slot: Option<u8>,
+
+    /// Derive the password with the hmac-secret extension of a FIDO2 security key, using
+    /// the libfido2 tools. A credential for pwgen is made on the key the first time.
+    #[arg(long = "fido2", group = "backend")]
+    fido2: bool,
+
+    /// Security key to use, as listed by `fido2-token -L`.
+    /// Defaults to the first one found.
+    #[arg(long = "device", conflicts_with = "yubikey")]
+    device: Option<String>,
+
+    /// Only require a touch and not the PIN of the security key.
+    /// Gives different passwords than with the PIN.
+    #[arg(long = "no-pin", conflicts_with = "yubikey")]
+    no_pin: bool,
+
+    /// Raise this to get a new password for a site, after a breach for example.
+    /// Defaults to 1.
+    #[arg(short = 'C', long = "counter")]
+    counter: Option<u32>,
+
+    /// Amount of characters.
+    /// Defaults to 16.
+    #[arg(short = 'l', long = "length")]
+    length: Option<u32>,
+
+    /// All the character sets used in the password.
+    /// By default this is set to all non-rare sets available.
+    /// Example usage: "--char-set lower,upper,digits".
+    #[arg(short = 'c', long = "char-set", value_enum, value_delimiter = ',')]
+    character_sets: Option<Vec<password::CharSet>>,
+
+    /// Exclude characters from the character sets.
+    /// Example usage: "--exclude abc!@#".
+    #[arg(short = 'e', long = "exclude")]
+    excluded_chars: Option<String>,
+
+    /// Disables copying the password to clipboard.
+    #[arg(short = 'o', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+
+    /// Disables hiding the password generated
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
+    pub hide_disabled: bool,
+}
+
+/// Derives the password for the site. The same token, site, counter and options
+/// always give the same password.
+pub fn generate(args: &DeriveArgs) -> Result<String, String> {
+    let site = normalize_site(&args.site)?;
+    let counter = args.counter.unwrap_or(1);
+    // Versioned, so a different derivation later on doesn't change existing passwords.
+    let challenge = format!("pwgen-derive-v1:{}:{}", site, counter);
+
+    let key = if args.fido2 {
+        let device = match &args.device {
+            Some(device) => device.clone(),
+            None => first_fido2_device()?,
+        };
+        fido2_hmac_secret(&device, &digest::sha256(challenge.as_bytes()), !args.no_pin)?
+    } else {
+        yubikey_response(args.slot.unwrap_or(2), challenge.as_bytes())?
+    };
+
+    let password_args = PasswordArgs {
+        length: args.length,
+        character_sets: args.character_sets.clone(),
+        excluded_chars: args.excluded_chars.clone(),
+        ..PasswordArgs::default()
+    };
+    let characters = password::character_pool(&password_args)?;
+    let length = password::password_length(&password_args)?;
+    let mut stream = ByteStream { key, block: 0, buffer: Vec::new() };
+    Ok((0..length).map(|_| characters[stream.index(characters.len())]).collect())
+}
+
+fn normalize_site(site: &str) -> Result<String, String> {
+    let site = site.trim().to_lowercase();
+    let site = site.strip_prefix("www.").unwrap_or(&site);
+    if site.is_empty() {
+        return Err(i18n::message("derive-empty-site", &[]));
+    }
+    Ok(site.to_string())
+}
+
+/// Asks the YubiKey for the HMAC-SHA1 of the challenge with the secret in `slot`.
+fn yubikey_response(slot: u8, challenge: &[u8]) -> Result<Vec<u8>, String> {
+    let challenge = hex(challenge);
+    eprintln!("{}", i18n::message("derive-touch-yubikey", &[]));
+    let output = if external::is_installed("ykman") {
+        external::run("ykman", &["otp", "calculate", &slot.to_string(), &challenge], &[])?
+    } else {
+        external::run("ykchalresp", &[&format!("-{}", slot), "-H", "-x", &challenge], &[])?
+    };
+    decode_hex(String::from_utf8_lossy(&output).trim())
+        .filter(|response| response.len() == 20)
+        .ok_or_else(|| i18n::message("derive-yubikey-response", &[]))
+}
+
+const FIDO2_RELYING_PARTY: &str = "pwgen";
+
+fn first_fido2_device() -> Result<String, String> {
+    let output = external::run("fido2-token", &["-L"], &[])?;
+    // Lines look like "/dev/hidraw0: vendor=0x1050, product=0x0407 (Yubico YubiKey OTP+FIDO+CCID)".
+    String::from_utf8_lossy(&output).lines()
+        .find_map(|line| line.split_once(": ").map(|(device, _)| device.to_string()))
+        .ok_or_else(|| i18n::message("derive-no-fido2", &[]))
+}
+
+/// Gets the hmac-secret of the pwgen credential on the key for `salt`, making the
+/// credential first if there isn't one yet. User presence is always required.
+fn fido2_hmac_secret(device: &str, salt: &[u8; 32], pin: bool) -> Result<Vec<u8>, String> {
+    let credential_id = match fs::read_to_string(credential_file()?) {
+        Ok(credential_id) => credential_id.trim().to_string(),
+        Err(_) => make_fido2_credential(device)?,
+    };
+
+    // The client data hash is signed but only matters to servers checking the signature.
+    let client_data_hash: [u8; 32] = rand::rng().random();
+    let input = format!(
+        "{}\n{}\n{}\n{}\n",
+        encoding::base64(&client_data_hash),
+        FIDO2_RELYING_PARTY,
+        credential_id,
+        encoding::base64(salt),
+    );
+    let mut arguments = vec!["-G", "-h", "-p"];
+    if pin {
+        arguments.push("-v");
+    }
+    arguments.push(device);
+    eprintln!("{}", i18n::message("derive-touch-security-key", &[]));
+    let output = external::run("fido2-assert", &arguments, input.as_bytes())?;
+    // The hmac-secret is the last line of the assertion.
+    String::from_utf8_lossy(&output).lines().last()
+        .and_then(|line| encoding::decode_base64(line.trim()))
+        .filter(|secret| secret.len() == 32)
+        .ok_or_else(|| i18n::message("derive-no-hmac-secret", &[]))
+}
+
+/// Makes a non-resident credential with the hmac-secret extension and saves its id.
+fn make_fido2_credential(device: &str) -> Result<String, String> {
+    let path = credential_file()?;
+    let client_data_hash: [u8; 32] = rand::rng().random();
+    let user_id: [u8; 16] = rand::rng().random();
+    let input = format!(
+        "{}\n{}\npwgen\n{}\n",
+        encoding::base64(&client_data_hash),
+        FIDO2_RELYING_PARTY,
+        encoding::base64(&user_id),
+    );
+    eprintln!("{}", i18n::message("derive-making-credential", &[]));
+    let output = external::run("fido2-cred", &["-M", "-h", device], input.as_bytes())?;
+    // The credential id is the fifth line, after the client data hash, relying party,
+    // format and authenticator data.
+    let credential_id = String::from_utf8_lossy(&output).lines().nth(4).map(|line| line.trim().to_string())
+        .filter(|id| encoding::decode_base64(id).is_some())
+        .ok_or_else(|| i18n::message("derive-no-credential", &[]))?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| i18n::message("unable-to-create", &[("path", &dir.display()), ("error", &error)]))?;
+    }
+    fs::write(&path, format!("{}\n", credential_id))
+        .map_err(|error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]))?;
+    Ok(credential_id)
+}
+
+fn credential_file() -> Result<PathBuf, String> {
+    paths::fido2_credential_file().ok_or_else(|| i18n::message("no-config-directory", &[]))
+}
+
+/// Bytes of HMAC-SHA256 over a block counter, a stream that never changes for a key,
+/// unlike the generators of the rand crate, which may between versions.
+struct ByteStream {
+    key: Vec<u8>,
+    block: u64,
+    buffer: Vec<u8>,
+}
+
+impl ByteStream {
+    fn next_byte(&mut self) -> u8 {
+        if self.buffer.is_empty() {
+            self.buffer = digest::hmac_sha256(&self.key, &self.block.to_be_bytes()).to_vec();
+            self.block += 1;
+        }
+        self.buffer.pop().unwrap()
+    }
+
+    /// A uniform index below `count`, which is at most 256, skipping bytes that would favor the low indexes.
+    fn index(&mut self, count: usize) -> usize {
+        let limit = 256 - 256 % count;
+        loop {
+            let byte = self.next_byte() as usize;
+            if byte < limit {
+                return byte % count;
+            }
+        }
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len()).step_by(2).map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok()).collect()
+}
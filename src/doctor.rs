@@ -0,0 +1,173 @@
+//! `pwgen doctor`, which finds out what works on this machine: the clipboard, the
+//! secret store, the config file, the terminal and the external tools some options use.
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arboard::Clipboard;
+
+use crate::config::Config;
+use crate::{defaults, external, health, i18n, keyring, paths};
+
+static FAILURES: AtomicUsize = AtomicUsize::new(0);
+
+enum Status {
+    Ok,
+    Warning,
+    Failure,
+}
+
+fn report(status: Status, check: &str, detail: &str) {
+    let label = match status {
+        Status::Ok => "doctor-ok",
+        Status::Warning => "doctor-warn",
+        Status::Failure => {
+            FAILURES.fetch_add(1, Ordering::Relaxed);
+            "doctor-fail"
+        }
+    };
+    // Padded to the longest label, so the checks line up in every language.
+    let width = ["doctor-ok", "doctor-warn", "doctor-fail"].iter()
+        .map(|key| i18n::message(key, &[]).chars().count())
+        .max()
+        .unwrap_or(4);
+    let line = i18n::message("doctor-check", &[("check", &check), ("detail", &detail)]);
+    println!("[{:>width$}] {}", i18n::message(label, &[]), line);
+}
+
+/// Runs every check, failing when one of them did so scripts can tell.
+pub fn run() -> Result<(), String> {
+    check_random_source();
+    check_clipboard();
+    check_osc52();
+    check_keyring();
+    check_config();
+    check_terminal();
+    check_tools();
+    match FAILURES.load(Ordering::Relaxed) {
+        0 => Ok(()),
+        failures => Err(i18n::message("doctor-failed", &[("count", &failures)])),
+    }
+}
+
+fn check_random_source() {
+    match health::check() {
+        Ok(()) => report(Status::Ok, &i18n::message("doctor-random-source", &[]), &i18n::message("doctor-random-ok", &[])),
+        Err(message) => report(Status::Failure, &i18n::message("doctor-random-source", &[]), &message),
+    }
+}
+
+fn check_clipboard() {
+    if cfg!(all(unix, not(target_os = "macos"))) {
+        let wayland = env::var_os("WAYLAND_DISPLAY").is_some();
+        let x11 = env::var_os("DISPLAY").is_some();
+        match (wayland, x11) {
+            (true, true) => report(Status::Ok, &i18n::message("doctor-display", &[]), &i18n::message("doctor-display-xwayland", &[])),
+            (true, false) => report(Status::Warning, &i18n::message("doctor-display", &[]), &i18n::message("doctor-display-wayland", &[])),
+            (false, true) => report(Status::Ok, &i18n::message("doctor-display", &[]), &i18n::message("doctor-display-x11", &[])),
+            (false, false) => report(Status::Failure, &i18n::message("doctor-display", &[]), &i18n::message("doctor-display-none", &[])),
+        }
+    }
+
+    // Only reading, so whatever is on the clipboard now stays there.
+    match Clipboard::new() {
+        Ok(mut clipboard) => match clipboard.get_text() {
+            Ok(_) | Err(arboard::Error::ContentNotAvailable) => report(Status::Ok, &i18n::message("doctor-clipboard", &[]), &i18n::message("doctor-clipboard-ok", &[])),
+            Err(error) => report(Status::Warning, &i18n::message("doctor-clipboard", &[]), &i18n::message("doctor-clipboard-unreadable", &[("error", &error)])),
+        },
+        Err(error) => report(Status::Failure, &i18n::message("doctor-clipboard", &[]), &i18n::message("doctor-clipboard-unavailable", &[("error", &error)])),
+    }
+}
+
+/// Terminals that are known to set the clipboard through the OSC 52 escape sequence,
+/// recognized from TERM or TERM_PROGRAM.
+fn check_osc52() {
+    const SUPPORTING: [&str; 9] = ["kitty", "wezterm", "alacritty", "foot", "iterm", "tmux", "contour", "ghostty", "windows terminal"];
+    let term = env::var("TERM").unwrap_or_default().to_lowercase();
+    let program = env::var("TERM_PROGRAM").unwrap_or_default().to_lowercase();
+    let windows_terminal = env::var_os("WT_SESSION").is_some();
+    let remote = env::var_os("SSH_CONNECTION").is_some();
+
+    let terminal = SUPPORTING.iter().find(|name| term.contains(*name) || program.contains(*name))
+        .map(|name| name.to_string())
+        .or_else(|| windows_terminal.then(|| "windows terminal".to_string()));
+    let detail = match terminal {
+        Some(terminal) if terminal == "tmux" => i18n::message("doctor-osc52-tmux", &[]),
+        Some(terminal) => i18n::message("doctor-osc52-supported", &[("terminal", &terminal)]),
+        None if term.is_empty() => i18n::message("doctor-osc52-no-terminal", &[]),
+        None => i18n::message("doctor-osc52-unknown", &[("terminal", &term)]),
+    };
+    let status = if remote { Status::Warning } else { Status::Ok };
+    let detail = if remote { i18n::message("doctor-osc52-remote", &[("detail", &detail)]) } else { detail };
+    report(status, "OSC 52", &detail);
+}
+
+fn check_keyring() {
+    match keyring::list() {
+        Ok(names) => report(Status::Ok, &i18n::message("doctor-keyring", &[]), &i18n::message("doctor-keyring-ok", &[("count", &names.len())])),
+        Err(message) => report(Status::Failure, &i18n::message("doctor-keyring", &[]), &i18n::message("doctor-keyring-unavailable", &[("error", &message)])),
+    }
+}
+
+fn check_config() {
+    let Some(path) = paths::config_file() else {
+        report(Status::Warning, &i18n::message("doctor-config", &[]), &i18n::message("doctor-config-no-directory", &[]));
+        return;
+    };
+    if !path.exists() {
+        report(Status::Ok, &i18n::message("doctor-config", &[]), &i18n::message("doctor-config-none", &[("path", &path.display())]));
+        return;
+    }
+    let config = match Config::load() {
+        Ok(config) => config,
+        Err(message) => {
+            report(Status::Failure, &i18n::message("doctor-config", &[]), &message);
+            return;
+        }
+    };
+    let problems: Vec<String> = config.iter()
+        .filter_map(|(key, value)| defaults::validate(key, value).err())
+        .collect();
+    if problems.is_empty() {
+        report(Status::Ok, &i18n::message("doctor-config", &[]), &i18n::message("doctor-config-valid", &[("path", &path.display())]));
+    }
+    for problem in problems {
+        report(Status::Failure, &i18n::message("doctor-config", &[]), &problem);
+    }
+}
+
+fn check_terminal() {
+    if io::stdin().is_terminal() {
+        report(Status::Ok, &i18n::message("doctor-terminal", &[]), &i18n::message("doctor-terminal-ok", &[]));
+    } else {
+        report(Status::Warning, &i18n::message("doctor-terminal", &[]), &i18n::message("doctor-terminal-stdin", &[]));
+    }
+    if !io::stdout().is_terminal() {
+        report(Status::Warning, &i18n::message("doctor-terminal", &[]), &i18n::message("doctor-terminal-stdout", &[]));
+    }
+}
+
+/// External tools used by some of the options, which are fine to be missing otherwise.
+fn check_tools() {
+    const TOOLS: [(&str, &str); 11] = [
+        ("qrencode", "otp-secret --qr"),
+        ("fzf", "--picker fzf"),
+        ("rofi", "--picker rofi"),
+        ("gpg", "--gpg-recipient"),
+        ("systemd-creds", "--systemd-cred"),
+        ("vault", "--vault-write"),
+        ("bw", "--bw-save"),
+        ("op", "--op-save"),
+        ("keepassxc-cli", "--kdbx"),
+        ("docker", "--docker-secret"),
+        ("podman", "--docker-secret"),
+    ];
+    for (program, option) in TOOLS {
+        if external::is_installed(program) {
+            report(Status::Ok, program, &i18n::message("doctor-tool-installed", &[("option", &option)]));
+        } else {
+            report(Status::Warning, program, &i18n::message("doctor-tool-missing", &[("option", &option)]));
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use clap::Args;
+use rand::Rng;
+
+use crate::UsernameArgs;
+use crate::i18n;
+use crate::username;
+
+#[derive(Args)]
+pub struct EmailArgs {
+    /// Domain of the email address.
+    /// Example usage: "--domain example.com".
+    #[arg(short = 'd', long = "domain")]
+    domain: String,
+
+    /// Your existing mailbox name, to create plus-addressed aliases like "me+tag".
+    /// Don't specify to use a random username as the whole local part.
+    #[arg(short = 'b', long = "base")]
+    base: Option<String>,
+
+    /// Tag identifying the site the alias is for.
+    /// Example usage: "--tag shop".
+    #[arg(short = 't', long = "tag")]
+    tag: Option<String>,
+
+    /// Disables copying the email address to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Generates an email alias, either `base+tag@domain` or a random `local[+tag]@domain`.
+pub fn generate<R: Rng + ?Sized>(args: &EmailArgs, rng: &mut R) -> Result<String, String> {
+    if !is_valid_domain(&args.domain) {
+        return Err(i18n::message("email-invalid-domain", &[("domain", &args.domain)]));
+    }
+    let tag = args.tag.as_deref().map(str::to_lowercase);
+    if let Some(tag) = &tag && !is_valid_local_part(tag) {
+        return Err(i18n::message("email-invalid-tag", &[("tag", &tag)]));
+    }
+
+    let local_part = match &args.base {
+        Some(base) => {
+            if !is_valid_local_part(base) {
+                return Err(i18n::message("email-invalid-mailbox", &[("mailbox", &base)]));
+            }
+            let suffix = match tag {
+                Some(tag) => tag,
+                None => random_local_part(rng)?,
+            };
+            format!("{}+{}", base, suffix)
+        }
+        None => {
+            let local_part = random_local_part(rng)?;
+            match tag {
+                Some(tag) => format!("{}+{}", local_part, tag),
+                None => local_part,
+            }
+        }
+    };
+
+    Ok(format!("{}@{}", local_part, args.domain.to_lowercase()))
+}
+
+/// A username like "brave.falcon.42" to use as the local part.
+fn random_local_part<R: Rng + ?Sized>(rng: &mut R) -> Result<String, String> {
+    let username_args = UsernameArgs { word_char: Some('.'), ..Default::default() };
+    username::generate(&username_args, rng).map(|username| username.to_lowercase())
+}
+
+fn is_valid_local_part(local_part: &str) -> bool {
+    !local_part.is_empty()
+        && !local_part.starts_with('.')
+        && !local_part.ends_with('.')
+        && !local_part.contains("..")
+        && local_part.chars().all(|c| c.is_ascii_alphanumeric() || "._-".contains(c))
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    domain.contains('.')
+        && domain.len() <= 253
+        && domain.split('.').all(|label| {
+            !label.is_empty()
+                && label.len() <= 63
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+}
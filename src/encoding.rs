@@ -0,0 +1,194 @@
+/// Crockford's base32 alphabet, which leaves out I, L, O and U to avoid misreading.
+pub const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Extra symbols only used for the Crockford check symbol, which works modulo 37.
+const CROCKFORD_CHECK_SYMBOLS: &[u8; 5] = b"*~$=U";
+
+/// The RFC 4648 base32 alphabet, used by authenticator apps for OTP secrets.
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Alphabet of bcrypt's own base64 flavour, which sorts differently from the standard one.
+const BCRYPT_ALPHABET: &[u8; 64] = b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+const BASE62_ALPHABET: &[u8; 62] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Encodes the low `length * 5` bits of `value` in Crockford base32, most significant first.
+pub fn crockford_base32(value: u128, length: usize) -> String {
+    (0..length)
+        .rev()
+        .map(|index| {
+            let shift = index * 5;
+            let digit = if shift >= 128 { 0 } else { (value >> shift) & 31 };
+            CROCKFORD_ALPHABET[digit as usize] as char
+        })
+        .collect()
+}
+
+/// Encodes `bytes` in RFC 4648 base32 without the trailing `=` padding.
+pub fn base32(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(5) * 8);
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+    for &byte in bytes {
+        buffer = (buffer << 8) | byte as u32;
+        buffered_bits += 8;
+        while buffered_bits >= 5 {
+            buffered_bits -= 5;
+            encoded.push(BASE32_ALPHABET[((buffer >> buffered_bits) & 31) as usize] as char);
+        }
+    }
+    if buffered_bits > 0 {
+        encoded.push(BASE32_ALPHABET[((buffer << (5 - buffered_bits)) & 31) as usize] as char);
+    }
+    encoded
+}
+
+/// Decodes RFC 4648 base32, ignoring case, spaces, hyphens and `=` padding the way
+/// authenticator apps do when a secret is typed in by hand.
+pub fn decode_base32(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+    for c in text.chars().filter(|&c| !matches!(c, ' ' | '-' | '=')) {
+        let value = BASE32_ALPHABET.iter().position(|&symbol| symbol as char == c.to_ascii_uppercase())?;
+        buffer = (buffer << 5) | value as u32;
+        buffered_bits += 5;
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Encodes `bytes` in standard base64 with `=` padding.
+pub fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let value = chunk.iter().enumerate().fold(0u32, |value, (index, &byte)| value | (byte as u32) << (16 - index * 8));
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[((value >> (18 - index * 6)) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes standard base64, with or without `=` padding.
+pub fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+    for c in text.trim_end_matches('=').bytes() {
+        let value = BASE64_ALPHABET.iter().position(|&symbol| symbol == c)?;
+        buffer = (buffer << 6) | value as u32;
+        buffered_bits += 6;
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Decodes bcrypt's base64, which has no padding, for salts given on the command line.
+pub fn decode_bcrypt_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+    let mut buffer = 0u32;
+    let mut buffered_bits = 0;
+    for c in text.bytes() {
+        let value = BCRYPT_ALPHABET.iter().position(|&symbol| symbol == c)?;
+        buffer = (buffer << 6) | value as u32;
+        buffered_bits += 6;
+        if buffered_bits >= 8 {
+            buffered_bits -= 8;
+            bytes.push((buffer >> buffered_bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+/// Percent-encodes everything except the characters RFC 3986 leaves unreserved.
+pub fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+/// Encodes big-endian `bytes` in base62, left padded with zeros to `length` characters.
+pub fn base62(bytes: &[u8], length: usize) -> String {
+    let mut number = bytes.to_vec();
+    let mut digits = Vec::new();
+
+    // Long division of the whole number by 62, collecting the remainders.
+    while number.iter().any(|&byte| byte != 0) {
+        let mut remainder = 0u32;
+        for byte in number.iter_mut() {
+            let accumulator = (remainder << 8) | *byte as u32;
+            *byte = (accumulator / 62) as u8;
+            remainder = accumulator % 62;
+        }
+        digits.push(BASE62_ALPHABET[remainder as usize]);
+    }
+    while digits.len() < length {
+        digits.push(b'0');
+    }
+
+    digits.iter().rev().map(|&digit| digit as char).collect()
+}
+
+/// Value of a Crockford base32 symbol, accepting the lowercase and look-alike forms it allows.
+fn crockford_value(c: char) -> Option<u32> {
+    let c = match c.to_ascii_uppercase() {
+        'O' => '0',
+        'I' | 'L' => '1',
+        c => c,
+    };
+    CROCKFORD_ALPHABET.iter().position(|&symbol| symbol as char == c).map(|value| value as u32)
+}
+
+/// Check symbol for a Crockford base32 string, the value of the whole number modulo 37.
+/// Hyphens are ignored. Returns `None` if `data` contains an invalid symbol.
+pub fn crockford_check_symbol(data: &str) -> Option<char> {
+    let mut remainder = 0u32;
+    for c in data.chars().filter(|&c| c != '-') {
+        remainder = (remainder * 32 + crockford_value(c)?) % 37;
+    }
+    let symbol = match remainder {
+        0..32 => CROCKFORD_ALPHABET[remainder as usize],
+        _ => CROCKFORD_CHECK_SYMBOLS[remainder as usize - 32],
+    };
+    Some(symbol as char)
+}
+
+/// Whether the last symbol of `code` is the correct check symbol for the rest.
+pub fn verify_crockford_check_symbol(code: &str) -> bool {
+    let code = code.trim();
+    let Some(check_symbol) = code.chars().last() else {
+        return false;
+    };
+    let data = &code[..code.len() - check_symbol.len_utf8()];
+    let data = data.strip_suffix('-').unwrap_or(data);
+    if data.is_empty() {
+        return false;
+    }
+    crockford_check_symbol(data).is_some_and(|expected| {
+        let normalized = match check_symbol.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        expected == normalized
+    })
+}
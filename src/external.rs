@@ -0,0 +1,57 @@
+use std::env;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::i18n;
+
+/// Runs an external tool with `input` on its stdin, returning its stdout.
+/// Secrets are passed through stdin so they never show up in the process list.
+pub fn run(program: &str, args: &[&str], input: &[u8]) -> Result<Vec<u8>, String> {
+    log::debug!("Running {} {:?}", program, args);
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| i18n::message("external-not-installed", &[("program", &program), ("error", &error)]))?;
+
+    // Writing fails if the tool exits without reading its input, its exit status explains why.
+    let _ = child.stdin.take().unwrap().write_all(input);
+    let output = child.wait_with_output()
+        .map_err(|error| i18n::message("external-unable-to-run", &[("program", &program), ("error", &error)]))?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        log::debug!("{} exited with {}", program, output.status);
+        return Err(i18n::message("external-failed", &[("program", &program), ("error", &stderr.trim())]));
+    }
+    Ok(output.stdout)
+}
+
+/// Runs an interactive tool such as a picker, which draws on the terminal through stderr.
+/// Returns `None` when it exits unsuccessfully, which such tools do when cancelled.
+pub fn run_interactive(program: &str, args: &[&str], input: &[u8]) -> Result<Option<Vec<u8>>, String> {
+    log::debug!("Running {} {:?}", program, args);
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|error| i18n::message("external-not-installed", &[("program", &program), ("error", &error)]))?;
+
+    let _ = child.stdin.take().unwrap().write_all(input);
+    let output = child.wait_with_output()
+        .map_err(|error| i18n::message("external-unable-to-run", &[("program", &program), ("error", &error)]))?;
+    Ok(output.status.success().then_some(output.stdout))
+}
+
+/// Whether `program` can be found on the PATH.
+pub fn is_installed(program: &str) -> bool {
+    let Some(path) = env::var_os("PATH") else {
+        return false;
+    };
+    env::split_paths(&path).any(|dir| {
+        dir.join(program).is_file() || (cfg!(windows) && dir.join(format!("{}.exe", program)).is_file())
+    })
+}
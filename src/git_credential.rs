@@ -0,0 +1,74 @@
+use clap::Args;
+use std::io::{self, BufRead};
+
+use crate::{i18n, keyring, password, PasswordArgs};
+
+#[derive(Args)]
+pub struct GitCredentialArgs {
+    /// Operation git asks for: get, store or erase.
+    operation: String,
+}
+
+/// Implements the git credential helper protocol on top of the keyring. A `get` for a
+/// host pwgen has no password for yet mints and saves a new one, so it can be set on
+/// the server on first use. Enable it with `git config --global credential.helper "!pwgen git-credential"`.
+pub fn run(args: &GitCredentialArgs) -> Result<(), String> {
+    let attributes = read_attributes()?;
+    let attribute = |key: &str| attributes.iter().find(|(name, _)| name == key).map(|(_, value)| value.as_str());
+
+    let (Some(protocol), Some(host)) = (attribute("protocol"), attribute("host")) else {
+        return Ok(());
+    };
+    let mut name = format!("git:{}://", protocol);
+    if let Some(username) = attribute("username") {
+        name.push_str(username);
+        name.push('@');
+    }
+    name.push_str(host);
+    if let Some(path) = attribute("path") {
+        name.push('/');
+        name.push_str(path);
+    }
+
+    match args.operation.as_str() {
+        "get" => get(&name),
+        "store" => match attribute("password") {
+            Some(password) => keyring::save(&name, password),
+            None => Ok(()),
+        },
+        "erase" => keyring::delete(&name),
+        // Git asks helpers for operations they don't know about, which are ignored.
+        _ => Ok(()),
+    }
+}
+
+fn get(name: &str) -> Result<(), String> {
+    let secret = match keyring::get(name) {
+        Ok(secret) => secret,
+        // Listing first tells a missing password apart from a keyring that doesn't work.
+        Err(_) if !keyring::list()?.iter().any(|saved| saved == name) => {
+            let secret = password::generate(&PasswordArgs::default(), &mut rand::rng())?;
+            keyring::save(name, &secret)?;
+            eprintln!("{}", i18n::message("git-credential-generated", &[("name", &&name[4..])]));
+            secret
+        }
+        Err(message) => return Err(message),
+    };
+    println!("password={}", secret);
+    Ok(())
+}
+
+/// Reads the `key=value` lines git sends, up to the first empty line.
+fn read_attributes() -> Result<Vec<(String, String)>, String> {
+    let mut attributes = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line.map_err(|error| i18n::message("git-credential-unreadable", &[("error", &error)]))?;
+        if line.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            attributes.push((key.to_string(), value.to_string()));
+        }
+    }
+    Ok(attributes)
+}
@@ -0,0 +1,126 @@
+//! Keeps secrets from ending up on disk through core dumps or swap, and other processes
+//! of the same user from reading them out of pwgen's memory.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static HARDENED: AtomicBool = AtomicBool::new(false);
+
+/// Turns off core dumps and, where the platform has it, attaching debuggers.
+/// Always done first, as nothing pwgen does needs either.
+pub fn protect_process() -> Result<(), String> {
+    platform::protect_process()
+}
+
+/// Locks memory so secrets are never swapped out, for `--hardened`.
+/// Fails when the locked memory limit is too low, see `ulimit -l`.
+pub fn lock_memory() -> Result<(), String> {
+    platform::lock_memory()?;
+    HARDENED.store(true, Ordering::Relaxed);
+    Ok(())
+}
+
+/// Locks the memory of a secret on platforms that can't lock all memory at once.
+pub fn lock_secret(secret: &str) {
+    if HARDENED.load(Ordering::Relaxed) {
+        platform::lock_secret(secret);
+    }
+}
+
+#[cfg(unix)]
+mod platform {
+    use std::io;
+
+    use crate::i18n;
+
+    pub fn protect_process() -> Result<(), String> {
+        let no_core = libc::rlimit { rlim_cur: 0, rlim_max: 0 };
+        // SAFETY: setrlimit only reads the limit passed to it.
+        if unsafe { libc::setrlimit(libc::RLIMIT_CORE, &no_core) } != 0 {
+            return Err(i18n::message("hardening-core-dumps", &[("error", &io::Error::last_os_error())]));
+        }
+        // Non-dumpable processes can't be attached to with ptrace by the same user, and
+        // their /proc files holding memory are only readable by root.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        // SAFETY: PR_SET_DUMPABLE takes a plain integer argument.
+        if unsafe { libc::prctl(libc::PR_SET_DUMPABLE, 0) } != 0 {
+            return Err(i18n::message("hardening-dumpable", &[("error", &io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+
+    pub fn lock_memory() -> Result<(), String> {
+        // Only locking pages once they're used keeps the mapped but untouched parts of
+        // the executable from counting against the limit.
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        let flags = libc::MCL_CURRENT | libc::MCL_FUTURE | libc::MCL_ONFAULT;
+        #[cfg(not(any(target_os = "linux", target_os = "android")))]
+        let flags = libc::MCL_CURRENT | libc::MCL_FUTURE;
+        // SAFETY: mlockall only takes flags.
+        if unsafe { libc::mlockall(flags) } != 0 {
+            return Err(i18n::message("hardening-lock", &[("error", &io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+
+    /// Everything is locked by `lock_memory` already.
+    pub fn lock_secret(_secret: &str) {}
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::c_void;
+    use std::io;
+
+    use crate::i18n;
+
+    const SEM_FAILCRITICALERRORS: u32 = 0x0001;
+    const SEM_NOGPFAULTERRORBOX: u32 = 0x0002;
+
+    #[link(name = "kernel32")]
+    unsafe extern "system" {
+        fn SetErrorMode(mode: u32) -> u32;
+        fn GetCurrentProcess() -> *mut c_void;
+        fn SetProcessWorkingSetSize(process: *mut c_void, minimum: usize, maximum: usize) -> i32;
+        fn VirtualLock(address: *const c_void, size: usize) -> i32;
+    }
+
+    /// Crashes don't bring up Windows Error Reporting, which would write a dump of the memory.
+    pub fn protect_process() -> Result<(), String> {
+        // SAFETY: SetErrorMode only takes flags.
+        unsafe { SetErrorMode(SEM_FAILCRITICALERRORS | SEM_NOGPFAULTERRORBOX) };
+        Ok(())
+    }
+
+    /// Windows locks memory a region at a time with VirtualLock, up to the minimum working
+    /// set, so that is raised to leave room for the secrets.
+    pub fn lock_memory() -> Result<(), String> {
+        const WORKING_SET: usize = 16 * 1024 * 1024;
+        // SAFETY: GetCurrentProcess returns a pseudo handle that doesn't need to be closed.
+        if unsafe { SetProcessWorkingSetSize(GetCurrentProcess(), WORKING_SET, 4 * WORKING_SET) } == 0 {
+            return Err(i18n::message("hardening-working-set", &[("error", &io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+
+    pub fn lock_secret(secret: &str) {
+        // SAFETY: the pages of a live string are locked, which doesn't change their contents.
+        if !secret.is_empty() && unsafe { VirtualLock(secret.as_ptr() as *const c_void, secret.len()) } == 0 {
+            log::warn!("Unable to lock the secret in memory: {}", io::Error::last_os_error());
+        }
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use crate::i18n;
+
+    pub fn protect_process() -> Result<(), String> {
+        Ok(())
+    }
+
+    pub fn lock_memory() -> Result<(), String> {
+        Err(i18n::message("hardening-lock-unsupported", &[]))
+    }
+
+    pub fn lock_secret(_secret: &str) {}
+}
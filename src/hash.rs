@@ -0,0 +1,193 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::encoding;
+use crate::i18n;
+use crate::prompt;
+
+/// Only the first 72 bytes of a password, including its terminating zero byte, are used by bcrypt.
+const BCRYPT_MAX_PASSWORD_BYTES: usize = 72;
+
+#[derive(Args)]
+pub struct HashArgs {
+    /// Algorithm the password is hashed with.
+    /// Defaults to argon2id.
+    #[arg(short = 'a', long = "algo", value_enum)]
+    algorithm: Option<HashAlgorithm>,
+
+    /// Memory used by argon2id in KiB.
+    /// Defaults to 19456, the OWASP recommendation.
+    #[arg(short = 'm', long = "memory")]
+    memory: Option<u32>,
+
+    /// Passes over the memory made by argon2id.
+    /// Defaults to 2.
+    #[arg(short = 't', long = "iterations")]
+    iterations: Option<u32>,
+
+    /// Lanes used by argon2id.
+    /// Defaults to 1.
+    #[arg(short = 'p', long = "parallelism")]
+    parallelism: Option<u32>,
+
+    /// Cost of bcrypt, every step doubling the work.
+    /// Defaults to 12.
+    #[arg(short = 'c', long = "cost")]
+    cost: Option<u32>,
+
+    /// Salt to use instead of a random one, encoded as in the hash.
+    /// Only meant for reproducing an existing hash.
+    #[arg(short = 's', long = "salt")]
+    salt: Option<String>,
+
+    /// Disables copying the hash to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum HashAlgorithm {
+    Argon2id,
+    Bcrypt,
+}
+
+/// Reads a password from a hidden prompt or stdin and hashes it into PHC string format.
+pub fn generate<R: Rng + ?Sized>(args: &HashArgs, rng: &mut R) -> Result<String, String> {
+    match args.algorithm.unwrap_or(HashAlgorithm::Argon2id) {
+        HashAlgorithm::Argon2id => {
+            if args.cost.is_some() {
+                return Err(i18n::message("hash-cost-bcrypt-only", &[]));
+            }
+            let memory = args.memory.unwrap_or(19456);
+            let iterations = args.iterations.unwrap_or(2);
+            let parallelism = args.parallelism.unwrap_or(1);
+            if iterations == 0 || iterations > 1000 {
+                return Err(i18n::message("hash-iterations-range", &[]));
+            }
+            if parallelism == 0 || parallelism > 255 {
+                return Err(i18n::message("hash-parallelism-range", &[]));
+            }
+            if memory < 8 * parallelism || memory > 4 * 1024 * 1024 {
+                return Err(i18n::message("hash-memory-range", &[("min", &(8 * parallelism))]));
+            }
+
+            let salt = match &args.salt {
+                Some(salt) => encoding::decode_base64(salt).filter(|salt| salt.len() >= 8)
+                    .ok_or_else(|| i18n::message("hash-argon2-salt", &[]))?,
+                None => {
+                    let mut salt = vec![0u8; 16];
+                    rng.fill(salt.as_mut_slice());
+                    salt
+                }
+            };
+
+            let password = prompt::secret("password")?;
+            let hash = argon2id(password.as_bytes(), &salt, memory, iterations, parallelism)?;
+            Ok(format!(
+                "$argon2id$v=19$m={},t={},p={}${}${}",
+                memory,
+                iterations,
+                parallelism,
+                encoding::base64(&salt).trim_end_matches('='),
+                encoding::base64(&hash).trim_end_matches('='),
+            ))
+        }
+        HashAlgorithm::Bcrypt => {
+            if args.memory.is_some() || args.iterations.is_some() || args.parallelism.is_some() {
+                return Err(i18n::message("hash-argon2-only", &[]));
+            }
+            let cost = args.cost.unwrap_or(12);
+            if !(4..=31).contains(&cost) {
+                return Err(i18n::message("hash-cost-range", &[]));
+            }
+
+            let salt: [u8; 16] = match &args.salt {
+                Some(salt) => encoding::decode_bcrypt_base64(salt).filter(|_| salt.len() == 22)
+                    .and_then(|salt| salt.try_into().ok())
+                    .ok_or_else(|| i18n::message("hash-bcrypt-salt", &[]))?,
+                None => rng.random(),
+            };
+
+            let password = prompt::secret("password")?;
+            if password.len() >= BCRYPT_MAX_PASSWORD_BYTES {
+                return Err(i18n::message("hash-bcrypt-too-long", &[("bytes", &(BCRYPT_MAX_PASSWORD_BYTES - 1))]));
+            }
+            bcrypt_2b(password.as_bytes(), salt, cost)
+        }
+    }
+}
+
+/// The 32 byte Argon2id hash of RFC 9106, version 1.3, with `memory` in KiB.
+fn argon2id(password: &[u8], salt: &[u8], memory: u32, iterations: u32, parallelism: u32) -> Result<[u8; 32], String> {
+    let params = Params::new(memory, iterations, parallelism, Some(32))
+        .map_err(|error| i18n::message("hash-invalid-parameters", &[("error", &error)]))?;
+    let mut hash = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password, salt, &mut hash)
+        .map_err(|error| i18n::message("hash-failed", &[("error", &error)]))?;
+    Ok(hash)
+}
+
+/// The bcrypt hash in the `$2b$<cost>$<salt><hash>` format.
+fn bcrypt_2b(password: &[u8], salt: [u8; 16], cost: u32) -> Result<String, String> {
+    bcrypt::hash_with_salt(password, cost, salt)
+        .map(|parts| parts.format_for_version(bcrypt::Version::TwoB))
+        .map_err(|error| i18n::message("hash-failed", &[("error", &error)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    #[test]
+    fn argon2id_matches_reference_implementation() {
+        // From the test suite of the reference implementation, without a secret or associated data.
+        let hash = argon2id(b"password", b"somesalt", 65536, 2, 1).unwrap();
+        assert_eq!(hex(&hash), "09316115d5cf24ed5a15a31a3ba326e5cf32edc24702987c02b6566f61913cf7");
+    }
+
+    #[test]
+    fn argon2id_matches_rfc_9106() {
+        // With the secret key and associated data pwgen doesn't use.
+        let params = argon2::ParamsBuilder::new()
+            .m_cost(32)
+            .t_cost(3)
+            .p_cost(4)
+            .data(argon2::AssociatedData::new(&[0x04; 12]).unwrap())
+            .output_len(32)
+            .build()
+            .unwrap();
+        let mut tag = [0u8; 32];
+        Argon2::new_with_secret(&[0x03; 8], Algorithm::Argon2id, Version::V0x13, params).unwrap()
+            .hash_password_into(&[0x01; 32], &[0x02; 16], &mut tag)
+            .unwrap();
+        assert_eq!(hex(&tag), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659");
+    }
+
+    fn check_bcrypt(password: &str, expected: &str) {
+        let salt: [u8; 16] = encoding::decode_bcrypt_base64(&expected[7..29]).unwrap().try_into().unwrap();
+        let cost = expected[4..6].parse().unwrap();
+        // $2a$ and $2b$ only differ for passwords of 255 bytes and more.
+        assert_eq!(bcrypt_2b(password.as_bytes(), salt, cost).unwrap(), expected.replacen("$2a$", "$2b$", 1));
+    }
+
+    #[test]
+    fn bcrypt_matches_openwall_vectors() {
+        check_bcrypt("U*U", "$2a$05$CCCCCCCCCCCCCCCCCCCCC.E5YPO9kmyuRGyh0XouQYb4YMJKvyOeW");
+        check_bcrypt("U*U*", "$2a$05$CCCCCCCCCCCCCCCCCCCCC.VGOzA784oUp/Z0DY336zx7pLYAy0lwK");
+        check_bcrypt("U*U*U", "$2a$05$XXXXXXXXXXXXXXXXXXXXXOAcXxm9kjPGEMsLznoKqmqw7tc8WCx4a");
+        check_bcrypt("", "$2a$05$CCCCCCCCCCCCCCCCCCCCC.7uG0VCzI2bS7j6ymqJi9CdcdxiRTWNy");
+    }
+
+    #[test]
+    fn bcrypt_truncates_after_72_bytes() {
+        let long = [b'a'; 100];
+        assert_eq!(bcrypt_2b(&long[..72], [7u8; 16], 4).unwrap(), bcrypt_2b(&long, [7u8; 16], 4).unwrap());
+    }
+}
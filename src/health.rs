@@ -0,0 +1,46 @@
+//! Health tests of the operating system's entropy source, after the repetition count and
+//! adaptive proportion tests of NIST SP 800-90B, run before any secret is generated.
+//! The random generator is seeded from this source and would hide a broken one: its
+//! output looks random even when seeded with nothing but zeroes.
+
+use rand::TryRngCore;
+use rand::rngs::OsRng;
+
+use crate::i18n;
+
+/// Bytes tested, which getrandom hands out in no time.
+const SAMPLES: usize = 4096;
+
+/// The cutoffs assume just 4 bits of entropy per byte, far less than a working source
+/// gives, with a chance of 2^-40 of failing a working source anyway.
+const REPETITION_CUTOFF: usize = 11;
+const WINDOW_SIZE: usize = 512;
+const PROPORTION_CUTOFF: usize = 79;
+
+pub fn check() -> Result<(), String> {
+    let mut samples = [0u8; SAMPLES];
+    OsRng.try_fill_bytes(&mut samples)
+        .map_err(|error| i18n::message("health-unavailable", &[("error", &error)]))?;
+
+    // Repetition count test: the same byte many times in a row.
+    let mut run = 1;
+    for pair in samples.windows(2) {
+        run = if pair[0] == pair[1] { run + 1 } else { 1 };
+        if run >= REPETITION_CUTOFF {
+            return Err(failure("health-repetition"));
+        }
+    }
+
+    // Adaptive proportion test: the first byte of a window showing up too often in it.
+    for window in samples.chunks(WINDOW_SIZE) {
+        let count = window.iter().filter(|&&sample| sample == window[0]).count();
+        if count >= PROPORTION_CUTOFF {
+            return Err(failure("health-proportion"));
+        }
+    }
+    Ok(())
+}
+
+fn failure(reason: &str) -> String {
+    i18n::message("health-failed", &[("reason", &i18n::message(reason, &[]))])
+}
@@ -0,0 +1,125 @@
+//! What `pwgen again` and `pwgen last` go on. The options of the last command that generated
+//! something are always kept in the config directory, unless they hold secrets like the seed of
+//! `otp` or the shares of `combine`. The secret itself is only kept with `history.enabled` in
+//! the config, in the platform secret store.
+
+use std::ffi::OsString;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+use clap::Args;
+
+use crate::Commands;
+use crate::config::Config;
+use crate::json::{self, Value};
+use crate::{i18n, keyring, paths, stdout_only};
+
+/// Name of the last secret in the platform secret store, shown by `pwgen vaults list` too.
+const SECRET_NAME: &str = "history: last";
+
+#[derive(Args)]
+pub struct LastArgs {
+    /// Copies the last secret to clipboard again.
+    #[arg(short = 'c', long = "copy")]
+    pub copy: bool,
+
+    /// Disables hiding the last secret.
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
+    pub hide_disabled: bool,
+}
+
+/// The last secret is kept when `history.enabled` is set, except with `--stdout-only`.
+pub fn saves_secret() -> bool {
+    !stdout_only::is_enabled() && Config::load().ok()
+        .and_then(|config| config.get_boolean("history.enabled").ok().flatten())
+        .unwrap_or(false)
+}
+
+/// Whether the arguments of `command` can be kept on disk. Commands given a secret, or
+/// reading one from a prompt to derive something from it, can't be repeated with `again`.
+fn keeps_arguments(command: &Commands) -> bool {
+    match command {
+        Commands::Otp(_) | Commands::Skey(_) | Commands::Combine(_) | Commands::Hash(_)
+        | Commands::Conform(_) | Commands::Derive(_) | Commands::License(_) | Commands::Validate(_)
+        | Commands::Again | Commands::Last(_) => false,
+        Commands::Token( args ) => args.verify.is_none(),
+        _ => true,
+    }
+}
+
+/// Keeps the arguments of `command` that generated `secret`, with the secret if history is enabled.
+/// Returns whether the secret was kept.
+pub fn record(command: &Commands, arguments: &[OsString], output_name: &str, secret: &str) -> bool {
+    let Some(path) = paths::last_command_file() else {
+        return false;
+    };
+    let mut fields = Vec::new();
+    if keeps_arguments(command) {
+        fields.push(("arguments".to_string(), Value::Array(arguments.iter()
+            .map(|argument| Value::String(argument.to_string_lossy().into_owned()))
+            .collect())));
+    }
+    fields.push(("output".to_string(), Value::String(output_name.to_string())));
+    let written = path.parent().map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| write_private(&path, &(Value::Object(fields).to_compact() + "\n")));
+    if let Err(error) = written {
+        log::warn!("Unable to write \"{}\": {}", path.display(), error);
+    }
+
+    if !saves_secret() {
+        return false;
+    }
+    match keyring::save(SECRET_NAME, secret) {
+        Ok(()) => true,
+        Err(message) => {
+            log::warn!("Unable to keep the secret in the history: {}", message);
+            false
+        }
+    }
+}
+
+/// Replaces the file at `path` with `text`, readable only by the user.
+fn write_private(path: &Path, text: &str) -> std::io::Result<()> {
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path)?;
+    // The mode only applies to new files, one written by an older version may be readable by others.
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+    file.write_all(text.as_bytes())
+}
+
+/// The arguments of the last command that generated something, to be parsed again.
+pub fn last_arguments() -> Result<Vec<OsString>, String> {
+    let (last, _) = last_command()?;
+    last.ok_or_else(|| i18n::message("history-given-secret", &[]))
+}
+
+/// The last secret with what it was, like "Password".
+pub fn last_secret() -> Result<(String, String), String> {
+    if !saves_secret() {
+        return Err(i18n::message("history-disabled", &[]));
+    }
+    let (_, output_name) = last_command()?;
+    let secret = keyring::get(SECRET_NAME).map_err(|_| i18n::message("history-nothing-since-enabled", &[]))?;
+    Ok((secret, output_name))
+}
+
+fn last_command() -> Result<(Option<Vec<OsString>>, String), String> {
+    let path = paths::last_command_file().ok_or_else(|| i18n::message("history-no-config-directory", &[]))?;
+    let text = fs::read_to_string(&path).map_err(|_| i18n::message("history-nothing-yet", &[]))?;
+    let invalid = || i18n::message("history-damaged", &[("path", &path.display())]);
+    let last = json::parse(&text).map_err(|_| invalid())?;
+    let arguments = match last.get("arguments") {
+        Some(arguments) => Some(arguments.as_array()
+            .and_then(|arguments| arguments.iter().map(|argument| argument.as_str().map(OsString::from)).collect::<Option<Vec<_>>>())
+            .filter(|arguments| !arguments.is_empty())
+            .ok_or_else(invalid)?),
+        None => None,
+    };
+    let output_name = last.get("output").and_then(Value::as_str).ok_or_else(invalid)?;
+    Ok((arguments, output_name.to_string()))
+}
@@ -0,0 +1,135 @@
+use clap::Args;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::collections::HashSet;
+
+use crate::i18n;
+use crate::password::{DIGITS_CHARS, LOWER_CHARS, SPECIAL_CHARS, UPPER_CHARS};
+use crate::wordlist::{ADJECTIVE_LIST, OBJECT_LIST};
+
+#[derive(Args)]
+pub struct HoneywordsArgs {
+    /// Password or shape the decoys imitate, keeping its length and character classes.
+    /// Example usage: "Summer2024!" or "Aaaaaa9999!".
+    #[arg(short = 'l', long = "like")]
+    like: String,
+
+    /// Amount of decoys, all different from each other and from the reference.
+    /// Defaults to 19, which makes 20 sweetwords together with the real password.
+    #[arg(short = 'C', long = "count")]
+    count: Option<usize>,
+}
+
+/// Class of a single character in the reference. Anything that is neither a letter,
+/// a digit nor a common symbol is kept as is.
+#[derive(Clone, Copy, PartialEq)]
+enum CharClass {
+    Lower,
+    Upper,
+    Digit,
+    Symbol,
+    Literal(char),
+}
+
+impl CharClass {
+    fn of(c: char) -> CharClass {
+        if c.is_ascii_lowercase() {
+            CharClass::Lower
+        } else if c.is_ascii_uppercase() {
+            CharClass::Upper
+        } else if c.is_ascii_digit() {
+            CharClass::Digit
+        } else if SPECIAL_CHARS.contains(c) {
+            CharClass::Symbol
+        } else {
+            CharClass::Literal(c)
+        }
+    }
+
+    fn is_letter(self) -> bool {
+        matches!(self, CharClass::Lower | CharClass::Upper)
+    }
+}
+
+pub fn run(args: &HoneywordsArgs) -> Result<(), String> {
+    for honeyword in generate(args, &mut rand::rng())? {
+        println!("{}", honeyword);
+    }
+    Ok(())
+}
+
+/// Generates decoys with the same shape as the reference. Runs of letters are filled
+/// with dictionary words of the same length where possible, since real passwords
+/// mostly are words, and random letters would make the decoys easy to tell apart.
+fn generate<R: Rng + ?Sized>(args: &HoneywordsArgs, rng: &mut R) -> Result<Vec<String>, String> {
+    let count = args.count.unwrap_or(19);
+    if count == 0 || count > 10000 {
+        return Err(i18n::message("count-range", &[("max", &10000)]));
+    }
+    if args.like.is_empty() {
+        return Err(i18n::message("honeywords-empty-reference", &[]));
+    }
+    let shape: Vec<CharClass> = args.like.chars().map(CharClass::of).collect();
+    if shape.iter().all(|class| matches!(class, CharClass::Literal(_))) {
+        return Err(i18n::message("honeywords-nothing-to-vary", &[]));
+    }
+
+    let dictionary: Vec<&str> = ADJECTIVE_LIST.iter().chain(OBJECT_LIST.iter())
+        .copied()
+        .filter(|word| word.chars().all(|c| c.is_ascii_lowercase()))
+        .collect();
+
+    let mut seen = HashSet::from([args.like.clone()]);
+    let mut honeywords = Vec::with_capacity(count);
+    for _ in 0..count * 100 {
+        let honeyword = decoy(&shape, &dictionary, rng);
+        if seen.insert(honeyword.clone()) {
+            honeywords.push(honeyword);
+            if honeywords.len() == count {
+                return Ok(honeywords);
+            }
+        }
+    }
+    Err(i18n::message("honeywords-too-few", &[("count", &honeywords.len())]))
+}
+
+fn decoy<R: Rng + ?Sized>(shape: &[CharClass], dictionary: &[&str], rng: &mut R) -> String {
+    let mut decoy = String::with_capacity(shape.len());
+    let mut index = 0;
+    while index < shape.len() {
+        let class = shape[index];
+        if class.is_letter() {
+            let run_length = shape[index..].iter().take_while(|class| class.is_letter()).count();
+            let run = &shape[index..index + run_length];
+            let candidates: Vec<&str> = dictionary.iter().copied().filter(|word| word.len() == run_length).collect();
+            match candidates.choose(rng) {
+                Some(word) => {
+                    for (c, class) in word.chars().zip(run) {
+                        decoy.push(if *class == CharClass::Upper { c.to_ascii_uppercase() } else { c });
+                    }
+                }
+                None => {
+                    for class in run {
+                        decoy.push(random_char(*class, rng));
+                    }
+                }
+            }
+            index += run_length;
+        } else {
+            decoy.push(random_char(class, rng));
+            index += 1;
+        }
+    }
+    decoy
+}
+
+fn random_char<R: Rng + ?Sized>(class: CharClass, rng: &mut R) -> char {
+    let characters = match class {
+        CharClass::Lower => LOWER_CHARS,
+        CharClass::Upper => UPPER_CHARS,
+        CharClass::Digit => DIGITS_CHARS,
+        CharClass::Symbol => SPECIAL_CHARS,
+        CharClass::Literal(c) => return c,
+    };
+    *characters.as_bytes().choose(rng).unwrap() as char
+}
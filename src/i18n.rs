@@ -0,0 +1,139 @@
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+use once_cell::sync::{Lazy, OnceCell};
+use std::env;
+use std::fmt::Display;
+use unic_langid::LanguageIdentifier;
+
+/// Message catalogs in the Fluent syntax, for every status and error message pwgen
+/// prints. Help texts, the errors clap reports about the command line and log lines
+/// stay in English. English is complete, the others fall back to it.
+const CATALOGS: [(&str, &str); 5] = [
+    ("en", include_str!("../data/i18n/en.ftl")),
+    ("de", include_str!("../data/i18n/de.ftl")),
+    ("es", include_str!("../data/i18n/es.ftl")),
+    ("fr", include_str!("../data/i18n/fr.ftl")),
+    ("nl", include_str!("../data/i18n/nl.ftl")),
+];
+
+static BUNDLES: Lazy<Vec<(&'static str, FluentBundle<FluentResource>)>> = Lazy::new(|| {
+    CATALOGS.iter().map(|(language, catalog)| {
+        let resource = FluentResource::try_new(catalog.to_string())
+            .unwrap_or_else(|(_, errors)| panic!("The \"{}\" catalog is invalid: {:?}", language, errors));
+        let id: LanguageIdentifier = language.parse().expect("Catalogs are named after languages");
+        let mut bundle = FluentBundle::new_concurrent(vec![id]);
+        // The Unicode isolation marks around variables only show up as noise in a terminal.
+        bundle.set_use_isolating(false);
+        bundle.add_resource(resource).expect("Catalogs define every message once");
+        (*language, bundle)
+    }).collect()
+});
+
+static LANGUAGE: OnceCell<&'static str> = OnceCell::new();
+static REQUESTED_LANGUAGE: OnceCell<&'static str> = OnceCell::new();
+
+/// Picks the language of the messages, `--lang` if given and otherwise the one of the locale.
+pub fn set_language(requested: Option<&str>) -> Result<(), String> {
+    let language = match requested {
+        Some(requested) => find_language(requested).ok_or_else(|| {
+            let languages: Vec<&str> = CATALOGS.iter().map(|(language, _)| *language).collect();
+            message("unknown-language", &[("language", &requested), ("languages", &languages.join(", "))])
+        })?,
+        None => detect_language(),
+    };
+    log::debug!("Messages are in \"{}\"", language);
+    let _ = LANGUAGE.set(language);
+    if requested.is_some() {
+        let _ = REQUESTED_LANGUAGE.set(language);
+    }
+    Ok(())
+}
+
+/// The language picked with `--lang`, which unlike the locale also changes the words of usernames.
+pub fn requested_language() -> Option<&'static str> {
+    REQUESTED_LANGUAGE.get().copied()
+}
+
+/// Matches "de", "de_DE.UTF-8" or "de-DE" to a catalog.
+fn find_language(locale: &str) -> Option<&'static str> {
+    let language = locale.split(['_', '-', '.', '@']).next()?.to_lowercase();
+    CATALOGS.iter().map(|(name, _)| *name).find(|name| *name == language)
+}
+
+/// Follows the usual precedence of the POSIX locale variables.
+fn detect_language() -> &'static str {
+    ["LC_ALL", "LC_MESSAGES", "LANG"].iter()
+        .filter_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .next()
+        .and_then(|locale| find_language(&locale))
+        .unwrap_or("en")
+}
+
+/// Formats `key` from the catalog of `language`, if it has it.
+fn format(language: &str, key: &str, args: &FluentArgs) -> Option<String> {
+    let (_, bundle) = BUNDLES.iter().find(|(name, _)| *name == language)?;
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = Vec::new();
+    let text = bundle.format_pattern(pattern, Some(args), &mut errors);
+    if !errors.is_empty() {
+        log::debug!("Formatting \"{}\" in \"{}\" failed: {:?}", key, language, errors);
+    }
+    Some(text.into_owned())
+}
+
+/// Whole numbers are passed as numbers so the catalogs can pick a plural form for them.
+/// Ones with leading zeros or too many digits for a float stay text, so they're shown as given.
+fn value(text: String) -> FluentValue<'static> {
+    let number = text.len() <= 15 && !(text.len() > 1 && text.starts_with('0'));
+    match text.parse::<u64>() {
+        Ok(number_value) if number => FluentValue::from(number_value),
+        _ => FluentValue::from(text),
+    }
+}
+
+/// The message for `key` in the chosen language with its variables filled in.
+pub fn message(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let language = LANGUAGE.get_or_init(detect_language);
+    let mut fluent_args = FluentArgs::new();
+    for (name, arg) in args {
+        fluent_args.set(*name, value(arg.to_string()));
+    }
+    format(language, key, &fluent_args)
+        .or_else(|| format("en", key, &fluent_args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Translates the name of something pwgen generates, like "Password".
+pub fn noun(english: &str) -> String {
+    let key = format!("noun-{}", english.to_lowercase().replace(' ', "-"));
+    let language = LANGUAGE.get_or_init(detect_language);
+    format(language, &key, &FluentArgs::new())
+        .or_else(|| format("en", &key, &FluentArgs::new()))
+        .unwrap_or_else(|| english.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalogs_only_translate_english_messages() {
+        let (_, english) = &BUNDLES[0];
+        for (language, catalog) in CATALOGS {
+            let keys = catalog.lines().filter_map(|line| line.split_once(" =")).map(|(key, _)| key)
+                .filter(|key| key.starts_with(|c: char| c.is_ascii_lowercase()));
+            for key in keys {
+                assert!(english.has_message(key), "{} has \"{}\"", language, key);
+            }
+        }
+    }
+
+    #[test]
+    fn plurals_follow_the_count() {
+        for (count, text) in [("1", "1 check failed."), ("2", "2 checks failed.")] {
+            let mut args = FluentArgs::new();
+            args.set("count", value(count.to_string()));
+            assert_eq!(format("en", "doctor-failed", &args).unwrap(), text);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use clap::Args;
+use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::encoding;
+use crate::i18n;
+
+/// KSUID timestamps count seconds from 2014-05-13 instead of the Unix epoch.
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+#[derive(Args)]
+pub struct IdArgs {
+    /// Amount of identifiers, increasing in order within the batch.
+    /// Defaults to 1.
+    #[arg(short = 'C', long = "count")]
+    count: Option<usize>,
+
+    /// Disables copying the identifiers to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+fn checked_count(args: &IdArgs) -> Result<usize, String> {
+    let count = args.count.unwrap_or(1);
+    if count == 0 || count > 1_000_000 {
+        return Err(i18n::message("count-range", &[("max", &1000000)]));
+    }
+    Ok(count)
+}
+
+/// Generates ULIDs: a 48 bit millisecond timestamp followed by 80 random bits.
+/// IDs sharing a millisecond increment the random part, so the batch stays sorted.
+pub fn generate_ulids<R: Rng + ?Sized>(args: &IdArgs, rng: &mut R) -> Result<Vec<String>, String> {
+    const RANDOM_MASK: u128 = (1 << 80) - 1;
+
+    let count = checked_count(args)?;
+    let mut ulids = Vec::with_capacity(count);
+    let mut previous: Option<(u128, u128)> = None;
+
+    for _ in 0..count {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_millis()) & ((1 << 48) - 1);
+        let random = match previous {
+            Some((previous_timestamp, previous_random)) if previous_timestamp >= timestamp => {
+                if previous_random == RANDOM_MASK {
+                    return Err(i18n::message("id-too-many-ulids", &[]));
+                }
+                previous_random + 1
+            }
+            _ => rng.random::<u128>() & RANDOM_MASK,
+        };
+        let timestamp = previous.map_or(timestamp, |(previous_timestamp, _)| previous_timestamp.max(timestamp));
+
+        previous = Some((timestamp, random));
+        ulids.push(encoding::crockford_base32((timestamp << 80) | random, 26));
+    }
+    Ok(ulids)
+}
+
+/// Generates KSUIDs: a 32 bit second timestamp followed by a 128 bit random payload, in base62.
+/// IDs sharing a second increment the payload, so the batch stays sorted.
+pub fn generate_ksuids<R: Rng + ?Sized>(args: &IdArgs, rng: &mut R) -> Result<Vec<String>, String> {
+    let count = checked_count(args)?;
+    let mut ksuids = Vec::with_capacity(count);
+    let mut previous: Option<(u32, u128)> = None;
+
+    for _ in 0..count {
+        let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+        let timestamp = seconds.saturating_sub(KSUID_EPOCH) as u32;
+        let payload = match previous {
+            Some((previous_timestamp, previous_payload)) if previous_timestamp >= timestamp => {
+                previous_payload.checked_add(1).ok_or_else(|| i18n::message("id-too-many-ksuids", &[]))?
+            }
+            _ => rng.random::<u128>(),
+        };
+        let timestamp = previous.map_or(timestamp, |(previous_timestamp, _)| previous_timestamp.max(timestamp));
+
+        previous = Some((timestamp, payload));
+        let mut bytes = [0u8; 20];
+        bytes[..4].copy_from_slice(&timestamp.to_be_bytes());
+        bytes[4..].copy_from_slice(&payload.to_be_bytes());
+        ksuids.push(encoding::base62(&bytes, 27));
+    }
+    Ok(ksuids)
+}
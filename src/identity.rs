@@ -0,0 +1,118 @@
+use clap::Args;
+use rand::Rng;
+
+use crate::PasswordArgs;
+use crate::date::Date;
+use crate::i18n;
+use crate::json;
+use crate::name::{self, Gender, Locale};
+use crate::password;
+
+#[derive(Args)]
+pub struct IdentityArgs {
+    /// Only use first names of this gender.
+    /// Defaults to any.
+    #[arg(short = 'g', long = "gender", value_enum)]
+    gender: Option<Gender>,
+
+    /// Which country's names to use.
+    /// Defaults to en-us.
+    #[arg(short = 'l', long = "locale", value_enum)]
+    locale: Option<Locale>,
+
+    /// Domain of the email address.
+    /// Defaults to example.com.
+    #[arg(short = 'd', long = "domain")]
+    domain: Option<String>,
+
+    /// Youngest possible age of the persona.
+    /// Defaults to 18.
+    #[arg(long = "min-age")]
+    min_age: Option<u32>,
+
+    /// Oldest possible age of the persona.
+    /// Defaults to 80.
+    #[arg(long = "max-age")]
+    max_age: Option<u32>,
+
+    /// Amount of characters in the password.
+    /// Defaults to 16.
+    #[arg(short = 'L', long = "password-length")]
+    password_length: Option<u32>,
+}
+
+/// Prints a fake persona whose username and email are derived from its name.
+pub fn run(args: &IdentityArgs) -> Result<(), String> {
+    println!("{}", generate(args, &mut rand::rng())?);
+    Ok(())
+}
+
+fn generate<R: Rng + ?Sized>(args: &IdentityArgs, rng: &mut R) -> Result<String, String> {
+    let min_age = args.min_age.unwrap_or(18);
+    let max_age = args.max_age.unwrap_or(80);
+    if min_age > max_age {
+        return Err(i18n::message("identity-age-order", &[]));
+    }
+    if max_age > 150 {
+        return Err(i18n::message("identity-age-max", &[]));
+    }
+
+    let (first_name, last_name) = name::random_name(args.gender, args.locale, rng);
+    let username = username_from_name(first_name, last_name, rng);
+    let domain = args.domain.as_deref().unwrap_or("example.com").to_lowercase();
+
+    let password_args = PasswordArgs { length: args.password_length, ..Default::default() };
+    let password = password::generate(&password_args, rng)?;
+
+    // Someone born on the day after today's date `max_age + 1` years ago is still `max_age`.
+    let today = Date::today();
+    let earliest = today.years_earlier(max_age as i64 + 1).to_days() + 1;
+    let latest = today.years_earlier(min_age as i64).to_days();
+    let date_of_birth = Date::from_days(rng.random_range(earliest..=latest));
+
+    Ok(json::object(&[
+        ("name", format!("{} {}", first_name, last_name)),
+        ("first_name", first_name.to_string()),
+        ("last_name", last_name.to_string()),
+        ("username", username.clone()),
+        ("email", format!("{}@{}", username, domain)),
+        ("password", password),
+        ("date_of_birth", date_of_birth.to_string()),
+    ]))
+}
+
+/// Builds a username like "mary.smith", "msmith42" or "mary_smith7" from a name.
+fn username_from_name<R: Rng + ?Sized>(first_name: &str, last_name: &str, rng: &mut R) -> String {
+    let first = ascii_fold(first_name);
+    let last = ascii_fold(last_name);
+    let initial = &first[..1];
+    let number = rng.random_range(1..100);
+
+    match rng.random_range(0..4) {
+        0 => format!("{}.{}", first, last),
+        1 => format!("{}{}{}", initial, last, number),
+        2 => format!("{}_{}{}", first, last, number),
+        _ => format!("{}{}{}", first, last, number),
+    }
+}
+
+/// Lowercases a name and strips accents so it can be used in usernames and email addresses.
+fn ascii_fold(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter_map(|c| {
+            let folded = match c {
+                'à' | 'á' | 'â' | 'ä' | 'ã' | 'å' => 'a',
+                'ç' => 'c',
+                'è' | 'é' | 'ê' | 'ë' => 'e',
+                'ì' | 'í' | 'î' | 'ï' => 'i',
+                'ñ' => 'n',
+                'ò' | 'ó' | 'ô' | 'ö' | 'õ' => 'o',
+                'ù' | 'ú' | 'û' | 'ü' => 'u',
+                'ß' => 's',
+                c => c,
+            };
+            folded.is_ascii_alphanumeric().then_some(folded)
+        })
+        .collect()
+}
@@ -0,0 +1,70 @@
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::i18n;
+
+const ONSETS: &[&str] = &[
+    "b", "c", "d", "f", "g", "h", "j", "k", "l", "m", "n", "p", "r", "s", "t", "v", "w", "z",
+    "br", "ch", "dr", "gr", "kr", "sh", "st", "th", "tr", "vr",
+];
+const VOWELS: &[&str] = &["a", "e", "i", "o", "u", "a", "e", "o", "ae", "ai", "ei", "ou"];
+const CODAS: &[&str] = &["l", "m", "n", "r", "s", "x", "k", "th"];
+
+/// Builds a pronounceable made-up name like "Velkaron" from random syllables.
+/// Syllables stop being added once the next one would exceed `max_length`.
+pub fn generate<R: Rng + ?Sized>(max_length: Option<usize>, rng: &mut R) -> Result<String, String> {
+    const MIN_LENGTH: usize = 2;
+
+    let max_length = max_length.unwrap_or(usize::MAX);
+    if max_length < MIN_LENGTH {
+        return Err(i18n::message("invented-too-long", &[("length", &max_length)]));
+    }
+
+    let syllable_amount = rng.random_range(2..=4);
+    let mut name = String::new();
+    for index in 0..syllable_amount {
+        let syllable = random_syllable(index == 0, rng);
+        if name.len() + syllable.len() > max_length {
+            break;
+        }
+        name.push_str(&syllable);
+    }
+
+    // Even the first syllable may not fit in very tight limits, fall back to the simplest one.
+    while name.len() < MIN_LENGTH {
+        name = format!("{}{}", ONSETS[..18].choose(rng).unwrap(), ["a", "e", "i", "o", "u"].choose(rng).unwrap());
+    }
+
+    let mut chars = name.chars();
+    Ok(chars.next().unwrap().to_uppercase().chain(chars).collect())
+}
+
+/// Shannon entropy in bits of the names without a length limit, a little more than they
+/// really have as different syllables can spell the same name.
+pub fn entropy() -> f64 {
+    let choice = |chance: f64| -(chance * chance.log2() + (1.0 - chance) * (1.0 - chance).log2());
+    let vowel_entropy = VOWELS.iter()
+        .map(|vowel| {
+            let chance = VOWELS.iter().filter(|other| other == &vowel).count() as f64 / VOWELS.len() as f64;
+            -chance.log2() / VOWELS.len() as f64
+        })
+        .sum::<f64>();
+    let syllable = |onset_chance: f64| choice(onset_chance) + onset_chance * (ONSETS.len() as f64).log2()
+        + vowel_entropy + choice(0.35) + 0.35 * (CODAS.len() as f64).log2();
+    // Two to four syllables, three on average.
+    3f64.log2() + syllable(0.85) + 2.0 * syllable(0.95)
+}
+
+fn random_syllable<R: Rng + ?Sized>(is_first: bool, rng: &mut R) -> String {
+    let mut syllable = String::new();
+    // Names read more naturally when they mostly start with a consonant.
+    let onset_chance = if is_first { 0.85 } else { 0.95 };
+    if rng.random_bool(onset_chance) {
+        syllable.push_str(ONSETS.choose(rng).unwrap());
+    }
+    syllable.push_str(VOWELS.choose(rng).unwrap());
+    if rng.random_bool(0.35) {
+        syllable.push_str(CODAS.choose(rng).unwrap());
+    }
+    syllable
+}
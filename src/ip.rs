@@ -0,0 +1,153 @@
+use clap::Args;
+use rand::Rng;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::i18n;
+
+#[derive(Args)]
+pub struct IpArgs {
+    /// Generate an IPv4 address. This is the default.
+    #[arg(short = '4', long = "v4", conflicts_with = "v6")]
+    v4: bool,
+
+    /// Generate an IPv6 address.
+    #[arg(short = '6', long = "v6")]
+    v6: bool,
+
+    /// Subnet the address is picked from, which also decides the IP version.
+    /// Example usage: "--cidr 10.0.0.0/8".
+    #[arg(short = 'c', long = "cidr", conflicts_with_all = ["v4", "v6"])]
+    cidr: Option<String>,
+
+    /// Skip special purpose addresses like loopback, multicast and documentation ranges,
+    /// as well as the network and broadcast address of the subnet.
+    /// Ranges that contain the whole subnet are not excluded.
+    #[arg(short = 'e', long = "exclude-reserved")]
+    exclude_reserved: bool,
+
+    /// Disables copying the IP address to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// IPv4 and IPv6 networks are both handled as integers, IPv4 using the low 32 bits.
+#[derive(Clone, Copy)]
+struct Network {
+    address: u128,
+    prefix: u32,
+    bits: u32,
+}
+
+impl Network {
+    const fn v4(address: u32, prefix: u32) -> Network {
+        Network { address: address as u128, prefix, bits: 32 }
+    }
+
+    const fn v6(address: u128, prefix: u32) -> Network {
+        Network { address, prefix, bits: 128 }
+    }
+
+    fn host_mask(self) -> u128 {
+        let host_bits = self.bits - self.prefix;
+        if host_bits == 128 { u128::MAX } else { (1u128 << host_bits) - 1 }
+    }
+
+    fn contains(self, address: u128) -> bool {
+        address & !self.host_mask() == self.address & !self.host_mask()
+    }
+
+    fn contains_network(self, other: Network) -> bool {
+        self.bits == other.bits && self.prefix <= other.prefix && self.contains(other.address)
+    }
+
+    fn format(self, address: u128) -> String {
+        if self.bits == 32 {
+            Ipv4Addr::from(address as u32).to_string()
+        } else {
+            Ipv6Addr::from(address).to_string()
+        }
+    }
+}
+
+const RESERVED_V4: [Network; 15] = [
+    Network::v4(0x00000000, 8),   // 0.0.0.0/8 "this network"
+    Network::v4(0x0a000000, 8),   // 10.0.0.0/8 private
+    Network::v4(0x64400000, 10),  // 100.64.0.0/10 carrier-grade NAT
+    Network::v4(0x7f000000, 8),   // 127.0.0.0/8 loopback
+    Network::v4(0xa9fe0000, 16),  // 169.254.0.0/16 link-local
+    Network::v4(0xac100000, 12),  // 172.16.0.0/12 private
+    Network::v4(0xc0000000, 24),  // 192.0.0.0/24 protocol assignments
+    Network::v4(0xc0000200, 24),  // 192.0.2.0/24 documentation
+    Network::v4(0xc0586300, 24),  // 192.88.99.0/24 6to4 relay
+    Network::v4(0xc0a80000, 16),  // 192.168.0.0/16 private
+    Network::v4(0xc6120000, 15),  // 198.18.0.0/15 benchmarking
+    Network::v4(0xc6336400, 24),  // 198.51.100.0/24 documentation
+    Network::v4(0xcb007100, 24),  // 203.0.113.0/24 documentation
+    Network::v4(0xe0000000, 4),   // 224.0.0.0/4 multicast
+    Network::v4(0xf0000000, 4),   // 240.0.0.0/4 reserved and broadcast
+];
+
+const RESERVED_V6: [Network; 11] = [
+    Network::v6(0, 128),                 // ::/128 unspecified
+    Network::v6(1, 128),                 // ::1/128 loopback
+    Network::v6(0xffff_0000_0000, 96),   // ::ffff:0:0/96 IPv4-mapped
+    Network::v6(0x0064_ff9b << 96, 96),  // 64:ff9b::/96 IPv4/IPv6 translation
+    Network::v6(0x0100 << 112, 64),      // 100::/64 discard
+    Network::v6(0x2001 << 112, 23),      // 2001::/23 protocol assignments
+    Network::v6(0x2001_0db8 << 96, 32),  // 2001:db8::/32 documentation
+    Network::v6(0x2002 << 112, 16),      // 2002::/16 6to4
+    Network::v6(0xfc00 << 112, 7),       // fc00::/7 unique local
+    Network::v6(0xfe80 << 112, 10),      // fe80::/10 link-local
+    Network::v6(0xff00 << 112, 8),       // ff00::/8 multicast
+];
+
+pub fn generate<R: Rng + ?Sized>(args: &IpArgs, rng: &mut R) -> Result<String, String> {
+    const MAX_ATTEMPTS: usize = 10000;
+
+    let network = match &args.cidr {
+        Some(cidr) => parse_cidr(cidr)?,
+        None if args.v6 => Network::v6(0, 0),
+        None => Network::v4(0, 0),
+    };
+    let reserved: &[Network] = if network.bits == 32 { &RESERVED_V4 } else { &RESERVED_V6 };
+
+    for _ in 0..MAX_ATTEMPTS {
+        let host: u128 = rng.random::<u128>() & network.host_mask();
+        let address = (network.address & !network.host_mask()) | host;
+        if args.exclude_reserved && is_reserved(address, network, reserved) {
+            continue;
+        }
+        return Ok(network.format(address));
+    }
+    Err(i18n::message("ip-exhausted", &[]))
+}
+
+fn is_reserved(address: u128, network: Network, reserved: &[Network]) -> bool {
+    // Network and broadcast addresses only exist in subnets with more than two addresses.
+    let host = address & network.host_mask();
+    if network.bits == 32 && network.bits - network.prefix >= 2 && (host == 0 || host == network.host_mask()) {
+        return true;
+    }
+    reserved.iter().any(|range| range.contains(address) && !range.contains_network(network))
+}
+
+/// Parses "10.0.0.0/8" or "2001:db8::/32".
+fn parse_cidr(cidr: &str) -> Result<Network, String> {
+    let (address, prefix) = cidr.split_once('/')
+        .ok_or_else(|| i18n::message("ip-invalid-cidr", &[("cidr", &cidr)]))?;
+    let prefix: u32 = prefix.parse().map_err(|_| i18n::message("ip-invalid-prefix", &[("prefix", &prefix)]))?;
+
+    if let Ok(address) = address.parse::<Ipv4Addr>() {
+        if prefix > 32 {
+            return Err(i18n::message("ip-v4-prefix", &[]));
+        }
+        return Ok(Network::v4(u32::from(address), prefix));
+    }
+    if let Ok(address) = address.parse::<Ipv6Addr>() {
+        if prefix > 128 {
+            return Err(i18n::message("ip-v6-prefix", &[]));
+        }
+        return Ok(Network::v6(u128::from(address), prefix));
+    }
+    Err(i18n::message("ip-invalid-address", &[("address", &address)]))
+}
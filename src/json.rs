@@ -0,0 +1,284 @@
+use crate::i18n;
+
+/// Quotes and escapes `text` as a JSON string.
+pub fn string(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Formats string fields as a pretty-printed JSON object, keeping their order.
+pub fn object(fields: &[(&str, String)]) -> String {
+    let body: Vec<String> = fields.iter()
+        .map(|(key, value)| format!("  {}: {}", string(key), string(value)))
+        .collect();
+    format!("{{\n{}\n}}", body.join(",\n"))
+}
+
+/// A parsed JSON value. Object fields keep the order they were written in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>),
+}
+
+impl Value {
+    /// Looks up a field of an object, `None` for anything else.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        match self {
+            Value::Object(fields) => fields.iter().find(|(name, _)| name == key).map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The value as a whole number that isn't negative.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(number) if number.fract() == 0.0 && *number >= 0.0 && *number < u64::MAX as f64 => Some(*number as u64),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Formats the value on a single line, as line based protocols need.
+    pub fn to_compact(&self) -> String {
+        match self {
+            Value::Null => "null".into(),
+            Value::Boolean(boolean) => boolean.to_string(),
+            Value::Number(number) => number.to_string(),
+            Value::String(text) => string(text),
+            Value::Array(items) => {
+                let items: Vec<String> = items.iter().map(Value::to_compact).collect();
+                format!("[{}]", items.join(","))
+            }
+            Value::Object(fields) => {
+                let fields: Vec<String> = fields.iter()
+                    .map(|(key, value)| format!("{}:{}", string(key), value.to_compact()))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            }
+        }
+    }
+}
+
+/// Parses a complete JSON document.
+pub fn parse(text: &str) -> Result<Value, String> {
+    let mut parser = Parser { text: text.as_bytes(), position: 0, depth: 0 };
+    let value = parser.value()?;
+    parser.skip_whitespace();
+    if parser.position != parser.text.len() {
+        return Err(parser.error(&i18n::message("json-text-after-value", &[])));
+    }
+    Ok(value)
+}
+
+struct Parser<'a> {
+    text: &'a [u8],
+    position: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> String {
+        i18n::message("json-invalid", &[("position", &self.position), ("reason", &message)])
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.text.get(self.position).is_some_and(|byte| b" \t\r\n".contains(byte)) {
+            self.position += 1;
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.text.get(self.position) != Some(&byte) {
+            return Err(self.error(&i18n::message("json-expected", &[("character", &(byte as char))])));
+        }
+        self.position += 1;
+        Ok(())
+    }
+
+    fn keyword(&mut self, word: &str, value: Value) -> Result<Value, String> {
+        if !self.text[self.position..].starts_with(word.as_bytes()) {
+            return Err(self.error(&i18n::message("json-unknown-value", &[])));
+        }
+        self.position += word.len();
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value, String> {
+        self.skip_whitespace();
+        match self.text.get(self.position) {
+            None => Err(self.error(&i18n::message("json-unexpected-end", &[]))),
+            Some(b'n') => self.keyword("null", Value::Null),
+            Some(b't') => self.keyword("true", Value::Boolean(true)),
+            Some(b'f') => self.keyword("false", Value::Boolean(false)),
+            Some(b'"') => Ok(Value::String(self.string()?)),
+            Some(b'[') | Some(b'{') => {
+                // Deeply nested input would otherwise overflow the stack.
+                self.depth += 1;
+                if self.depth > 64 {
+                    return Err(self.error(&i18n::message("json-nested-too-deeply", &[])));
+                }
+                let value = if self.text[self.position] == b'[' { self.array() } else { self.object() };
+                self.depth -= 1;
+                value
+            }
+            Some(_) => self.number(),
+        }
+    }
+
+    fn array(&mut self) -> Result<Value, String> {
+        self.position += 1;
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.text.get(self.position) == Some(&b']') {
+            self.position += 1;
+            return Ok(Value::Array(items));
+        }
+        loop {
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.text.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b']') => {
+                    self.position += 1;
+                    return Ok(Value::Array(items));
+                }
+                _ => return Err(self.error(&i18n::message("json-expected-comma-bracket", &[]))),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Value, String> {
+        self.position += 1;
+        let mut fields = Vec::new();
+        self.skip_whitespace();
+        if self.text.get(self.position) == Some(&b'}') {
+            self.position += 1;
+            return Ok(Value::Object(fields));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.text.get(self.position) != Some(&b'"') {
+                return Err(self.error(&i18n::message("json-expected-field-name", &[])));
+            }
+            let key = self.string()?;
+            self.expect(b':')?;
+            fields.push((key, self.value()?));
+            self.skip_whitespace();
+            match self.text.get(self.position) {
+                Some(b',') => self.position += 1,
+                Some(b'}') => {
+                    self.position += 1;
+                    return Ok(Value::Object(fields));
+                }
+                _ => return Err(self.error(&i18n::message("json-expected-comma-brace", &[]))),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.position += 1;
+        let mut bytes = Vec::new();
+        loop {
+            let Some(&byte) = self.text.get(self.position) else {
+                return Err(self.error(&i18n::message("json-unterminated-string", &[])));
+            };
+            self.position += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&escape) = self.text.get(self.position) else {
+                        return Err(self.error(&i18n::message("json-unterminated-string", &[])));
+                    };
+                    self.position += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => self.unicode_escape()?,
+                        _ => return Err(self.error(&i18n::message("json-invalid-escape", &[]))),
+                    };
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte if byte < 0x20 => return Err(self.error(&i18n::message("json-control-character", &[]))),
+                byte => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).map_err(|_| self.error(&i18n::message("json-invalid-utf8", &[])))
+    }
+
+    /// Decodes the hex digits after "\u", joining surrogate pairs.
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let first = self.hex_digits()?;
+        if (0xD800..0xDC00).contains(&first) {
+            if !self.text[self.position..].starts_with(b"\\u") {
+                return Err(self.error(&i18n::message("json-unpaired-surrogate", &[])));
+            }
+            self.position += 2;
+            let second = self.hex_digits()?;
+            if !(0xDC00..0xE000).contains(&second) {
+                return Err(self.error(&i18n::message("json-unpaired-surrogate", &[])));
+            }
+            let code_point = 0x10000 + ((first - 0xD800) << 10) + (second - 0xDC00);
+            return char::from_u32(code_point).ok_or_else(|| self.error(&i18n::message("json-invalid-escape", &[])));
+        }
+        char::from_u32(first).ok_or_else(|| self.error(&i18n::message("json-unpaired-surrogate", &[])))
+    }
+
+    fn hex_digits(&mut self) -> Result<u32, String> {
+        let digits = self.text.get(self.position..self.position + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+            .ok_or_else(|| self.error(&i18n::message("json-invalid-escape", &[])))?;
+        self.position += 4;
+        Ok(digits)
+    }
+
+    fn number(&mut self) -> Result<Value, String> {
+        let start = self.position;
+        while self.text.get(self.position).is_some_and(|byte| b"+-.eE0123456789".contains(byte)) {
+            self.position += 1;
+        }
+        std::str::from_utf8(&self.text[start..self.position]).ok()
+            .filter(|number| !number.is_empty())
+            .and_then(|number| number.parse().ok())
+            .map(Value::Number)
+            .ok_or_else(|| self.error(&i18n::message("json-invalid-number", &[])))
+    }
+}
@@ -0,0 +1,307 @@
+//! Secrets kept in the platform secret store: the Secret Service on Linux and BSD,
+//! the login Keychain on macOS and the Credential Manager on Windows.
+//! Everything pwgen saves lives under one service so it can be listed again.
+
+use crate::i18n;
+
+const SERVICE: &str = "pwgen";
+
+pub fn save(name: &str, secret: &str) -> Result<(), String> {
+    check_name(name)?;
+    log::info!("Saving \"{}\" in the platform secret store", name);
+    platform::save(name, secret)
+}
+
+pub fn get(name: &str) -> Result<String, String> {
+    check_name(name)?;
+    platform::get(name)
+}
+
+pub fn delete(name: &str) -> Result<(), String> {
+    check_name(name)?;
+    platform::delete(name)
+}
+
+/// Names of all secrets saved by pwgen, sorted.
+pub fn list() -> Result<Vec<String>, String> {
+    let mut names = platform::list()?;
+    names.sort();
+    names.dedup();
+    Ok(names)
+}
+
+fn check_name(name: &str) -> Result<(), String> {
+    if name.is_empty() || name.chars().any(char::is_control) {
+        return Err(i18n::message("keyring-invalid-name", &[("name", &name.escape_debug())]));
+    }
+    Ok(())
+}
+
+#[cfg(all(unix, not(target_os = "macos")))]
+mod platform {
+    use super::SERVICE;
+    use crate::external;
+    use crate::i18n;
+
+    // secret-tool from libsecret talks to whichever Secret Service is running,
+    // such as GNOME Keyring or KWallet.
+    pub fn save(name: &str, secret: &str) -> Result<(), String> {
+        let label = format!("--label={}: {}", SERVICE, name);
+        external::run("secret-tool", &["store", &label, "service", SERVICE, "name", name], secret.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn get(name: &str) -> Result<String, String> {
+        let output = external::run("secret-tool", &["lookup", "service", SERVICE, "name", name], &[])?;
+        if output.is_empty() {
+            return Err(i18n::message("keyring-not-found", &[("name", &name)]));
+        }
+        String::from_utf8(output).map_err(|_| i18n::message("keyring-not-utf8", &[("name", &name)]))
+    }
+
+    pub fn delete(name: &str) -> Result<(), String> {
+        get(name)?;
+        external::run("secret-tool", &["clear", "service", SERVICE, "name", name], &[])?;
+        Ok(())
+    }
+
+    pub fn list() -> Result<Vec<String>, String> {
+        let output = external::run("secret-tool", &["search", "--all", "service", SERVICE], &[])?;
+        let names = String::from_utf8_lossy(&output).lines()
+            .filter_map(|line| line.strip_prefix("attribute.name = "))
+            .map(str::to_string)
+            .collect();
+        Ok(names)
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::SERVICE;
+    use crate::external;
+    use crate::i18n;
+
+    pub fn save(name: &str, secret: &str) -> Result<(), String> {
+        // Commands given to `security -i` on stdin keep the secret out of the process list.
+        let command = format!(
+            "add-generic-password -U -s {} -a {} -w {}\n",
+            quote(SERVICE),
+            quote(name),
+            quote(secret),
+        );
+        external::run("security", &["-i"], command.as_bytes())?;
+        Ok(())
+    }
+
+    pub fn get(name: &str) -> Result<String, String> {
+        let output = external::run("security", &["find-generic-password", "-s", SERVICE, "-a", name, "-w"], &[])
+            .map_err(|_| i18n::message("keyring-not-in-keychain", &[("name", &name)]))?;
+        let secret = String::from_utf8(output).map_err(|_| i18n::message("keyring-not-utf8", &[("name", &name)]))?;
+        Ok(secret.strip_suffix('\n').unwrap_or(&secret).to_string())
+    }
+
+    pub fn delete(name: &str) -> Result<(), String> {
+        external::run("security", &["delete-generic-password", "-s", SERVICE, "-a", name], &[])
+            .map_err(|_| i18n::message("keyring-not-in-keychain", &[("name", &name)]))?;
+        Ok(())
+    }
+
+    /// Picks the items of the pwgen service out of the keychain dump, which lists the
+    /// attributes of every item without their secrets.
+    pub fn list() -> Result<Vec<String>, String> {
+        let output = external::run("security", &["dump-keychain"], &[])?;
+        let mut names = Vec::new();
+        let mut account = None;
+        let mut is_pwgen = false;
+        for line in String::from_utf8_lossy(&output).lines() {
+            let line = line.trim();
+            if line.starts_with("keychain: ") {
+                if is_pwgen && let Some(account) = account.take() {
+                    names.push(account);
+                }
+                account = None;
+                is_pwgen = false;
+            } else if let Some(value) = line.strip_prefix("\"acct\"<blob>=") {
+                account = unquote(value);
+            } else if let Some(value) = line.strip_prefix("\"svce\"<blob>=") {
+                is_pwgen = unquote(value).as_deref() == Some(SERVICE);
+            }
+        }
+        if is_pwgen && let Some(account) = account {
+            names.push(account);
+        }
+        Ok(names)
+    }
+
+    fn quote(text: &str) -> String {
+        format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+
+    fn unquote(value: &str) -> Option<String> {
+        value.strip_prefix('"')?.strip_suffix('"').map(str::to_string)
+    }
+}
+
+#[cfg(windows)]
+mod platform {
+    use std::ffi::{OsStr, c_void};
+    use std::os::windows::ffi::OsStrExt;
+    use std::ptr;
+
+    use super::SERVICE;
+    use crate::i18n;
+
+    const CRED_TYPE_GENERIC: u32 = 1;
+    const CRED_PERSIST_LOCAL_MACHINE: u32 = 2;
+
+    #[repr(C)]
+    struct FileTime {
+        low: u32,
+        high: u32,
+    }
+
+    #[repr(C)]
+    struct Credential {
+        flags: u32,
+        kind: u32,
+        target_name: *mut u16,
+        comment: *mut u16,
+        last_written: FileTime,
+        blob_size: u32,
+        blob: *mut u8,
+        persist: u32,
+        attribute_count: u32,
+        attributes: *mut c_void,
+        target_alias: *mut u16,
+        user_name: *mut u16,
+    }
+
+    #[link(name = "advapi32")]
+    unsafe extern "system" {
+        fn CredWriteW(credential: *const Credential, flags: u32) -> i32;
+        fn CredReadW(target_name: *const u16, kind: u32, flags: u32, credential: *mut *mut Credential) -> i32;
+        fn CredDeleteW(target_name: *const u16, kind: u32, flags: u32) -> i32;
+        fn CredEnumerateW(filter: *const u16, flags: u32, count: *mut u32, credentials: *mut *mut *mut Credential) -> i32;
+        fn CredFree(buffer: *mut c_void);
+    }
+
+    /// Credentials are named "pwgen:<name>" since the Credential Manager only has target names.
+    fn target(name: &str) -> Vec<u16> {
+        wide(&format!("{}:{}", SERVICE, name))
+    }
+
+    fn wide(text: &str) -> Vec<u16> {
+        OsStr::new(text).encode_wide().chain(Some(0)).collect()
+    }
+
+    /// Reads a null terminated UTF-16 string.
+    ///
+    /// # Safety
+    /// `text` has to point at a null terminated string.
+    unsafe fn from_wide(text: *const u16) -> String {
+        let mut length = 0;
+        // SAFETY: the caller guarantees a terminating null.
+        while unsafe { *text.add(length) } != 0 {
+            length += 1;
+        }
+        // SAFETY: the `length` characters before the null were just read.
+        String::from_utf16_lossy(unsafe { std::slice::from_raw_parts(text, length) })
+    }
+
+    pub fn save(name: &str, secret: &str) -> Result<(), String> {
+        let mut target = target(name);
+        let mut user_name = wide(name);
+        let mut blob = secret.as_bytes().to_vec();
+        let credential = Credential {
+            flags: 0,
+            kind: CRED_TYPE_GENERIC,
+            target_name: target.as_mut_ptr(),
+            comment: ptr::null_mut(),
+            last_written: FileTime { low: 0, high: 0 },
+            blob_size: blob.len() as u32,
+            blob: blob.as_mut_ptr(),
+            persist: CRED_PERSIST_LOCAL_MACHINE,
+            attribute_count: 0,
+            attributes: ptr::null_mut(),
+            target_alias: ptr::null_mut(),
+            user_name: user_name.as_mut_ptr(),
+        };
+        // SAFETY: every pointer in the credential outlives the call.
+        if unsafe { CredWriteW(&credential, 0) } == 0 {
+            return Err(i18n::message("keyring-unable-to-save", &[("name", &name), ("error", &std::io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+
+    pub fn get(name: &str) -> Result<String, String> {
+        let target = target(name);
+        let mut credential = ptr::null_mut();
+        // SAFETY: the target is null terminated and the credential is only read if the call succeeds.
+        if unsafe { CredReadW(target.as_ptr(), CRED_TYPE_GENERIC, 0, &mut credential) } == 0 {
+            return Err(i18n::message("keyring-not-in-credential-manager", &[("name", &name)]));
+        }
+        // SAFETY: CredReadW returned a valid credential, which is freed right after copying the blob.
+        let blob = unsafe {
+            let blob = std::slice::from_raw_parts((*credential).blob, (*credential).blob_size as usize).to_vec();
+            CredFree(credential as *mut c_void);
+            blob
+        };
+        String::from_utf8(blob).map_err(|_| i18n::message("keyring-not-utf8", &[("name", &name)]))
+    }
+
+    pub fn delete(name: &str) -> Result<(), String> {
+        let target = target(name);
+        // SAFETY: the target is null terminated.
+        if unsafe { CredDeleteW(target.as_ptr(), CRED_TYPE_GENERIC, 0) } == 0 {
+            return Err(i18n::message("keyring-not-in-credential-manager", &[("name", &name)]));
+        }
+        Ok(())
+    }
+
+    pub fn list() -> Result<Vec<String>, String> {
+        let filter = wide(&format!("{}:*", SERVICE));
+        let mut count = 0;
+        let mut credentials = ptr::null_mut();
+        // SAFETY: the filter is null terminated, the results are only read if the call succeeds.
+        if unsafe { CredEnumerateW(filter.as_ptr(), 0, &mut count, &mut credentials) } == 0 {
+            // Failing because nothing matched the filter just means nothing was saved yet.
+            return Ok(Vec::new());
+        }
+        let prefix = format!("{}:", SERVICE);
+        // SAFETY: CredEnumerateW returned `count` valid credentials, freed after copying their names.
+        let names = unsafe {
+            let names = std::slice::from_raw_parts(credentials, count as usize).iter()
+                .map(|credential| from_wide((**credential).target_name))
+                .filter_map(|target| target.strip_prefix(&prefix).map(str::to_string))
+                .collect();
+            CredFree(credentials as *mut c_void);
+            names
+        };
+        Ok(names)
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod platform {
+    use crate::i18n;
+
+    fn unsupported() -> String {
+        i18n::message("keyring-unsupported", &[])
+    }
+
+    pub fn save(_name: &str, _secret: &str) -> Result<(), String> {
+        Err(unsupported())
+    }
+
+    pub fn get(_name: &str) -> Result<String, String> {
+        Err(unsupported())
+    }
+
+    pub fn delete(_name: &str) -> Result<(), String> {
+        Err(unsupported())
+    }
+
+    pub fn list() -> Result<Vec<String>, String> {
+        Err(unsupported())
+    }
+}
@@ -0,0 +1,98 @@
+//! Keyboard layouts for `--layout-safe`, which keeps to the characters typed with the same
+//! key on every named layout and US QWERTY, the layout of most BIOS and boot prompts.
+//! Only the main block with and without shift is compared, dead keys and AltGr are never safe.
+
+use clap::ValueEnum;
+
+#[derive(ValueEnum, Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// US QWERTY.
+    Qwerty,
+    /// UK QWERTY.
+    Uk,
+    /// German QWERTZ.
+    Qwertz,
+    /// French AZERTY.
+    Azerty,
+}
+
+/// The rows of a layout from the digits down, without and with shift. A space is a
+/// position that doesn't type anything useful.
+struct Keys {
+    rows: [(&'static str, &'static str); 4],
+    dead_keys: &'static str,
+}
+
+impl Layout {
+    fn keys(self) -> Keys {
+        match self {
+            Layout::Qwerty => Keys {
+                rows: [
+                    ("`1234567890-=", "~!@#$%^&*()_+"),
+                    ("qwertyuiop[]", "QWERTYUIOP{}"),
+                    ("asdfghjkl;'", "ASDFGHJKL:\""),
+                    ("zxcvbnm,./", "ZXCVBNM<>?"),
+                ],
+                dead_keys: "",
+            },
+            Layout::Uk => Keys {
+                rows: [
+                    ("`1234567890-=", "¬!\"£$%^&*()_+"),
+                    ("qwertyuiop[]", "QWERTYUIOP{}"),
+                    ("asdfghjkl;'", "ASDFGHJKL:@"),
+                    ("zxcvbnm,./", "ZXCVBNM<>?"),
+                ],
+                dead_keys: "",
+            },
+            Layout::Qwertz => Keys {
+                rows: [
+                    ("^1234567890ß´", "°!\"§$%&/()=?`"),
+                    ("qwertzuiopü+", "QWERTZUIOPÜ*"),
+                    ("asdfghjklöä", "ASDFGHJKLÖÄ"),
+                    ("yxcvbnm,.-", "YXCVBNM;:_"),
+                ],
+                dead_keys: "^´`",
+            },
+            Layout::Azerty => Keys {
+                rows: [
+                    ("²&é\"'(-è_çà)=", " 1234567890°+"),
+                    ("azertyuiop^$", "AZERTYUIOP¨£"),
+                    ("qsdfghjklmù", "QSDFGHJKLM%"),
+                    ("wxcvbn,;:!", "WXCVBN?./§"),
+                ],
+                dead_keys: "^¨",
+            },
+        }
+    }
+
+    /// Row, column and whether shift is held for `c`.
+    fn position(self, c: char) -> Option<(usize, usize, bool)> {
+        let keys = self.keys();
+        if c == ' ' || keys.dead_keys.contains(c) {
+            return None;
+        }
+        keys.rows.iter().enumerate().find_map(|(row, (plain, shifted))| {
+            plain.chars().position(|key| key == c).map(|column| (row, column, false))
+                .or_else(|| shifted.chars().position(|key| key == c).map(|column| (row, column, true)))
+        })
+    }
+}
+
+/// Whether `c` is typed with the left hand on US QWERTY, `None` for the space bar and
+/// anything not on the keyboard.
+pub fn is_left_hand(c: char) -> Option<bool> {
+    // The US rows leave out the backslash key, which is on the right either way.
+    if matches!(c, '\\' | '|') {
+        return Some(false);
+    }
+    let (row, column, _) = Layout::Qwerty.position(c)?;
+    Some(column < if row == 0 { 6 } else { 5 })
+}
+
+/// Whether `c` is typed with the same key on US QWERTY and every one of `layouts`.
+pub fn is_safe(c: char, layouts: &[Layout]) -> bool {
+    let Some(position) = Layout::Qwerty.position(c) else {
+        return false;
+    };
+    layouts.iter().all(|layout| layout.position(c) == Some(position))
+}
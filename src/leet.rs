@@ -0,0 +1,62 @@
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+/// How aggressively letters are swapped for look-alike digits and symbols.
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum LeetLevel {
+    /// Only the most common substitutions (a, e, o, s).
+    Light,
+    /// Substitutes most letters that have a recognizable look-alike.
+    Heavy,
+}
+
+const LIGHT_SUBSTITUTIONS: &[(char, &[char])] = &[
+    ('a', &['4']),
+    ('e', &['3']),
+    ('o', &['0']),
+    ('s', &['$']),
+];
+
+const HEAVY_SUBSTITUTIONS: &[(char, &[char])] = &[
+    ('a', &['4', '@']),
+    ('b', &['8']),
+    ('e', &['3']),
+    ('g', &['9']),
+    ('i', &['1', '!']),
+    ('l', &['1']),
+    ('o', &['0']),
+    ('s', &['$', '5']),
+    ('t', &['7']),
+    ('z', &['2']),
+];
+
+fn substitutions(level: LeetLevel) -> &'static [(char, &'static [char])] {
+    match level {
+        LeetLevel::Light => LIGHT_SUBSTITUTIONS,
+        LeetLevel::Heavy => HEAVY_SUBSTITUTIONS,
+    }
+}
+
+/// Randomly substitutes letters in `word`, each eligible letter being kept or
+/// replaced with equal odds between the letter and each of its substitutes.
+/// Returns the transformed word and the entropy in bits the choices added.
+pub fn apply<R: Rng + ?Sized>(word: &str, level: LeetLevel, rng: &mut R) -> (String, f64) {
+    let table = substitutions(level);
+    let mut added_bits = 0.0;
+
+    let transformed = word.chars()
+        .map(|c| {
+            let Some((_, replacements)) = table.iter().find(|(letter, _)| *letter == c.to_ascii_lowercase()) else {
+                return c;
+            };
+            added_bits += ((replacements.len() + 1) as f64).log2();
+            if rng.random_range(0..=replacements.len()) == 0 {
+                c
+            } else {
+                *replacements.choose(rng).unwrap()
+            }
+        })
+        .collect();
+
+    (transformed, added_bits)
+}
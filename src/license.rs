@@ -0,0 +1,144 @@
+use clap::{Args, Subcommand};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::encoding::CROCKFORD_ALPHABET;
+use crate::digest;
+use crate::i18n;
+use crate::prompt;
+
+const DEFAULT_PATTERN: &str = "XXXXX-XXXXX-XXXXX-XXXXX";
+
+/// Amount of symbols at the end of the key taken up by the checksum.
+const CHECKSUM_LENGTH: usize = 4;
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct LicenseArgs {
+    #[command(subcommand)]
+    pub command: Option<LicenseCommands>,
+
+    #[command(flatten)]
+    pub generate: GenerateArgs,
+}
+
+#[derive(Subcommand)]
+pub enum LicenseCommands {
+    /// Check whether a license key has a valid checksum.
+    Verify(VerifyArgs),
+}
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Layout of the key, every 'X' being a random character and anything else kept as is.
+    /// Defaults to "XXXXX-XXXXX-XXXXX-XXXXX".
+    #[arg(short = 'p', long = "pattern")]
+    pattern: Option<String>,
+
+    /// Use the last 4 random characters as a checksum over the others.
+    #[arg(short = 'c', long = "checksum")]
+    checksum: bool,
+
+    /// Secret the checksum is keyed with, so only you can issue valid keys.
+    /// The same secret is needed to verify them, use "-" to type it in hidden and "@FILE" to read it from a file.
+    #[arg(short = 's', long = "secret", requires = "checksum", value_parser = prompt::argument("secret"))]
+    secret: Option<String>,
+
+    /// Disables copying the license key to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// License key to check.
+    key: String,
+
+    /// Layout the key was generated with.
+    /// Defaults to "XXXXX-XXXXX-XXXXX-XXXXX".
+    #[arg(short = 'p', long = "pattern")]
+    pattern: Option<String>,
+
+    /// Secret the key was generated with, if any.
+    #[arg(short = 's', long = "secret", value_parser = prompt::argument("secret"))]
+    secret: Option<String>,
+}
+
+/// Generates a license key following the pattern, using Crockford base32 characters
+/// so keys read over the phone or typed from a printout can't be confused.
+pub fn generate<R: Rng + ?Sized>(args: &GenerateArgs, rng: &mut R) -> Result<String, String> {
+    let pattern = args.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+    let symbol_amount = pattern.chars().filter(|&c| c == 'X').count();
+    if symbol_amount == 0 {
+        return Err(i18n::message("license-no-x", &[]));
+    }
+    if args.checksum && symbol_amount <= CHECKSUM_LENGTH {
+        return Err(i18n::message("license-too-few-x", &[("checksum", &CHECKSUM_LENGTH)]));
+    }
+
+    let data_amount = if args.checksum { symbol_amount - CHECKSUM_LENGTH } else { symbol_amount };
+    let mut symbols: Vec<char> = (0..data_amount).map(|_| *CROCKFORD_ALPHABET.choose(rng).unwrap() as char).collect();
+    if args.checksum {
+        symbols.extend(checksum(&symbols, args.secret.as_deref().unwrap_or("")));
+    }
+
+    let mut symbols = symbols.into_iter();
+    Ok(pattern.chars().map(|c| if c == 'X' { symbols.next().unwrap() } else { c }).collect())
+}
+
+pub fn verify(args: &VerifyArgs) -> Result<(), String> {
+    let pattern = args.pattern.as_deref().unwrap_or(DEFAULT_PATTERN);
+    check(&args.key, pattern, args.secret.as_deref().unwrap_or(""))
+        .map_err(|problem| i18n::message("license-invalid", &[("problem", &problem)]))?;
+    println!("{}", i18n::message("license-valid", &[]));
+    Ok(())
+}
+
+fn check(key: &str, pattern: &str, secret: &str) -> Result<(), String> {
+    let key: Vec<char> = key.trim().chars().collect();
+    let pattern: Vec<char> = pattern.chars().collect();
+    if key.len() != pattern.len() {
+        return Err(i18n::message("license-pattern-mismatch", &[]));
+    }
+
+    let mut symbols = Vec::new();
+    for (&key_char, &pattern_char) in key.iter().zip(&pattern) {
+        if pattern_char != 'X' {
+            if key_char != pattern_char {
+                return Err(i18n::message("license-pattern-mismatch", &[]));
+            }
+            continue;
+        }
+        // Accept the look-alikes Crockford base32 allows when reading keys back.
+        let symbol = match key_char.to_ascii_uppercase() {
+            'O' => '0',
+            'I' | 'L' => '1',
+            c => c,
+        };
+        if !CROCKFORD_ALPHABET.contains(&(symbol as u8)) {
+            return Err(i18n::message("license-invalid-character", &[("character", &key_char)]));
+        }
+        symbols.push(symbol);
+    }
+
+    if symbols.len() <= CHECKSUM_LENGTH {
+        return Err(i18n::message("license-too-short", &[]));
+    }
+    let (data, key_checksum) = symbols.split_at(symbols.len() - CHECKSUM_LENGTH);
+    if checksum(data, secret) != key_checksum {
+        return Err(i18n::message("license-checksum-mismatch", &[]));
+    }
+    Ok(())
+}
+
+/// First 20 bits of the HMAC-SHA256 of the data symbols as 4 Crockford base32 characters.
+/// Without a secret this is a plain checksum that catches typos.
+fn checksum(data: &[char], secret: &str) -> Vec<char> {
+    let data: String = data.iter().collect();
+    let digest = digest::hmac_sha256(secret.as_bytes(), data.as_bytes());
+    let bits = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) >> 12;
+    (0..CHECKSUM_LENGTH)
+        .rev()
+        .map(|index| CROCKFORD_ALPHABET[((bits >> (index * 5)) & 31) as usize] as char)
+        .collect()
+}
@@ -0,0 +1,77 @@
+//! Diagnostics on stderr, written with the `log` macros throughout pwgen and its dependencies.
+//! Log messages describe what was tried and why it failed, never the secrets involved.
+//!
+//! This uses `log` rather than `tracing`. The daemon serves every connection on a thread of
+//! its own and `bench` spreads its work over several, but only the thread accepting
+//! connections logs, so there are no spans or concurrent tasks to follow. Every line is written
+//! whole, so lines from different threads never mix. A small logger over `log` gives the
+//! levels and the JSON lines without the subscriber stack of `tracing`.
+
+use clap::ValueEnum;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use once_cell::sync::OnceCell;
+
+use crate::date::Date;
+use crate::json::Value;
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+struct Logger {
+    format: LogFormat,
+}
+
+static LOGGER: OnceCell<Logger> = OnceCell::new();
+
+/// Shows warnings by default, every `-v` adds a level and `--quiet` leaves only errors.
+pub fn init(verbosity: u8, quiet: bool, format: LogFormat) {
+    let level = match (quiet, verbosity) {
+        (true, _) => LevelFilter::Error,
+        (false, 0) => LevelFilter::Warn,
+        (false, 1) => LevelFilter::Info,
+        (false, 2) => LevelFilter::Debug,
+        (false, _) => LevelFilter::Trace,
+    };
+    if log::set_logger(LOGGER.get_or_init(|| Logger { format })).is_ok() {
+        log::set_max_level(level);
+    }
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        match self.format {
+            LogFormat::Text => eprintln!("[{} {}] {}", level_name(record.level()), record.target(), record.args()),
+            LogFormat::Json => {
+                let line = Value::Object(vec![
+                    ("time".into(), Value::String(Date::timestamp_now())),
+                    ("level".into(), Value::String(level_name(record.level()).into())),
+                    ("target".into(), Value::String(record.target().into())),
+                    ("message".into(), Value::String(record.args().to_string())),
+                ]);
+                eprintln!("{}", line.to_compact());
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+fn level_name(level: Level) -> &'static str {
+    match level {
+        Level::Error => "error",
+        Level::Warn => "warn",
+        Level::Info => "info",
+        Level::Debug => "debug",
+        Level::Trace => "trace",
+    }
+}
@@ -0,0 +1,51 @@
+use clap::Args;
+use rand::Rng;
+
+#[derive(Args)]
+pub struct MacArgs {
+    /// Sets the locally administered bit, so the address can't clash with vendor assigned ones.
+    #[arg(short = 'l', long = "local")]
+    local: bool,
+
+    /// Clears the multicast bit, as required for addresses of network interfaces.
+    #[arg(short = 'u', long = "unicast")]
+    unicast: bool,
+
+    /// How the address is written.
+    /// Defaults to colon.
+    #[arg(short = 'f', long = "format", value_enum)]
+    format: Option<MacFormat>,
+
+    /// Disables copying the MAC address to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum MacFormat {
+    /// Like "02:1a:2b:3c:4d:5e".
+    Colon,
+    /// Like "02-1A-2B-3C-4D-5E".
+    Dash,
+    /// Like "021a.2b3c.4d5e".
+    Cisco,
+}
+
+const MULTICAST_BIT: u8 = 0b01;
+const LOCAL_BIT: u8 = 0b10;
+
+pub fn generate<R: Rng + ?Sized>(args: &MacArgs, rng: &mut R) -> String {
+    let mut octets: [u8; 6] = rng.random();
+    if args.local {
+        octets[0] |= LOCAL_BIT;
+    }
+    if args.unicast {
+        octets[0] &= !MULTICAST_BIT;
+    }
+
+    match args.format.unwrap_or(MacFormat::Colon) {
+        MacFormat::Colon => octets.iter().map(|octet| format!("{:02x}", octet)).collect::<Vec<_>>().join(":"),
+        MacFormat::Dash => octets.iter().map(|octet| format!("{:02X}", octet)).collect::<Vec<_>>().join("-"),
+        MacFormat::Cisco => octets.chunks(2).map(|pair| format!("{:02x}{:02x}", pair[0], pair[1])).collect::<Vec<_>>().join("."),
+    }
+}
@@ -1,8 +1,85 @@
-use clap::{Parser, Subcommand, Args};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand, Args};
 use arboard::Clipboard;
-use rand::seq::IndexedRandom;
-use rand::Rng;
-use once_cell::sync::Lazy;
+use std::thread;
+use std::time::Duration;
+
+mod accounts;
+mod alias;
+mod aes;
+mod apikey;
+mod audit;
+mod bench;
+mod breach;
+mod capitalize;
+mod char_pattern;
+mod codename;
+mod config;
+mod coupons;
+mod crypt;
+mod daemon;
+mod date;
+mod dead_end;
+mod defaults;
+mod derive;
+mod digest;
+mod doctor;
+mod ed25519;
+mod email;
+mod encoding;
+mod external;
+mod git_credential;
+mod hardening;
+mod hash;
+mod health;
+mod history;
+mod honeywords;
+mod i18n;
+mod id;
+mod identity;
+mod invented;
+mod ip;
+mod json;
+mod keyring;
+mod layout;
+mod leet;
+mod logging;
+mod license;
+mod mac;
+mod name;
+mod nanoid;
+mod native_host;
+mod otp;
+mod output;
+mod passphrase;
+mod patterns;
+mod password;
+mod paths;
+mod petname;
+mod picker;
+mod platform;
+mod policy;
+mod porcelain;
+mod prompt;
+mod request;
+mod salt;
+mod sandbox;
+mod separator;
+mod setup;
+mod shamir;
+mod skey;
+mod slug;
+mod sites;
+mod sshkey;
+mod stats;
+mod stdout_only;
+mod strength;
+mod testcard;
+mod token;
+mod unicode;
+mod username;
+mod vaults;
+mod wordlist;
+mod xkpasswd;
 
 #[derive(Parser)]
 #[command(
@@ -12,6 +89,48 @@ use once_cell::sync::Lazy;
     subcommand_required(false),
 )]
 struct Cli {
+    /// Lock pwgen's memory so secrets are never swapped to disk.
+    /// Core dumps are always turned off.
+    #[arg(long = "hardened", global = true)]
+    hardened: bool,
+    
+    /// Only ever write secrets to stdout, never to the clipboard, a secret store or a file.
+    /// Options that would are refused.
+    #[arg(long = "stdout-only", global = true, env = "PWGEN_STDOUT_ONLY")]
+    stdout_only: bool,
+    
+    /// Don't sandbox pwgen, in case the sandbox gets in the way of something it should do.
+    #[arg(long = "no-sandbox", global = true)]
+    no_sandbox: bool,
+    
+    /// Keep the config and data next to the executable, in pwgen.toml and pwgen-data.
+    /// Also turned on by a pwgen.toml next to the executable.
+    #[arg(long = "portable", global = true)]
+    portable: bool,
+    
+    /// Language of the messages, like "de" or "nl".
+    /// Defaults to the language of the locale.
+    #[arg(long = "lang", global = true)]
+    lang: Option<String>,
+    
+    /// Log what pwgen does on stderr, more with every -v up to -vvv.
+    #[arg(short = 'v', long = "verbose", action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    
+    /// Only print the secret and errors, and only log errors.
+    #[arg(short = 'q', long = "quiet", conflicts_with = "verbose", global = true)]
+    quiet: bool,
+    
+    /// Format of the log lines.
+    /// Defaults to text.
+    #[arg(long = "log-format", value_enum, global = true)]
+    log_format: Option<logging::LogFormat>,
+    
+    /// Print stable "<field> <value>" lines for scripts instead of messages.
+    /// The format is described in the readme.
+    #[arg(long = "porcelain", global = true)]
+    porcelain: bool,
+    
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -20,204 +139,1297 @@ struct Cli {
 enum Commands {
     /// Generate a password consisting of random characters.
     Password(PasswordArgs),
+    /// Generate a passphrase consisting of random words.
+    Passphrase(PassphraseArgs),
     /// Generate a username consisting of two words and some numbers.
     Username(UsernameArgs),
+    /// Generate a realistic first and last name.
+    Name(name::NameArgs),
+    /// Generate a unique email alias for signing up on a site.
+    Email(email::EmailArgs),
+    /// Generate a lowercase name for servers, branches and cloud resources.
+    Petname(petname::PetnameArgs),
+    /// Generate a DNS-safe slug for hostnames and URLs.
+    Slug(slug::SlugArgs),
+    /// Generate a two-word project or release codename.
+    Codename(codename::CodenameArgs),
+    /// Generate a fake persona as JSON for test data.
+    Identity(identity::IdentityArgs),
+    /// Print a documented test card number for testing payment forms.
+    Testcard(testcard::TestCardArgs),
+    /// Generate a random MAC address.
+    Mac(mac::MacArgs),
+    /// Generate a random IP address, optionally within a subnet.
+    Ip(ip::IpArgs),
+    /// Generate sortable ULID identifiers.
+    Ulid(id::IdArgs),
+    /// Generate sortable KSUID identifiers.
+    Ksuid(id::IdArgs),
+    /// Generate a short URL-friendly Nano ID.
+    Nanoid(nanoid::NanoIdArgs),
+    /// Generate a Crockford base32 token with a check symbol against typos.
+    Token(token::TokenArgs),
+    /// Generate a prefixed API key, optionally with a checksum.
+    Apikey(apikey::ApiKeyArgs),
+    /// Generate a TOTP secret and the otpauth:// URI for provisioning 2FA.
+    OtpSecret(otp::OtpSecretArgs),
+    /// Print the current TOTP code for a base32 secret.
+    Otp(otp::OtpArgs),
+    /// Generate a sheet of RFC 2289 (S/KEY) one-time passwords.
+    Skey(skey::SkeyArgs),
+    /// Generate decoy passwords shaped like a real one, for honeyword detection.
+    Honeywords(honeywords::HoneywordsArgs),
+    /// Recover a secret from the shares printed by --split.
+    Combine(shamir::CombineArgs),
+    /// Hash a password with argon2id or bcrypt for storing in config files.
+    Hash(hash::HashArgs),
+    /// Generate random bytes for a salt, IV or nonce.
+    Salt(salt::SaltArgs),
+    /// Generate an SSH keypair in OpenSSH format.
+    Sshkey(sshkey::SshKeyArgs),
+    /// Generate a batch of Unix accounts for chpasswd.
+    Accounts(accounts::AccountsArgs),
+    /// Generate a batch of unique coupon codes.
+    Coupons(coupons::CouponsArgs),
+    /// Generate or verify an offline license key.
+    License(license::LicenseArgs),
+    /// Import and manage wordlists.
+    Wordlist(wordlist::WordlistArgs),
+    /// List, print and delete secrets saved in the platform secret store.
+    Vaults(vaults::VaultsArgs),
+    /// Serve password requests from a browser extension over native messaging.
+    NativeHost(native_host::NativeHostArgs),
+    /// Serve password requests from local tools over a Unix socket.
+    Daemon(daemon::DaemonArgs),
+    /// Act as a git credential helper that mints and keeps passwords in the keyring.
+    GitCredential(git_credential::GitCredentialArgs),
+    /// Set, print and edit the defaults in the config file.
+    Config(config::ConfigArgs),
+    /// Ask about the most common defaults and save them to the config file.
+    Setup,
+    /// Check what works on this machine, like the clipboard, keyring and config file.
+    Doctor,
+    /// Measure how fast passwords, passphrases and tokens are generated with the current settings.
+    Bench(bench::BenchArgs),
+    /// Derive the same password for a site every time from a hardware token, without storing it.
+    Derive(derive::DeriveArgs),
+    /// Import and query an offline filter of breached passwords, checked with --check-offline.
+    Breach(breach::BreachArgs),
+    /// Work with password policies, the composition rules of sites and organisations.
+    Policy(policy::PolicyArgs),
+    /// Report the keyspace and entropy of the current settings without generating anything.
+    /// Example usage: "pwgen stats passphrase --words 5".
+    Stats(stats::StatsArgs),
+    /// Check an existing password against a policy, rule by rule.
+    /// Example usage: "pwgen validate --policy company.toml".
+    Validate(policy::ValidateArgs),
+    /// Change an existing password as little as possible to follow a policy.
+    /// Example usage: "pwgen conform --policy company.toml".
+    Conform(policy::ConformArgs),
+    /// Generate a password of words, separators and padding the way xkpasswd does.
+    Xkpasswd(xkpasswd::XkpasswdArgs),
+    /// Run the last command that generated something again, with the same options.
+    Again,
+    /// Show or copy the last secret generated again, when the history is enabled.
+    Last(history::LastArgs),
 }
 
-#[derive(Args)]
+#[derive(Args, Default)]
 struct PasswordArgs {
     /// Amount of characters.
     /// Defaults to 16.
-    #[arg(short = 'l', long = "length")]
+    #[arg(short = 'l', long = "length", env = "PWGEN_LENGTH")]
     length: Option<u32>,
     
     /// All the character sets used in the password.
     /// By default this is set to all non-rare sets available.
     /// Example usage: "--char-set lower,upper,digits".
-    #[arg(short = 'c', long = "char-set", value_enum, value_delimiter = ',')]
-    character_sets: Option<Vec<CharSet>>,
+    #[arg(short = 'c', long = "char-set", value_enum, value_delimiter = ',', env = "PWGEN_CHAR_SETS")]
+    character_sets: Option<Vec<password::CharSet>>,
     
     /// Exclude characters from the character sets.
+    /// Use "-" to type them in hidden and "@FILE" to read them from a file.
     /// Example usage: "--exclude abc!@#".
-    #[arg(short = 'e', long = "exclude")]
+    #[arg(short = 'e', long = "exclude", env = "PWGEN_EXCLUDE", value_parser = prompt::argument("excluded characters"))]
     excluded_chars: Option<String>,
     
+    /// Leave out vowels and the digits 0 and 1 that pass for them, like classic pwgen -v,
+    /// so the password can't spell out words by accident.
+    #[arg(long = "no-vowels")]
+    no_vowels: bool,
+    
+    /// Exclude the characters matching a regular expression of a single character.
+    /// Example usage: "--exclude-regex '[\p{P}0O1lI]'".
+    #[arg(long = "exclude-regex", value_parser = char_pattern::parse)]
+    exclude_regex: Option<char_pattern::CharPattern>,
+    
+    /// Exclude whole categories of characters.
+    /// Example usage: "--exclude-category punctuation,uppercase".
+    #[arg(long = "exclude-category", value_enum, value_delimiter = ',')]
+    exclude_category: Option<Vec<unicode::Category>>,
+    
+    
+    /// Also split the password into shares, so any threshold of them recovers it with `pwgen combine`.
+    /// Example usage: "--split 3-of-5".
+    #[arg(long = "split", value_parser = shamir::parse_split)]
+    split: Option<shamir::Split>,
+    
+    /// Explain how the characters are picked and the entropy that gives on stderr.
+    #[arg(long = "explain")]
+    explain: bool,
+    
+    /// Follow the password rules of a known site, so the password is accepted first try.
+    /// Example usage: "--site github.com".
+    #[arg(long = "site")]
+    site: Option<String>,
+    
+    /// Follow a policy file, as used by `pwgen validate`.
+    /// Example usage: "--policy company.toml".
+    #[arg(long = "policy", conflicts_with = "site")]
+    policy_file: Option<std::path::PathBuf>,
+    
+    /// The policy every password has to follow, set by --site or --policy.
+    #[arg(skip)]
+    policy: Option<Box<policy::Policy>>,
+    
+    /// Pick the characters of a set this many times as often as the others.
+    /// Costs some entropy, which is printed on stderr.
+    /// Example usage: "--weight digits=3,symbols=2".
+    #[arg(long = "weight", value_name = "WEIGHT", value_parser = password::parse_weight, value_delimiter = ',',
+        conflicts_with_all = ["site", "policy_file", "style", "mobile_friendly", "alternate_hands"])]
+    weights: Vec<password::Weight>,
+    
+    /// Only use characters typed with the same key on these keyboard layouts and US QWERTY,
+    /// for passwords typed where the layout is unknown, like BIOS or disk encryption prompts.
+    /// Example usage: "--layout-safe qwertz,azerty".
+    #[arg(long = "layout-safe", value_enum, value_delimiter = ',')]
+    layout_safe: Option<Vec<layout::Layout>>,
+    
+    /// How the password is put together.
+    /// Defaults to random.
+    #[arg(long = "style", value_enum)]
+    style: Option<password::PasswordStyle>,
+    
+    /// Put letters, digits and at most one symbol in blocks, to switch keyboard panes less on phones.
+    /// Costs some entropy, which is printed on stderr.
+    #[arg(long = "mobile-friendly")]
+    mobile_friendly: bool,
+    
+    /// Alternate between keys of the left and right hand on QWERTY, which is faster to type.
+    /// Costs some entropy, which is printed on stderr.
+    #[arg(long = "alternate-hands", conflicts_with = "mobile_friendly")]
+    alternate_hands: bool,
+    
+    /// Regenerate the password while it contains a dictionary word, keyboard walk or date.
+    #[arg(long = "no-patterns")]
+    no_patterns: bool,
+    
+    /// Regenerate the password while it's in the breach filter imported with `pwgen breach import`.
+    #[arg(long = "check-offline")]
+    check_offline: bool,
+    
+    /// Passwords generated before giving up on --site, --policy and --no-patterns.
+    /// Defaults to 1000.
+    #[arg(long = "max-attempts", value_parser = clap::value_parser!(u64).range(1..))]
+    max_attempts: Option<u64>,
+    
+    /// Seconds spent generating passwords before giving up on --site, --policy and --no-patterns.
+    /// Example usage: "--timeout 5".
+    #[arg(long = "timeout", value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: Option<u64>,
+    
+    #[command(flatten)]
+    picker: picker::PickerArgs,
+    
+    #[command(flatten)]
+    output: output::OutputArgs,
     
     /// Disables copying the password to clipboard.
-    #[arg(short = 'o', long = "no-copy")]
+    #[arg(short = 'o', long = "no-copy", env = "PWGEN_NO_COPY")]
     copy_disabled: bool,
     
     /// Disables hiding the password generated
-    #[arg(short = 'i', long = "no-hide")]
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
+    hide_disabled: bool,
+}
+
+#[derive(Args, Default)]
+struct PassphraseArgs {
+    /// Amount of words.
+    /// Defaults to 6.
+    #[arg(short = 'w', long = "words", env = "PWGEN_PASSPHRASE_WORDS")]
+    words: Option<u32>,
+    
+    /// Character in between the words.
+    /// Defaults to '-'.
+    #[arg(short = 'c', long = "word-char", env = "PWGEN_PASSPHRASE_WORD_CHAR")]
+    word_char: Option<char>,
+    
+    /// Separators picked at random instead, spaces separate ones longer than a character.
+    /// Example usage: "--separator-set -_." or "--separator-set ':: -- ..'".
+    #[arg(long = "separator-set", value_parser = separator::parse, allow_hyphen_values = true, conflicts_with = "word_char")]
+    separator_set: Option<separator::SeparatorSet>,
+    
+    /// Picks a separator of --separator-set for every gap instead of one for all of them.
+    #[arg(long = "separator-per-gap", requires = "separator_set")]
+    separator_per_gap: bool,
+    
+    /// Name of the wordlist to pick words from.
+    /// By default the built-in adjective and object lists are combined.
+    #[arg(short = 'W', long = "wordlist", env = "PWGEN_PASSPHRASE_WORDLIST")]
+    wordlist: Option<String>,
+    
+    /// Maximum total length including separators.
+    /// Shorter words are picked until the passphrase fits.
+    #[arg(short = 'm', long = "max-length", env = "PWGEN_PASSPHRASE_MAX_LENGTH")]
+    max_length: Option<usize>,
+    
+    /// Randomly swap letters for look-alike digits and symbols.
+    /// Example usage: "--leet light".
+    #[arg(short = 'L', long = "leet", value_enum)]
+    leet: Option<leet::LeetLevel>,
+    
+    /// Capitalize the words or a random subset of letters.
+    /// Example usage: "--capitalize random".
+    #[arg(short = 'C', long = "capitalize", value_enum)]
+    capitalization: Option<capitalize::Capitalization>,
+    
+    /// Prints the estimated entropy of the passphrase.
+    #[arg(short = 's', long = "show-strength")]
+    show_strength: bool,
+    
+    /// Also split the passphrase into shares, so any threshold of them recovers it with `pwgen combine`.
+    /// Example usage: "--split 3-of-5".
+    #[arg(long = "split", value_parser = shamir::parse_split)]
+    split: Option<shamir::Split>,
+    
+    /// Regenerate the passphrase while it's in the breach filter imported with `pwgen breach import`.
+    #[arg(long = "check-offline")]
+    check_offline: bool,
+    
+    #[command(flatten)]
+    picker: picker::PickerArgs,
+    
+    #[command(flatten)]
+    output: output::OutputArgs,
+    
+    /// Disables copying the passphrase to clipboard.
+    #[arg(short = 'o', long = "no-copy", env = "PWGEN_NO_COPY")]
+    copy_disabled: bool,
+    
+    /// Disables hiding the passphrase generated
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
     hide_disabled: bool,
 }
 
-#[derive(Args)]
+#[derive(Args, Default)]
 struct UsernameArgs {
+    /// How the name part is built.
+    /// Defaults to words.
+    #[arg(long = "style", value_enum, env = "PWGEN_USERNAME_STYLE")]
+    style: Option<username::UsernameStyle>,
+    
     /// Amount of numbers after the username.
     /// Defaults to 2.
-    #[arg(short = 'N', long = "numbers")]
+    #[arg(short = 'N', long = "numbers", conflicts_with = "number_range", env = "PWGEN_USERNAME_NUMBERS")]
     numbers: Option<u32>,
     
+    /// Range the number after the username is picked from, both ends included.
+    /// Example usage: "--number-range 10..99".
+    #[arg(short = 'r', long = "number-range", value_parser = username::parse_number_range)]
+    number_range: Option<username::NumberRange>,
+    
+    /// Rerolls numbers that look like a year, such as 1987 or 2004.
+    #[arg(short = 'y', long = "avoid-years")]
+    avoid_years: bool,
+    
     /// Character in between the words and numbers.
     /// Don't specify to omit it.
-    #[arg(short = 'c', long = "word-char")]
+    #[arg(short = 'c', long = "word-char", env = "PWGEN_USERNAME_WORD_CHAR")]
     word_char: Option<char>,
     
+    /// Separators picked at random instead, spaces separate ones longer than a character.
+    /// Example usage: "--separator-set -_." or "--separator-set ':: -- ..'".
+    #[arg(long = "separator-set", value_parser = separator::parse, allow_hyphen_values = true, conflicts_with = "word_char")]
+    separator_set: Option<separator::SeparatorSet>,
+    
+    /// Picks a separator of --separator-set for every gap instead of one for all of them.
+    #[arg(long = "separator-per-gap", requires = "separator_set")]
+    separator_per_gap: bool,
+    
+    /// Maximum total length including separators and numbers.
+    /// Shorter words are picked until the username fits.
+    #[arg(short = 'm', long = "max-length", env = "PWGEN_USERNAME_MAX_LENGTH")]
+    max_length: Option<usize>,
+    
+    /// Minimum length of each word.
+    #[arg(long = "min-word-len")]
+    min_word_length: Option<usize>,
+    
+    /// Maximum length of each word.
+    #[arg(long = "max-word-len")]
+    max_word_length: Option<usize>,
+    
+    /// Randomly swap letters for look-alike digits and symbols.
+    /// Example usage: "--leet light".
+    #[arg(short = 'L', long = "leet", value_enum)]
+    leet: Option<leet::LeetLevel>,
+    
+    /// Makes both words start with the same letter.
+    #[arg(short = 'a', long = "alliterate")]
+    alliterate: bool,
+    
+    /// Only generate usernames the platform accepts.
+    /// Enforces its length, allowed characters and start character rules.
+    #[arg(short = 'p', long = "platform", value_enum, env = "PWGEN_USERNAME_PLATFORM")]
+    platform: Option<platform::Platform>,
+    
+    /// Keep generating until the username contains this text.
+    /// Falls back to embedding the text directly.
+    #[arg(long = "contains", conflicts_with = "starts_with")]
+    contains: Option<String>,
+    
+    /// Keep generating until the username starts with this text.
+    /// Falls back to embedding the text directly.
+    #[arg(long = "starts-with")]
+    starts_with: Option<String>,
+    
+    /// Attempts made for --contains and --starts-with before embedding the text.
+    /// Defaults to 10000.
+    #[arg(long = "max-attempts")]
+    max_attempts: Option<usize>,
+    
+    /// Show this many usernames and pick one to copy.
+    /// Example usage: "--suggest 10".
+    #[arg(short = 'S', long = "suggest")]
+    suggest: Option<usize>,
+    
+    /// Prints how many usernames are possible and the chance of a duplicate.
+    #[arg(short = 's', long = "show-strength")]
+    show_strength: bool,
+    
+    /// Amount of users the chance of a duplicate is estimated for.
+    /// Defaults to 1000.
+    #[arg(long = "users", requires = "show_strength")]
+    users: Option<u64>,
+    
+    /// Never generates a username issued with --unique before.
+    /// Issued usernames are kept in usernames.txt in the config directory.
+    #[arg(short = 'u', long = "unique")]
+    unique: bool,
+    
+    /// File to keep the issued usernames of --unique in instead.
+    /// Example usage: "--history cohort-2024.txt".
+    #[arg(long = "history", requires = "unique")]
+    history: Option<std::path::PathBuf>,
+    
+    /// File of words, one per line, that usernames must not contain.
+    /// Example usage: "--block-words trademarks.txt".
+    #[arg(long = "block-words", env = "PWGEN_BLOCK_WORDS")]
+    block_words: Option<std::path::PathBuf>,
+    
+    /// Hides the username generated, like a password.
+    #[arg(short = 'H', long = "hide")]
+    hide: bool,
+    
+    /// Only shows the first characters of the username generated.
+    #[arg(long = "mask")]
+    mask: bool,
+    
+    /// Always derives the same username from this text, like an email address or UUID.
+    /// Example usage: "--from alice@example.com".
+    #[arg(long = "from", conflicts_with_all = ["leet", "alliterate", "contains", "starts_with", "suggest", "unique"])]
+    from: Option<String>,
+    
+    /// Shows the username generated when the config hides it.
+    #[arg(short = 'i', long = "no-hide", conflicts_with_all = ["hide", "mask"])]
+    hide_disabled: bool,
+    
     /// Disables copying the username to clipboard.
-    #[arg(short = 'n', long = "no-copy")]
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
     copy_disabled: bool,
 }
 
 
-#[derive(clap::ValueEnum, Clone)]
-enum CharSet {
-    Lower,
-    Upper,
-    Digits,
-    Symbols,
-    RareSymbols,
-}
-
-fn get_char_set (sets: &CharSet) -> &'static str {
-    match sets {
-        CharSet::Lower => LOWER_CHARS,
-        CharSet::Upper => UPPER_CHARS,
-        CharSet::Digits => DIGITS_CHARS,
-        CharSet::Symbols => SPECIAL_CHARS,
-        CharSet::RareSymbols => SPECIAL_RARE_CHARS,
-    }
-}
-
-const DEFAULT_CHAR_SETS: [CharSet; 4] = [CharSet::Lower, CharSet::Upper, CharSet::Digits, CharSet::Symbols];
-
-const LOWER_CHARS: &str = "abcdefghijklmnopqrstuvwxyz";
-const UPPER_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-const DIGITS_CHARS: &str = "0123456789";
-const SPECIAL_CHARS: &str = "!@#$%^&*-_=+()[]{}<>:;,.?~";
-const SPECIAL_RARE_CHARS: &str = "/\\\'\"|` ";
-
-const ADJECTIVE_LIST_RAW: &str = include_str!("../data/adjective.txt");
-const OBJECT_LIST_RAW: &str = include_str!("../data/object.txt");
-
-static ADJECTIVE_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    ADJECTIVE_LIST_RAW.lines().collect()
-});
-static OBJECT_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    OBJECT_LIST_RAW.lines().collect()
-});
-
 fn main() {
-    let cli = Cli::parse();
+    // Parsing the arguments can read secrets already.
+    let protection = hardening::protect_process();
+    let mut args: Vec<std::ffi::OsString> = std::env::args_os().collect();
+    if args.iter().take_while(|arg| *arg != "--").any(|arg| arg == "--hardened")
+        && let Err(message) = hardening::lock_memory() {
+        exit_with_error(&message);
+    }
+    // Paths are needed for aliases before the arguments are parsed.
+    let is_portable = |arg: &std::ffi::OsString| arg == "--portable";
+    if args.iter().take_while(|arg| *arg != "--").any(is_portable) {
+        paths::enable_portable();
+    }
+    
+    let mut cli_command = Cli::command();
+    if let Some(aliases) = alias::help() {
+        cli_command = cli_command.after_help(aliases);
+    }
+    // Bare `pwgen` runs the default command, parsed as if it was typed so environment variables apply.
+    if only_global_options(&cli_command, &args[1..]) {
+        match defaults::default_command() {
+            Ok(name) => args.push(name.into()),
+            Err(message) => exit_with_error(&message),
+        }
+    }
+    let args = match alias::expand(&mut cli_command, args) {
+        Ok(args) => args,
+        Err(message) => exit_with_error(&message),
+    };
+    let mut invocation = args;
+    let mut matches = cli_command.clone().get_matches_from(invocation.clone());
+    if matches.subcommand_name() == Some("again") {
+        invocation = match history::last_arguments() {
+            Ok(arguments) => arguments,
+            Err(message) => exit_with_error(&message),
+        };
+        matches = cli_command.get_matches_from(invocation.clone());
+        if matches.subcommand_name() == Some("again") {
+            exit_with_error(&i18n::message("again-itself", &[]));
+        }
+    }
+    let command_name = matches.subcommand_name().unwrap_or("password").to_string();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|error| error.exit());
+    logging::init(cli.verbose, cli.quiet, cli.log_format.unwrap_or(logging::LogFormat::Text));
+    if cli.quiet {
+        porcelain::set_quiet();
+    }
+    if let Err(message) = protection {
+        log::warn!("{}", message);
+    }
+    if let Err(message) = i18n::set_language(cli.lang.as_deref()) {
+        exit_with_error(&message);
+    }
+    
+    if cli.porcelain {
+        porcelain::enable();
+    }
     
     let mut copy_to_clipboard = true;
-    let mut final_output: String = "".into();
+    let mut final_output: String;
     let mut rng = rand::rng();
-    let is_password: bool;
+    let output_name: &str;
+    let last_output_name: String;
     
-    match &cli.command.unwrap_or(Commands::Password(PasswordArgs { length: None, character_sets: None, excluded_chars: None, copy_disabled: false, hide_disabled: false })) {
+    let mut command = cli.command.unwrap_or_else(|| Cli::parse_from(["pwgen", "password"]).command.unwrap());
+    if uses_randomness(&command) && let Err(message) = health::check() {
+        exit_with_error(&message);
+    }
+    if cli.stdout_only && let Err(message) = stdout_only::enable(&command) {
+        exit_with_error(&message);
+    }
+    if !cli.no_sandbox && let Err(message) = sandbox::apply(&command) {
+        exit_with_error(&message);
+    }
+    if porcelain::is_enabled() && !supports_porcelain(&command) {
+        exit_with_error(&i18n::message("porcelain-unsupported", &[]));
+    }
+    if matches!(command, Commands::Password(_) | Commands::Passphrase(_) | Commands::Username(_)) && !porcelain::is_enabled() && !cli.quiet {
+        setup::offer_on_first_run();
+    }
+    // Options from the command line and environment win over the config file.
+    if let Err(message) = defaults::apply(&mut command) {
+        exit_with_error(&message);
+    }
+    
+    match &command {
         
         Commands::Password( args ) => {
-            is_password = true;
+            output_name = "Password";
             if args.copy_disabled {
                 copy_to_clipboard = false;
             }
             
-            let chosen_character_sets: &[CharSet] = if let Some(user_character_sets) = &args.character_sets {
-                user_character_sets
-            } else {
-                &DEFAULT_CHAR_SETS // Use default
+            if args.explain {
+                match password::explain(args) {
+                    Ok(explanation) => eprintln!("{}", explanation),
+                    Err(message) => exit_with_error(&message),
+                }
+            } else if !cli.quiet && let Ok(Some(trade_off)) = password::trade_off(args) {
+                eprintln!("{}", trade_off);
+            }
+            
+            let filter = match breach_filter(args.check_offline) {
+                Ok(filter) => filter,
+                Err(message) => exit_with_error(&message),
+            };
+            let sampler = match password::Sampler::new(args) {
+                Ok(sampler) => sampler,
+                Err(message) => exit_with_error(&message),
+            };
+            let generate = || breach::avoiding(filter.as_ref(), || password::generate_with(args, &sampler, &mut rng), |password| password);
+            final_output = match args.picker.choose(generate, |password| password) {
+                Ok(Some(password)) => password,
+                Ok(None) => exit_with_error(&i18n::message("no-password-picked", &[])),
+                Err(message) => exit_with_error(&message),
             };
             
-            let mut all_characters: Vec<char> = Vec::new();
-            for character_set in chosen_character_sets {
-                all_characters.extend(get_char_set(character_set).chars());
+            if let Some(split) = args.split {
+                for share in shamir::split(final_output.as_bytes(), split, &mut rng) {
+                    porcelain::secret("share", &share, &share);
+                }
             }
             
-            if let Some(excluded_chars) = &args.excluded_chars {
-                for excluded_char in excluded_chars.chars() {
-                    all_characters.retain(|&c| c != excluded_char);
+            match args.output.store(&output_name.to_lowercase(), &final_output, &mut rng) {
+                Ok(Some(destination)) => {
+                    porcelain::report("destination", &destination, i18n::message("written", &[("name", &i18n::noun(output_name)), ("destination", &destination)]));
+                    audit::record(&command_name, &command, &[&destination]);
+                    return;
                 }
+                Ok(None) => {}
+                Err(message) => exit_with_error(&message),
             }
             
-            if all_characters.is_empty() {
-                println!("No characters are allowed! Try to add more character sets or exclude less characters.");
-                return;
+            final_output = match args.output.format(&output_name.to_lowercase(), &final_output, &mut rng) {
+                Ok(formatted) => formatted,
+                Err(message) => exit_with_error(&message),
+            };
+            // Formatted output such as an encrypted vault is safe to show, and with
+            // --stdout-only there's nowhere else for the secret to go.
+            if args.hide_disabled || args.output.is_formatted() || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
+            }
+        }
+        
+        Commands::Passphrase( args ) => {
+            output_name = "Passphrase";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            let filter = match breach_filter(args.check_offline) {
+                Ok(filter) => filter,
+                Err(message) => exit_with_error(&message),
+            };
+            let generate = || breach::avoiding(filter.as_ref(), || passphrase::generate(args, &mut rng), |passphrase| &passphrase.text);
+            let passphrase = match args.picker.choose(generate, |passphrase| &passphrase.text) {
+                Ok(Some(passphrase)) => passphrase,
+                Ok(None) => exit_with_error(&i18n::message("no-passphrase-picked", &[])),
+                Err(message) => exit_with_error(&message),
+            };
+            final_output = passphrase.text;
+            
+            if let Some(split) = args.split {
+                for share in shamir::split(final_output.as_bytes(), split, &mut rng) {
+                    porcelain::secret("share", &share, &share);
+                }
             }
             
-            let mut password_length = 16;
-            if let Some(new_length) = args.length {
-                if new_length > 65536 {
-                    println!("Password too long! Cannot be longer than 65536.");
+            match args.output.store(&output_name.to_lowercase(), &final_output, &mut rng) {
+                Ok(Some(destination)) => {
+                    porcelain::report("destination", &destination, i18n::message("written", &[("name", &i18n::noun(output_name)), ("destination", &destination)]));
+                    audit::record(&command_name, &command, &[&destination]);
                     return;
                 }
-                password_length = new_length;
+                Ok(None) => {}
+                Err(message) => exit_with_error(&message),
             }
             
-            for _ in 0..password_length {
-                let random_char = all_characters.choose(&mut rng).unwrap();
-                final_output.push(*random_char);
+            final_output = match args.output.format(&output_name.to_lowercase(), &final_output, &mut rng) {
+                Ok(formatted) => formatted,
+                Err(message) => exit_with_error(&message),
+            };
+            // Formatted output such as an encrypted vault is safe to show, and with
+            // --stdout-only there's nowhere else for the secret to go.
+            if args.hide_disabled || args.output.is_formatted() || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
             }
             
-            if args.hide_disabled {
-                println!("{}", final_output);
+            if args.show_strength {
+                let bits = passphrase.word_bits + passphrase.leet_bits + passphrase.capitalization_bits + passphrase.separator_bits;
+                porcelain::report("entropy", &format!("{:.1}", bits), strength::describe(bits));
+                if passphrase.leet_bits > 0.0 {
+                    porcelain::human(strength::describe_boost("leet", passphrase.leet_bits));
+                }
+                if passphrase.capitalization_bits > 0.0 {
+                    porcelain::human(strength::describe_boost("capitalization", passphrase.capitalization_bits));
+                }
+                if passphrase.separator_bits > 0.0 {
+                    porcelain::human(strength::describe_boost("separators", passphrase.separator_bits));
+                }
             }
         }
         
         Commands::Username( args ) => {
-            is_password = false;
+            output_name = "Username";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            if let Some(suggestion_amount) = args.suggest {
+                if suggestion_amount == 0 || suggestion_amount > 1000 {
+                    exit_with_error(&i18n::message("suggestions-range", &[]));
+                }
+                
+                let mut suggestions = match username::suggest(args, suggestion_amount, &mut rng) {
+                    Ok(suggestions) => suggestions,
+                    Err(message) => exit_with_error(&message),
+                };
+                
+                // Hidden suggestions are masked, or there would be nothing to pick from.
+                let is_hidden = (args.hide || args.mask) && !args.hide_disabled && !stdout_only::is_enabled();
+                for (index, suggestion) in suggestions.iter().enumerate() {
+                    let shown = if is_hidden { picker::masked(suggestion) } else { suggestion.clone() };
+                    if is_hidden {
+                        porcelain::report("suggestion", suggestion, format!("{}. {}", index + 1, shown));
+                    } else {
+                        porcelain::secret("suggestion", suggestion, format!("{}. {}", index + 1, shown));
+                    }
+                }
+                if args.show_strength && let Err(message) = username::report_strength(args) {
+                    exit_with_error(&message);
+                }
+                
+                // Scripts get all suggestions instead of a prompt to pick one, and may use any of them.
+                if !copy_to_clipboard || porcelain::is_enabled() {
+                    for suggestion in &suggestions {
+                        if let Err(message) = username::record(args, suggestion) {
+                            exit_with_error(&message);
+                        }
+                    }
+                    return;
+                }
+                let Some(picked_index) = prompt::pick(suggestions.len()) else {
+                    return;
+                };
+                final_output = suggestions.swap_remove(picked_index);
+                if let Err(message) = username::record(args, &final_output) {
+                    exit_with_error(&message);
+                }
+            } else {
+                let generated = match &args.from {
+                    Some(input) => username::from_input(args, input),
+                    None => username::generate(args, &mut rng),
+                };
+                final_output = match generated {
+                    Ok(username) => username,
+                    Err(message) => exit_with_error(&message),
+                };
+                
+                let is_hidden = (args.hide || args.mask) && !args.hide_disabled;
+                if !is_hidden || porcelain::is_enabled() || stdout_only::is_enabled() {
+                    porcelain::value(&final_output);
+                } else if args.mask {
+                    porcelain::human(picker::masked(&final_output));
+                }
+                if args.show_strength && let Err(message) = username::report_strength(args) {
+                    exit_with_error(&message);
+                }
+            }
+        }
+        
+        Commands::Name( args ) => {
+            output_name = "Name";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = name::generate(args, &mut rng);
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Email( args ) => {
+            output_name = "Email";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match email::generate(args, &mut rng) {
+                Ok(email) => email,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Petname( args ) => {
+            output_name = "Petname";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match petname::generate(args, &mut rng) {
+                Ok(petname) => petname,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Slug( args ) => {
+            output_name = "Slug";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match slug::generate(args, &mut rng) {
+                Ok(slug) => slug,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Codename( args ) => {
+            output_name = "Codename";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match codename::generate(args, &mut rng) {
+                Ok(codename) => codename,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Identity( args ) => {
+            if let Err(message) = identity::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Testcard( args ) => {
+            output_name = "Card number";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            let test_card = testcard::generate(args, &mut rng);
+            porcelain::described_value(&test_card.number, test_card.describe());
+            porcelain::field("expiry", &test_card.expiry());
+            porcelain::field("cvc", &test_card.cvc);
+            final_output = test_card.number;
+        }
+        
+        Commands::Mac( args ) => {
+            output_name = "MAC address";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = mac::generate(args, &mut rng);
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Ip( args ) => {
+            output_name = "IP address";
             if args.copy_disabled {
                 copy_to_clipboard = false;
             }
             
-            let first_random_word = *ADJECTIVE_LIST.choose(&mut rng).expect("Adjective word list empty. This is a build error!");
-            let second_random_word = *OBJECT_LIST.choose(&mut rng).expect("Object word list empty. This is a build error!");
+            final_output = match ip::generate(args, &mut rng) {
+                Ok(address) => address,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        command @ (Commands::Ulid( args ) | Commands::Ksuid( args )) => {
+            let is_ulid = matches!(command, Commands::Ulid(_));
+            output_name = if is_ulid { "ULID" } else { "KSUID" };
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
             
-            let chosen_word_char = if let Some(user_word_char) = args.word_char {
-                user_word_char.to_string()
+            let identifiers = if is_ulid {
+                id::generate_ulids(args, &mut rng)
             } else {
-                String::new()
+                id::generate_ksuids(args, &mut rng)
+            };
+            final_output = match identifiers {
+                Ok(identifiers) => identifiers.join("\n"),
+                Err(message) => exit_with_error(&message),
             };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Nanoid( args ) => {
+            output_name = "Nano ID";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
             
-            final_output = format!("{}{}{}", first_random_word, chosen_word_char, second_random_word);
+            final_output = match nanoid::generate(args, &mut rng) {
+                Ok(id) => id,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Token( args ) => {
+            if let Some(token) = &args.verify {
+                if let Err(message) = token::verify(token) {
+                    exit_with_error(&message);
+                }
+                return;
+            }
             
-            let chosen_number_amount = if let Some(user_number_amount) = args.numbers {
-                if user_number_amount > 65536 {
-                    println!("Too many numbers! Cannot be more than 65536.");
-                    return;
+            output_name = "Token";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match token::generate(args, &mut rng) {
+                Ok(token) => token,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Apikey( args ) => {
+            output_name = "API key";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match apikey::generate(args, &mut rng) {
+                Ok(key) => key,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::OtpSecret( args ) => {
+            output_name = "OTP secret";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            let otp_secret = match otp::generate(args, &mut rng) {
+                Ok(otp_secret) => otp_secret,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&otp_secret.secret);
+            porcelain::secret("uri", &otp_secret.uri, &otp_secret.uri);
+            if args.qr {
+                match otp::qr_code(&otp_secret.uri) {
+                    Ok(qr_code) => porcelain::human(qr_code.trim_end_matches('\n')),
+                    Err(message) => exit_with_error(&message),
                 }
-                user_number_amount
-            } else {
-                2 // Default number amount
+            }
+            final_output = otp_secret.secret;
+        }
+        
+        Commands::Otp( args ) => {
+            output_name = "OTP code";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            let otp_code = match otp::current_code(args) {
+                Ok(otp_code) => otp_code,
+                Err(message) => exit_with_error(&message),
             };
+            porcelain::value(&otp_code.code);
+            let seconds = otp_code.seconds_remaining.to_string();
+            porcelain::report("expires-in", &seconds, i18n::message("otp-valid", &[("seconds", &seconds)]));
+            final_output = otp_code.code;
+        }
+        
+        Commands::Skey( args ) => {
+            if let Err(message) = skey::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Honeywords( args ) => {
+            if let Err(message) = honeywords::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Combine( args ) => {
+            output_name = "Secret";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match shamir::combine(args) {
+                Ok(secret) => secret,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Hash( args ) => {
+            output_name = "Hash";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match hash::generate(args, &mut rng) {
+                Ok(hash) => hash,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Salt( args ) => {
+            output_name = "Salt";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
             
-            if chosen_number_amount > 0 {
-                final_output.push_str(&chosen_word_char);
+            final_output = match salt::generate(args, &mut rng) {
+                Ok(salt) => salt,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Sshkey( args ) => {
+            output_name = if args.passphrase { "Passphrase" } else { "Public key" };
+            if args.copy_disabled {
+                copy_to_clipboard = false;
             }
             
-            for _ in 0..chosen_number_amount {
-                final_output.push_str(&rng.random_range(0..10).to_string());
+            let ssh_key = match sshkey::generate(args, &mut rng) {
+                Ok(ssh_key) => ssh_key,
+                Err(message) => exit_with_error(&message),
+            };
+            let private_path = ssh_key.private_path.display().to_string();
+            let public_path = ssh_key.public_path.display().to_string();
+            porcelain::report("private-key-file", &private_path, i18n::message("saved-private-key", &[("path", &private_path)]));
+            porcelain::report("public-key-file", &public_path, i18n::message("saved-public-key", &[("path", &public_path)]));
+            final_output = match ssh_key.passphrase {
+                Some(passphrase) => {
+                    porcelain::report("public-key", &ssh_key.public_key, &ssh_key.public_key);
+                    porcelain::secret("passphrase", &passphrase, i18n::message("sshkey-passphrase", &[("passphrase", &passphrase)]));
+                    passphrase
+                }
+                // Without a passphrase the public key is what can be piped on.
+                None => {
+                    porcelain::secret("public-key", &ssh_key.public_key, &ssh_key.public_key);
+                    ssh_key.public_key
+                }
+            };
+        }
+        
+        Commands::Accounts( args ) => {
+            if let Err(message) = accounts::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Coupons( args ) => {
+            if let Err(message) = coupons::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::License( args ) => {
+            if let Some(license::LicenseCommands::Verify(verify_args)) = &args.command {
+                if let Err(message) = license::verify(verify_args) {
+                    exit_with_error(&message);
+                }
+                return;
             }
             
+            output_name = "License key";
+            if args.generate.copy_disabled {
+                copy_to_clipboard = false;
+            }
             
-            println!("{}", final_output);
+            final_output = match license::generate(&args.generate, &mut rng) {
+                Ok(key) => key,
+                Err(message) => exit_with_error(&message),
+            };
+            porcelain::value(&final_output);
+        }
+        
+        Commands::Wordlist( args ) => {
+            if let Err(message) = wordlist::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Vaults( args ) => {
+            if let Err(message) = vaults::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::NativeHost( args ) => {
+            if let Err(message) = native_host::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Daemon( args ) => {
+            if let Err(message) = daemon::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::GitCredential( args ) => {
+            if let Err(message) = git_credential::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Config( args ) => {
+            if let Err(message) = config::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Setup => {
+            if let Err(message) = setup::run() {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Doctor => {
+            if let Err(message) = doctor::run() {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Bench( args ) => {
+            if let Err(message) = bench::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Derive( args ) => {
+            output_name = "Password";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match derive::generate(args) {
+                Ok(password) => password,
+                Err(message) => exit_with_error(&message),
+            };
+            if args.hide_disabled || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
+            }
+        }
+        
+        Commands::Breach( args ) => {
+            if let Err(message) = breach::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Policy( args ) => {
+            if let Err(message) = policy::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Stats( args ) => {
+            if let Err(message) = stats::run(args) {
+                exit_with_error(&message);
+            }
+            return;
+        }
+        
+        Commands::Validate( args ) => {
+            policy::validate(args);
+            return;
+        }
+        
+        Commands::Conform( args ) => {
+            output_name = "Password";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            final_output = match policy::conform(args, &mut rng) {
+                Ok(password) => password,
+                Err(message) => exit_with_error(&message),
+            };
+            if args.hide_disabled || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
+            }
+        }
+        
+        Commands::Xkpasswd( args ) => {
+            output_name = "Password";
+            if args.copy_disabled {
+                copy_to_clipboard = false;
+            }
+            
+            let bits;
+            (final_output, bits) = match xkpasswd::generate(args, &mut rng) {
+                Ok(generated) => generated,
+                Err(message) => exit_with_error(&message),
+            };
+            if args.hide_disabled || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
+            }
+            if args.show_strength {
+                porcelain::report("entropy", &format!("{:.1}", bits), strength::describe(bits));
+            }
+        }
+        
+        // Replaced by the last command before parsing.
+        Commands::Again => exit_with_error(&i18n::message("again-nothing", &[])),
+        
+        Commands::Last( args ) => {
+            (final_output, last_output_name) = match history::last_secret() {
+                Ok(last) => last,
+                Err(message) => exit_with_error(&message),
+            };
+            output_name = &last_output_name;
+            if !args.copy {
+                copy_to_clipboard = false;
+            }
+            if args.hide_disabled || porcelain::is_enabled() || stdout_only::is_enabled() {
+                porcelain::value(&final_output);
+            } else if !args.copy {
+                exit_with_error(&i18n::message("again-hidden", &[]));
+            }
         }
     }
     
+    hardening::lock_secret(&final_output);
+    if stdout_only::is_enabled() {
+        copy_to_clipboard = false;
+    }
+    let mut destinations = Vec::new();
+    if porcelain::value_shown() {
+        destinations.push("stdout");
+    }
+    let mut clipboard_to_clear = None;
     if copy_to_clipboard {
-        if let Ok(mut clipboard) = Clipboard::new() {
-            let clipboard_success = clipboard.set_text(final_output);
-            if clipboard_success.is_ok() {
-                if is_password {
-                    println!("Password copied to clipboard.");
-                } else {
-                    println!("Username copied to clipboard.");
+        match Clipboard::new() {
+            Ok(mut clipboard) => match clipboard.set_text(&final_output) {
+                Ok(()) => {
+                    destinations.push("clipboard");
+                    porcelain::report("clipboard", "copied", i18n::message("copied", &[("name", &i18n::noun(output_name))]));
+                    clipboard_to_clear = defaults::clear_after().map(|seconds| (clipboard, seconds));
+                }
+                Err(error) => {
+                    log::info!("Setting the clipboard failed: {}", error);
+                    porcelain::report("clipboard", "failed", i18n::message("copy-failed", &[]));
+                }
+            },
+            Err(error) => {
+                log::info!("No clipboard available: {}", error);
+                porcelain::report("clipboard", "unsupported", i18n::message("copy-unsupported", &[]));
+            }
+        }
+    } else {
+        porcelain::field("clipboard", "disabled");
+    }
+    if !matches!(command, Commands::Last(_)) && history::record(&command, &invocation, output_name, &final_output) {
+        destinations.push("history");
+    }
+    audit::record(&command_name, &command, &destinations);
+    
+    if let Some((mut clipboard, seconds)) = clipboard_to_clear {
+        let seconds_text = seconds.to_string();
+        porcelain::report("clear-after", &seconds_text, i18n::message("clearing", &[("seconds", &seconds_text)]));
+        thread::sleep(Duration::from_secs(seconds));
+        // Something copied in the meantime is left alone.
+        if clipboard.get_text().is_ok_and(|text| text == final_output) {
+            if let Err(error) = clipboard.clear() {
+                log::warn!("Unable to clear the clipboard: {}", error);
+            }
+        } else {
+            log::debug!("The clipboard changed in the meantime, leaving it alone.");
+        }
+    }
+}
+
+/// Whether `args` are nothing but global options like `-q` or `--lang de`, which leave the
+/// command to run to the config file.
+fn only_global_options(cli_command: &clap::Command, args: &[std::ffi::OsString]) -> bool {
+    let globals: Vec<&clap::Arg> = cli_command.get_arguments().filter(|arg| arg.is_global_set()).collect();
+    let takes_value = |arg: &&clap::Arg| arg.get_action().takes_values();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let Some(arg) = arg.to_str() else {
+            return false;
+        };
+        let value_follows = if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, _)) => (name, true),
+                None => (long, false),
+            };
+            match globals.iter().find(|global| global.get_long() == Some(name)) {
+                Some(global) => takes_value(global) && !value,
+                None => return false,
+            }
+        } else if let Some(shorts) = arg.strip_prefix('-') && !shorts.is_empty() {
+            // Flags can be grouped like `-vvq`.
+            let mut value_follows = false;
+            for short in shorts.chars() {
+                match globals.iter().find(|global| global.get_short() == Some(short)) {
+                    Some(global) if !value_follows => value_follows = takes_value(global),
+                    _ => return false,
                 }
-            } else {
-                println!("Unable to copy to clipboard.");
             }
+            value_follows
         } else {
-            println!("Unable to copy to clipboard because it is not supported by OS.");
+            return false;
+        };
+        if value_follows && args.next().is_none() {
+            return false;
         }
     }
-}
\ No newline at end of file
+    true
+}
+
+/// Commands that draw from the random generator, which is health checked first.
+fn uses_randomness(command: &Commands) -> bool {
+    match command {
+        Commands::Token( args ) => args.verify.is_none(),
+        Commands::License( args ) => args.command.is_none(),
+        Commands::Otp(_) | Commands::Combine(_) | Commands::Derive(_) | Commands::Wordlist(_) | Commands::Vaults(_)
+        | Commands::Config(_) | Commands::Setup | Commands::Doctor | Commands::Breach(_)
+        | Commands::Policy(_) | Commands::Stats(_)
+        | Commands::Validate(_) | Commands::Last(_) => false,
+        _ => true,
+    }
+}
+
+/// Commands that print their own output, which has no porcelain format.
+fn supports_porcelain(command: &Commands) -> bool {
+    match command {
+        Commands::Token( args ) => args.verify.is_none(),
+        Commands::License( args ) => args.command.is_none(),
+        Commands::Identity(_) | Commands::Skey(_) | Commands::Honeywords(_) | Commands::Accounts(_)
+        | Commands::Coupons(_) | Commands::Wordlist(_) | Commands::Vaults(_) | Commands::NativeHost(_)
+        | Commands::Daemon(_) | Commands::GitCredential(_) | Commands::Config(_) | Commands::Setup
+        | Commands::Doctor | Commands::Bench(_) | Commands::Breach(_)
+        | Commands::Policy(_) | Commands::Stats(_)
+        | Commands::Validate(_) => false,
+        _ => true,
+    }
+}
+
+/// Prints the error a command failed with and exits with 1, or with 3 for a dead end.
+fn exit_with_error(message: &str) -> ! {
+    dead_end::error(message);
+    std::process::exit(1);
+}
+
+/// The breach filter for --check-offline, which has to be imported beforehand.
+fn breach_filter(check_offline: bool) -> Result<Option<breach::Filter>, String> {
+    if check_offline {
+        breach::Filter::load().map(Some)
+    } else {
+        Ok(None)
+    }
+}
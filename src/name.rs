@@ -0,0 +1,94 @@
+use clap::Args;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+#[derive(Args)]
+pub struct NameArgs {
+    /// Only use first names of this gender.
+    /// Defaults to any.
+    #[arg(short = 'g', long = "gender", value_enum)]
+    gender: Option<Gender>,
+
+    /// Which country's names to use.
+    /// Defaults to en-us.
+    #[arg(short = 'l', long = "locale", value_enum)]
+    locale: Option<Locale>,
+
+    /// Disables copying the name to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Gender {
+    Any,
+    Female,
+    Male,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Locale {
+    #[value(name = "en-us")]
+    EnUs,
+    #[value(name = "de-de")]
+    DeDe,
+    #[value(name = "es-es")]
+    EsEs,
+    #[value(name = "fr-fr")]
+    FrFr,
+}
+
+/// Embedded name lists of a locale, most common names first.
+/// The en-us lists are taken from US census and social security name statistics.
+struct NameLists {
+    female: &'static str,
+    male: &'static str,
+    last: &'static str,
+}
+
+fn name_lists(locale: Locale) -> NameLists {
+    match locale {
+        Locale::EnUs => NameLists {
+            female: include_str!("../data/names/en-us/female.txt"),
+            male: include_str!("../data/names/en-us/male.txt"),
+            last: include_str!("../data/names/en-us/last.txt"),
+        },
+        Locale::DeDe => NameLists {
+            female: include_str!("../data/names/de-de/female.txt"),
+            male: include_str!("../data/names/de-de/male.txt"),
+            last: include_str!("../data/names/de-de/last.txt"),
+        },
+        Locale::EsEs => NameLists {
+            female: include_str!("../data/names/es-es/female.txt"),
+            male: include_str!("../data/names/es-es/male.txt"),
+            last: include_str!("../data/names/es-es/last.txt"),
+        },
+        Locale::FrFr => NameLists {
+            female: include_str!("../data/names/fr-fr/female.txt"),
+            male: include_str!("../data/names/fr-fr/male.txt"),
+            last: include_str!("../data/names/fr-fr/last.txt"),
+        },
+    }
+}
+
+/// Generates a "First Last" name combination.
+pub fn generate<R: Rng + ?Sized>(args: &NameArgs, rng: &mut R) -> String {
+    let (first_name, last_name) = random_name(args.gender, args.locale, rng);
+    format!("{} {}", first_name, last_name)
+}
+
+/// Picks a random first and last name.
+pub fn random_name<R: Rng + ?Sized>(gender: Option<Gender>, locale: Option<Locale>, rng: &mut R) -> (&'static str, &'static str) {
+    let lists = name_lists(locale.unwrap_or(Locale::EnUs));
+
+    let first_names: Vec<&str> = match gender.unwrap_or(Gender::Any) {
+        Gender::Female => lists.female.lines().collect(),
+        Gender::Male => lists.male.lines().collect(),
+        Gender::Any => lists.female.lines().chain(lists.male.lines()).collect(),
+    };
+    let last_names: Vec<&str> = lists.last.lines().collect();
+
+    let first_name = first_names.choose(rng).expect("First name list empty. This is a build error!");
+    let last_name = last_names.choose(rng).expect("Last name list empty. This is a build error!");
+    (first_name, last_name)
+}
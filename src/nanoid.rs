@@ -0,0 +1,65 @@
+use clap::Args;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::i18n;
+
+/// URL-safe alphabet used by the reference Nano ID implementation.
+const DEFAULT_ALPHABET: &str = "useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+
+#[derive(Args)]
+pub struct NanoIdArgs {
+    /// Amount of characters.
+    /// Defaults to 21.
+    #[arg(short = 's', long = "size")]
+    size: Option<usize>,
+
+    /// Characters the ID is made of, between 2 and 256 distinct characters.
+    /// Defaults to the URL-safe "A-Za-z0-9_-".
+    #[arg(short = 'a', long = "alphabet")]
+    alphabet: Option<String>,
+
+    /// Disables copying the ID to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Generates a Nano ID. Random bytes are masked down to the smallest power of two
+/// covering the alphabet and out of range values are skipped, so every character
+/// is equally likely no matter the alphabet size.
+pub fn generate<R: Rng + ?Sized>(args: &NanoIdArgs, rng: &mut R) -> Result<String, String> {
+    let size = args.size.unwrap_or(21);
+    if size == 0 || size > 65536 {
+        return Err(i18n::message("nanoid-size-range", &[]));
+    }
+
+    let alphabet: Vec<char> = args.alphabet.as_deref().unwrap_or(DEFAULT_ALPHABET).chars().collect();
+    if alphabet.len() < 2 || alphabet.len() > 256 {
+        return Err(i18n::message("nanoid-alphabet-range", &[]));
+    }
+    let mut seen = HashSet::new();
+    if let Some(duplicate) = alphabet.iter().find(|&&c| !seen.insert(c)) {
+        return Err(i18n::message("nanoid-duplicate", &[("character", &duplicate)]));
+    }
+
+    let mask = (2u32 << (31 - ((alphabet.len() as u32 - 1) | 1).leading_zeros())) - 1;
+    // Same batch size as the reference implementation: enough bytes to finish in one go on average.
+    let step = ((1.6 * mask as f64 * size as f64) / alphabet.len() as f64).ceil() as usize;
+
+    let mut id = String::with_capacity(size);
+    let mut id_length = 0;
+    let mut bytes = vec![0u8; step];
+    loop {
+        rng.fill(bytes.as_mut_slice());
+        for &byte in &bytes {
+            let index = (byte as u32 & mask) as usize;
+            if let Some(&c) = alphabet.get(index) {
+                id.push(c);
+                id_length += 1;
+                if id_length == size {
+                    return Ok(id);
+                }
+            }
+        }
+    }
+}
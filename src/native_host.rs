@@ -0,0 +1,46 @@
+use clap::Args;
+use std::io::{self, Read, Write};
+
+use crate::i18n;
+use crate::request;
+
+/// Requests are only a few fields, anything bigger is not meant for pwgen.
+const MAX_MESSAGE_LENGTH: usize = 1024 * 1024;
+
+#[derive(Args)]
+pub struct NativeHostArgs {
+    /// Origin of the calling extension and other arguments the browser passes along.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, hide = true)]
+    caller: Vec<String>,
+}
+
+/// Serves the native messaging protocol of Chrome and Firefox: every message in either
+/// direction is a JSON request or response preceded by its length as a native endian u32.
+/// Runs until the browser closes stdin.
+pub fn run(_args: &NativeHostArgs) -> Result<(), String> {
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut rng = rand::rng();
+
+    loop {
+        let mut length = [0u8; 4];
+        if stdin.read_exact(&mut length).is_err() {
+            return Ok(());
+        }
+        let length = u32::from_ne_bytes(length) as usize;
+        if length > MAX_MESSAGE_LENGTH {
+            // Stdout belongs to the protocol, so problems can only be reported on stderr.
+            return Err(i18n::message("native-host-too-long", &[("length", &length)]));
+        }
+
+        let mut message = vec![0u8; length];
+        stdin.read_exact(&mut message)
+            .map_err(|error| i18n::message("native-host-unreadable", &[("error", &error)]))?;
+        let response = request::handle(&String::from_utf8_lossy(&message), &mut rng).to_compact();
+
+        stdout.write_all(&(response.len() as u32).to_ne_bytes())
+            .and_then(|()| stdout.write_all(response.as_bytes()))
+            .and_then(|()| stdout.flush())
+            .map_err(|error| i18n::message("native-host-unanswerable", &[("error", &error)]))?;
+    }
+}
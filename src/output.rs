@@ -0,0 +1,468 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{aes, digest, encoding, external, i18n, json, keyring, prompt};
+
+/// Options for where and how a generated secret ends up, shared by the secret generating commands.
+#[derive(Args, Default)]
+pub struct OutputArgs {
+    /// Format the secret is printed and copied in.
+    /// Defaults to plain.
+    #[arg(short = 'O', long = "output", value_enum, conflicts_with = "store")]
+    pub format: Option<SecretFormat>,
+
+    /// Write the formatted secret to a new file instead of showing or copying it.
+    /// Example usage: "--out .env --output dotenv --key DB_PASSWORD".
+    #[arg(long = "out", value_name = "FILE", conflicts_with = "store")]
+    out: Option<PathBuf>,
+
+    /// Add the secret to the end of an existing --out file, refusing keys it already has.
+    #[arg(long = "append", requires = "out")]
+    append: bool,
+
+    /// Name of the secret in dotenv, CSV, JSON, YAML and TOML output.
+    /// Defaults to what is generated, like PASSWORD for dotenv and password otherwise.
+    #[arg(long = "key")]
+    key: Option<String>,
+
+    /// File holding the vault password for --output ansible-vault.
+    #[arg(long = "vault-password-file")]
+    vault_password_file: Option<PathBuf>,
+
+    /// Print the secret encrypted with `systemd-creds` for LoadCredentialEncrypted= under this name.
+    /// Example usage: "--systemd-cred db-password > /etc/credstore.encrypted/db-password".
+    #[arg(long = "systemd-cred", value_name = "NAME", conflicts_with_all = ["format", "store"])]
+    systemd_cred: Option<String>,
+
+    /// Print the secret encrypted to this GnuPG key, the way pass stores its secrets.
+    /// Can be given more than once to encrypt to several keys.
+    #[arg(long = "gpg-recipient", value_name = "KEYID", conflicts_with_all = ["format", "systemd_cred", "store"])]
+    gpg_recipients: Vec<String>,
+
+    /// Write the secret to a HashiCorp Vault KV path instead of showing or copying it.
+    /// Uses the `vault` CLI with the usual VAULT_ADDR and VAULT_TOKEN.
+    /// Example usage: "--vault-write secret/data/myapp#password".
+    #[arg(long = "vault-write", value_name = "PATH#FIELD", group = "store")]
+    vault_write: Option<String>,
+
+    /// Save the secret as a new login item with the Bitwarden CLI instead of showing or copying it.
+    /// Needs an unlocked session in BW_SESSION.
+    #[arg(long = "bw-save", group = "store", group = "save", requires = "name")]
+    bw_save: bool,
+
+    /// Name of the item saved by --bw-save.
+    /// Example usage: "--name \"Example Site\"".
+    #[arg(long = "name", requires = "bw_save")]
+    name: Option<String>,
+
+    /// Save the secret as a new login item with the 1Password CLI instead of showing or copying it.
+    #[arg(long = "op-save", group = "store", group = "save", requires = "title")]
+    op_save: bool,
+
+    /// 1Password vault the item is saved in by --op-save.
+    /// Defaults to the vault `op` picks.
+    #[arg(long = "vault", requires = "op_save")]
+    vault: Option<String>,
+
+    /// Title of the item saved by --op-save.
+    /// Example usage: "--title \"Example Site\"".
+    #[arg(long = "title", requires = "op_save")]
+    title: Option<String>,
+
+    /// Add the secret as a new entry to a KeePass database with `keepassxc-cli` instead of showing or copying it.
+    /// The database password is prompted for.
+    #[arg(long = "kdbx", value_name = "FILE", group = "store", group = "save", requires = "entry")]
+    kdbx: Option<PathBuf>,
+
+    /// Path of the entry added by --kdbx, its groups have to exist already.
+    /// Example usage: "--entry Internet/example.com".
+    #[arg(long = "entry", requires = "kdbx")]
+    entry: Option<String>,
+
+    /// Save the secret in the platform secret store under this name instead of showing or copying it.
+    /// Saved secrets are managed with `pwgen vaults`.
+    #[arg(long = "keyring", value_name = "NAME", group = "store")]
+    keyring: Option<String>,
+
+    /// Create a Docker secret with this name holding the secret instead of showing or copying it.
+    /// Falls back to Podman when Docker isn't installed.
+    #[arg(long = "docker-secret", value_name = "NAME", group = "store")]
+    docker_secret: Option<String>,
+
+    /// Username stored along with the secret by --bw-save, --op-save or --kdbx.
+    #[arg(long = "username", requires = "save")]
+    username: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, ValueEnum)]
+pub enum SecretFormat {
+    /// The secret as is.
+    Plain,
+    /// An `!vault` encrypted YAML value for Ansible playbooks.
+    AnsibleVault,
+    /// A JSON object like `{"password": "..."}`, the way `pwgen daemon` answers.
+    Json,
+    /// The same document in YAML, like `password: "..."`.
+    Yaml,
+    /// The same document in TOML, like `password = "..."`.
+    Toml,
+    /// A `PASSWORD='...'` line for .env files, refusing secrets with a ' in them.
+    Dotenv,
+    /// A `password,...` row of a CSV file with a name and a value column.
+    Csv,
+}
+
+impl OutputArgs {
+    /// Whether the secret is turned into something else than itself that is safe to show,
+    /// which documents holding it as it is aren't.
+    pub fn is_formatted(&self) -> bool {
+        self.format == Some(SecretFormat::AnsibleVault) || self.systemd_cred.is_some()
+            || !self.gpg_recipients.is_empty()
+    }
+
+    /// Whether the secret is handed to `systemd-creds` or `gpg` to be encrypted before it's shown.
+    pub fn is_encrypted_by_program(&self) -> bool {
+        self.systemd_cred.is_some() || !self.gpg_recipients.is_empty()
+    }
+
+    /// Whether the secret goes to an external store instead of being shown.
+    pub fn is_stored(&self) -> bool {
+        self.vault_write.is_some() || self.bw_save || self.op_save || self.kdbx.is_some()
+            || self.keyring.is_some() || self.docker_secret.is_some() || self.out.is_some()
+    }
+
+    /// The `--key` of the secret, or `name` in the case of the format.
+    fn entry_key(&self, name: &str) -> String {
+        match (&self.key, self.format) {
+            (Some(key), _) => key.clone(),
+            (None, Some(SecretFormat::Dotenv)) => name.to_uppercase(),
+            (None, _) => name.to_string(),
+        }
+    }
+
+    /// Whether the secret is written to a file of the user's choosing.
+    pub fn writes_file(&self) -> bool {
+        self.out.is_some()
+    }
+
+    /// Hands the secret to the external store or file chosen, if any, returning where it went.
+    /// A stored secret isn't shown or copied anymore.
+    pub fn store<R: Rng + ?Sized>(&self, name: &str, secret: &str, rng: &mut R) -> Result<Option<String>, String> {
+        if let Some(path) = &self.out {
+            let entry = self.format(name, secret, rng)?;
+            write_file(path, self.append, self.format.unwrap_or(SecretFormat::Plain), &self.entry_key(name), &entry)?;
+            return Ok(Some(i18n::message("output-file", &[("path", &path.display())])));
+        }
+        if let Some(target) = &self.vault_write {
+            vault_write(target, secret)?;
+            return Ok(Some(i18n::message("output-vault", &[("target", &target)])));
+        }
+        if self.bw_save {
+            let name = self.name.as_deref().unwrap_or_default();
+            bitwarden_save(name, self.username.as_deref(), secret)?;
+            return Ok(Some(i18n::message("output-bitwarden", &[("name", &name)])));
+        }
+        if self.op_save {
+            let title = self.title.as_deref().unwrap_or_default();
+            one_password_save(self.vault.as_deref(), title, self.username.as_deref(), secret)?;
+            return Ok(Some(i18n::message("output-1password", &[("title", &title)])));
+        }
+        if let Some(database) = &self.kdbx {
+            let entry = self.entry.as_deref().unwrap_or_default();
+            keepass_add(database, entry, self.username.as_deref(), secret)?;
+            return Ok(Some(i18n::message("output-kdbx", &[("database", &database.display()), ("entry", &entry)])));
+        }
+        if let Some(name) = &self.keyring {
+            keyring::save(name, secret)?;
+            return Ok(Some(i18n::message("output-keyring", &[("name", &name)])));
+        }
+        if let Some(name) = &self.docker_secret {
+            let engine = if !external::is_installed("docker") && external::is_installed("podman") { "podman" } else { "docker" };
+            // A file of "-" makes both read the secret from stdin.
+            external::run(engine, &["secret", "create", name, "-"], secret.as_bytes())?;
+            return Ok(Some(i18n::message("output-docker", &[("engine", &engine), ("name", &name)])));
+        }
+        Ok(None)
+    }
+
+    /// Formats the secret, with `name` like "password" as the key of documents.
+    pub fn format<R: Rng + ?Sized>(&self, name: &str, secret: &str, rng: &mut R) -> Result<String, String> {
+        if let Some(name) = &self.systemd_cred {
+            let name_argument = format!("--name={}", name);
+            let output = external::run("systemd-creds", &["encrypt", &name_argument, "-", "-"], secret.as_bytes())?;
+            return Ok(String::from_utf8_lossy(&output).trim_end().to_string());
+        }
+        if !self.gpg_recipients.is_empty() {
+            return gpg_encrypt(&self.gpg_recipients, secret);
+        }
+        let format = self.format.unwrap_or(SecretFormat::Plain);
+        if self.vault_password_file.is_some() && format != SecretFormat::AnsibleVault {
+            return Err(i18n::message("output-vault-password-file-only", &[]));
+        }
+        // JSON strings are valid YAML and TOML strings as well, apart from the escapes of
+        // control characters both of them know too.
+        match format {
+            SecretFormat::Plain => Ok(secret.to_string()),
+            SecretFormat::Json => Ok(json::object(&[(&self.entry_key(name), secret.to_string())])),
+            SecretFormat::Yaml => Ok(format!("{}: {}", document_key(&self.entry_key(name)), json::string(secret))),
+            SecretFormat::Toml => Ok(format!("{} = {}", document_key(&self.entry_key(name)), json::string(secret))),
+            SecretFormat::Dotenv => {
+                let key = self.entry_key(name);
+                let mut chars = key.chars();
+                let is_variable = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+                if !is_variable {
+                    return Err(i18n::message("output-dotenv-invalid-name", &[("key", &key)]));
+                }
+                // Single quotes keep "$" from being expanded, which most dotenv readers do in double
+                // quotes. Dotenv readers don't agree on escapes inside them, so there are none.
+                if secret.contains('\'') || secret.contains(char::is_control) {
+                    return Err(i18n::message("output-dotenv-invalid-secret", &[]));
+                }
+                Ok(format!("{}='{}'", key, secret))
+            }
+            SecretFormat::Csv => Ok(format!("{},{}", csv_field(&self.entry_key(name)), csv_field(secret))),
+            SecretFormat::AnsibleVault => {
+                let Some(path) = &self.vault_password_file else {
+                    return Err(i18n::message("output-needs-vault-password-file", &[]));
+                };
+                let vault_password = fs::read_to_string(path)
+                    .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+                // Ansible strips surrounding whitespace from password files as well.
+                let vault_password = vault_password.trim();
+                if vault_password.is_empty() {
+                    return Err(i18n::message("output-no-vault-password", &[("path", &path.display())]));
+                }
+                Ok(ansible_vault(secret, vault_password, rng))
+            }
+        }
+    }
+}
+
+/// A YAML or TOML key, quoted unless it's only letters, digits, '_' and '-'.
+fn document_key(key: &str) -> String {
+    if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        key.to_string()
+    } else {
+        json::string(key)
+    }
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        i18n::message("output-file", &[("path", &text.replace('"', "\"\""))])
+    } else {
+        text.to_string()
+    }
+}
+
+/// Writes `entry` to a new file, or with `append` to the end of one while holding a lock on
+/// it, so runs at the same time don't mix up their lines. Appending fails when the file
+/// already has `key`, instead of leaving two values of which one is silently used.
+fn write_file(path: &Path, append: bool, format: SecretFormat, key: &str, entry: &str) -> Result<(), String> {
+    let error = |error: std::io::Error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]);
+    if append && matches!(format, SecretFormat::Json | SecretFormat::AnsibleVault) {
+        return Err(i18n::message("output-not-appendable", &[]));
+    }
+    let mut options = OpenOptions::new();
+    if append {
+        options.read(true).append(true).create(true);
+    } else {
+        options.write(true).create_new(true);
+    }
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(path).map_err(|open_error| match open_error.kind() {
+        std::io::ErrorKind::AlreadyExists => i18n::message("output-exists", &[("path", &path.display())]),
+        _ => error(open_error),
+    })?;
+
+    let mut text = String::new();
+    if append {
+        file.lock().map_err(error)?;
+        file.read_to_string(&mut text).map_err(error)?;
+        check_appendable(&text, format, key).map_err(|message| i18n::message("output-unable-to-append", &[("path", &path.display()), ("problem", &message)]))?;
+    }
+    let mut addition = String::new();
+    if !text.is_empty() && !text.ends_with('\n') {
+        addition.push('\n');
+    }
+    if format == SecretFormat::Csv && text.trim().is_empty() {
+        addition.push_str("name,value\n");
+    }
+    addition.push_str(entry);
+    addition.push('\n');
+    file.write_all(addition.as_bytes()).and_then(|()| file.sync_all()).map_err(error)
+}
+
+/// Whether an entry named `key` can be added to the end of `text` in `format`.
+fn check_appendable(text: &str, format: SecretFormat, key: &str) -> Result<(), String> {
+    let existing_key = |line: &str| -> Option<String> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let unquote = |key: &str| key.trim().trim_matches(['"', '\'']).to_string();
+        match format {
+            SecretFormat::Dotenv => line.strip_prefix("export ").unwrap_or(line).split_once('=').map(|(key, _)| unquote(key)),
+            SecretFormat::Yaml => line.split_once(':').map(|(key, _)| unquote(key)),
+            SecretFormat::Toml => line.split_once('=').map(|(key, _)| unquote(key)),
+            SecretFormat::Csv => Some(unquote(line.split(',').next().unwrap_or_default())),
+            _ => None,
+        }
+    };
+    if format == SecretFormat::Toml && text.lines().any(|line| line.trim_start().starts_with('[')) {
+        return Err(i18n::message("output-toml-table", &[]));
+    }
+    // Only top level YAML keys count, indented ones belong to something else.
+    let lines = text.lines().filter(|line| format != SecretFormat::Yaml || !line.starts_with([' ', '\t', '-']));
+    if format != SecretFormat::Plain && lines.filter_map(existing_key).any(|existing| existing == key) {
+        return Err(i18n::message("output-key-exists", &[("key", &key)]));
+    }
+    Ok(())
+}
+
+/// Encrypts `secret` with the options pass uses, but ASCII armored for printing.
+/// Like in pass the secret ends with a newline, so decrypting it gives the usual line.
+fn gpg_encrypt(recipients: &[String], secret: &str) -> Result<String, String> {
+    let mut arguments = vec!["--encrypt", "--armor", "--quiet", "--batch", "--compress-algo=none", "--no-encrypt-to"];
+    for recipient in recipients {
+        arguments.extend(["--recipient", recipient]);
+    }
+    let output = external::run("gpg", &arguments, format!("{}\n", secret).as_bytes())?;
+    Ok(String::from_utf8_lossy(&output).trim_end().to_string())
+}
+
+/// Writes `secret` into one field of a Vault secret. KV version 2 paths are given the way
+/// the API sees them, with "data" after the mount, anything else is written as is.
+fn vault_write(target: &str, secret: &str) -> Result<(), String> {
+    let (path, field) = target.rsplit_once('#')
+        .filter(|(path, field)| !path.is_empty() && !field.is_empty() && !field.contains('='))
+        .ok_or_else(|| i18n::message("output-vault-target", &[]))?;
+    if env::var_os("VAULT_ADDR").is_none() {
+        return Err(i18n::message("output-no-vault-addr", &[]));
+    }
+
+    // A value of "-" makes the CLI read it from stdin.
+    let field_argument = format!("{}=-", field);
+    match path.split_once("/data/") {
+        Some((mount, name)) => {
+            let mount_argument = format!("-mount={}", mount);
+            // Patching keeps the other fields of an existing secret, new secrets have to be put.
+            let patched = external::run("vault", &["kv", "patch", &mount_argument, name, &field_argument], secret.as_bytes());
+            match patched {
+                Err(message) if message.contains("No value found") => {
+                    external::run("vault", &["kv", "put", &mount_argument, name, &field_argument], secret.as_bytes())?;
+                }
+                result => {
+                    result?;
+                }
+            }
+        }
+        None => {
+            external::run("vault", &["write", path, &field_argument], secret.as_bytes())?;
+        }
+    }
+    Ok(())
+}
+
+/// Creates a Bitwarden login item holding `secret`. `bw create item` takes the item
+/// as base64 encoded JSON, which is passed on stdin like `bw encode` would.
+fn bitwarden_save(name: &str, username: Option<&str>, secret: &str) -> Result<(), String> {
+    if env::var_os("BW_SESSION").is_none() {
+        return Err(i18n::message("output-bw-locked", &[]));
+    }
+
+    let username = username.map(json::string).unwrap_or_else(|| "null".into());
+    let item = format!(
+        "{{\"type\":1,\"name\":{},\"notes\":null,\"login\":{{\"username\":{},\"password\":{}}}}}",
+        json::string(name),
+        username,
+        json::string(secret),
+    );
+    external::run("bw", &["create", "item"], encoding::base64(item.as_bytes()).as_bytes())?;
+    Ok(())
+}
+
+/// Creates a 1Password login item holding `secret`. The item is piped to `op item create`
+/// as a JSON template, so the secret isn't passed as an argument.
+fn one_password_save(vault: Option<&str>, title: &str, username: Option<&str>, secret: &str) -> Result<(), String> {
+    let mut fields = Vec::new();
+    if let Some(username) = username {
+        fields.push(format!(
+            "{{\"id\":\"username\",\"type\":\"STRING\",\"purpose\":\"USERNAME\",\"label\":\"username\",\"value\":{}}}",
+            json::string(username),
+        ));
+    }
+    fields.push(format!(
+        "{{\"id\":\"password\",\"type\":\"CONCEALED\",\"purpose\":\"PASSWORD\",\"label\":\"password\",\"value\":{}}}",
+        json::string(secret),
+    ));
+    let item = format!(
+        "{{\"title\":{},\"category\":\"LOGIN\",\"fields\":[{}]}}",
+        json::string(title),
+        fields.join(","),
+    );
+
+    let mut arguments = vec!["item", "create", "--format", "json"];
+    if let Some(vault) = vault {
+        arguments.extend(["--vault", vault]);
+    }
+    external::run("op", &arguments, item.as_bytes())?;
+    Ok(())
+}
+
+/// Adds an entry holding `secret` to a KeePass database. keepassxc-cli reads the database
+/// password and then the entry password from stdin when it isn't a terminal. It writes the
+/// database instead of pwgen, like the CLIs of the other stores, so its KDF and cipher
+/// settings and the other entries stay as they are.
+fn keepass_add(database: &Path, entry: &str, username: Option<&str>, secret: &str) -> Result<(), String> {
+    if !database.is_file() {
+        return Err(i18n::message("output-kdbx-missing", &[("path", &database.display())]));
+    }
+    let database_password = prompt::existing_secret("database password")?;
+
+    let database = database.to_string_lossy();
+    let mut arguments = vec!["add", "--quiet", "--password-prompt"];
+    if let Some(username) = username {
+        arguments.extend(["--username", username]);
+    }
+    arguments.extend([database.as_ref(), entry]);
+    let input = format!("{}\n{}\n", database_password, secret);
+    external::run("keepassxc-cli", &arguments, input.as_bytes())?;
+    Ok(())
+}
+
+/// Encrypts `secret` in the Ansible Vault 1.1 format: AES-256-CTR with keys from
+/// PBKDF2-SHA256 and an HMAC-SHA256 over the ciphertext, all hex encoded twice.
+fn ansible_vault<R: Rng + ?Sized>(secret: &str, vault_password: &str, rng: &mut R) -> String {
+    let salt: [u8; 32] = rng.random();
+    let keys = digest::pbkdf2_sha256(vault_password.as_bytes(), &salt, 10000, 80);
+    let (cipher_key, rest) = keys.split_at(32);
+    let (hmac_key, iv) = rest.split_at(32);
+
+    // PKCS#7 padding, even though CTR mode wouldn't need it.
+    let mut ciphertext = secret.as_bytes().to_vec();
+    let padding = 16 - ciphertext.len() % 16;
+    ciphertext.extend(std::iter::repeat_n(padding as u8, padding));
+    aes::ctr_256(cipher_key.try_into().unwrap(), iv.try_into().unwrap(), &mut ciphertext);
+    let hmac = digest::hmac_sha256(hmac_key, &ciphertext);
+
+    let inner = format!("{}\n{}\n{}", hex(&salt), hex(&hmac), hex(&ciphertext));
+    let outer = hex(inner.as_bytes());
+
+    let mut block = String::from("!vault |\n          $ANSIBLE_VAULT;1.1;AES256");
+    for line in outer.as_bytes().chunks(80) {
+        block.push_str("\n          ");
+        block.push_str(std::str::from_utf8(line).unwrap());
+    }
+    block
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,66 @@
+use rand::Rng;
+
+use crate::PassphraseArgs;
+use crate::{capitalize, i18n, leet, separator, wordlist};
+
+/// A generated passphrase with the entropy each part of it contributes.
+pub struct Passphrase {
+    pub text: String,
+    pub word_bits: f64,
+    pub leet_bits: f64,
+    pub capitalization_bits: f64,
+    pub separator_bits: f64,
+}
+
+/// Generates a passphrase of random words, optionally transformed with leet
+/// substitutions and capitalization.
+pub fn generate<R: Rng + ?Sized>(args: &PassphraseArgs, rng: &mut R) -> Result<Passphrase, String> {
+    let chosen_word_amount = if let Some(user_word_amount) = args.words {
+        if user_word_amount == 0 {
+            return Err(i18n::message("passphrase-no-words", &[]));
+        }
+        if user_word_amount > 1024 {
+            return Err(i18n::message("passphrase-too-many-words", &[]));
+        }
+        user_word_amount as usize
+    } else {
+        6 // Default word amount
+    };
+
+    let gaps = chosen_word_amount - 1;
+    let (separators, separator_bits) = match &args.separator_set {
+        Some(set) => (set.pick(gaps, args.separator_per_gap, rng), set.bits(gaps, args.separator_per_gap)),
+        None => (vec![args.word_char.unwrap_or('-').to_string(); gaps], 0.0),
+    };
+
+    let available_words = wordlist::passphrase_words(args.wordlist.as_deref())?;
+    let available_words: Vec<&str> = available_words.iter().map(|word| word.as_str()).collect();
+
+    let fixed_length = separators.iter().map(|separator| separator.chars().count()).sum();
+    let word_lists = vec![available_words.as_slice(); chosen_word_amount];
+    let (random_words, word_bits) = wordlist::choose_words_counted(&word_lists, fixed_length, args.max_length, rng)?;
+    let mut leet_bits = 0.0;
+    let mut capitalization_bits = 0.0;
+    let random_words: Vec<String> = random_words.iter().map(|word| {
+        let mut transformed_word = word.clone();
+        if let Some(leet_level) = args.leet {
+            let (leet_word, added_bits) = leet::apply(&transformed_word, leet_level, rng);
+            transformed_word = leet_word;
+            leet_bits += added_bits;
+        }
+        if let Some(capitalization) = args.capitalization {
+            let (capitalized_word, added_bits) = capitalize::apply(&transformed_word, capitalization, rng);
+            transformed_word = capitalized_word;
+            capitalization_bits += added_bits;
+        }
+        transformed_word
+    }).collect();
+
+    Ok(Passphrase {
+        text: separator::join(&random_words, &separators),
+        word_bits,
+        leet_bits,
+        capitalization_bits,
+        separator_bits,
+    })
+}
@@ -0,0 +1,434 @@
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use rand::Rng;
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use crate::PasswordArgs;
+use crate::{capitalize, dead_end, i18n, layout, patterns, strength, unicode, wordlist};
+
+#[derive(clap::ValueEnum, Clone, PartialEq)]
+pub enum CharSet {
+    Lower,
+    Upper,
+    Digits,
+    Symbols,
+    RareSymbols,
+    UnicodeLatin1,
+    Greek,
+    Emoji,
+}
+
+pub fn get_char_set (sets: &CharSet) -> &'static str {
+    match sets {
+        CharSet::Lower => LOWER_CHARS,
+        CharSet::Upper => UPPER_CHARS,
+        CharSet::Digits => DIGITS_CHARS,
+        CharSet::Symbols => SPECIAL_CHARS,
+        CharSet::RareSymbols => SPECIAL_RARE_CHARS,
+        CharSet::UnicodeLatin1 => &unicode::LATIN1_CHARS,
+        CharSet::Greek => &unicode::GREEK_CHARS,
+        CharSet::Emoji => &unicode::EMOJI_CHARS,
+    }
+}
+
+/// How the characters of a password are put together.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum PasswordStyle {
+    /// Every character picked at random.
+    Random,
+    /// A capitalized word, a hyphen, a block of random characters, a digit and a symbol,
+    /// like "Falcon-x7Kq2!". --length sets the size of the block.
+    Hybrid,
+}
+
+/// How much more often the characters of a set are picked, from `--weight digits=3`.
+#[derive(Clone)]
+pub struct Weight {
+    pub set: CharSet,
+    pub factor: u32,
+}
+
+/// Parses a weight like "digits=3".
+pub fn parse_weight(text: &str) -> Result<Weight, String> {
+    let (set, factor) = text.split_once('=').ok_or("expected a weight like \"digits=3\"")?;
+    let set = CharSet::from_str(set.trim(), true).map_err(|_| format!("\"{}\" isn't a character set", set.trim()))?;
+    let factor = factor.trim().parse::<u32>().ok().filter(|factor| (1..=1000).contains(factor))
+        .ok_or_else(|| format!("\"{}\" isn't a whole number from 1 to 1000", factor.trim()))?;
+    Ok(Weight { set, factor })
+}
+
+pub const DEFAULT_CHAR_SETS: [CharSet; 4] = [CharSet::Lower, CharSet::Upper, CharSet::Digits, CharSet::Symbols];
+
+pub const LOWER_CHARS: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const UPPER_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub const DIGITS_CHARS: &str = "0123456789";
+pub const SPECIAL_CHARS: &str = "!@#$%^&*-_=+()[]{}<>:;,.?~";
+const MAX_ATTEMPTS: u64 = 1000;
+/// Longest password `--length` and the `password.length` of the config file allow.
+pub const MAX_LENGTH: u32 = 65536;
+/// Vowels as classic pwgen leaves them out, with the accented and Greek ones of the other sets.
+const VOWELS: &str = "01aeiouyAEIOUYàáâãäåæèéêëìíîïòóôõöøùúûüýÿÀÁÂÃÄÅÆÈÉÊËÌÍÎÏÒÓÔÕÖØÙÚÛÜÝαεηιουωΑΕΗΙΟΥΩ";
+const SPECIAL_RARE_CHARS: &str = "/\\\'\"|` ";
+
+/// The characters passwords are picked from, built once for everything generated with the
+/// same options, since filtering the pool takes longer than picking from it.
+pub struct Sampler {
+    pool: Vec<char>,
+    /// Picks a character of the pool by the weights of `--weight`, in the same time
+    /// however large the pool is.
+    weighted: Option<AliasTable>,
+}
+
+impl Sampler {
+    pub fn new(args: &PasswordArgs) -> Result<Sampler, String> {
+        let pool = character_pool(args)?;
+        let weighted = (!args.weights.is_empty()).then(|| AliasTable::new(&character_weights(args, &pool)));
+        Ok(Sampler { pool, weighted })
+    }
+
+    fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        match &self.weighted {
+            Some(table) => self.pool[table.pick(rng)],
+            None => *self.pool.choose(rng).unwrap(),
+        }
+    }
+}
+
+/// Walker's alias table, picking an index by integer weights with one uniform index and one
+/// uniform number. Every index gets a bucket of the total weight, filled with its own weight
+/// and topped up by one heavier index, so no rounding skews the picks.
+struct AliasTable {
+    total: u64,
+    /// Up to which number of its bucket an index picks itself instead of its alias.
+    thresholds: Vec<u64>,
+    aliases: Vec<usize>,
+}
+
+impl AliasTable {
+    fn new(weights: &[u64]) -> AliasTable {
+        let total: u64 = weights.iter().sum();
+        let mut scaled: Vec<u64> = weights.iter().map(|weight| weight * weights.len() as u64).collect();
+        let mut thresholds = vec![total; weights.len()];
+        let mut aliases: Vec<usize> = (0..weights.len()).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..weights.len()).partition(|index| scaled[*index] < total);
+        while let (Some(light), Some(&heavy)) = (small.pop(), large.last()) {
+            thresholds[light] = scaled[light];
+            aliases[light] = heavy;
+            scaled[heavy] -= total - scaled[light];
+            if scaled[heavy] < total {
+                large.pop();
+                small.push(heavy);
+            }
+        }
+        AliasTable { total, thresholds, aliases }
+    }
+
+    fn pick<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let index = rng.random_range(0..self.thresholds.len());
+        if rng.random_range(0..self.total) < self.thresholds[index] { index } else { self.aliases[index] }
+    }
+}
+
+/// The weight of every character of `pool`, 1 unless `--weight` gives its set another.
+fn character_weights(args: &PasswordArgs, pool: &[char]) -> Vec<u64> {
+    pool.iter().map(|c| {
+        args.weights.iter().find(|weight| get_char_set(&weight.set).contains(*c)).map_or(1, |weight| weight.factor as u64)
+    }).collect()
+}
+
+/// Generates a password of random characters from the chosen character sets.
+pub fn generate<R: Rng + ?Sized>(args: &PasswordArgs, rng: &mut R) -> Result<String, String> {
+    generate_with(args, &Sampler::new(args)?, rng)
+}
+
+/// Generates a password like `generate`, picking from a `sampler` of the same options.
+pub fn generate_with<R: Rng + ?Sized>(args: &PasswordArgs, sampler: &Sampler, rng: &mut R) -> Result<String, String> {
+    let all_characters = &sampler.pool;
+    let password_length = password_length(args)?;
+    
+    // A password breaking the policy of --site or --policy or with a pattern --no-patterns
+    // keeps out is thrown away. Random passwords follow the counts and positions of the policy
+    // from the start, so only its rarer rules like max_consecutive and --no-patterns take a few tries.
+    let words = match args.style {
+        Some(PasswordStyle::Hybrid) if args.mobile_friendly || args.alternate_hands || args.no_patterns => {
+            return Err(i18n::message("password-hybrid-conflict", &[]));
+        }
+        Some(PasswordStyle::Hybrid) => wordlist::passphrase_words(None)?,
+        _ => Vec::new(),
+    };
+    let max_attempts = args.max_attempts.unwrap_or(MAX_ATTEMPTS);
+    let deadline = args.timeout.map(|seconds| Instant::now() + Duration::from_secs(seconds));
+    let mut attempts = 0;
+    let (mut policy_failures, mut pattern_failures) = (0, 0);
+    let (mut policy_problem, mut pattern_problem) = (String::new(), "");
+    while attempts < max_attempts && deadline.is_none_or(|deadline| Instant::now() < deadline) {
+        attempts += 1;
+        let mut password = String::new();
+        if args.style == Some(PasswordStyle::Hybrid) {
+            let word = words.choose(rng).ok_or_else(|| i18n::message("wordlist-empty", &[]))?;
+            password.push_str(&capitalize::apply(word, capitalize::Capitalization::First, rng).0);
+            password.push('-');
+            password.extend((0..password_length).map(|_| *all_characters.choose(rng).unwrap()));
+            for (_, characters) in hybrid_endings(all_characters) {
+                password.push(*characters.choose(rng).unwrap());
+            }
+        } else if args.mobile_friendly {
+            let mut blocks = mobile_blocks(all_characters, password_length);
+            blocks.shuffle(rng);
+            for (characters, amount) in blocks {
+                password.extend((0..amount).map(|_| *characters.choose(rng).unwrap()));
+            }
+        } else if args.alternate_hands {
+            let (left, right) = hands(all_characters)?;
+            // Starting with the hand that has more passwords to offer as often as it should
+            // keeps every alternating password equally likely.
+            let (left_start, right_start) = alternating_counts(left.len(), right.len(), password_length);
+            let mut hand_is_left = rng.random_bool(1.0 / (1.0 + (right_start - left_start).exp2()));
+            for _ in 0..password_length {
+                let hand = if hand_is_left { &left } else { &right };
+                password.push(*hand.choose(rng).unwrap());
+                hand_is_left = !hand_is_left;
+            }
+        } else if let Some(sampled) = args.policy.as_ref().and_then(|policy| policy.sample(all_characters, password_length, rng)) {
+            password = sampled;
+        } else {
+            password.extend((0..password_length).map(|_| sampler.pick(rng)));
+        }
+        
+        if let Some(policy) = &args.policy
+            && let Some(check) = policy.check(&password).into_iter().find(|check| !check.passed) {
+            policy_failures += 1;
+            policy_problem = check.text;
+            continue;
+        }
+        if args.no_patterns && let Some(pattern) = patterns::find(&password) {
+            log::debug!("Generated a password with {}, trying again", pattern);
+            pattern_failures += 1;
+            pattern_problem = pattern;
+            continue;
+        }
+        return Ok(password);
+    }
+    
+    // Only the constraint that failed most is named, the other one rarely matters.
+    let (constraint, reason) = match &args.policy {
+        Some(policy) if policy_failures >= pattern_failures => {
+            ("policy", i18n::message("password-policy-kept-failing", &[("policy", &policy.name), ("problem", &policy_problem.trim_end_matches('.'))]))
+        }
+        _ => ("no-patterns", i18n::message("password-patterns-kept-failing", &[("pattern", &i18n::message(pattern_problem, &[]))])),
+    };
+    let tried = match deadline {
+        Some(_) if attempts < max_attempts => {
+            let seconds = args.timeout.unwrap_or_default();
+            i18n::message("password-tried-within", &[("seconds", &seconds), ("attempts", &attempts)])
+        }
+        _ => i18n::message("password-tried", &[("attempts", &attempts)]),
+    };
+    Err(dead_end::reached(constraint, attempts as usize, i18n::message("password-dead-end", &[("tried", &tried), ("reason", &reason)])))
+}
+
+/// Describes how the characters the password is picked from are put together and
+/// what that means for its entropy.
+pub fn explain(args: &PasswordArgs) -> Result<String, String> {
+    let mut lines = Vec::new();
+    let sets = chosen_character_sets(args).iter()
+        .map(|set| format!("{} ({})", set.to_possible_value().unwrap().get_name(), get_char_set(set).chars().count()))
+        .collect::<Vec<_>>();
+    lines.push(i18n::message("explain-sets", &[("sets", &sets.join(", "))]));
+    
+    let all_characters = character_pool(args)?;
+    let mut excluded: Vec<char> = chosen_character_sets(args).iter()
+        .flat_map(|set| get_char_set(set).chars())
+        .filter(|c| !all_characters.contains(c))
+        .collect();
+    excluded.dedup();
+    if !excluded.is_empty() {
+        lines.push(i18n::message("explain-excluded", &[("count", &excluded.len()), ("characters", &excluded.into_iter().collect::<String>())]));
+    }
+    
+    let bits_per_character = (all_characters.len() as f64).log2();
+    lines.push(i18n::message("explain-pool", &[("count", &all_characters.len()), ("bits", &format!("{:.2}", bits_per_character))]));
+    if !args.weights.is_empty() {
+        let weights = args.weights.iter()
+            .map(|weight| i18n::message("explain-weight", &[("set", &weight.set.to_possible_value().unwrap().get_name()), ("factor", &weight.factor)]))
+            .collect::<Vec<_>>();
+        lines.push(i18n::message("explain-weights", &[("weights", &weights.join(", ")), ("bits", &format!("{:.2}", weighted_bits(args, &all_characters)))]));
+    }
+    let password_length = password_length(args)?;
+    if args.style == Some(PasswordStyle::Hybrid) {
+        let words = wordlist::passphrase_words(None)?.len();
+        let mut parts = vec![i18n::message("explain-hybrid-word", &[("count", &words)]), i18n::message("random-characters", &[("count", &password_length)])];
+        parts.extend(hybrid_endings(&all_characters).iter().map(|(key, characters)| i18n::message(key, &[("count", &characters.len())])));
+        lines.push(i18n::message("explain-hybrid", &[("parts", &parts.join(", "))]));
+    } else {
+        lines.push(i18n::message("explain-length", &[("length", &password_length)]));
+    }
+    if args.mobile_friendly {
+        lines.push(i18n::message("explain-mobile", &[]));
+    }
+    if args.alternate_hands {
+        let (left, right) = hands(&all_characters)?;
+        lines.push(i18n::message("explain-hands", &[("left", &left.len()), ("right", &right.len())]));
+    }
+    lines.push(strength::describe(entropy(args)?));
+    if let Some(trade_off) = trade_off(args)? {
+        lines.push(trade_off);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Entropy of the generated passwords, which the options shaping them lower.
+pub fn entropy(args: &PasswordArgs) -> Result<f64, String> {
+    let all_characters = character_pool(args)?;
+    let password_length = password_length(args)?;
+    if args.style == Some(PasswordStyle::Hybrid) {
+        let word_bits = (wordlist::passphrase_words(None)?.len() as f64).log2();
+        let ending_bits: f64 = hybrid_endings(&all_characters).iter().map(|(_, characters)| (characters.len() as f64).log2()).sum();
+        return Ok(word_bits + (all_characters.len() as f64).log2() * password_length as f64 + ending_bits);
+    }
+    if args.mobile_friendly {
+        let blocks = mobile_blocks(&all_characters, password_length);
+        let orders: f64 = (1..=blocks.len()).map(|count| (count as f64).log2()).sum();
+        let bits: f64 = blocks.iter().map(|(characters, amount)| *amount as f64 * (characters.len() as f64).log2()).sum();
+        return Ok(bits + orders);
+    }
+    if args.alternate_hands {
+        let (left, right) = hands(&all_characters)?;
+        let (left_start, right_start) = alternating_counts(left.len(), right.len(), password_length);
+        // log2(2^a + 2^b) without overflowing for long passwords.
+        let (larger, smaller) = if left_start > right_start { (left_start, right_start) } else { (right_start, left_start) };
+        return Ok(larger + (1.0 + (smaller - larger).exp2()).log2());
+    }
+    if !args.weights.is_empty() {
+        return Ok(weighted_bits(args, &all_characters) * password_length as f64);
+    }
+    Ok((all_characters.len() as f64).log2() * password_length as f64)
+}
+
+/// Entropy of a character picked by the weights of `--weight`, less than of one picked uniformly.
+fn weighted_bits(args: &PasswordArgs, all_characters: &[char]) -> f64 {
+    let weights = character_weights(args, all_characters);
+    let total = weights.iter().sum::<u64>() as f64;
+    weights.iter().map(|weight| *weight as f64 / total).map(|chance| -chance * chance.log2()).sum()
+}
+
+/// What the options shaping the password cost compared to picking every character
+/// from the whole pool, if any.
+pub fn trade_off(args: &PasswordArgs) -> Result<Option<String>, String> {
+    if args.style == Some(PasswordStyle::Hybrid) {
+        return Ok(Some(i18n::message("trade-off-hybrid", &[("bits", &format!("{:.1}", entropy(args)?))])));
+    }
+    if !args.mobile_friendly && !args.alternate_hands && args.weights.is_empty() {
+        return Ok(None);
+    }
+    let unshaped = (character_pool(args)?.len() as f64).log2() * password_length(args)? as f64;
+    let bits = entropy(args)?;
+    Ok(Some(i18n::message("trade-off", &[("lost", &format!("{:.1}", unshaped - bits)), ("bits", &format!("{:.1}", unshaped))])))
+}
+
+/// The digits and the symbols of the pool a hybrid password ends in, with the key of the message
+/// naming them, leaving out what the pool has none of.
+fn hybrid_endings(all_characters: &[char]) -> Vec<(&'static str, Vec<char>)> {
+    let digits: Vec<char> = all_characters.iter().copied().filter(char::is_ascii_digit).collect();
+    let symbols: Vec<char> = all_characters.iter().copied().filter(|c| !c.is_alphanumeric()).collect();
+    [("explain-hybrid-digit", digits), ("explain-hybrid-symbol", symbols)].into_iter().filter(|(_, characters)| !characters.is_empty()).collect()
+}
+
+/// The characters of the pool typed with the left and the right hand.
+fn hands(all_characters: &[char]) -> Result<(Vec<char>, Vec<char>), String> {
+    let left: Vec<char> = all_characters.iter().copied().filter(|&c| layout::is_left_hand(c) == Some(true)).collect();
+    let right: Vec<char> = all_characters.iter().copied().filter(|&c| layout::is_left_hand(c) == Some(false)).collect();
+    if left.is_empty() || right.is_empty() {
+        return Err(i18n::message("password-hands", &[]));
+    }
+    Ok((left, right))
+}
+
+/// log2 of how many alternating passwords start with the left and with the right hand.
+fn alternating_counts(left: usize, right: usize, password_length: u32) -> (f64, f64) {
+    let first = password_length.div_ceil(2) as f64;
+    let second = (password_length / 2) as f64;
+    let (left, right) = ((left as f64).log2(), (right as f64).log2());
+    (first * left + second * right, first * right + second * left)
+}
+
+/// Splits the pool into the keyboard panes of a phone, with the amount of characters each
+/// block gets: a quarter of the password for digits, one symbol and letters for the rest.
+fn mobile_blocks(all_characters: &[char], password_length: u32) -> Vec<(Vec<char>, u32)> {
+    let letters: Vec<char> = all_characters.iter().copied().filter(|c| c.is_alphabetic()).collect();
+    let digits: Vec<char> = all_characters.iter().copied().filter(char::is_ascii_digit).collect();
+    let symbols: Vec<char> = all_characters.iter().copied().filter(|c| !c.is_alphabetic() && !c.is_ascii_digit()).collect();
+
+    if letters.is_empty() && digits.is_empty() {
+        return vec![(symbols, password_length)];
+    }
+    let mut remaining = password_length;
+    let mut blocks = Vec::new();
+    if !symbols.is_empty() && remaining > 1 {
+        blocks.push((symbols, 1));
+        remaining -= 1;
+    }
+    if !digits.is_empty() && (remaining > 1 || letters.is_empty()) {
+        let amount = if letters.is_empty() { remaining } else { (password_length / 4).clamp(1, remaining - 1) };
+        blocks.push((digits, amount));
+        remaining -= amount;
+    }
+    if remaining > 0 {
+        // Without letters, the rest of the password has to come from the symbols.
+        let rest = if letters.is_empty() { all_characters.to_vec() } else { letters };
+        blocks.push((rest, remaining));
+    }
+    blocks
+}
+
+fn chosen_character_sets(args: &PasswordArgs) -> &[CharSet] {
+    if let Some(user_character_sets) = &args.character_sets {
+        user_character_sets
+    } else {
+        &DEFAULT_CHAR_SETS // Use default
+    }
+}
+
+pub fn character_pool(args: &PasswordArgs) -> Result<Vec<char>, String> {
+    let mut all_characters: Vec<char> = Vec::new();
+    for character_set in chosen_character_sets(args) {
+        all_characters.extend(get_char_set(character_set).chars());
+    }
+    
+    if let Some(excluded_chars) = &args.excluded_chars {
+        for excluded_char in excluded_chars.chars() {
+            all_characters.retain(|&c| c != excluded_char);
+        }
+    }
+    
+    if args.no_vowels {
+        all_characters.retain(|&c| !VOWELS.contains(c));
+    }
+    if let Some(pattern) = &args.exclude_regex {
+        all_characters.retain(|&c| !pattern.matches(c));
+    }
+    if let Some(categories) = &args.exclude_category {
+        all_characters.retain(|&c| !categories.iter().any(|category| category.contains(c)));
+    }
+    if let Some(layouts) = &args.layout_safe {
+        all_characters.retain(|&c| layout::is_safe(c, layouts));
+    }
+    
+    if all_characters.is_empty() {
+        return Err(i18n::message("password-no-characters", &[]));
+    }
+    Ok(all_characters)
+}
+
+pub fn password_length(args: &PasswordArgs) -> Result<u32, String> {
+    // The random block of a hybrid password is short, the word makes up for it.
+    let mut password_length = if args.style == Some(PasswordStyle::Hybrid) { 4 } else { 16 };
+    if let Some(new_length) = args.length {
+        if new_length > MAX_LENGTH {
+            return Err(i18n::message("password-too-long", &[("max", &MAX_LENGTH)]));
+        }
+        password_length = new_length;
+    }
+    Ok(password_length)
+}
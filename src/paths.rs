@@ -0,0 +1,86 @@
+use once_cell::sync::OnceCell;
+use std::env;
+use std::path::PathBuf;
+
+/// Config file that turns on portable mode when it sits next to the executable.
+const PORTABLE_CONFIG_NAME: &str = "pwgen.toml";
+
+/// Directory next to the executable holding everything else in portable mode.
+static PORTABLE_DIR: OnceCell<Option<PathBuf>> = OnceCell::new();
+
+/// Keeps the config and all data next to the executable instead of in the user's
+/// directories, for running pwgen from a USB stick. Has to be called before any path is used.
+pub fn enable_portable() {
+    let _ = PORTABLE_DIR.set(executable_dir());
+}
+
+fn executable_dir() -> Option<PathBuf> {
+    let executable = env::current_exe().ok()?;
+    executable.parent().map(PathBuf::from)
+}
+
+/// The directory of the executable when running portable, which is either asked for
+/// or detected by a pwgen.toml next to the executable.
+fn portable_dir() -> Option<&'static PathBuf> {
+    PORTABLE_DIR.get_or_init(|| {
+        executable_dir().filter(|dir| dir.join(PORTABLE_CONFIG_NAME).is_file())
+    }).as_ref()
+}
+
+/// Directory where pwgen keeps its configuration and imported data.
+/// Follows the usual per-platform conventions without pulling in a crate for it.
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = portable_dir() {
+        return Some(dir.join("pwgen-data"));
+    }
+
+    let base = if cfg!(target_os = "windows") {
+        env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join("Library").join("Application Support"))
+    } else if let Some(xdg_config) = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        Some(PathBuf::from(xdg_config))
+    } else {
+        env::var_os("HOME").map(|home| PathBuf::from(home).join(".config"))
+    };
+
+    base.map(|dir| dir.join("pwgen"))
+}
+
+/// The config file, which is pwgen.toml next to the executable in portable mode.
+pub fn config_file() -> Option<PathBuf> {
+    if let Some(dir) = portable_dir() {
+        return Some(dir.join(PORTABLE_CONFIG_NAME));
+    }
+    config_dir().map(|dir| dir.join("config.toml"))
+}
+
+/// Bloom filter of breached passwords imported with `pwgen breach import`.
+pub fn breach_filter_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("breach.bloom"))
+}
+
+/// Password rules of sites that add to the built-in ones of `--site`.
+pub fn site_rules_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("password-rules.json"))
+}
+
+/// Credential id of the security key `derive --fido2` uses, created on first use.
+pub fn fido2_credential_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("fido2-credential"))
+}
+
+/// Arguments of the last command that generated something, for `pwgen again`.
+pub fn last_command_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("last-command.json"))
+}
+
+/// Usernames issued with `username --unique`, one per line.
+pub fn username_history_file() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("usernames.txt"))
+}
+
+/// Directory holding imported wordlists, one `<name>.txt` file per list.
+pub fn wordlist_dir() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join("wordlists"))
+}
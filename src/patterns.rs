@@ -0,0 +1,78 @@
+//! Patterns `--no-patterns` keeps out of random passwords: dictionary words, keyboard
+//! walks and dates. They appear by chance now and then, and some audits flag them even
+//! though a random password containing one isn't any weaker.
+
+use std::collections::HashSet;
+
+use once_cell::sync::Lazy;
+
+use crate::wordlist;
+
+const MIN_LENGTH: usize = 4;
+
+/// Words that are common in passwords without being in the built-in wordlists.
+const PASSWORD_WORDS: [&str; 20] = [
+    "pass", "word", "love", "admin", "user", "root", "test", "secret", "login", "hello",
+    "welcome", "dragon", "monkey", "master", "shadow", "sunshine", "football", "baseball", "letmein", "princess",
+];
+
+/// Rows of a QWERTY keyboard and the alphabet, walked forwards or backwards.
+const SEQUENCES: [&str; 6] = ["1234567890", "qwertyuiop", "asdfghjkl", "zxcvbnm", "abcdefghijklmnopqrstuvwxyz", "!@#$%^&*()"];
+
+static WORDS: Lazy<HashSet<String>> = Lazy::new(|| {
+    wordlist::passphrase_words(None).unwrap_or_default().into_iter()
+        .chain(PASSWORD_WORDS.iter().map(|word| word.to_string()))
+        .filter(|word| word.chars().count() >= MIN_LENGTH && word.chars().all(|c| c.is_ascii_alphabetic()))
+        .collect()
+});
+
+static LONGEST_WORD: Lazy<usize> = Lazy::new(|| WORDS.iter().map(|word| word.len()).max().unwrap_or(MIN_LENGTH));
+
+/// The key of the message naming the kind of pattern `password` contains, if any. Only the
+/// kind is returned so the password itself never ends up in a log.
+pub fn find(password: &str) -> Option<&'static str> {
+    let lowered: Vec<char> = password.to_lowercase().chars().collect();
+
+    for start in 0..lowered.len() {
+        for end in start + MIN_LENGTH..=lowered.len().min(start + *LONGEST_WORD) {
+            let part: String = lowered[start..end].iter().collect();
+            if WORDS.contains(&part) {
+                return Some("pattern-dictionary-word");
+            }
+        }
+    }
+
+    for window in lowered.windows(MIN_LENGTH) {
+        let part: String = window.iter().collect();
+        let reversed: String = window.iter().rev().collect();
+        if SEQUENCES.iter().any(|sequence| sequence.contains(&part) || sequence.contains(&reversed)) {
+            return Some("pattern-keyboard-walk");
+        }
+    }
+
+    if has_date(&lowered) {
+        return Some("pattern-date");
+    }
+    None
+}
+
+/// A year from 1900 to 2099, or day and month like "12/05" or "2024-01".
+fn has_date(chars: &[char]) -> bool {
+    let digits_at = |start: usize, length: usize| {
+        chars.get(start..start + length).is_some_and(|part| part.iter().all(char::is_ascii_digit))
+    };
+    for start in 0..chars.len() {
+        if digits_at(start, 4) && matches!((chars[start], chars[start + 1]), ('1', '9') | ('2', '0')) {
+            return true;
+        }
+        if digits_at(start, 2) && chars.get(start + 2).is_some_and(|c| matches!(c, '/' | '-' | '.'))
+            && digits_at(start + 3, 2) {
+            let first: u32 = chars[start..start + 2].iter().collect::<String>().parse().unwrap();
+            let second: u32 = chars[start + 3..start + 5].iter().collect::<String>().parse().unwrap();
+            if (1..=31).contains(&first) && (1..=12).contains(&second) || (1..=12).contains(&first) && (1..=31).contains(&second) {
+                return true;
+            }
+        }
+    }
+    false
+}
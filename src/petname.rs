@@ -0,0 +1,81 @@
+use clap::Args;
+use rand::Rng;
+
+use crate::i18n;
+use crate::wordlist::{self, ADJECTIVE_LIST, OBJECT_LIST};
+
+/// Longest name allowed as a DNS label by RFC 1123.
+pub const DNS_LABEL_MAX_LENGTH: usize = 63;
+
+#[derive(Args)]
+pub struct PetnameArgs {
+    /// Amount of words, all but the last one being adjectives.
+    /// Defaults to 2.
+    #[arg(short = 'w', long = "words")]
+    words: Option<usize>,
+
+    /// Text in between the words and numbers.
+    /// Defaults to '-'.
+    #[arg(short = 's', long = "separator")]
+    separator: Option<String>,
+
+    /// Amount of numbers after the words.
+    /// Defaults to 0.
+    #[arg(short = 'N', long = "numbers")]
+    numbers: Option<usize>,
+
+    /// Guarantees the name is a valid RFC 1123 DNS label, as required for
+    /// Kubernetes resource names.
+    #[arg(short = 'd', long = "dns1123")]
+    dns1123: bool,
+
+    /// Disables copying the name to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Generates a lowercase name like "brave-falcon" or "brave-falcon-7".
+pub fn generate<R: Rng + ?Sized>(args: &PetnameArgs, rng: &mut R) -> Result<String, String> {
+    let word_amount = args.words.unwrap_or(2);
+    if word_amount == 0 || word_amount > 16 {
+        return Err(i18n::message("petname-words-range", &[]));
+    }
+    let number_amount = args.numbers.unwrap_or(0);
+    let separator = args.separator.as_deref().unwrap_or("-");
+
+    let max_length = if args.dns1123 {
+        if !separator.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+            return Err(i18n::message("petname-invalid-separator", &[]));
+        }
+        Some(DNS_LABEL_MAX_LENGTH)
+    } else {
+        None
+    };
+
+    let separator_amount = word_amount - 1 + if number_amount > 0 { 1 } else { 0 };
+    let fixed_length = separator.chars().count() * separator_amount + number_amount;
+    let mut word_lists: Vec<&[&str]> = vec![&ADJECTIVE_LIST; word_amount - 1];
+    word_lists.push(&OBJECT_LIST);
+
+    let words = wordlist::choose_words_within(&word_lists, fixed_length, max_length, rng)?;
+    let mut name = words.join(separator).to_lowercase();
+    if number_amount > 0 {
+        name.push_str(separator);
+        name.extend((0..number_amount).map(|_| char::from(b'0' + rng.random_range(0..10))));
+    }
+
+    if args.dns1123 && !is_dns_label(&name) {
+        return Err(i18n::message("invalid-dns-label", &[("label", &name)]));
+    }
+    Ok(name)
+}
+
+/// Whether `name` is a valid RFC 1123 DNS label: 1 to 63 lowercase letters,
+/// digits and '-', starting and ending with a letter or digit.
+pub fn is_dns_label(name: &str) -> bool {
+    let is_alphanumeric = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+    (1..=DNS_LABEL_MAX_LENGTH).contains(&name.len())
+        && name.starts_with(is_alphanumeric)
+        && name.ends_with(is_alphanumeric)
+        && name.chars().all(|c| is_alphanumeric(c) || c == '-')
+}
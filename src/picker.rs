@@ -0,0 +1,76 @@
+use clap::{Args, ValueEnum};
+
+use crate::external;
+use crate::i18n;
+
+#[derive(Args, Default)]
+pub struct PickerArgs {
+    /// Generate several candidates and choose one of them with rofi or fzf.
+    #[arg(long = "picker", value_enum)]
+    picker: Option<Picker>,
+
+    /// Amount of candidates offered by --picker.
+    /// Defaults to 5.
+    #[arg(long = "candidates", requires = "picker")]
+    candidates: Option<usize>,
+
+    /// Only show the first characters of every candidate in --picker.
+    #[arg(long = "mask", requires = "picker")]
+    mask: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Picker {
+    Rofi,
+    Fzf,
+}
+
+/// Characters of a candidate left visible by --mask.
+const UNMASKED_LENGTH: usize = 3;
+
+/// The first characters of `text` with the rest replaced by '*'.
+pub fn masked(text: &str) -> String {
+    let visible: String = text.chars().take(UNMASKED_LENGTH).collect();
+    format!("{}{}", visible, "*".repeat(text.chars().count().saturating_sub(UNMASKED_LENGTH)))
+}
+
+impl PickerArgs {
+    /// Generates one secret, or lets the user pick among several when a picker is set.
+    /// Returns `None` when the picker was cancelled.
+    pub fn is_enabled(&self) -> bool {
+        self.picker.is_some()
+    }
+
+    pub fn choose<T>(&self, mut generate: impl FnMut() -> Result<T, String>, text: impl Fn(&T) -> &str) -> Result<Option<T>, String> {
+        let Some(picker) = self.picker else {
+            return generate().map(Some);
+        };
+        let amount = self.candidates.unwrap_or(5);
+        if amount == 0 || amount > 100 {
+            return Err(i18n::message("picker-candidates-range", &[]));
+        }
+
+        let mut candidates = (0..amount).map(|_| generate()).collect::<Result<Vec<T>, String>>()?;
+        // Every line starts with its number, so the pick is found even when it's masked.
+        let lines: Vec<String> = candidates.iter().enumerate().map(|(index, candidate)| {
+            let text = text(candidate);
+            let shown = if self.mask { masked(text) } else { text.to_string() };
+            format!("{}: {}", index + 1, shown)
+        }).collect();
+        let input = lines.join("\n") + "\n";
+
+        let output = match picker {
+            Picker::Rofi => external::run_interactive("rofi", &["-dmenu", "-i", "-p", "pwgen"], input.as_bytes())?,
+            Picker::Fzf => external::run_interactive("fzf", &["--prompt", "pwgen> ", "--no-sort"], input.as_bytes())?,
+        };
+        let Some(output) = output else {
+            return Ok(None);
+        };
+        let picked = String::from_utf8_lossy(&output);
+        let index = picked.split_once(':')
+            .and_then(|(number, _)| number.trim().parse::<usize>().ok())
+            .filter(|number| (1..=candidates.len()).contains(number))
+            .ok_or_else(|| i18n::message("picker-unknown-choice", &[]))?;
+        Ok(Some(candidates.swap_remove(index - 1)))
+    }
+}
@@ -0,0 +1,114 @@
+use crate::i18n;
+
+/// Services whose username rules can be enforced with `--platform`.
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Platform {
+    Github,
+    Twitter,
+    Xbox,
+    Psn,
+    Minecraft,
+    /// Login names accepted by useradd's default rules.
+    Unix,
+}
+
+/// What a service accepts as a username.
+pub struct PlatformRules {
+    pub name: &'static str,
+    pub min_length: usize,
+    pub max_length: usize,
+    /// Characters allowed besides ASCII letters and digits.
+    pub allowed_symbols: &'static str,
+    pub must_start_with_letter: bool,
+    /// Symbols may not start or end the username, or appear twice in a row.
+    pub symbols_inside_only: bool,
+}
+
+impl Platform {
+    pub fn rules(self) -> PlatformRules {
+        match self {
+            Platform::Github => PlatformRules {
+                name: "GitHub",
+                min_length: 1,
+                max_length: 39,
+                allowed_symbols: "-",
+                must_start_with_letter: false,
+                symbols_inside_only: true,
+            },
+            Platform::Twitter => PlatformRules {
+                name: "Twitter",
+                min_length: 4,
+                max_length: 15,
+                allowed_symbols: "_",
+                must_start_with_letter: false,
+                symbols_inside_only: false,
+            },
+            Platform::Xbox => PlatformRules {
+                name: "Xbox",
+                min_length: 1,
+                max_length: 12,
+                allowed_symbols: " ",
+                must_start_with_letter: true,
+                symbols_inside_only: true,
+            },
+            Platform::Psn => PlatformRules {
+                name: "PSN",
+                min_length: 3,
+                max_length: 16,
+                allowed_symbols: "-_",
+                must_start_with_letter: true,
+                symbols_inside_only: false,
+            },
+            Platform::Minecraft => PlatformRules {
+                name: "Minecraft",
+                min_length: 3,
+                max_length: 16,
+                allowed_symbols: "_",
+                must_start_with_letter: false,
+                symbols_inside_only: false,
+            },
+            Platform::Unix => PlatformRules {
+                name: "Unix",
+                min_length: 1,
+                max_length: 32,
+                allowed_symbols: "_-",
+                must_start_with_letter: true,
+                symbols_inside_only: false,
+            },
+        }
+    }
+}
+
+impl PlatformRules {
+    pub fn is_allowed(&self, c: char) -> bool {
+        c.is_ascii_alphanumeric() || self.allowed_symbols.contains(c)
+    }
+
+    /// Checks `username` against the rules, describing the first one it breaks.
+    pub fn check(&self, username: &str) -> Result<(), String> {
+        let length = username.chars().count();
+        if length < self.min_length {
+            return Err(i18n::message("platform-too-short", &[("min", &self.min_length)]));
+        }
+        if length > self.max_length {
+            return Err(i18n::message("platform-too-long", &[("max", &self.max_length)]));
+        }
+        if let Some(invalid_char) = username.chars().find(|&c| !self.is_allowed(c)) {
+            return Err(i18n::message("platform-invalid-character", &[("character", &invalid_char)]));
+        }
+        if self.must_start_with_letter && !username.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(i18n::message("platform-no-letter-start", &[]));
+        }
+        if self.symbols_inside_only {
+            let is_symbol = |c: char| !c.is_ascii_alphanumeric();
+            let chars: Vec<char> = username.chars().collect();
+            if chars.first().is_some_and(|&c| is_symbol(c)) || chars.last().is_some_and(|&c| is_symbol(c)) {
+                return Err(i18n::message("platform-symbol-edge", &[]));
+            }
+            if chars.windows(2).any(|pair| is_symbol(pair[0]) && is_symbol(pair[1])) {
+                return Err(i18n::message("platform-double-symbol", &[]));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,861 @@
+//! Password policies, the composition rules of a site or organisation, read from a TOML file:
+//!
+//! ```toml
+//! min_length = 12
+//! max_length = 64
+//! char_sets = ["lower", "upper", "digits", "symbols"]
+//! required = ["lower", "upper", "digits"]
+//! forbidden = "\"'`"
+//! max_consecutive = 3
+//! min_entropy = 60
+//! first = ["lower", "upper"]
+//! last = ["lower", "upper", "digits"]
+//!
+//! [min_counts]
+//! digits = 2
+//!
+//! [max_counts]
+//! symbols = 3
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand, ValueEnum};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::config::Config;
+use crate::{defaults, i18n, porcelain, prompt};
+use crate::PasswordArgs;
+use crate::password::{self, CharSet};
+use crate::{strength, unicode};
+
+const KEYS: [&str; 10] = ["min_length", "max_length", "length", "char_sets", "required", "forbidden", "max_consecutive", "min_entropy", "first", "last"];
+const TABLES: [&str; 2] = ["min_counts", "max_counts"];
+
+/// Most numbers `Policy::sample` keeps while counting passwords, so rules that would take
+/// too much memory fall back to generating until a password follows them.
+const MAX_COUNTED: usize = 1 << 22;
+
+#[derive(Args)]
+pub struct PolicyArgs {
+    #[command(subcommand)]
+    command: PolicyCommands,
+}
+
+#[derive(Subcommand)]
+enum PolicyCommands {
+    /// Compare the keyspace and entropy of passwords generated under two policies.
+    /// Example usage: "pwgen policy compare old.toml new.toml".
+    Compare(CompareArgs),
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// First policy file.
+    first: PathBuf,
+
+    /// Second policy file.
+    second: PathBuf,
+}
+
+#[derive(Args)]
+pub struct ValidateArgs {
+    /// Policy file the password is checked against.
+    /// Example usage: "--policy company.toml".
+    #[arg(short = 'p', long = "policy")]
+    policy: PathBuf,
+}
+
+#[derive(Args)]
+pub struct ConformArgs {
+    /// Policy file the password is changed to follow.
+    /// Example usage: "--policy company.toml".
+    #[arg(short = 'p', long = "policy")]
+    policy: PathBuf,
+
+    /// Disables copying the password to clipboard.
+    #[arg(short = 'o', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+
+    /// Disables hiding the changed password.
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
+    pub hide_disabled: bool,
+}
+
+/// The outcome of one rule of a policy for an existing password.
+pub struct Check {
+    pub passed: bool,
+    pub text: String,
+}
+
+pub struct Policy {
+    pub name: String,
+    pub min_length: u32,
+    pub max_length: Option<u32>,
+    /// Length of the passwords generated under the policy.
+    pub length: u32,
+    pub char_sets: Vec<CharSet>,
+    /// Character sets every password needs at least one character of.
+    pub required: Vec<CharSet>,
+    /// Fewest characters of a set a password needs.
+    pub min_counts: Vec<(CharSet, u32)>,
+    /// Most characters of a set a password may have.
+    pub max_counts: Vec<(CharSet, u32)>,
+    /// Character sets the first character is from, any allowed character when empty.
+    pub first: Vec<CharSet>,
+    /// Character sets the last character is from, any allowed character when empty.
+    pub last: Vec<CharSet>,
+    pub forbidden: String,
+    /// Most times the same character may follow itself.
+    pub max_consecutive: Option<u32>,
+    pub min_entropy: Option<f64>,
+}
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Policy, String> {
+        let text = fs::read_to_string(path)
+            .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+        let name = path.file_name().map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().into_owned());
+        Policy::parse(&name, &text).map_err(|error| i18n::message("policy-invalid", &[("path", &path.display()), ("error", &error)]))
+    }
+
+    pub fn parse(name: &str, text: &str) -> Result<Policy, String> {
+        let config = Config::parse(text)?;
+        let is_known = |key: &str| match key.split_once('.') {
+            Some((table, _)) => TABLES.contains(&table),
+            None => KEYS.contains(&key),
+        };
+        if let Some((key, _)) = config.iter().find(|(key, _)| !is_known(key)) {
+            return Err(i18n::message("policy-unknown-rule", &[("rule", &key), ("keys", &KEYS.join(", ")), ("tables", &TABLES.join("] or ["))]));
+        }
+
+        let length = |key| -> Result<Option<u32>, String> {
+            config.get_integer(key)?
+                .map(|number| u32::try_from(number).ok().filter(|number| (1..=65536).contains(number))
+                    .ok_or_else(|| i18n::message("policy-length-range", &[("key", &key)])))
+                .transpose()
+        };
+        let min_length = length("min_length")?.unwrap_or(1);
+        let max_length = length("max_length")?;
+        if max_length.is_some_and(|max_length| max_length < min_length) {
+            return Err(i18n::message("policy-max-below-min", &[]));
+        }
+        let length = match length("length")? {
+            Some(length) if length < min_length || max_length.is_some_and(|max_length| length > max_length) => {
+                return Err(i18n::message("policy-length-outside", &[]));
+            }
+            Some(length) => length,
+            None => default_length(min_length, max_length),
+        };
+        let max_consecutive = config.get_integer("max_consecutive")?
+            .map(|number| u32::try_from(number).ok().filter(|number| *number >= 1)
+                .ok_or_else(|| i18n::message("policy-max-consecutive", &[])))
+            .transpose()?;
+        let counts = |table: &str| -> Result<Vec<(CharSet, u32)>, String> {
+            let prefix = format!("{}.", table);
+            config.iter().filter_map(|(key, _)| key.strip_prefix(&prefix).map(|name| (key, name))).map(|(key, name)| {
+                let set = CharSet::from_str(name, true).map_err(|_| i18n::message("policy-unknown-set", &[("key", &key)]))?;
+                let count = config.get_integer(key)?
+                    .and_then(|number| u32::try_from(number).ok().filter(|number| *number <= 65536))
+                    .ok_or_else(|| i18n::message("policy-count-range", &[("key", &key)]))?;
+                Ok((set, count))
+            }).collect()
+        };
+
+        let policy = Policy {
+            name: name.to_string(),
+            min_length,
+            max_length,
+            length,
+            char_sets: defaults::list(&config, "char_sets")?.unwrap_or_else(|| password::DEFAULT_CHAR_SETS.to_vec()),
+            required: defaults::list(&config, "required")?.unwrap_or_default(),
+            min_counts: counts("min_counts")?,
+            max_counts: counts("max_counts")?,
+            first: defaults::list(&config, "first")?.unwrap_or_default(),
+            last: defaults::list(&config, "last")?.unwrap_or_default(),
+            forbidden: config.get_string("forbidden")?.unwrap_or_default().to_string(),
+            max_consecutive,
+            min_entropy: config.get_integer("min_entropy")?.map(|bits| bits as f64),
+        };
+        policy.verify()?;
+        Ok(policy)
+    }
+
+    /// Makes sure a password can follow the policy at all.
+    pub fn verify(&self) -> Result<(), String> {
+        if self.pool().is_empty() {
+            return Err(i18n::message("policy-no-characters", &[]));
+        }
+        if let Some((set, _)) = self.minimums().iter().find(|(set, _)| self.required_count(set) == 0) {
+            return Err(i18n::message("policy-required-unavailable", &[("set", &set_name(set))]));
+        }
+        if let Some((set, _)) = self.max_counts.iter().find(|(set, max)| self.min_count(set) > *max) {
+            return Err(i18n::message("policy-max-counts", &[("set", &set_name(set))]));
+        }
+        for (key, sets) in [("first", &self.first), ("last", &self.last)] {
+            if !sets.is_empty() && sets.iter().all(|set| self.required_count(set) == 0) {
+                return Err(i18n::message("policy-ends-unavailable", &[("key", &key)]));
+            }
+        }
+        if self.max_length.is_some_and(|max_length| (max_length as usize) < self.required.len()) {
+            return Err(i18n::message("policy-max-length-required", &[]));
+        }
+        Ok(())
+    }
+
+    /// Narrows the options of `pwgen password` down to what the policy allows and keeps the
+    /// policy in them, so every generated password is checked against it.
+    pub fn apply(self, args: &mut PasswordArgs) -> Result<(), String> {
+        match args.length {
+            Some(length) if length < self.min_length || self.max_length.is_some_and(|max_length| length > max_length) => {
+                return Err(match self.max_length {
+                    Some(max_length) => i18n::message("policy-needs-length-between", &[("policy", &self.name), ("min", &self.min_length), ("max", &max_length)]),
+                    None => i18n::message("policy-needs-length", &[("policy", &self.name), ("min", &self.min_length)]),
+                });
+            }
+            Some(_) => {}
+            None => args.length = Some(self.length),
+        }
+        if args.character_sets.is_none() {
+            args.character_sets = Some(self.char_sets.clone());
+        }
+        // Whatever the policy doesn't allow is excluded, also from character sets given by hand.
+        let pool = self.pool();
+        let excluded: String = CharSet::value_variants().iter()
+            .flat_map(|set| password::get_char_set(set).chars())
+            .filter(|c| !pool.contains(c))
+            .collect();
+        args.excluded_chars = Some(args.excluded_chars.take().unwrap_or_default() + &excluded);
+
+        let available = password::character_pool(args)?;
+        if let Some(set) = self.required.iter().find(|set| !password::get_char_set(set).chars().any(|c| available.contains(&c))) {
+            return Err(i18n::message("policy-requires-set", &[("policy", &self.name), ("set", &set_name(set))]));
+        }
+        
+        // Rules no password could follow would only be found out after every attempt failed.
+        let length = args.length.unwrap_or(self.length);
+        if (length as usize) < self.required.len() {
+            return Err(i18n::message("policy-requires-sets", &[("policy", &self.name), ("count", &self.required.len()), ("length", &length)]));
+        }
+        if self.log_count(&available, length) == Some(f64::NEG_INFINITY) {
+            return Err(i18n::message("policy-impossible", &[("policy", &self.name), ("length", &length)]));
+        }
+        if let Some(max_consecutive) = self.max_consecutive
+            && available.len() == 1 && length > max_consecutive {
+            return Err(i18n::message("policy-max-consecutive-impossible", &[("policy", &self.name), ("max", &max_consecutive)]));
+        }
+        if let Some(min_entropy) = self.min_entropy {
+            let best = length as f64 * (estimated_pool_size(&available.iter().collect::<String>()) as f64).log2();
+            if best < min_entropy {
+                return Err(i18n::message("policy-entropy-impossible", &[("policy", &self.name), ("bits", &min_entropy), ("length", &length), ("best", &format!("{:.1}", best))]));
+            }
+        }
+        args.policy = Some(Box::new(self));
+        Ok(())
+    }
+
+    /// All characters the policy allows.
+    pub fn pool(&self) -> Vec<char> {
+        let mut pool: Vec<char> = self.char_sets.iter()
+            .flat_map(|set| password::get_char_set(set).chars())
+            .filter(|c| !self.forbidden.contains(*c))
+            .collect();
+        pool.dedup();
+        pool
+    }
+
+    /// Fewest characters of `set` a password needs, one for a required set.
+    fn min_count(&self, set: &CharSet) -> u32 {
+        let required = u32::from(self.required.contains(set));
+        self.min_counts.iter().filter(|(other, _)| other == set).map(|(_, count)| *count).fold(required, u32::max)
+    }
+
+    fn max_count(&self, set: &CharSet) -> Option<u32> {
+        self.max_counts.iter().filter(|(other, _)| other == set).map(|(_, count)| *count).min()
+    }
+
+    /// Every set a password needs characters of, with how many.
+    fn minimums(&self) -> Vec<(CharSet, u32)> {
+        CharSet::value_variants().iter()
+            .map(|set| (set.clone(), self.min_count(set)))
+            .filter(|(_, count)| *count > 0)
+            .collect()
+    }
+
+    fn required_count(&self, set: &CharSet) -> usize {
+        let pool = self.pool();
+        password::get_char_set(set).chars().filter(|c| pool.contains(c)).count()
+    }
+
+    /// Entropy of a random password of `length` that follows the policy, from the number of
+    /// passwords following it. Rules too big to count give the entropy without them.
+    pub fn entropy(&self, length: u32) -> f64 {
+        let pool = self.pool();
+        match self.log_count(&pool, length) {
+            Some(count) if count == f64::NEG_INFINITY => 0.0,
+            Some(count) => count / std::f64::consts::LN_2,
+            None => length as f64 * (pool.len() as f64).log2(),
+        }
+    }
+
+    /// Natural log of how many passwords of `length` characters of `pool` follow the
+    /// counting rules, `None` when they would take too much memory to count.
+    fn log_count(&self, pool: &[char], length: u32) -> Option<f64> {
+        let composition = Composition::new(self, pool)?;
+        let table = composition.log_counts(length as usize)?;
+        Some(table[length as usize][0])
+    }
+
+    /// Picks a password of `length` characters of `pool` following the required sets,
+    /// `min_counts`, `max_counts`, `first` and `last`, without throwing passwords away.
+    /// Every character is picked weighted by how many passwords can still follow the rules
+    /// after it, so every password following them is equally likely. Returns `None` when no
+    /// password can, or when the rules are too big to count.
+    pub fn sample<R: Rng + ?Sized>(&self, pool: &[char], length: u32, rng: &mut R) -> Option<String> {
+        let composition = Composition::new(self, pool)?;
+        let length = length as usize;
+        let table = composition.log_counts(length)?;
+        if table[length][0] == f64::NEG_INFINITY {
+            return None;
+        }
+
+        let mut password = String::with_capacity(length);
+        let mut state = 0;
+        for position in 0..length {
+            let remaining = length - position;
+            let choices: Vec<(usize, usize)> = composition.choices(position, length, state).collect();
+            let weights: Vec<f64> = choices.iter()
+                .map(|(class, next)| (composition.log_sizes[*class] + table[remaining - 1][*next] - table[remaining][state]).exp())
+                .collect();
+            let mut target = rng.random::<f64>() * weights.iter().sum::<f64>();
+            let mut picked = weights.iter().rposition(|weight| *weight > 0.0)?;
+            for (index, weight) in weights.iter().enumerate() {
+                if target < *weight {
+                    picked = index;
+                    break;
+                }
+                target -= weight;
+            }
+            let (class, next) = choices[picked];
+            password.push(*composition.classes[class].choose(rng).unwrap());
+            state = next;
+        }
+        Some(password)
+    }
+}
+
+/// The rules of a policy `Policy::sample` follows, for one pool. The pool is split into
+/// classes of the characters in exactly the same counted sets and allowed in the same
+/// positions, so every character is in one class even when the sets overlap.
+struct Composition {
+    classes: Vec<Vec<char>>,
+    log_sizes: Vec<f64>,
+    /// Whether the characters of a class may be first and last.
+    first: Vec<bool>,
+    last: Vec<bool>,
+    /// For every class and state, the state after one more character of the class, `None`
+    /// when that's too many of a set. A state numbers how many characters of every counted
+    /// set there are so far, counting up to the minimum, or the maximum when there is one.
+    steps: Vec<Vec<Option<usize>>>,
+    /// Whether a state has the fewest characters of every counted set.
+    complete: Vec<bool>,
+}
+
+impl Composition {
+    fn new(policy: &Policy, pool: &[char]) -> Option<Composition> {
+        let mut sets: Vec<CharSet> = Vec::new();
+        for set in policy.required.iter().chain(policy.min_counts.iter().chain(&policy.max_counts).map(|(set, _)| set)) {
+            if !sets.contains(set) {
+                sets.push(set.clone());
+            }
+        }
+        let limits: Vec<(usize, Option<usize>)> = sets.iter()
+            .map(|set| (policy.min_count(set) as usize, policy.max_count(set).map(|count| count as usize)))
+            .collect();
+        let caps: Vec<usize> = limits.iter().map(|(min, max)| max.unwrap_or(*min)).collect();
+        let state_count = caps.iter().try_fold(1usize, |product, cap| product.checked_mul(cap + 1))?;
+        if state_count > MAX_COUNTED {
+            return None;
+        }
+
+        let is_in = |sets: &[CharSet], c: char| sets.is_empty() || sets.iter().any(|set| password::get_char_set(set).contains(c));
+        let mut signatures: Vec<(Vec<bool>, bool, bool)> = Vec::new();
+        let mut classes: Vec<Vec<char>> = Vec::new();
+        for &c in pool {
+            let signature = (
+                sets.iter().map(|set| password::get_char_set(set).contains(c)).collect(),
+                is_in(&policy.first, c),
+                is_in(&policy.last, c),
+            );
+            match signatures.iter().position(|other| *other == signature) {
+                Some(index) => classes[index].push(c),
+                None => {
+                    signatures.push(signature);
+                    classes.push(vec![c]);
+                }
+            }
+        }
+
+        let counts_of = |mut state: usize| -> Vec<usize> {
+            caps.iter().map(|cap| {
+                let count = state % (cap + 1);
+                state /= cap + 1;
+                count
+            }).collect()
+        };
+        let state_of = |counts: &[usize]| counts.iter().zip(&caps).rev().fold(0, |state, (count, cap)| state * (cap + 1) + count);
+        let steps = signatures.iter().map(|(members, _, _)| {
+            (0..state_count).map(|state| {
+                let mut counts = counts_of(state);
+                for (index, _) in members.iter().enumerate().filter(|(_, member)| **member) {
+                    match limits[index].1 {
+                        Some(max) if counts[index] == max => return None,
+                        _ => counts[index] = (counts[index] + 1).min(caps[index]),
+                    }
+                }
+                Some(state_of(&counts))
+            }).collect()
+        }).collect();
+        let complete = (0..state_count)
+            .map(|state| counts_of(state).iter().zip(&limits).all(|(count, (min, _))| count >= min))
+            .collect();
+
+        Some(Composition {
+            log_sizes: classes.iter().map(|class| (class.len() as f64).ln()).collect(),
+            first: signatures.iter().map(|(_, first, _)| *first).collect(),
+            last: signatures.iter().map(|(_, _, last)| *last).collect(),
+            classes,
+            steps,
+            complete,
+        })
+    }
+
+    /// The classes a character at `position` can be of in `state`, with the state after it.
+    fn choices(&self, position: usize, length: usize, state: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.classes.len())
+            .filter(move |class| (position != 0 || self.first[*class]) && (position + 1 != length || self.last[*class]))
+            .filter_map(move |class| Some((class, self.steps[class][state]?)))
+    }
+
+    /// Natural log of how many ways the last `remaining` characters of a password of
+    /// `length` can follow the rules from every state, for every `remaining` up to `length`.
+    fn log_counts(&self, length: usize) -> Option<Vec<Vec<f64>>> {
+        if (length + 1).checked_mul(self.complete.len())? > MAX_COUNTED {
+            return None;
+        }
+        let mut table: Vec<Vec<f64>> = Vec::with_capacity(length + 1);
+        table.push(self.complete.iter().map(|complete| if *complete { 0.0 } else { f64::NEG_INFINITY }).collect());
+        for remaining in 1..=length {
+            let position = length - remaining;
+            let row = (0..self.complete.len())
+                .map(|state| log_sum(self.choices(position, length, state).map(|(class, next)| self.log_sizes[class] + table[remaining - 1][next])))
+                .collect();
+            table.push(row);
+        }
+        Some(table)
+    }
+}
+
+/// Natural log of the sum of the numbers whose natural logs are `values`.
+pub fn log_sum(values: impl Iterator<Item = f64>) -> f64 {
+    let values: Vec<f64> = values.collect();
+    let largest = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if largest == f64::NEG_INFINITY {
+        return largest;
+    }
+    largest + values.iter().map(|value| (value - largest).exp()).sum::<f64>().ln()
+}
+
+impl Policy {
+    /// Checks `password` against every rule, in the order of the policy file.
+    pub fn check(&self, password: &str) -> Vec<Check> {
+        let mut checks = Vec::new();
+        let mut check = |passed, text: String| checks.push(Check { passed, text });
+        let length = unicode::grapheme_count(password) as u32;
+        check(length >= self.min_length, i18n::message("check-min-length", &[("length", &length), ("min", &self.min_length)]));
+        if let Some(max_length) = self.max_length {
+            check(length <= max_length, i18n::message("check-max-length", &[("length", &length), ("max", &max_length)]));
+        }
+
+        let allowed: Vec<char> = self.char_sets.iter().flat_map(|set| password::get_char_set(set).chars()).collect();
+        let mut outside: Vec<char> = password.chars().filter(|c| !allowed.contains(c)).collect();
+        outside.sort_unstable();
+        outside.dedup();
+        if outside.is_empty() {
+            check(true, i18n::message("check-allowed", &[]));
+        } else {
+            check(false, i18n::message("check-outside", &[("characters", &outside.into_iter().collect::<String>())]));
+        }
+
+        let mut forbidden: Vec<char> = password.chars().filter(|c| self.forbidden.contains(*c)).collect();
+        forbidden.sort_unstable();
+        forbidden.dedup();
+        if forbidden.is_empty() {
+            check(true, i18n::message("check-not-forbidden", &[]));
+        } else {
+            check(false, i18n::message("check-forbidden", &[("characters", &forbidden.into_iter().collect::<String>())]));
+        }
+
+        for set in &self.required {
+            let name = set_name(set);
+            if password.chars().any(|c| password::get_char_set(set).contains(c)) {
+                check(true, i18n::message("check-contains", &[("set", &name)]));
+            } else {
+                check(false, i18n::message("check-contains-no", &[("set", &name)]));
+            }
+        }
+        let count_of = |set: &CharSet| password.chars().filter(|c| password::get_char_set(set).contains(*c)).count();
+        for (set, min) in &self.min_counts {
+            let count = count_of(set);
+            check(count >= *min as usize, i18n::message("check-min-count", &[("min", &min), ("set", &set_name(set)), ("count", &count)]));
+        }
+        for (set, max) in &self.max_counts {
+            let count = count_of(set);
+            check(count <= *max as usize, i18n::message("check-max-count", &[("max", &max), ("set", &set_name(set)), ("count", &count)]));
+        }
+        for (sets, c, key) in [(&self.first, password.chars().next(), "check-starts"), (&self.last, password.chars().last(), "check-ends")] {
+            if !sets.is_empty() {
+                let passed = c.is_some_and(|c| sets.iter().any(|set| password::get_char_set(set).contains(c)));
+                check(passed, i18n::message(key, &[("sets", &names(sets))]));
+            }
+        }
+
+        if let Some(max_consecutive) = self.max_consecutive {
+            let longest = longest_run(password);
+            check(longest <= max_consecutive, i18n::message("check-consecutive", &[("max", &max_consecutive), ("longest", &longest)]));
+        }
+
+        if let Some(min_entropy) = self.min_entropy {
+            let bits = estimate_entropy(password);
+            check(bits >= min_entropy, i18n::message("check-entropy", &[("bits", &format!("{:.1}", bits)), ("min", &min_entropy)]));
+        }
+        checks
+    }
+}
+
+impl Policy {
+    /// Changes `password` as little as possible to follow the policy: characters that aren't
+    /// allowed go, the case of a letter flips or a random character is appended for missing
+    /// sets, and the length is padded or trimmed. Returns the password and what was changed.
+    pub fn conform<R: Rng + ?Sized>(&self, password: &str, rng: &mut R) -> Result<(String, Vec<String>), String> {
+        let pool = self.pool();
+        let mut chars: Vec<char> = password.chars().collect();
+        let mut changes = Vec::new();
+
+        let length = chars.len();
+        chars.retain(|c| pool.contains(c));
+        if chars.len() < length {
+            changes.push(i18n::message("conform-removed", &[("count", &(length - chars.len()))]));
+        }
+
+        for (set, min) in &self.minimums() {
+            while chars.iter().filter(|c| password::get_char_set(set).contains(**c)).count() < *min as usize {
+                if let Some(index) = self.case_to_flip(&chars, set) {
+                    chars[index] = flip_case(chars[index]);
+                    let key = if chars[index].is_ascii_uppercase() { "conform-made-uppercase" } else { "conform-made-lowercase" };
+                    changes.push(i18n::message(key, &[("position", &(index + 1))]));
+                } else {
+                    let set_chars: Vec<char> = password::get_char_set(set).chars().filter(|c| pool.contains(c)).collect();
+                    chars.push(*set_chars.choose(rng).unwrap());
+                    changes.push(i18n::message("conform-appended-set", &[("set", &describe_set(set))]));
+                }
+            }
+        }
+
+        let padding = self.min_length.saturating_sub(chars.len() as u32);
+        if padding > 0 {
+            chars.extend((0..padding).map(|_| *pool.choose(rng).unwrap()));
+            changes.push(i18n::message("conform-padded", &[("characters", &random_characters(padding)), ("min", &self.min_length)]));
+        }
+
+        if let Some(max_length) = self.max_length {
+            let mut trimmed = 0;
+            let mut index = chars.len();
+            while chars.len() > max_length as usize && index > 0 {
+                index -= 1;
+                // A character a set needs to keep its fewest characters stays.
+                let removable = !self.minimums().iter().any(|(set, min)| {
+                    password::get_char_set(set).contains(chars[index])
+                        && chars.iter().filter(|c| password::get_char_set(set).contains(**c)).count() <= *min as usize
+                });
+                if removable {
+                    chars.remove(index);
+                    trimmed += 1;
+                }
+            }
+            if trimmed > 0 {
+                changes.push(i18n::message("conform-trimmed", &[("count", &trimmed), ("max", &max_length)]));
+            }
+        }
+
+        if let Some(min_entropy) = self.min_entropy {
+            let mut added = 0u32;
+            while estimate_entropy(&chars.iter().collect::<String>()) < min_entropy
+                && self.max_length.is_none_or(|max_length| (chars.len() as u32) < max_length) {
+                chars.push(*pool.choose(rng).unwrap());
+                added += 1;
+            }
+            if added > 0 {
+                changes.push(i18n::message("conform-entropy", &[("characters", &random_characters(added)), ("bits", &min_entropy)]));
+            }
+        }
+
+        if let Some(max_consecutive) = self.max_consecutive {
+            let mut replaced = 0;
+            let mut run = 1;
+            for index in 1..chars.len() {
+                run = if chars[index] == chars[index - 1] { run + 1 } else { 1 };
+                if run > max_consecutive {
+                    // Only one of a run is replaced, so a required set it belongs to stays.
+                    let neighbours = [Some(chars[index - 1]), chars.get(index + 1).copied()];
+                    let others: Vec<char> = pool.iter().copied().filter(|c| !neighbours.contains(&Some(*c))).collect();
+                    if let Some(other) = others.choose(rng) {
+                        chars[index] = *other;
+                        replaced += 1;
+                        run = 1;
+                    }
+                }
+            }
+            if replaced > 0 {
+                changes.push(i18n::message("conform-runs", &[("characters", &random_characters(replaced))]));
+            }
+        }
+
+        for (set, max) in &self.max_counts {
+            let in_set = |c: &char| password::get_char_set(set).contains(*c);
+            let others: Vec<char> = pool.iter().copied().filter(|c| !in_set(c)).collect();
+            let mut replaced = 0;
+            while chars.iter().filter(|c| in_set(c)).count() > *max as usize && !others.is_empty() {
+                let index = chars.iter().rposition(in_set).unwrap();
+                chars[index] = *others.choose(rng).unwrap();
+                replaced += 1;
+            }
+            if replaced > 0 {
+                changes.push(i18n::message("conform-max-count", &[("characters", &random_characters(replaced)), ("max", &max), ("set", &set_name(set))]));
+            }
+        }
+
+        // A character is added in front or at the end, or replaced there when that's too long.
+        for (sets, is_first) in [(&self.first, true), (&self.last, false)] {
+            let in_sets = |c: &char| sets.iter().any(|set| password::get_char_set(set).contains(*c));
+            let end = if is_first { chars.first() } else { chars.last() };
+            if sets.is_empty() || end.is_some_and(in_sets) {
+                continue;
+            }
+            let set_chars: Vec<char> = pool.iter().copied().filter(in_sets).collect();
+            let c = *set_chars.choose(rng).unwrap();
+            let described = or_list(sets.iter().map(describe_set).collect());
+            let is_full = self.max_length.is_some_and(|max_length| chars.len() as u32 >= max_length);
+            let (place, index) = if is_first { ("first", 0) } else { ("last", chars.len().saturating_sub(1)) };
+            if is_full && !chars.is_empty() {
+                chars[index] = c;
+                changes.push(i18n::message(&format!("conform-replaced-{}", place), &[("set", &described)]));
+            } else {
+                if is_first { chars.insert(0, c) } else { chars.push(c) }
+                changes.push(i18n::message(&format!("conform-added-{}", place), &[("set", &described)]));
+            }
+        }
+
+        let conformed: String = chars.into_iter().collect();
+        if let Some(check) = self.check(&conformed).into_iter().find(|check| !check.passed) {
+            return Err(i18n::message("conform-failed", &[("rule", &check.text)]));
+        }
+        Ok((conformed, changes))
+    }
+
+    /// A letter whose case can flip to add the missing `set`, without losing another required set.
+    fn case_to_flip(&self, chars: &[char], set: &CharSet) -> Option<usize> {
+        let other_set = match set {
+            CharSet::Lower => CharSet::Upper,
+            CharSet::Upper => CharSet::Lower,
+            _ => return None,
+        };
+        let other_count = chars.iter().filter(|c| password::get_char_set(&other_set).contains(**c)).count();
+        if other_count <= self.min_count(&other_set) as usize {
+            return None;
+        }
+        let pool = self.pool();
+        chars.iter().position(|c| password::get_char_set(&other_set).contains(*c) && pool.contains(&flip_case(*c)))
+    }
+}
+
+/// Length generated passwords get by default, 16 characters unless the limits say otherwise.
+pub fn default_length(min_length: u32, max_length: Option<u32>) -> u32 {
+    min_length.max(16).min(max_length.unwrap_or(u32::MAX))
+}
+
+fn longest_run(password: &str) -> u32 {
+    let chars: Vec<char> = password.chars().collect();
+    chars.chunk_by(|a, b| a == b).map(|run| run.len() as u32).max().unwrap_or(0)
+}
+
+fn random_characters(amount: u32) -> String {
+    i18n::message("random-characters", &[("count", &amount)])
+}
+
+fn flip_case(c: char) -> char {
+    if c.is_ascii_lowercase() { c.to_ascii_uppercase() } else { c.to_ascii_lowercase() }
+}
+
+fn describe_set(set: &CharSet) -> String {
+    let key = match set {
+        CharSet::Lower => "set-lower",
+        CharSet::Upper => "set-upper",
+        CharSet::Digits => "set-digit",
+        CharSet::Symbols => "set-symbol",
+        CharSet::RareSymbols => "set-rare-symbol",
+        CharSet::UnicodeLatin1 => "set-latin-1",
+        CharSet::Greek => "set-greek",
+        CharSet::Emoji => "set-emoji",
+    };
+    i18n::message(key, &[])
+}
+
+/// Rough entropy of a password someone picked, as if every character was random from the
+/// character sets it uses. Real passwords made by people are usually a lot weaker.
+pub fn estimate_entropy(password: &str) -> f64 {
+    let pool_size = estimated_pool_size(password);
+    if pool_size == 0 {
+        return 0.0;
+    }
+    unicode::grapheme_count(password) as f64 * (pool_size as f64).log2()
+}
+
+/// The characters `estimate_entropy` takes `password` to be picked from.
+fn estimated_pool_size(password: &str) -> usize {
+    let sets = CharSet::value_variants();
+    let mut pool_size = 0;
+    for set in sets {
+        if password.chars().any(|c| password::get_char_set(set).contains(c)) {
+            pool_size += password::get_char_set(set).chars().count();
+        }
+    }
+    let mut others: Vec<char> = password.chars()
+        .filter(|c| !sets.iter().any(|set| password::get_char_set(set).contains(*c)))
+        .collect();
+    others.sort_unstable();
+    others.dedup();
+    pool_size + others.len()
+}
+
+/// `pwgen validate`, which exits with 1 when a rule fails so scripts can tell.
+pub fn validate(args: &ValidateArgs) {
+    let result = Policy::load(&args.policy).and_then(|policy| {
+        let password = prompt::existing_secret("password")?;
+        Ok(policy.check(&password))
+    });
+    let checks = match result {
+        Ok(checks) => checks,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(1);
+        }
+    };
+
+    for check in &checks {
+        let label = i18n::message(if check.passed { "check-pass" } else { "check-fail" }, &[]);
+        println!("[{}] {}", label, check.text);
+    }
+    let failed = checks.iter().filter(|check| !check.passed).count();
+    if failed > 0 {
+        println!("{}", i18n::message("validate-broken", &[("failed", &failed), ("total", &checks.len())]));
+        std::process::exit(1);
+    }
+    println!("{}", i18n::message("validate-followed", &[]));
+}
+
+/// `pwgen conform`, printing what was changed and returning the changed password.
+pub fn conform<R: Rng + ?Sized>(args: &ConformArgs, rng: &mut R) -> Result<String, String> {
+    let policy = Policy::load(&args.policy)?;
+    let password = prompt::existing_secret("password")?;
+    let (conformed, changes) = policy.conform(&password, rng)?;
+
+    if changes.is_empty() {
+        porcelain::human(i18n::message("conform-nothing", &[]));
+    }
+    for change in &changes {
+        porcelain::report("change", change, change);
+    }
+    let before = estimate_entropy(&password);
+    let after = estimate_entropy(&conformed);
+    porcelain::report("entropy", &format!("{:.1}", after), i18n::message("conform-entropy-change", &[("bits", &format!("{:.1}", after)), ("before", &format!("{:.1}", before))]));
+    Ok(conformed)
+}
+
+pub fn run(args: &PolicyArgs) -> Result<(), String> {
+    match &args.command {
+        PolicyCommands::Compare(compare_args) => compare(compare_args),
+    }
+}
+
+fn compare(args: &CompareArgs) -> Result<(), String> {
+    let first = Policy::load(&args.first)?;
+    let second = Policy::load(&args.second)?;
+
+    let first_rows = summary(&first);
+    let second_rows = summary(&second);
+    let label_width = first_rows.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+    let width = first_rows.iter().map(|(_, value)| value.chars().count()).max().unwrap_or(0);
+    for ((label, first_value), (_, second_value)) in first_rows.iter().zip(&second_rows) {
+        println!("{:<label_width$} {:<width$}  {}", label, first_value, second_value, label_width = label_width, width = width);
+    }
+
+    let difference = first.entropy(first.length) - second.entropy(second.length);
+    let (stronger, weaker) = if difference >= 0.0 { (&first, &second) } else { (&second, &first) };
+    println!();
+    if difference.abs() < 0.05 {
+        println!("{}", i18n::message("compare-same", &[]));
+    } else {
+        println!("{}", i18n::message("compare-stronger", &[("stronger", &stronger.name), ("bits", &format!("{:.1}", difference.abs())), ("weaker", &weaker.name), ("guesses", &strength::keyspace(difference.abs()))]));
+    }
+    Ok(())
+}
+
+/// The rows of `pwgen policy compare` for one policy.
+fn summary(policy: &Policy) -> Vec<(String, String)> {
+    let entropy = policy.entropy(policy.length);
+    let unrestricted = policy.length as f64 * (policy.pool().len() as f64).log2();
+    let required = if policy.required.is_empty() {
+        i18n::message("compare-nothing", &[])
+    } else {
+        policy.required.iter().map(set_name).collect::<Vec<_>>().join(", ")
+    };
+    let rows = vec![
+        ("compare-policy", policy.name.clone()),
+        ("compare-length", match policy.max_length {
+            Some(max_length) => i18n::message("compare-length-range", &[("length", &policy.length), ("min", &policy.min_length), ("max", &max_length)]),
+            None => i18n::message("compare-length-open", &[("length", &policy.length), ("min", &policy.min_length)]),
+        }),
+        ("compare-characters", policy.pool().len().to_string()),
+        ("compare-required", required),
+        ("compare-keyspace", strength::keyspace(entropy)),
+        ("compare-entropy", in_bits(&format!("{:.1}", entropy))),
+        ("compare-lost", in_bits(&format!("{:.2}", unrestricted - entropy))),
+        ("compare-target", match policy.min_entropy {
+            Some(bits) if entropy >= bits => i18n::message("compare-met", &[("bits", &bits)]),
+            Some(bits) => i18n::message("compare-missed", &[("bits", &bits)]),
+            None => i18n::message("compare-none", &[]),
+        }),
+        ("compare-shortest", in_bits(&format!("{:.1}", policy.entropy(policy.min_length)))),
+    ];
+    rows.into_iter().map(|(key, value)| (i18n::message(key, &[]), value)).collect()
+}
+
+fn in_bits(bits: &str) -> String {
+    i18n::message("bits", &[("bits", &bits)])
+}
+
+fn set_name(set: &CharSet) -> String {
+    set.to_possible_value().unwrap().get_name().to_string()
+}
+
+/// The names of `sets`, like "lower or upper".
+fn names(sets: &[CharSet]) -> String {
+    or_list(sets.iter().map(set_name).collect())
+}
+
+fn or_list(items: Vec<String>) -> String {
+    items.join(&format!(" {} ", i18n::message("or", &[])))
+}
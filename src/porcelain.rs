@@ -0,0 +1,88 @@
+//! Output for scripts, turned on by `--porcelain`. Every line is a field name, a space
+//! and the value, after a first "pwgen-porcelain <version>" line. The version only goes
+//! up when the meaning of an existing field changes, new fields can be added at any time.
+//!
+//! Without `--porcelain`, only secrets go to stdout so they can be piped, and everything
+//! else goes to stderr, where `--quiet` leaves out all but the errors.
+
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub const VERSION: u32 = 1;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static VALUE_SHOWN: AtomicBool = AtomicBool::new(false);
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+    println!("pwgen-porcelain {}", VERSION);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Leaves out everything people would see apart from secrets and errors.
+pub fn set_quiet() {
+    QUIET.store(true, Ordering::Relaxed);
+}
+
+/// Prints `text` for people on stderr, or `field value` for scripts.
+/// A value over several lines becomes one field line per line.
+pub fn report(field: &str, value: &str, text: impl Display) {
+    if is_enabled() {
+        self::field(field, value);
+    } else if !QUIET.load(Ordering::Relaxed) {
+        eprintln!("{}", text);
+    }
+}
+
+/// Prints a secret, as `text` on stdout for people or `field value` for scripts.
+pub fn secret(field: &str, value: &str, text: impl Display) {
+    if is_enabled() {
+        self::field(field, value);
+    } else {
+        println!("{}", text);
+    }
+}
+
+/// Prints the generated value, the same for people and scripts apart from the field name.
+pub fn value(value: &str) {
+    described_value(value, value);
+}
+
+/// Prints the generated value, described by `text` for people.
+pub fn described_value(value: &str, text: impl Display) {
+    VALUE_SHOWN.store(true, Ordering::Relaxed);
+    secret("value", value, text);
+}
+
+/// Whether the generated value was printed, which the audit log records.
+pub fn value_shown() -> bool {
+    VALUE_SHOWN.load(Ordering::Relaxed)
+}
+
+pub fn error(message: &str) {
+    if is_enabled() {
+        field("error", message);
+    } else {
+        eprintln!("{}", message);
+    }
+}
+
+/// Prints a field only scripts get to see.
+pub fn field(field: &str, value: &str) {
+    if is_enabled() {
+        for line in value.lines() {
+            println!("{} {}", field, line);
+        }
+    }
+}
+
+/// Prints a message only people get to see, on stderr.
+pub fn human(text: impl Display) {
+    if !is_enabled() && !QUIET.load(Ordering::Relaxed) {
+        eprintln!("{}", text);
+    }
+}
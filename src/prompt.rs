@@ -0,0 +1,143 @@
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use crate::i18n;
+
+/// Asks the user to pick one of `count` numbered entries.
+/// Returns the zero-based index, or `None` if the user cancels.
+pub fn pick(count: usize) -> Option<usize> {
+    let stdin = io::stdin();
+    loop {
+        eprint!("{} ", i18n::message("prompt-pick", &[("count", &count)]));
+        io::stderr().flush().ok()?;
+
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).ok()? == 0 {
+            eprintln!();
+            return None;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+        match line.parse::<usize>() {
+            Ok(number) if (1..=count).contains(&number) => return Some(number - 1),
+            _ => eprintln!("{}", i18n::message("prompt-number-range", &[("max", &count)])),
+        }
+    }
+}
+
+/// Asks a question and reads the answer from a line of stdin.
+/// Returns `None` when stdin is closed.
+pub fn ask(question: &str) -> Option<String> {
+    eprint!("{} ", question);
+    io::stderr().flush().ok()?;
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line).ok()? == 0 {
+        eprintln!();
+        return None;
+    }
+    Some(line.trim().to_string())
+}
+
+/// Asks a yes or no question, where an empty answer picks `default`.
+pub fn confirm(question: &str, default: bool) -> Option<bool> {
+    let hint = if default { "[Y/n]" } else { "[y/N]" };
+    loop {
+        let answer = ask(&format!("{} {}", question, hint))?.to_lowercase();
+        match answer.as_str() {
+            "" => return Some(default),
+            "y" | "yes" => return Some(true),
+            "n" | "no" => return Some(false),
+            _ => eprintln!("{}", i18n::message("prompt-yes-no", &[])),
+        }
+    }
+}
+
+/// Reads a secret such as a password. At a terminal it is typed in twice without being
+/// shown, otherwise the first line of stdin is used so it can be piped in.
+pub fn secret(name: &str) -> Result<String, String> {
+    read_secret(name, true)
+}
+
+/// Reads a secret that already exists, such as a database password, so it is only typed in once.
+pub fn existing_secret(name: &str) -> Result<String, String> {
+    read_secret(name, false)
+}
+
+/// Parser for options that can hold a secret, which shouldn't be typed on the command line
+/// where other users see it in the process list. "-" asks for it like `existing_secret` and
+/// "@FILE" reads it from a file, while "@@" starts a value with a literal "@".
+pub fn argument(name: &'static str) -> impl Fn(&str) -> Result<String, String> + Clone + Send + Sync + 'static {
+    move |value| {
+        if value == "-" {
+            return existing_secret(name);
+        }
+        if value.starts_with("@@") {
+            return Ok(value[1..].to_string());
+        }
+        let Some(path) = value.strip_prefix('@') else {
+            return Ok(value.to_string());
+        };
+        let text = fs::read_to_string(path).map_err(|error| i18n::message("unable-to-read", &[("path", &path), ("error", &error)]))?;
+        let text = text.strip_suffix('\n').unwrap_or(&text);
+        Ok(text.strip_suffix('\r').unwrap_or(text).to_string())
+    }
+}
+
+fn read_secret(name: &str, confirm: bool) -> Result<String, String> {
+    let stdin = io::stdin();
+    if !stdin.is_terminal() {
+        let mut line = String::new();
+        stdin.lock().read_line(&mut line).map_err(|error| i18n::message("prompt-unreadable-stdin", &[("error", &error)]))?;
+        let line = line.strip_suffix('\n').unwrap_or(&line);
+        return Ok(line.strip_suffix('\r').unwrap_or(line).to_string());
+    }
+
+    // The prompts go to stderr so only the result ends up in redirected output.
+    eprint!("{}: ", i18n::noun(name));
+    let first = read_hidden_line().map_err(|error| i18n::message("prompt-unreadable", &[("error", &error)]))?;
+    if !confirm {
+        return Ok(first);
+    }
+    eprint!("{} ", i18n::message("prompt-repeat", &[]));
+    let second = read_hidden_line().map_err(|error| i18n::message("prompt-unreadable", &[("error", &error)]))?;
+    if first != second {
+        return Err(i18n::message("prompt-mismatch", &[]));
+    }
+    Ok(first)
+}
+
+/// Reads a line from the terminal with echoing turned off.
+#[cfg(unix)]
+fn read_hidden_line() -> io::Result<String> {
+    let file_descriptor = libc::STDIN_FILENO;
+    let mut original = std::mem::MaybeUninit::<libc::termios>::uninit();
+    // SAFETY: tcgetattr only writes to the termios struct it is given.
+    if unsafe { libc::tcgetattr(file_descriptor, original.as_mut_ptr()) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    // SAFETY: tcgetattr succeeded, so the struct is initialized.
+    let original = unsafe { original.assume_init() };
+
+    let mut hidden = original;
+    hidden.c_lflag &= !libc::ECHO;
+    hidden.c_lflag |= libc::ECHONL;
+    // SAFETY: both calls only read the termios structs they are given.
+    unsafe { libc::tcsetattr(file_descriptor, libc::TCSANOW, &hidden) };
+    let line = read_line();
+    unsafe { libc::tcsetattr(file_descriptor, libc::TCSANOW, &original) };
+    line
+}
+
+/// Reads a line from the terminal. Hiding the input is only supported on Unix for now.
+#[cfg(not(unix))]
+fn read_hidden_line() -> io::Result<String> {
+    read_line()
+}
+
+fn read_line() -> io::Result<String> {
+    let mut line = String::new();
+    io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim_end_matches(['\r', '\n']).to_string())
+}
@@ -0,0 +1,137 @@
+use clap::ValueEnum;
+use rand::Rng;
+use std::path::PathBuf;
+
+use crate::json::{self, Value};
+use crate::{PassphraseArgs, PasswordArgs};
+use crate::config::Config;
+use crate::{defaults, i18n, passphrase, password};
+
+/// Answers a JSON generation request from another program, such as
+/// `{"command": "password", "length": 20, "char_sets": ["lower", "digits"]}` or
+/// `{"command": "password", "site": "example.com"}`.
+/// The response is `{"password": "..."}` or `{"error": "..."}`, echoing any "id" of the request.
+pub fn handle<R: Rng + ?Sized>(request: &str, rng: &mut R) -> Value {
+    let request = json::parse(request);
+    let id = request.as_ref().ok().and_then(|request| request.get("id")).cloned();
+
+    let mut fields = Vec::new();
+    if let Some(id) = id {
+        fields.push(("id".to_string(), id));
+    }
+    match request.and_then(|request| generate(&request, rng)) {
+        Ok((name, secret)) => fields.push((name.to_string(), Value::String(secret))),
+        Err(message) => fields.push(("error".to_string(), Value::String(message))),
+    }
+    Value::Object(fields)
+}
+
+fn generate<R: Rng + ?Sized>(request: &Value, rng: &mut R) -> Result<(&'static str, String), String> {
+    let Value::Object(fields) = request else {
+        return Err(i18n::message("request-not-object", &[]));
+    };
+
+    match request.get("command").and_then(Value::as_str) {
+        Some("password") => {
+            let mut args = PasswordArgs::default();
+            for (key, value) in fields {
+                match key.as_str() {
+                    "id" | "command" => {}
+                    "length" => args.length = Some(integer(key, value)?),
+                    "char_sets" => {
+                        let names = value.as_array().ok_or_else(|| i18n::message("request-char-sets", &[]))?;
+                        let sets = names.iter()
+                            .map(|name| name.as_str().and_then(|name| password::CharSet::from_str(name, true).ok())
+                                .ok_or_else(|| i18n::message("request-unknown-set", &[("set", &name.to_compact())])))
+                            .collect::<Result<Vec<_>, String>>()?;
+                        args.character_sets = Some(sets);
+                    }
+                    "exclude" => args.excluded_chars = Some(text(key, value)?),
+                    "site" => args.site = Some(text(key, value)?),
+                    "policy" => args.policy_file = Some(PathBuf::from(text(key, value)?)),
+                    _ => return Err(i18n::message("request-unknown-password-option", &[("key", &key)])),
+                }
+            }
+            if args.site.is_some() && args.policy_file.is_some() {
+                return Err(i18n::message("request-site-and-policy", &[]));
+            }
+            // What the request leaves out comes from the config file, like on the command line.
+            defaults::apply_password(&mut args, &Config::load()?)?;
+            Ok(("password", password::generate(&args, rng)?))
+        }
+        Some("passphrase") => {
+            let mut args = PassphraseArgs::default();
+            for (key, value) in fields {
+                match key.as_str() {
+                    "id" | "command" => {}
+                    "words" => args.words = Some(integer(key, value)?),
+                    "separator" => {
+                        let separator = text(key, value)?;
+                        let mut chars = separator.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None) => args.word_char = Some(c),
+                            _ => return Err(i18n::message("request-separator", &[])),
+                        }
+                    }
+                    "wordlist" => args.wordlist = Some(text(key, value)?),
+                    "max_length" => args.max_length = Some(integer::<usize>(key, value)?),
+                    _ => return Err(i18n::message("request-unknown-passphrase-option", &[("key", &key)])),
+                }
+            }
+            defaults::apply_passphrase(&mut args, &Config::load()?)?;
+            Ok(("passphrase", passphrase::generate(&args, rng)?.text))
+        }
+        Some(command) => Err(i18n::message("request-unknown-command", &[("command", &command)])),
+        None => Err(i18n::message("request-no-command", &[])),
+    }
+}
+
+fn integer<T: TryFrom<u64>>(key: &str, value: &Value) -> Result<T, String> {
+    value.as_u64().and_then(|number| T::try_from(number).ok())
+        .ok_or_else(|| i18n::message("request-not-positive", &[("key", &key)]))
+}
+
+fn text(key: &str, value: &Value) -> Result<String, String> {
+    value.as_str().map(str::to_string).ok_or_else(|| i18n::message("request-not-string", &[("key", &key)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policy::Policy;
+    use crate::sites;
+
+    fn password(request: &str) -> String {
+        let response = handle(request, &mut rand::rng());
+        match response.get("password").and_then(Value::as_str) {
+            Some(password) => password.to_string(),
+            None => panic!("{}", response.to_compact()),
+        }
+    }
+
+    #[test]
+    fn site_rules_are_followed() {
+        let policy = sites::policy("americanexpress.com").unwrap();
+        for _ in 0..20 {
+            let password = password(r#"{"command": "password", "site": "americanexpress.com"}"#);
+            assert!(policy.check(&password).iter().all(|check| check.passed), "{}", password);
+        }
+    }
+
+    #[test]
+    fn policy_file_is_followed() {
+        let path = std::env::temp_dir().join(format!("pwgen-request-{}.toml", std::process::id()));
+        std::fs::write(&path, "length = 12\nchar_sets = [\"lower\", \"digits\"]\nfirst = [\"lower\"]\n\n[min_counts]\ndigits = 4\n").unwrap();
+        let policy = Policy::load(&path).unwrap();
+        let request = Value::Object(vec![
+            ("command".into(), Value::String("password".into())),
+            ("policy".into(), Value::String(path.display().to_string())),
+        ]).to_compact();
+        for _ in 0..20 {
+            let password = password(&request);
+            assert_eq!(password.chars().count(), 12);
+            assert!(policy.check(&password).iter().all(|check| check.passed), "{}", password);
+        }
+        std::fs::remove_file(&path).unwrap();
+    }
+}
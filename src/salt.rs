@@ -0,0 +1,44 @@
+use clap::{Args, ValueEnum};
+use rand::Rng;
+
+use crate::encoding;
+use crate::i18n;
+
+#[derive(Args)]
+pub struct SaltArgs {
+    /// Amount of random bytes.
+    /// Defaults to 16.
+    #[arg(short = 'b', long = "bytes")]
+    bytes: Option<usize>,
+
+    /// How the bytes are written out.
+    /// Defaults to hex.
+    #[arg(short = 'e', long = "encoding", value_enum)]
+    encoding: Option<SaltEncoding>,
+
+    /// Disables copying the salt to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum SaltEncoding {
+    Hex,
+    Base64,
+}
+
+/// Generates raw random bytes for salts, IVs and nonces. Unlike passwords no characters are
+/// filtered, so the full entropy of every byte is kept.
+pub fn generate<R: Rng + ?Sized>(args: &SaltArgs, rng: &mut R) -> Result<String, String> {
+    let byte_amount = args.bytes.unwrap_or(16);
+    if byte_amount == 0 || byte_amount > 65536 {
+        return Err(i18n::message("salt-bytes-range", &[]));
+    }
+
+    let mut bytes = vec![0u8; byte_amount];
+    rng.fill(bytes.as_mut_slice());
+    Ok(match args.encoding.unwrap_or(SaltEncoding::Hex) {
+        SaltEncoding::Hex => bytes.iter().map(|byte| format!("{:02x}", byte)).collect(),
+        SaltEncoding::Base64 => encoding::base64(&bytes),
+    })
+}
@@ -0,0 +1,203 @@
+//! Gives up abilities pwgen won't need once the arguments are parsed, so a misbehaving
+//! dependency can do less: a seccomp filter on Linux and pledge and unveil on OpenBSD.
+//! Commands running other programs keep network access, as those programs may need it.
+
+use std::env;
+
+use crate::{history, Commands};
+
+pub fn apply(command: &Commands) -> Result<(), String> {
+    // Keeping the last secret for `pwgen last` runs the secret store's program.
+    let runs_programs = runs_programs(command) || history::saves_secret();
+    // X11 over TCP, as set up by `ssh -X`, needs the network for the clipboard.
+    let remote_display = env::var("DISPLAY")
+        .is_ok_and(|display| !display.is_empty() && !display.starts_with(':') && !display.starts_with('/') && !display.starts_with("unix:"));
+    // Files named on the command line and the daemon's socket can be anywhere.
+    let writes_anywhere = matches!(command, Commands::Sshkey(_) | Commands::Wordlist(_) | Commands::Config(_) | Commands::Daemon(_))
+        || matches!(command, Commands::Username( args ) if args.history.is_some())
+        || matches!(command, Commands::Codename( args ) if args.history.is_some())
+        || matches!(command, Commands::Password( args ) if args.output.writes_file())
+        || matches!(command, Commands::Passphrase( args ) if args.output.writes_file());
+    platform::restrict(runs_programs, runs_programs || remote_display, runs_programs || writes_anywhere)
+}
+
+/// Whether the command starts external programs, like secret stores, pickers or editors.
+fn runs_programs(command: &Commands) -> bool {
+    match command {
+        Commands::Password( args ) => args.output.is_stored() || args.output.is_formatted() || args.picker.is_enabled(),
+        Commands::Passphrase( args ) => args.output.is_stored() || args.output.is_formatted() || args.picker.is_enabled(),
+        Commands::OtpSecret( args ) => args.qr,
+        Commands::Derive(_) | Commands::Vaults(_) | Commands::GitCredential(_) | Commands::Config(_)
+        | Commands::Doctor | Commands::Last(_) => true,
+        _ => false,
+    }
+}
+
+#[cfg(all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod platform {
+    use std::io;
+
+    use libc::{sock_filter, sock_fprog};
+
+    use crate::i18n;
+
+    #[cfg(target_arch = "x86_64")]
+    const AUDIT_ARCH: u32 = 0xc000_003e;
+    #[cfg(target_arch = "aarch64")]
+    const AUDIT_ARCH: u32 = 0xc000_00b7;
+
+    /// Offsets into `struct seccomp_data`.
+    const NR_OFFSET: u32 = 0;
+    const ARCH_OFFSET: u32 = 4;
+    const FIRST_ARGUMENT_OFFSET: u32 = 16;
+
+    /// Syscalls for debugging, kernel modules, mounting or io_uring, which nothing in pwgen uses
+    /// and which could get around the rest of the filter or reach into other processes.
+    const DENIED: [libc::c_long; 17] = [
+        libc::SYS_ptrace,
+        libc::SYS_process_vm_readv,
+        libc::SYS_process_vm_writev,
+        libc::SYS_mount,
+        libc::SYS_umount2,
+        libc::SYS_pivot_root,
+        libc::SYS_kexec_load,
+        libc::SYS_init_module,
+        libc::SYS_finit_module,
+        libc::SYS_delete_module,
+        libc::SYS_bpf,
+        libc::SYS_perf_event_open,
+        libc::SYS_userfaultfd,
+        libc::SYS_swapon,
+        libc::SYS_swapoff,
+        libc::SYS_reboot,
+        libc::SYS_io_uring_setup,
+    ];
+
+    fn statement(code: u32, k: u32) -> sock_filter {
+        sock_filter { code: code as u16, jt: 0, jf: 0, k }
+    }
+
+    fn jump_if_equal(k: u32, jt: u8, jf: u8) -> sock_filter {
+        sock_filter { code: (libc::BPF_JMP | libc::BPF_JEQ | libc::BPF_K) as u16, jt, jf, k }
+    }
+
+    fn deny(errno: i32) -> sock_filter {
+        statement(libc::BPF_RET | libc::BPF_K, libc::SECCOMP_RET_ERRNO | errno as u32)
+    }
+
+    /// Files can't be confined with seccomp, so `_writes_anywhere` only matters on OpenBSD.
+    pub fn restrict(runs_programs: bool, uses_network: bool, _writes_anywhere: bool) -> Result<(), String> {
+        let load = libc::BPF_LD | libc::BPF_W | libc::BPF_ABS;
+        let allow = statement(libc::BPF_RET | libc::BPF_K, libc::SECCOMP_RET_ALLOW);
+        let mut program = vec![
+            statement(load, ARCH_OFFSET),
+            jump_if_equal(AUDIT_ARCH, 1, 0),
+            statement(libc::BPF_RET | libc::BPF_K, libc::SECCOMP_RET_KILL_PROCESS),
+            statement(load, NR_OFFSET),
+        ];
+        // The x32 syscalls of x86_64 have their own numbers, which the list below doesn't match.
+        #[cfg(target_arch = "x86_64")]
+        program.extend([
+            sock_filter { code: (libc::BPF_JMP | libc::BPF_JGE | libc::BPF_K) as u16, jt: 0, jf: 1, k: 0x4000_0000 },
+            deny(libc::EPERM),
+        ]);
+        for syscall in DENIED {
+            program.extend([jump_if_equal(syscall as u32, 0, 1), deny(libc::EPERM)]);
+        }
+        if !runs_programs {
+            for syscall in [libc::SYS_execve, libc::SYS_execveat] {
+                program.extend([jump_if_equal(syscall as u32, 0, 1), deny(libc::EACCES)]);
+            }
+        }
+        if !uses_network {
+            // Only local sockets, which the clipboard and secret stores talk over.
+            program.extend([
+                jump_if_equal(libc::SYS_socket as u32, 0, 4),
+                statement(load, FIRST_ARGUMENT_OFFSET),
+                jump_if_equal(libc::AF_UNIX as u32, 0, 1),
+                allow,
+                deny(libc::EAFNOSUPPORT),
+            ]);
+        }
+        program.push(allow);
+
+        let filter = sock_fprog { len: program.len() as u16, filter: program.as_mut_ptr() };
+        // SAFETY: PR_SET_NO_NEW_PRIVS takes plain integers, which a filter can't be installed without.
+        if unsafe { libc::prctl(libc::PR_SET_NO_NEW_PRIVS, 1, 0, 0, 0) } != 0 {
+            return Err(i18n::message("sandbox-failed", &[("error", &io::Error::last_os_error())]));
+        }
+        // SAFETY: the filter points at `program`, which outlives the call; the kernel copies it.
+        if unsafe { libc::prctl(libc::PR_SET_SECCOMP, libc::SECCOMP_MODE_FILTER, &filter as *const sock_fprog) } != 0 {
+            return Err(i18n::message("sandbox-failed", &[("error", &io::Error::last_os_error())]));
+        }
+        log::debug!("Sandboxed with seccomp, running programs: {}, network: {}", runs_programs, uses_network);
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "openbsd")]
+mod platform {
+    use std::ffi::CString;
+    use std::io;
+    use std::path::Path;
+    use std::ptr;
+
+    use crate::{audit, i18n, paths};
+
+    fn unveil(path: &Path, permissions: &str) -> Result<(), String> {
+        let Ok(path_text) = CString::new(path.as_os_str().as_encoded_bytes()) else {
+            return Ok(());
+        };
+        let permissions = CString::new(permissions).unwrap();
+        // SAFETY: both strings are null terminated and live for the call.
+        // Paths that don't exist yet can fail, those are simply not reachable then.
+        if unsafe { libc::unveil(path_text.as_ptr(), permissions.as_ptr()) } != 0
+            && io::Error::last_os_error().raw_os_error() != Some(libc::ENOENT) {
+            return Err(i18n::message("sandbox-unveil-path", &[("path", &path.display()), ("error", &io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+
+    pub fn restrict(runs_programs: bool, uses_network: bool, writes_anywhere: bool) -> Result<(), String> {
+        if !writes_anywhere {
+            // Anything can be read, like wordlists to import, but only pwgen's own files written.
+            unveil(Path::new("/"), "r")?;
+            if let Some(dir) = paths::config_dir() {
+                unveil(&dir, "rwc")?;
+            }
+            if let Some(file) = paths::config_file() {
+                unveil(&file, "rwc")?;
+            }
+            if let Some(file) = audit::audit_file() {
+                unveil(&file, "rwc")?;
+            }
+            unveil(Path::new("/tmp"), "rwc")?;
+            unveil(Path::new("/dev/tty"), "rw")?;
+            // SAFETY: two null pointers lock the unveiled paths.
+            if unsafe { libc::unveil(ptr::null(), ptr::null()) } != 0 {
+                return Err(i18n::message("sandbox-unveil", &[("error", &io::Error::last_os_error())]));
+            }
+        }
+
+        let mut promises = String::from("stdio rpath wpath cpath flock tty unix");
+        if uses_network {
+            promises.push_str(" inet dns");
+        }
+        if runs_programs {
+            promises.push_str(" proc exec");
+        }
+        let promises = CString::new(promises).unwrap();
+        // SAFETY: the promises are null terminated, programs run are left unrestricted.
+        if unsafe { libc::pledge(promises.as_ptr(), ptr::null()) } != 0 {
+            return Err(i18n::message("sandbox-pledge", &[("error", &io::Error::last_os_error())]));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(any(target_os = "openbsd", all(target_os = "linux", any(target_arch = "x86_64", target_arch = "aarch64")))))]
+mod platform {
+    pub fn restrict(_runs_programs: bool, _uses_network: bool, _writes_anywhere: bool) -> Result<(), String> {
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! `--separator-set` of passphrases and usernames: separators picked at random from a set,
+//! once for the whole result or for every gap with `--separator-per-gap`.
+
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+#[derive(Clone)]
+pub struct SeparatorSet {
+    /// The set as it was given, for the audit log.
+    pub text: String,
+    separators: Vec<String>,
+}
+
+/// Parses "-_." as a set of single characters. With spaces in it, like ":: -- ..", the set
+/// holds the separators in between them instead, which can be several characters long.
+pub fn parse(text: &str) -> Result<SeparatorSet, String> {
+    let mut separators: Vec<String> = if text.contains(char::is_whitespace) {
+        text.split_whitespace().map(str::to_string).collect()
+    } else {
+        text.chars().map(String::from).collect()
+    };
+    separators.sort();
+    separators.dedup();
+    if separators.is_empty() {
+        return Err("the set needs at least one separator".into());
+    }
+    Ok(SeparatorSet { text: text.to_string(), separators })
+}
+
+impl SeparatorSet {
+    /// Separators for `gaps` gaps, the same one everywhere unless `per_gap` is set.
+    pub fn pick<R: Rng + ?Sized>(&self, gaps: usize, per_gap: bool, rng: &mut R) -> Vec<String> {
+        if per_gap {
+            (0..gaps).map(|_| self.separators.choose(rng).unwrap().clone()).collect()
+        } else {
+            vec![self.separators.choose(rng).unwrap().clone(); gaps]
+        }
+    }
+
+    /// Separators for `gaps` gaps picked by `value`, like a hash, instead of at random.
+    pub fn pick_by(&self, gaps: usize, per_gap: bool, mut value: u64) -> Vec<String> {
+        let amount = self.separators.len() as u64;
+        let first = self.separators[(value % amount) as usize].clone();
+        if !per_gap {
+            return vec![first; gaps];
+        }
+        (0..gaps).map(|_| {
+            let separator = self.separators[(value % amount) as usize].clone();
+            value /= amount;
+            separator
+        }).collect()
+    }
+
+    /// Entropy the separators add to a result with `gaps` gaps.
+    pub fn bits(&self, gaps: usize, per_gap: bool) -> f64 {
+        let picks = if per_gap { gaps } else { gaps.min(1) };
+        picks as f64 * (self.separators.len() as f64).log2()
+    }
+
+    /// Length of the shortest separator, for fitting results within a maximum length.
+    pub fn shortest_length(&self) -> usize {
+        self.separators.iter().map(|separator| separator.chars().count()).min().unwrap()
+    }
+}
+
+/// Joins `parts` with `separators[i]` in between part i and i + 1.
+pub fn join(parts: &[String], separators: &[String]) -> String {
+    let mut joined = String::new();
+    for (index, part) in parts.iter().enumerate() {
+        if index > 0 {
+            joined.push_str(&separators[index - 1]);
+        }
+        joined.push_str(part);
+    }
+    joined
+}
@@ -0,0 +1,86 @@
+use std::io::{self, IsTerminal};
+
+use crate::config::{self, Value};
+use crate::i18n;
+use crate::prompt;
+
+/// Offers the setup wizard the first time pwgen is used at a terminal, which is when there
+/// is no config file yet. Declining still writes the file, so it's only offered once.
+pub fn offer_on_first_run() {
+    let Some(path) = config::config_path() else {
+        return;
+    };
+    if path.exists() || !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+        return;
+    }
+
+    eprintln!("{}", i18n::message("setup-welcome", &[]));
+    let result = match prompt::confirm(&i18n::message("setup-ask", &[]), false) {
+        Some(true) => run(),
+        Some(false) => config::save_values(&[])
+            .map(|()| eprintln!("{}", i18n::message("setup-skipped", &[]))),
+        None => return,
+    };
+    if let Err(message) = result {
+        eprintln!("{}", message);
+    }
+    eprintln!();
+}
+
+/// Asks about the most common defaults and writes them to the config file.
+pub fn run() -> Result<(), String> {
+    let mut values = Vec::new();
+
+    let length = loop {
+        let Some(answer) = prompt::ask(&i18n::message("setup-length", &[])) else {
+            return Ok(());
+        };
+        if answer.is_empty() {
+            break 16;
+        }
+        match answer.parse::<i64>() {
+            Ok(length) if (4..=65536).contains(&length) => break length,
+            _ => eprintln!("{}", i18n::message("setup-length-range", &[])),
+        }
+    };
+    values.push(("password.length", Value::Integer(length)));
+
+    let Some(symbols) = prompt::confirm(&i18n::message("setup-symbols", &[]), true) else {
+        return Ok(());
+    };
+    if !symbols {
+        let sets = ["lower", "upper", "digits"].map(|set| Value::String(set.to_string()));
+        values.push(("password.char_sets", Value::Array(sets.to_vec())));
+    }
+
+    let Some(copy) = prompt::confirm(&i18n::message("setup-copy", &[]), true) else {
+        return Ok(());
+    };
+    if copy {
+        let clear_after = loop {
+            let Some(answer) = prompt::ask(&i18n::message("setup-clear", &[])) else {
+                return Ok(());
+            };
+            if answer.is_empty() {
+                break 0;
+            }
+            match answer.parse::<i64>() {
+                Ok(seconds) if (0..=86400).contains(&seconds) => break seconds,
+                _ => eprintln!("{}", i18n::message("setup-clear-range", &[])),
+            }
+        };
+        if clear_after > 0 {
+            values.push(("clipboard.clear_after", Value::Integer(clear_after)));
+        }
+    } else {
+        for key in ["password.no_copy", "passphrase.no_copy", "username.no_copy"] {
+            values.push((key, Value::Boolean(true)));
+        }
+    }
+
+    config::save_values(&values)?;
+    if let Some(path) = config::config_path() {
+        eprintln!("{}", i18n::message("setup-saved", &[("path", &path.display())]));
+    }
+    Ok(())
+}
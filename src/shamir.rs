@@ -0,0 +1,173 @@
+//! Shamir secret sharing over GF(256), one polynomial per secret byte.
+//!
+//! A share is written as `<id>-<threshold>-<index>-<data>-<checksum>`, for example
+//! `7f3a-3-1-9c02e4d1...-5b0e91c7`. The id is random per split, so shares of different
+//! secrets can't be mixed up. Data is the hex encoded share bytes and the checksum is
+//! the CRC-32 of everything before it, catching typos when shares are typed back in.
+
+use clap::Args;
+use rand::Rng;
+use std::collections::HashSet;
+
+use crate::i18n;
+use crate::prompt;
+
+#[derive(Clone, Copy)]
+pub struct Split {
+    pub threshold: u8,
+    pub shares: u8,
+}
+
+#[derive(Args)]
+pub struct CombineArgs {
+    /// Shares printed by --split, at least as many as the threshold.
+    /// Every "-" asks for a share and "@FILE" reads one from a file.
+    #[arg(required = true, value_parser = prompt::argument("share"))]
+    shares: Vec<String>,
+
+    /// Disables copying the secret to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Parses a split like "3-of-5".
+pub fn parse_split(text: &str) -> Result<Split, String> {
+    let (threshold, shares) = text.split_once("-of-")
+        .ok_or("expected a split like \"3-of-5\"")?;
+    let threshold: u8 = threshold.trim().parse().map_err(|_| format!("\"{}\" is not a valid number", threshold))?;
+    let shares: u8 = shares.trim().parse().map_err(|_| format!("\"{}\" is not a valid number", shares))?;
+    if threshold < 2 {
+        return Err("at least 2 shares must be needed to recover the secret".into());
+    }
+    if threshold > shares {
+        return Err("the threshold cannot be larger than the amount of shares".into());
+    }
+    if shares == 255 {
+        return Err("there can be at most 254 shares".into());
+    }
+    Ok(Split { threshold, shares })
+}
+
+/// Splits `secret` so any `threshold` of the shares recover it, while fewer reveal nothing.
+pub fn split<R: Rng + ?Sized>(secret: &[u8], split: Split, rng: &mut R) -> Vec<String> {
+    let id: u16 = rng.random();
+    let mut coefficients = vec![0u8; split.threshold as usize - 1];
+
+    let mut share_data = vec![Vec::with_capacity(secret.len()); split.shares as usize];
+    for &byte in secret {
+        rng.fill(coefficients.as_mut_slice());
+        for (index, data) in share_data.iter_mut().enumerate() {
+            let x = index as u8 + 1;
+            // Horner's method, with the secret byte as the constant term.
+            let y = coefficients.iter().rev().fold(0, |y, &coefficient| multiply(y, x) ^ coefficient);
+            data.push(multiply(y, x) ^ byte);
+        }
+    }
+
+    share_data.iter().enumerate().map(|(index, data)| {
+        let body = format!("{:04x}-{}-{}-{}", id, split.threshold, index + 1, hex(data));
+        format!("{}-{:08x}", body, crc32fast::hash(body.as_bytes()))
+    }).collect()
+}
+
+pub fn combine(args: &CombineArgs) -> Result<String, String> {
+    let shares = args.shares.iter().map(|share| parse_share(share)).collect::<Result<Vec<Share>, String>>()?;
+
+    let first = &shares[0];
+    if shares.iter().any(|share| share.id != first.id || share.threshold != first.threshold) {
+        return Err(i18n::message("shamir-different-secrets", &[]));
+    }
+    if shares.iter().any(|share| share.data.len() != first.data.len()) {
+        return Err(i18n::message("shamir-different-lengths", &[]));
+    }
+    let mut indexes = HashSet::new();
+    let shares: Vec<&Share> = shares.iter().filter(|share| indexes.insert(share.index)).collect();
+    if shares.len() < first.threshold as usize {
+        return Err(i18n::message("shamir-too-few", &[("threshold", &first.threshold), ("given", &shares.len())]));
+    }
+
+    // Lagrange interpolation at x = 0. Subtraction is XOR in GF(256).
+    let mut secret = vec![0u8; first.data.len()];
+    for share in &shares {
+        let mut basis = 1;
+        for other in &shares {
+            if other.index != share.index {
+                basis = multiply(basis, divide(other.index, other.index ^ share.index));
+            }
+        }
+        for (byte, &y) in secret.iter_mut().zip(&share.data) {
+            *byte ^= multiply(y, basis);
+        }
+    }
+
+    String::from_utf8(secret).map_err(|_| i18n::message("shamir-invalid-secret", &[]))
+}
+
+struct Share {
+    id: u16,
+    threshold: u8,
+    index: u8,
+    data: Vec<u8>,
+}
+
+fn parse_share(text: &str) -> Result<Share, String> {
+    let text = text.trim();
+    let invalid = || i18n::message("shamir-invalid-share", &[("share", &text)]);
+
+    let (body, checksum) = text.rsplit_once('-').ok_or_else(invalid)?;
+    if u32::from_str_radix(checksum, 16).ok() != Some(crc32fast::hash(body.as_bytes())) {
+        return Err(i18n::message("shamir-typo", &[("share", &text)]));
+    }
+
+    let parts: Vec<&str> = body.split('-').collect();
+    let [id, threshold, index, data] = parts[..] else {
+        return Err(invalid());
+    };
+    let index: u8 = index.parse().map_err(|_| invalid())?;
+    if index == 0 {
+        return Err(invalid());
+    }
+    Ok(Share {
+        id: u16::from_str_radix(id, 16).map_err(|_| invalid())?,
+        threshold: threshold.parse().map_err(|_| invalid())?,
+        index,
+        data: decode_hex(data).ok_or_else(invalid)?,
+    })
+}
+
+/// Multiplication in GF(256) with the AES polynomial x^8 + x^4 + x^3 + x + 1.
+fn multiply(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+        let carry = a & 0x80 != 0;
+        a <<= 1;
+        if carry {
+            a ^= 0x1b;
+        }
+        b >>= 1;
+    }
+    product
+}
+
+/// Division in GF(256), using a^254 as the inverse of a.
+fn divide(a: u8, b: u8) -> u8 {
+    let mut inverse = 1;
+    for _ in 0..254 {
+        inverse = multiply(inverse, b);
+    }
+    multiply(a, inverse)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len()).step_by(2).map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok()).collect()
+}
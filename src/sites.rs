@@ -0,0 +1,174 @@
+//! Password rules of known sites for `--site`, in the passwordrules format of Apple's
+//! password manager resources, like "minlength: 8; maxlength: 20; required: lower; required: digit;".
+//! A `password-rules.json` in the config directory, such as the community file itself,
+//! adds to and overrides the built-in rules.
+
+use std::fs;
+
+use crate::i18n;
+use crate::json::{self, Value};
+use crate::password::{self, CharSet};
+use crate::paths;
+use crate::policy::{self, Policy};
+
+const BUILT_IN_RULES: &str = include_str!("password-rules.json");
+
+const ALL_SETS: [CharSet; 5] = [CharSet::Lower, CharSet::Upper, CharSet::Digits, CharSet::Symbols, CharSet::RareSymbols];
+
+/// The policy of `site`, looked up by its domain and then the domains above it.
+pub fn policy(site: &str) -> Result<Policy, String> {
+    let domain = normalize(site);
+    let mut datasets = Vec::new();
+    if let Some(path) = paths::site_rules_file() && path.exists() {
+        let text = fs::read_to_string(&path).map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+        datasets.push(json::parse(&text).map_err(|error| i18n::message("sites-invalid", &[("path", &path.display()), ("error", &error)]))?);
+    }
+    datasets.push(json::parse(BUILT_IN_RULES).expect("built-in site rules are valid JSON"));
+
+    let labels: Vec<&str> = domain.split('.').collect();
+    for start in 0..labels.len().saturating_sub(1) {
+        let candidate = labels[start..].join(".");
+        for dataset in &datasets {
+            if let Some(rules) = dataset.get(&candidate).and_then(|site| site.get("password-rules")).and_then(Value::as_str) {
+                log::debug!("Using the password rules of {}: {}", candidate, rules);
+                return parse(&candidate, rules).map_err(|error| i18n::message("sites-invalid-rules", &[("site", &candidate), ("error", &error)]));
+            }
+        }
+    }
+    Err(i18n::message("sites-unknown", &[("site", &domain)]))
+}
+
+/// Turns "https://www.Example.com/login" into "example.com".
+fn normalize(site: &str) -> String {
+    let site = site.trim().to_lowercase();
+    let site = site.split_once("://").map_or(site.as_str(), |(_, rest)| rest);
+    let site = site.split(['/', '?', '#']).next().unwrap_or_default();
+    let site = site.rsplit_once('@').map_or(site, |(_, host)| host);
+    let site = site.split(':').next().unwrap_or_default();
+    site.strip_prefix("www.").unwrap_or(site).to_string()
+}
+
+/// Converts passwordrules into a policy. Rules the character sets can't express exactly,
+/// like a requirement for one of several classes, are made stricter rather than looser,
+/// so a password that follows the policy is always accepted by the site.
+fn parse(name: &str, rules: &str) -> Result<Policy, String> {
+    let mut min_length = None;
+    let mut max_length = None;
+    let mut max_consecutive = None;
+    let mut allowed: Vec<char> = Vec::new();
+    let mut required: Vec<Vec<char>> = Vec::new();
+
+    for property in split_outside_brackets(rules, ';') {
+        let property = property.trim();
+        if property.is_empty() {
+            continue;
+        }
+        let (key, value) = property.split_once(':').ok_or_else(|| i18n::message("sites-expected-property", &[("property", &property)]))?;
+        let number = || value.trim().parse::<u32>().map_err(|_| i18n::message("sites-not-number", &[("key", &key.trim())]));
+        match key.trim() {
+            "minlength" => min_length = Some(number()?),
+            "maxlength" => max_length = Some(number()?),
+            "max-consecutive" => max_consecutive = Some(number()?),
+            "allowed" => allowed.extend(classes(value)?),
+            "required" => required.push(classes(value)?),
+            _ => log::info!("Ignoring the unknown password rule \"{}\"", key.trim()),
+        }
+    }
+
+    // Required characters are allowed too, and without any allowed or required
+    // characters everything printable is.
+    allowed.extend(required.iter().flatten());
+    if allowed.is_empty() {
+        allowed = class("ascii-printable")?;
+    }
+
+    let mut policy_required = Vec::new();
+    let mut forbidden: Vec<char> = Vec::new();
+    for group in &required {
+        for set in ALL_SETS.iter().filter(|set| password::get_char_set(set).chars().any(|c| group.contains(&c))) {
+            if !policy_required.contains(set) {
+                policy_required.push(set.clone());
+            }
+            // Any character of the set has to meet the requirement, so the others go.
+            forbidden.extend(password::get_char_set(set).chars().filter(|c| !group.contains(c)));
+        }
+    }
+    let char_sets: Vec<CharSet> = ALL_SETS.iter()
+        .filter(|set| password::get_char_set(set).chars().any(|c| allowed.contains(&c)))
+        .cloned()
+        .collect();
+    forbidden.extend(char_sets.iter().flat_map(|set| password::get_char_set(set).chars()).filter(|c| !allowed.contains(c)));
+    forbidden.sort_unstable();
+    forbidden.dedup();
+
+    let min_length = min_length.unwrap_or(1).max(1);
+    if max_length.is_some_and(|max_length| max_length < min_length) {
+        return Err(i18n::message("sites-max-below-min", &[]));
+    }
+    let policy = Policy {
+        name: name.to_string(),
+        min_length,
+        max_length,
+        length: policy::default_length(min_length, max_length),
+        char_sets,
+        required: policy_required,
+        min_counts: Vec::new(),
+        max_counts: Vec::new(),
+        first: Vec::new(),
+        last: Vec::new(),
+        forbidden: forbidden.into_iter().collect(),
+        max_consecutive,
+        min_entropy: None,
+    };
+    policy.verify()?;
+    Ok(policy)
+}
+
+/// The characters of a comma separated list of classes, like "lower, digit, [-_.]".
+fn classes(value: &str) -> Result<Vec<char>, String> {
+    let mut chars = Vec::new();
+    for item in split_outside_brackets(value, ',') {
+        chars.extend(class(item.trim())?);
+    }
+    Ok(chars)
+}
+
+fn class(name: &str) -> Result<Vec<char>, String> {
+    let printable = || (' '..='~').collect::<Vec<char>>();
+    Ok(match name {
+        "lower" => password::LOWER_CHARS.chars().collect(),
+        "upper" => password::UPPER_CHARS.chars().collect(),
+        "digit" => password::DIGITS_CHARS.chars().collect(),
+        "special" => printable().into_iter().filter(|c| !c.is_ascii_alphanumeric()).collect(),
+        // Only ASCII is generated, so unicode allows the same.
+        "ascii-printable" | "unicode" => printable(),
+        _ => match name.strip_prefix('[').and_then(|name| name.strip_suffix(']')) {
+            Some(custom) => custom.chars().collect(),
+            None => return Err(i18n::message("sites-unknown-class", &[("class", &name)])),
+        },
+    })
+}
+
+/// Splits on `separator` except inside "[...]", where a "]" only closes the brackets
+/// when it's followed by the end or a separator, so "[]]" is a class of just "]".
+fn split_outside_brackets(text: &str, separator: char) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_brackets = false;
+    for (position, &(index, c)) in chars.iter().enumerate() {
+        if in_brackets {
+            let next = chars[position + 1..].iter().map(|(_, c)| *c).find(|c| !c.is_whitespace());
+            if c == ']' && matches!(next, None | Some(',') | Some(';')) {
+                in_brackets = false;
+            }
+        } else if c == '[' {
+            in_brackets = true;
+        } else if c == separator {
+            parts.push(&text[start..index]);
+            start = index + c.len_utf8();
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
@@ -0,0 +1,169 @@
+use clap::{Args, ValueEnum};
+use once_cell::sync::Lazy;
+use rand::Rng;
+
+use crate::digest;
+use crate::i18n;
+use crate::prompt;
+
+const DICTIONARY_RAW: &str = include_str!("../data/skey/dictionary.txt");
+
+/// The 2048 word dictionary from RFC 2289, each word standing for 11 bits.
+static DICTIONARY: Lazy<Vec<&'static str>> = Lazy::new(|| DICTIONARY_RAW.lines().collect());
+
+#[derive(Args)]
+pub struct SkeyArgs {
+    /// Amount of one-time passwords on the sheet.
+    /// Defaults to 100.
+    #[arg(short = 'C', long = "count")]
+    count: Option<u32>,
+
+    /// Seed the server shows in its challenge, 1 to 16 letters and digits.
+    /// Defaults to a random seed like "ke4821".
+    #[arg(short = 's', long = "seed")]
+    seed: Option<String>,
+
+    /// Secret pass phrase the sheet is derived from, between 10 and 63 characters.
+    /// Defaults to a random one that is never shown, so the sheet can't be recreated.
+    /// Use "-" to type it in hidden and "@FILE" to read it from a file.
+    #[arg(short = 'p', long = "passphrase", value_parser = prompt::argument("pass phrase"))]
+    passphrase: Option<String>,
+
+    /// Hash the one-time passwords are computed with.
+    /// Defaults to md5, the one every RFC 2289 implementation supports.
+    #[arg(short = 'a', long = "algorithm", value_enum)]
+    algorithm: Option<Algorithm>,
+
+    /// Also print every one-time password in hex.
+    #[arg(short = 'x', long = "hex")]
+    hex: bool,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum Algorithm {
+    Md5,
+    Sha1,
+}
+
+impl Algorithm {
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::Md5 => "otp-md5",
+            Algorithm::Sha1 => "otp-sha1",
+        }
+    }
+
+    /// Hashes `message` and folds the digest down to the 64 bits RFC 2289 works with.
+    fn hash_and_fold(self, message: &[u8]) -> [u8; 8] {
+        let mut folded = [0u8; 8];
+        match self {
+            Algorithm::Md5 => {
+                let digest = digest::md5(message);
+                for index in 0..8 {
+                    folded[index] = digest[index] ^ digest[index + 8];
+                }
+            }
+            Algorithm::Sha1 => {
+                // RFC 2289 folds SHA-1 as five 32 bit words and stores them little-endian.
+                let digest = digest::sha1(message);
+                let word = |index: usize| u32::from_be_bytes([digest[index * 4], digest[index * 4 + 1], digest[index * 4 + 2], digest[index * 4 + 3]]);
+                let first = word(0) ^ word(2) ^ word(4);
+                let second = word(1) ^ word(3);
+                folded[..4].copy_from_slice(&first.to_le_bytes());
+                folded[4..].copy_from_slice(&second.to_le_bytes());
+            }
+        }
+        folded
+    }
+}
+
+pub fn run(args: &SkeyArgs) -> Result<(), String> {
+    print!("{}", generate(args, &mut rand::rng())?);
+    Ok(())
+}
+
+/// Generates a sheet of one-time passwords for sequence numbers `count - 1` down to 0,
+/// headed by the line to set up the server with, which holds the password for `count`.
+fn generate<R: Rng + ?Sized>(args: &SkeyArgs, rng: &mut R) -> Result<String, String> {
+    let count = args.count.unwrap_or(100);
+    if count == 0 || count > 9999 {
+        return Err(i18n::message("count-range", &[("max", &9999)]));
+    }
+
+    let seed = match &args.seed {
+        Some(seed) => {
+            if seed.is_empty() || seed.len() > 16 || !seed.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return Err(i18n::message("skey-invalid-seed", &[]));
+            }
+            seed.to_lowercase()
+        }
+        None => random_seed(rng),
+    };
+
+    let passphrase = match &args.passphrase {
+        Some(passphrase) => {
+            if !(10..=63).contains(&passphrase.chars().count()) {
+                return Err(i18n::message("skey-passphrase-range", &[]));
+            }
+            passphrase.clone()
+        }
+        None => {
+            let mut bytes = [0u8; 32];
+            rng.fill(&mut bytes);
+            bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+        }
+    };
+
+    let algorithm = args.algorithm.unwrap_or(Algorithm::Md5);
+    let mut passwords = Vec::with_capacity(count as usize + 1);
+    let mut password = algorithm.hash_and_fold(format!("{}{}", seed, passphrase).as_bytes());
+    passwords.push(password);
+    for _ in 0..count {
+        password = algorithm.hash_and_fold(&password);
+        passwords.push(password);
+    }
+
+    let mut sheet = i18n::message("skey-server-setup", &[
+        ("algorithm", &algorithm.name()),
+        ("count", &count),
+        ("seed", &seed),
+        ("key", &hex(&passwords[count as usize])),
+    ]);
+    sheet.push('\n');
+    let number_width = (count - 1).to_string().len();
+    for sequence in (0..count as usize).rev() {
+        sheet.push_str(&format!("{:>width$}: {}", sequence, six_words(&passwords[sequence]), width = number_width));
+        if args.hex {
+            sheet.push_str(&format!("  ({})", hex(&passwords[sequence])));
+        }
+        sheet.push('\n');
+    }
+    Ok(sheet)
+}
+
+fn random_seed<R: Rng + ?Sized>(rng: &mut R) -> String {
+    let letters: String = (0..2).map(|_| rng.random_range(b'a'..=b'z') as char).collect();
+    format!("{}{:04}", letters, rng.random_range(0..10000))
+}
+
+/// Hex form of a one-time password in the groups of four RFC 2289 uses.
+fn hex(password: &[u8; 8]) -> String {
+    password
+        .chunks(2)
+        .map(|pair| format!("{:02X}{:02X}", pair[0], pair[1]))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Encodes the 64 bits plus a 2 bit checksum as six dictionary words.
+fn six_words(password: &[u8; 8]) -> String {
+    let value = u64::from_be_bytes(*password);
+    let checksum: u64 = (0..32).map(|index| (value >> (index * 2)) & 3).sum();
+    let bits = ((value as u128) << 2) | (checksum & 3) as u128;
+
+    (0..6)
+        .rev()
+        .map(|index| DICTIONARY[((bits >> (index * 11)) & 2047) as usize])
+        .collect::<Vec<_>>()
+        .join(" ")
+}
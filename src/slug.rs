@@ -0,0 +1,66 @@
+use clap::Args;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::i18n;
+use crate::petname::{self, DNS_LABEL_MAX_LENGTH};
+use crate::wordlist::{self, ADJECTIVE_LIST, OBJECT_LIST};
+
+const SLUG_LETTERS: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+const SLUG_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789";
+
+#[derive(Args)]
+pub struct SlugArgs {
+    /// Amount of characters, or the maximum when using --words.
+    /// Defaults to 12, or 63 when using --words.
+    #[arg(short = 'l', long = "length")]
+    length: Option<usize>,
+
+    /// Start the slug with a random word pair, like "brave-falcon-x7k2".
+    #[arg(short = 'w', long = "words")]
+    words: bool,
+
+    /// Amount of random characters after the word pair.
+    /// Defaults to 4.
+    #[arg(short = 'r', long = "random-suffix", requires = "words")]
+    random_suffix: Option<usize>,
+
+    /// Disables copying the slug to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Generates a lowercase slug that is always a valid DNS label.
+pub fn generate<R: Rng + ?Sized>(args: &SlugArgs, rng: &mut R) -> Result<String, String> {
+    let default_length = if args.words { DNS_LABEL_MAX_LENGTH } else { 12 };
+    let length = args.length.unwrap_or(default_length);
+    if length == 0 || length > DNS_LABEL_MAX_LENGTH {
+        return Err(i18n::message("length-range", &[("max", &DNS_LABEL_MAX_LENGTH)]));
+    }
+
+    let slug = if args.words {
+        let suffix_length = args.random_suffix.unwrap_or(4);
+        let fixed_length = 1 + if suffix_length > 0 { suffix_length + 1 } else { 0 };
+        let words = wordlist::choose_words_within(&[&ADJECTIVE_LIST, &OBJECT_LIST], fixed_length, Some(length), rng)?;
+
+        let mut slug = words.join("-").to_lowercase();
+        if suffix_length > 0 {
+            slug.push('-');
+            slug.push_str(&random_chars(suffix_length, rng));
+        }
+        slug
+    } else {
+        // Starting with a letter keeps the slug usable where names may not begin with a digit.
+        let first = *SLUG_LETTERS.choose(rng).unwrap() as char;
+        format!("{}{}", first, random_chars(length - 1, rng))
+    };
+
+    if !petname::is_dns_label(&slug) {
+        return Err(i18n::message("invalid-dns-label", &[("label", &slug)]));
+    }
+    Ok(slug)
+}
+
+fn random_chars<R: Rng + ?Sized>(amount: usize, rng: &mut R) -> String {
+    (0..amount).map(|_| *SLUG_CHARS.choose(rng).unwrap() as char).collect()
+}
@@ -0,0 +1,90 @@
+//! `pwgen stats`, the size of the keyspace the current settings pick from and what it
+//! takes to reach common security targets, without generating anything.
+
+use clap::{Args, Parser};
+
+use crate::{Cli, Commands};
+use crate::{defaults, encoding, i18n, password, strength, wordlist};
+
+/// Security targets in bits of entropy, from guessing against a rate limited login to
+/// secrets that have to hold up for decades.
+const TARGETS: [(&str, u32); 4] = [
+    ("stats-online", 48),
+    ("stats-offline-slow", 64),
+    ("stats-offline-fast", 80),
+    ("stats-long-term", 128),
+];
+
+#[derive(Args)]
+pub struct StatsArgs {
+    /// The command and flags to report on, like "password --length 20".
+    /// Defaults to "password".
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true, value_name = "COMMAND")]
+    command: Vec<String>,
+}
+
+pub fn run(args: &StatsArgs) -> Result<(), String> {
+    stats(args)
+}
+
+fn stats(args: &StatsArgs) -> Result<(), String> {
+    // Parsed like `pwgen <command>` and completed from the config, just like a real run.
+    let words = if args.command.is_empty() { vec!["password".to_string()] } else { args.command.clone() };
+    let mut command = Cli::try_parse_from(["pwgen".to_string()].into_iter().chain(words))
+        .map_err(|error| error.to_string())?
+        .command.ok_or_else(|| i18n::message("stats-no-command", &[]))?;
+    defaults::apply(&mut command)?;
+
+    let (unit, pool_size, amount) = match &command {
+        Commands::Password( args ) => ("character", password::character_pool(args)?.len(), password::password_length(args)?),
+        Commands::Passphrase( args ) => {
+            if args.leet.is_some() || args.capitalization.is_some() || args.max_length.is_some() {
+                println!("{}", i18n::message("stats-word-dependent", &[]));
+            }
+            if args.separator_set.is_some() {
+                println!("{}", i18n::message("stats-separator-set", &[]));
+            }
+            let words = args.words.unwrap_or(6);
+            ("word", wordlist::passphrase_words(args.wordlist.as_deref())?.len(), words)
+        }
+        Commands::Token( args ) => ("character", encoding::CROCKFORD_ALPHABET.len(), args.length.unwrap_or(16) as u32),
+        _ => return Err(i18n::message("stats-unsupported", &[])),
+    };
+
+    let bits_per_unit = (pool_size as f64).log2();
+    let bits = bits_per_unit * amount as f64;
+    let unit_message = |key: &str, args: &[(&str, &dyn std::fmt::Display)]| i18n::message(&format!("{}-{}", key, unit), args);
+    println!("{}", unit_message("stats-pool", &[("pool", &group(pool_size)), ("bits", &format!("{:.2}", bits_per_unit))]));
+    println!("{}", unit_message("stats-length", &[("amount", &amount)]));
+    println!("{}", i18n::message("stats-keyspace", &[("keyspace", &strength::keyspace(bits)), ("bits", &format!("{:.1}", bits))]));
+    println!("{}", strength::describe(bits));
+    println!();
+
+    let heading = unit_message("stats-heading", &[]);
+    let targets: Vec<(String, u32)> = TARGETS.iter().map(|(target, bits)| (i18n::message(target, &[]), *bits)).collect();
+    let target_heading = i18n::message("stats-target", &[]);
+    let width = targets.iter().map(|(target, _)| target.chars().count()).chain([target_heading.chars().count()]).max().unwrap_or(0);
+    println!("{:<width$} {:>4}  {:>10}", target_heading, i18n::message("stats-bits", &[]), heading);
+    for (target, target_bits) in targets {
+        let needed = (target_bits as f64 / bits_per_unit).ceil();
+        let line = format!("{:<width$} {:>4}  {:>10}", target, target_bits, needed);
+        if amount as f64 >= needed {
+            println!("{}  {}", line, i18n::message("stats-met", &[]));
+        } else {
+            println!("{}", line);
+        }
+    }
+    Ok(())
+}
+
+fn group(number: usize) -> String {
+    let digits = number.to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    grouped
+}
@@ -0,0 +1,45 @@
+//! `--stdout-only`, for places where secrets may go nowhere but the pipe: not to the
+//! clipboard, a secret store, a file or another program.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::{Commands, i18n};
+use crate::output::OutputArgs;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Turns the mode on, failing for commands and options that would hand the secret elsewhere
+/// instead of quietly leaving them out.
+pub fn enable(command: &Commands) -> Result<(), String> {
+    ENABLED.store(true, Ordering::Relaxed);
+    let conflict = match command {
+        Commands::Password( args ) => option_conflict(&args.output, args.picker.is_enabled()),
+        Commands::Passphrase( args ) => option_conflict(&args.output, args.picker.is_enabled()),
+        Commands::Username( args ) if args.unique => Some("stdout-only-unique"),
+        Commands::Codename( args ) if args.history.is_some() => Some("stdout-only-history"),
+        Commands::Sshkey(_) => Some("stdout-only-sshkey"),
+        Commands::Vaults(_) | Commands::GitCredential(_) | Commands::Last(_) => Some("stdout-only-keyring"),
+        Commands::Daemon(_) => Some("stdout-only-daemon"),
+        _ => None,
+    };
+    match conflict {
+        Some(conflict) => Err(i18n::message("stdout-only-conflict", &[("conflict", &i18n::message(conflict, &[]))])),
+        None => Ok(()),
+    }
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+fn option_conflict(output: &OutputArgs, is_picked: bool) -> Option<&'static str> {
+    if output.is_stored() {
+        Some("stdout-only-stored")
+    } else if output.is_encrypted_by_program() {
+        Some("stdout-only-program")
+    } else if is_picked {
+        Some("stdout-only-picker")
+    } else {
+        None
+    }
+}
@@ -0,0 +1,32 @@
+use crate::i18n;
+
+/// Human readable summary of how strong a secret with `bits` of entropy is.
+pub fn describe(bits: f64) -> String {
+    let rating = if bits < 40.0 {
+        "rating-weak"
+    } else if bits < 60.0 {
+        "rating-fair"
+    } else if bits < 80.0 {
+        "rating-strong"
+    } else {
+        "rating-very-strong"
+    };
+    i18n::message("entropy", &[("bits", &format!("{:.1}", bits)), ("rating", &i18n::message(rating, &[]))])
+}
+
+/// A number of possibilities, `2^bits`, in scientific notation once it gets large.
+pub fn keyspace(bits: f64) -> String {
+    let count = 2f64.powf(bits);
+    if bits < 40.0 {
+        format!("{:.0}", count)
+    } else {
+        let exponent = bits * 2f64.log10();
+        format!("{:.1}e{}", 10f64.powf(exponent.fract()), exponent.floor())
+    }
+}
+
+/// Line explaining how much entropy an optional transformation contributed, with `source`
+/// naming its message, like "leet" for "boost-leet".
+pub fn describe_boost(source: &str, bits: f64) -> String {
+    format!("  {}", i18n::message(&format!("boost-{}", source), &[("bits", &format!("{:.1}", bits))]))
+}
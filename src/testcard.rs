@@ -0,0 +1,135 @@
+use clap::Args;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::date::Date;
+use crate::i18n;
+
+#[derive(Args)]
+pub struct TestCardArgs {
+    /// Card brand to generate a number for.
+    /// Defaults to visa.
+    #[arg(short = 'b', long = "brand", value_enum)]
+    brand: Option<Brand>,
+
+    /// Disables copying the card number to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+pub enum Brand {
+    Visa,
+    Mastercard,
+    Amex,
+}
+
+/// A documented test card number with a random expiry and CVC, for testing payment forms only.
+pub struct TestCard {
+    brand: Brand,
+    pub number: String,
+    expiry_month: u32,
+    expiry_year: i64,
+    pub cvc: String,
+}
+
+impl Brand {
+    fn name(self) -> &'static str {
+        match self {
+            Brand::Visa => "Visa",
+            Brand::Mastercard => "Mastercard",
+            Brand::Amex => "American Express",
+        }
+    }
+
+    /// Test numbers documented by the sandboxes of payment providers like Stripe, Braintree
+    /// and Adyen. Random digits after a test prefix could still be a real card, these can't.
+    fn test_numbers(self) -> &'static [&'static str] {
+        match self {
+            Brand::Visa => &["4111111111111111", "4242424242424242", "4012888888881881", "4000056655665556"],
+            Brand::Mastercard => &["5555555555554444", "5105105105105100", "2223003122003222", "5200828282828210"],
+            Brand::Amex => &["378282246310005", "371449635398431", "378734493671000"],
+        }
+    }
+
+    fn cvc_length(self) -> usize {
+        match self {
+            Brand::Amex => 4,
+            _ => 3,
+        }
+    }
+
+    /// Digit group sizes the number is usually printed in.
+    fn grouping(self) -> &'static [usize] {
+        match self {
+            Brand::Amex => &[4, 6, 5],
+            _ => &[4, 4, 4, 4],
+        }
+    }
+}
+
+pub fn generate<R: Rng + ?Sized>(args: &TestCardArgs, rng: &mut R) -> TestCard {
+    let brand = args.brand.unwrap_or(Brand::Visa);
+
+    let number = brand.test_numbers().choose(rng).unwrap().to_string();
+
+    let today = Date::today();
+    let expiry_month = rng.random_range(1..=12);
+    let expiry_year = today.year + rng.random_range(1..=5);
+    let cvc = (0..brand.cvc_length()).map(|_| random_digit(rng)).collect();
+
+    TestCard { brand, number, expiry_month, expiry_year, cvc }
+}
+
+impl TestCard {
+    pub fn describe(&self) -> String {
+        let mut grouped = Vec::new();
+        let mut rest = self.number.as_str();
+        for &group_length in self.brand.grouping() {
+            let (group, remaining) = rest.split_at(group_length);
+            grouped.push(group);
+            rest = remaining;
+        }
+
+        [
+            i18n::message("testcard-brand", &[("brand", &self.brand.name())]),
+            i18n::message("testcard-number", &[("number", &grouped.join(" "))]),
+            i18n::message("testcard-expiry", &[("expiry", &self.expiry())]),
+            i18n::message("testcard-cvc", &[("cvc", &self.cvc)]),
+        ].join("\n")
+    }
+
+    /// Expiry date as MM/YY.
+    pub fn expiry(&self) -> String {
+        format!("{:02}/{:02}", self.expiry_month, self.expiry_year % 100)
+    }
+}
+
+fn random_digit<R: Rng + ?Sized>(rng: &mut R) -> char {
+    char::from(b'0' + rng.random_range(0..10))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Whether `number` passes the Luhn check, doubling every second digit from the right.
+    fn passes_luhn(number: &str) -> bool {
+        let sum: u32 = number.chars().rev().enumerate().map(|(index, c)| {
+            let digit = c.to_digit(10).unwrap();
+            if index % 2 == 1 { (digit * 2) % 10 + digit * 2 / 10 } else { digit }
+        }).sum();
+        sum.is_multiple_of(10)
+    }
+
+    #[test]
+    fn test_numbers_pass_luhn() {
+        for brand in [Brand::Visa, Brand::Mastercard, Brand::Amex] {
+            for number in brand.test_numbers() {
+                let length: usize = brand.grouping().iter().sum();
+                assert_eq!(number.len(), length, "{}", number);
+                assert!(passes_luhn(number), "{}", number);
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use clap::Args;
+use rand::seq::IndexedRandom;
+use rand::Rng;
+
+use crate::encoding::{self, CROCKFORD_ALPHABET};
+use crate::i18n;
+use crate::prompt;
+
+#[derive(Args)]
+pub struct TokenArgs {
+    /// Amount of characters, not counting the check symbol.
+    /// Defaults to 16.
+    #[arg(short = 'l', long = "length")]
+    pub length: Option<usize>,
+
+    /// Insert a hyphen after every this many characters to ease reading.
+    /// Example usage: "--group 4".
+    #[arg(short = 'g', long = "group")]
+    group: Option<usize>,
+
+    /// Check whether a token was read back without typos instead of generating one.
+    #[arg(long = "verify", value_name = "TOKEN", value_parser = prompt::argument("token"))]
+    pub verify: Option<String>,
+
+    /// Disables copying the token to clipboard.
+    #[arg(short = 'n', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+}
+
+/// Generates a Crockford base32 token followed by its check symbol, like "7K2M-9QXD-4".
+pub fn generate<R: Rng + ?Sized>(args: &TokenArgs, rng: &mut R) -> Result<String, String> {
+    let length = args.length.unwrap_or(16);
+    if length == 0 || length > 65536 {
+        return Err(i18n::message("length-range", &[("max", &65536)]));
+    }
+
+    let data: String = (0..length).map(|_| *CROCKFORD_ALPHABET.choose(rng).unwrap() as char).collect();
+    let check_symbol = encoding::crockford_check_symbol(&data).unwrap();
+
+    let mut token = match args.group {
+        Some(group) if group > 0 => group_symbols(&data, group),
+        _ => data,
+    };
+    if args.group.is_some_and(|group| group > 0) {
+        token.push('-');
+    }
+    token.push(check_symbol);
+    Ok(token)
+}
+
+/// Prints whether `token` ends in the right check symbol, failing when it doesn't.
+pub fn verify(token: &str) -> Result<(), String> {
+    if !encoding::verify_crockford_check_symbol(token) {
+        return Err(i18n::message("token-invalid", &[]));
+    }
+    println!("{}", i18n::message("token-valid", &[]));
+    Ok(())
+}
+
+fn group_symbols(data: &str, group: usize) -> String {
+    data.as_bytes()
+        .chunks(group)
+        .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+        .collect::<Vec<_>>()
+        .join("-")
+}
@@ -0,0 +1,98 @@
+//! Character sets beyond ASCII and counting characters the way people see them.
+
+use once_cell::sync::Lazy;
+
+/// Latin-1 supplement letters and symbols, without the invisible soft hyphen.
+pub static LATIN1_CHARS: Lazy<String> = Lazy::new(|| ('\u{A1}'..='\u{FF}').filter(|&c| c != '\u{AD}').collect());
+
+/// Greek capital and small letters.
+pub static GREEK_CHARS: Lazy<String> = Lazy::new(|| {
+    ('\u{391}'..='\u{3A9}').chain('\u{3B1}'..='\u{3C9}').filter(|&c| c != '\u{3A2}').collect()
+});
+
+/// Emoticons and transport emoji. Each is a single code point shown as an emoji without
+/// a variation selector, so every one counts as one character.
+pub static EMOJI_CHARS: Lazy<String> = Lazy::new(|| ('\u{1F600}'..='\u{1F64F}').chain('\u{1F680}'..='\u{1F6C5}').collect());
+
+/// Amount of characters as people see them, where an accent, a skin tone or the parts of
+/// an emoji joined with a zero width joiner belong to the character before them.
+pub fn grapheme_count(text: &str) -> usize {
+    let mut count = 0;
+    let mut joined = false;
+    let mut regional_indicators = 0;
+    for c in text.chars() {
+        let extends = joined || matches!(c,
+            '\u{300}'..='\u{36F}' | '\u{1AB0}'..='\u{1AFF}' | '\u{1DC0}'..='\u{1DFF}' | '\u{20D0}'..='\u{20FF}'
+            | '\u{FE00}'..='\u{FE0F}' | '\u{FE20}'..='\u{FE2F}' | '\u{1F3FB}'..='\u{1F3FF}' | '\u{200D}'
+            | '\u{E0020}'..='\u{E007F}' | '\u{E0100}'..='\u{E01EF}');
+        joined = c == '\u{200D}';
+
+        // Flags are pairs of regional indicators.
+        if ('\u{1F1E6}'..='\u{1F1FF}').contains(&c) {
+            regional_indicators += 1;
+            if regional_indicators % 2 == 0 {
+                continue;
+            }
+        } else {
+            regional_indicators = 0;
+        }
+        if !extends || count == 0 {
+            count += 1;
+        }
+    }
+    count
+}
+
+/// Broad Unicode categories for `--exclude-category`.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum Category {
+    Letter,
+    Uppercase,
+    Lowercase,
+    Number,
+    Punctuation,
+    Symbol,
+    Space,
+    /// Anything outside of ASCII.
+    NonAscii,
+}
+
+/// Punctuation outside of ASCII in the character sets, the rest of those are symbols.
+const LATIN1_PUNCTUATION: &str = "¡§«¶·»¿";
+
+impl Category {
+    /// The category for a `\p{...}` name of a regular expression, like "P" or "Letter".
+    pub fn from_property(name: &str) -> Option<Category> {
+        Some(match name {
+            "L" | "Letter" => Category::Letter,
+            "Lu" | "Uppercase_Letter" => Category::Uppercase,
+            "Ll" | "Lowercase_Letter" => Category::Lowercase,
+            "N" | "Nd" | "Number" => Category::Number,
+            "P" | "Punctuation" => Category::Punctuation,
+            "S" | "Symbol" => Category::Symbol,
+            "Z" | "Zs" | "Separator" => Category::Space,
+            _ => return None,
+        })
+    }
+
+    pub fn contains(self, c: char) -> bool {
+        match self {
+            Category::Letter => c.is_alphabetic(),
+            Category::Uppercase => c.is_uppercase(),
+            Category::Lowercase => c.is_lowercase(),
+            Category::Number => c.is_numeric(),
+            Category::Punctuation => is_punctuation(c),
+            Category::Symbol => !c.is_alphanumeric() && !c.is_whitespace() && !c.is_control() && !is_punctuation(c),
+            Category::Space => c.is_whitespace(),
+            Category::NonAscii => !c.is_ascii(),
+        }
+    }
+}
+
+fn is_punctuation(c: char) -> bool {
+    if c.is_ascii() {
+        // ASCII punctuation as Unicode sees it, where "$+<=>^`|~" are symbols.
+        return c.is_ascii_punctuation() && !"$+<=>^`|~".contains(c);
+    }
+    LATIN1_PUNCTUATION.contains(c) || ('\u{2010}'..='\u{2027}').contains(&c) || ('\u{2030}'..='\u{205E}').contains(&c)
+}
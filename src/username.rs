@@ -0,0 +1,475 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use rand::Rng;
+
+use crate::UsernameArgs;
+use crate::digest;
+use crate::i18n;
+use crate::invented;
+use crate::leet;
+use crate::paths;
+use crate::porcelain;
+use crate::separator;
+use crate::strength;
+use crate::wordlist;
+
+/// Generates a username, with --unique one that isn't in the history of issued usernames
+/// yet, which it's added to.
+pub fn generate<R: Rng + ?Sized>(args: &UsernameArgs, rng: &mut R) -> Result<String, String> {
+    let username = generate_unissued(args, &issued(args)?, rng)?;
+    record(args, &username)?;
+    Ok(username)
+}
+
+/// Generates `amount` usernames for --suggest. With --unique they differ from each other
+/// and the issued ones, but only those the user goes on with are [`record`]ed.
+pub fn suggest<R: Rng + ?Sized>(args: &UsernameArgs, amount: usize, rng: &mut R) -> Result<Vec<String>, String> {
+    let mut taken = issued(args)?;
+    let mut suggestions = Vec::with_capacity(amount);
+    for _ in 0..amount {
+        let username = generate_unissued(args, &taken, rng)?;
+        if args.unique {
+            taken.insert(username.to_lowercase());
+        }
+        suggestions.push(username);
+    }
+    Ok(suggestions)
+}
+
+/// Adds `username` to the history of issued usernames with --unique.
+pub fn record(args: &UsernameArgs, username: &str) -> Result<(), String> {
+    if !args.unique {
+        return Ok(());
+    }
+    let path = history_file(args)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|error| i18n::message("unable-to-create", &[("path", &dir.display()), ("error", &error)]))?;
+    }
+    let mut history_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .map_err(|error| i18n::message("unable-to-open", &[("path", &path.display()), ("error", &error)]))?;
+    writeln!(history_file, "{}", username)
+        .map_err(|error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]))
+}
+
+/// The usernames issued with --unique before, lowercased, and none without it.
+fn issued(args: &UsernameArgs) -> Result<HashSet<String>, String> {
+    if !args.unique {
+        return Ok(HashSet::new());
+    }
+    let path = history_file(args)?;
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+    Ok(fs::read_to_string(&path)
+        .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?
+        .lines()
+        .map(|line| line.trim().to_lowercase())
+        .collect())
+}
+
+fn history_file(args: &UsernameArgs) -> Result<PathBuf, String> {
+    args.history.clone().or_else(paths::username_history_file)
+        .ok_or_else(|| i18n::message("username-no-config-directory", &[]))
+}
+
+/// Generates a username that isn't `issued` yet, regenerating until it passes the rules
+/// of the chosen platform and contains the requested text. If the text never shows up on
+/// its own, it is embedded in place of one of the words instead.
+fn generate_unissued<R: Rng + ?Sized>(args: &UsernameArgs, issued: &HashSet<String>, rng: &mut R) -> Result<String, String> {
+    const PLATFORM_ATTEMPTS: usize = 1000;
+    const SEARCH_ATTEMPTS: usize = 10000;
+
+    let rules = args.platform.map(|platform| platform.rules());
+    let wanted = if let Some(contains) = &args.contains {
+        Some((contains.to_lowercase(), false))
+    } else {
+        args.starts_with.as_ref().map(|starts_with| (starts_with.to_lowercase(), true))
+    };
+
+    if args.alliterate && args.style == Some(UsernameStyle::Invented) {
+        return Err(i18n::message("username-alliteration-style", &[]));
+    }
+
+    let mut max_length = args.max_length;
+    if let Some(rules) = &rules {
+        if let Some(word_char) = args.word_char && !rules.is_allowed(word_char) {
+            return Err(i18n::message("username-character-not-allowed", &[("character", &word_char), ("platform", &rules.name)]));
+        }
+        if let Some((text, _)) = &wanted && let Some(invalid_char) = text.chars().find(|&c| !rules.is_allowed(c)) {
+            return Err(i18n::message("username-character-not-allowed", &[("character", &invalid_char), ("platform", &rules.name)]));
+        }
+        let platform_max_length = max_length.map_or(rules.max_length, |max_length| max_length.min(rules.max_length));
+        if platform_max_length < rules.min_length {
+            return Err(i18n::message("username-platform-length", &[("platform", &rules.name), ("min", &rules.min_length)]));
+        }
+        max_length = Some(platform_max_length);
+    }
+    if let Some((text, _)) = &wanted && text.is_empty() {
+        return Err(i18n::message("username-empty-search", &[]));
+    }
+
+    let blocked = blocked_words(args)?;
+    let [first_words, second_words] = word_lists(args, &blocked)?;
+    let word_lists: [&[&str]; 2] = [&first_words, &second_words];
+
+    let check_platform = |username: &str| -> Result<(), String> {
+        let lowercase = username.to_lowercase();
+        if issued.contains(&lowercase) {
+            return Err(i18n::message("username-issued", &[]));
+        }
+        if let Some(word) = blocked.iter().find(|word| lowercase.contains(word.as_str())) {
+            return Err(i18n::message("username-blocked", &[("word", &word)]));
+        }
+        match &rules {
+            Some(rules) => rules.check(username),
+            None => Ok(()),
+        }
+    };
+
+    let attempts = if wanted.is_some() {
+        args.max_attempts.unwrap_or(SEARCH_ATTEMPTS)
+    } else if rules.is_some() || !issued.is_empty() || !blocked.is_empty() {
+        PLATFORM_ATTEMPTS
+    } else {
+        1
+    };
+    let mut last_problem = String::new();
+    for _ in 0..attempts {
+        let username = generate_once(args, word_lists, max_length, None, rng)?;
+        if let Err(problem) = check_platform(&username) {
+            last_problem = problem;
+            continue;
+        }
+        match &wanted {
+            Some((text, true)) if !username.to_lowercase().starts_with(text.as_str()) => continue,
+            Some((text, false)) if !username.to_lowercase().contains(text.as_str()) => continue,
+            _ => return Ok(username),
+        }
+    }
+
+    let Some((text, at_start)) = &wanted else {
+        return Err(match rules {
+            Some(rules) => i18n::message("username-invalid-for-platform", &[("platform", &rules.name), ("problem", &last_problem)]),
+            None => i18n::message("username-invalid", &[("problem", &last_problem)]),
+        });
+    };
+
+    let embedded = Embedded { text, position: if *at_start { 0 } else { 1 } };
+    for _ in 0..PLATFORM_ATTEMPTS {
+        let username = generate_once(args, word_lists, max_length, Some(&embedded), rng)?;
+        match check_platform(&username) {
+            Ok(()) => return Ok(username),
+            Err(problem) => last_problem = problem,
+        }
+    }
+    Err(i18n::message("username-embed-failed", &[("text", &text), ("problem", &last_problem)]))
+}
+
+/// Maps `input`, like an email address or UUID, to the same username every time by hashing
+/// it into the wordlists, so no mapping has to be kept. The input is trimmed and lowercased.
+/// When the result breaks a rule, the input is hashed again with the next round number.
+pub fn from_input(args: &UsernameArgs, input: &str) -> Result<String, String> {
+    const MAX_ROUNDS: u32 = 1000;
+
+    if args.style == Some(UsernameStyle::Invented) {
+        return Err(i18n::message("username-from-style", &[]));
+    }
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return Err(i18n::message("username-from-empty", &[]));
+    }
+    let blocked = blocked_words(args)?;
+    let [first_words, second_words] = word_lists(args, &blocked)?;
+    let rules = args.platform.map(|platform| platform.rules());
+    let number_amount = args.numbers.unwrap_or(2);
+    if number_amount > 19 {
+        return Err(i18n::message("username-from-numbers", &[]));
+    }
+
+    for round in 0..MAX_ROUNDS {
+        let hash = digest::sha256(&[&round.to_be_bytes()[..], input.as_bytes()].concat());
+        let value = |index: usize| u64::from_be_bytes(hash[index * 8..index * 8 + 8].try_into().unwrap());
+
+        let mut parts = vec![
+            first_words[(value(0) % first_words.len() as u64) as usize].to_string(),
+            second_words[(value(1) % second_words.len() as u64) as usize].to_string(),
+        ];
+        let number = match args.number_range {
+            Some(range) => match (range.end - range.start).checked_add(1) {
+                Some(size) => range.start + value(2) % size,
+                // The range holds every u64, so any value is in it.
+                None => value(2),
+            }.to_string(),
+            None if number_amount == 0 => String::new(),
+            None => format!("{:0width$}", value(2) % 10u64.pow(number_amount), width = number_amount as usize),
+        };
+        if !number.is_empty() {
+            parts.push(number.clone());
+        }
+        let separators = match &args.separator_set {
+            Some(set) => set.pick_by(parts.len() - 1, args.separator_per_gap, value(3)),
+            None => vec![args.word_char.map(String::from).unwrap_or_default(); parts.len() - 1],
+        };
+        let username = separator::join(&parts, &separators);
+
+        let lowercase = username.to_lowercase();
+        let fits = args.max_length.is_none_or(|max_length| username.chars().count() <= max_length)
+            && !(args.avoid_years && looks_like_year(&number))
+            && !blocked.iter().any(|word| lowercase.contains(word.as_str()))
+            && rules.as_ref().is_none_or(|rules| rules.check(&username).is_ok());
+        if fits {
+            return Ok(username);
+        }
+    }
+    Err(i18n::message("username-derive-failed", &[]))
+}
+
+/// How the name part of a username is built.
+#[derive(clap::ValueEnum, Clone, Copy, PartialEq)]
+pub enum UsernameStyle {
+    /// An adjective followed by an object, like "irritatingspud".
+    Words,
+    /// A pronounceable made-up name, like "Velkaron".
+    Invented,
+}
+
+/// Text that takes the place of one of the generated words.
+struct Embedded<'a> {
+    text: &'a str,
+    position: usize,
+}
+
+fn generate_once<R: Rng + ?Sized>(args: &UsernameArgs, base_word_lists: [&[&str]; 2], max_length: Option<usize>, embedded: Option<&Embedded>, rng: &mut R) -> Result<String, String> {
+    let number = random_number(args, rng)?;
+    
+    let is_invented = args.style == Some(UsernameStyle::Invented);
+    let word_amount = if is_invented && embedded.is_none() { 1 } else { 2 };
+    let separator_amount = word_amount - 1 + if number.is_empty() { 0 } else { 1 };
+    let separators = match &args.separator_set {
+        Some(set) => set.pick(separator_amount, args.separator_per_gap, rng),
+        None => vec![args.word_char.map(String::from).unwrap_or_default(); separator_amount],
+    };
+    let fixed_length = separators.iter().map(|separator| separator.chars().count()).sum::<usize>() + number.chars().count();
+    
+    let random_words = if is_invented {
+        let embedded_length = embedded.map_or(0, |embedded| embedded.text.chars().count());
+        let name_max_length = max_length.map(|max_length| max_length.saturating_sub(fixed_length + embedded_length));
+        let name = invented::generate(name_max_length, rng)?;
+        match embedded {
+            Some(embedded) if embedded.position == 0 => vec![embedded.text.to_string(), name],
+            Some(embedded) => vec![name, embedded.text.to_string()],
+            None => vec![name],
+        }
+    } else {
+        let embedded_list = embedded.map(|embedded| [embedded.text]);
+        let mut word_lists = base_word_lists;
+        if let (Some(embedded), Some(embedded_list)) = (embedded, &embedded_list) {
+            word_lists[embedded.position] = embedded_list;
+        }
+        
+        if args.alliterate {
+            wordlist::choose_alliterating_within(word_lists[0], word_lists[1], fixed_length, max_length, rng)?
+        } else {
+            wordlist::choose_words_within(&word_lists, fixed_length, max_length, rng)?
+        }
+    };
+    
+    let random_words: Vec<String> = if let Some(leet_level) = args.leet {
+        random_words.iter().enumerate().map(|(position, word)| {
+            if embedded.is_some_and(|embedded| embedded.position == position) {
+                word.clone()
+            } else {
+                leet::apply(word, leet_level, rng).0
+            }
+        }).collect()
+    } else {
+        random_words
+    };
+    
+    let mut parts = random_words;
+    if !number.is_empty() {
+        parts.push(number);
+    }
+    Ok(separator::join(&parts, &separators))
+}
+
+
+/// The filtered adjectives and objects of the `--lang` language, in the order of the username.
+fn word_lists(args: &UsernameArgs, blocked: &[String]) -> Result<[Vec<&'static str>; 2], String> {
+    let words = wordlist::username_words(i18n::requested_language());
+    let adjectives = filter_words(words.adjectives, args, blocked, "username-no-adjectives")?;
+    let objects = filter_words(words.objects, args, blocked, "username-no-objects")?;
+    Ok(if words.object_first { [objects, adjectives] } else { [adjectives, objects] })
+}
+
+/// Keeps only the words within the `--min-word-len` and `--max-word-len` bounds that
+/// don't contain a blocked word.
+fn filter_words(words: &[&'static str], args: &UsernameArgs, blocked: &[String], empty_message: &str) -> Result<Vec<&'static str>, String> {
+    let min_length = args.min_word_length.unwrap_or(0);
+    let max_length = args.max_word_length.unwrap_or(usize::MAX);
+
+    let filtered: Vec<&'static str> = words.iter()
+        .copied()
+        .filter(|word| (min_length..=max_length).contains(&word.chars().count()))
+        .filter(|word| {
+            let lowercase = word.to_lowercase();
+            !blocked.iter().any(|blocked_word| lowercase.contains(blocked_word.as_str()))
+        })
+        .collect();
+    if filtered.is_empty() {
+        return Err(i18n::message(empty_message, &[]));
+    }
+    Ok(filtered)
+}
+
+/// Words of the `--block-words` file in lowercase, one per line. Empty lines and lines
+/// starting with '#' are skipped.
+fn blocked_words(args: &UsernameArgs) -> Result<Vec<String>, String> {
+    let Some(path) = &args.block_words else {
+        return Ok(Vec::new());
+    };
+    let text = fs::read_to_string(path).map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+    Ok(text.lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect())
+}
+
+/// Prints how many usernames the options can produce and how likely a duplicate is among
+/// `--users` of them, leaving out --leet, --platform and the search options.
+pub fn report_strength(args: &UsernameArgs) -> Result<(), String> {
+    const DUPLICATE_CHANCE: f64 = 0.01;
+
+    let users = args.users.unwrap_or(1000);
+    let bits = possible_usernames(args)?.log2();
+    let keyspace = strength::keyspace(bits);
+    porcelain::field("entropy", &format!("{:.1}", bits));
+    porcelain::report("keyspace", &keyspace, i18n::message("username-keyspace", &[("keyspace", &keyspace), ("bits", &format!("{:.1}", bits))]));
+
+    // The birthday problem: 1 - e^(-n(n-1)/2k) for n users out of k usernames.
+    let pairs = users as f64 * (users as f64 - 1.0) / 2.0;
+    let chance = -(-pairs / 2f64.powf(bits)).exp_m1();
+    let unique_users = (2.0 * 2f64.powf(bits) * -(1.0 - DUPLICATE_CHANCE).ln()).sqrt().floor();
+    porcelain::report("duplicate-chance", &format!("{:.6}", chance), i18n::message("username-duplicate-chance", &[("users", &users), ("chance", &percentage(chance)), ("threshold", &(DUPLICATE_CHANCE * 100.0)), ("unique", &format!("{:.0}", unique_users))]));
+    porcelain::field("unique-users", &format!("{:.0}", unique_users));
+    Ok(())
+}
+
+fn percentage(chance: f64) -> String {
+    if chance >= 0.01 {
+        format!("{:.0}%", chance * 100.0)
+    } else if chance >= 0.0001 {
+        format!("{:.2}%", chance * 100.0)
+    } else {
+        i18n::message("username-chance-tiny", &[])
+    }
+}
+
+/// Amount of different usernames the options can produce.
+fn possible_usernames(args: &UsernameArgs) -> Result<f64, String> {
+    let numbers = match args.number_range {
+        Some(range) => (range.end - range.start) as f64 + 1.0,
+        None => 10f64.powi(args.numbers.unwrap_or(2) as i32),
+    };
+    let number_length = match args.number_range {
+        Some(range) => range.end.to_string().len(),
+        None => args.numbers.unwrap_or(2) as usize,
+    };
+    let is_invented = args.style == Some(UsernameStyle::Invented);
+    let gaps = usize::from(!is_invented) + usize::from(number_length > 0);
+    let separator_choices = args.separator_set.as_ref()
+        .map_or(1.0, |set| 2f64.powf(set.bits(gaps, args.separator_per_gap)));
+    if is_invented {
+        return Ok(2f64.powf(invented::entropy()) * numbers * separator_choices);
+    }
+
+    let separator_length = match &args.separator_set {
+        Some(set) => set.shortest_length(),
+        None => usize::from(args.word_char.is_some()),
+    };
+    let fixed_length = separator_length * gaps + number_length;
+    let budget = args.max_length.map(|max_length| max_length.saturating_sub(fixed_length));
+
+    // Counting word lengths per first letter is enough to count the pairs that fit.
+    let counts = |words: &[&str]| -> HashMap<(Option<char>, usize), f64> {
+        let mut counts = HashMap::new();
+        for word in words {
+            let letter = args.alliterate.then(|| word.chars().next().and_then(|c| c.to_lowercase().next())).flatten();
+            *counts.entry((letter, word.chars().count())).or_default() += 1.0;
+        }
+        counts
+    };
+    let [first_words, second_words] = word_lists(args, &blocked_words(args)?)?;
+    let first_counts = counts(&first_words);
+    let second_counts = counts(&second_words);
+    let pairs: f64 = first_counts.iter()
+        .flat_map(|((first_letter, first_length), first_count)| second_counts.iter()
+            .filter(move |((second_letter, second_length), _)| second_letter == first_letter
+                && budget.is_none_or(|budget| first_length + second_length <= budget))
+            .map(move |(_, second_count)| first_count * second_count))
+        .sum();
+    if pairs == 0.0 {
+        return Err(i18n::message("username-none-fit", &[]));
+    }
+    Ok(pairs * numbers * separator_choices)
+}
+
+/// Inclusive range of values for the number at the end of a username.
+#[derive(Clone, Copy)]
+pub struct NumberRange {
+    start: u64,
+    end: u64,
+}
+
+/// Parses "10..99" or "10..=99", both meaning 10 up to and including 99.
+pub fn parse_number_range(text: &str) -> Result<NumberRange, String> {
+    let (start, end) = text.split_once("..")
+        .ok_or("expected a range like \"10..99\"")?;
+    let end = end.strip_prefix('=').unwrap_or(end);
+    let start: u64 = start.trim().parse().map_err(|_| format!("\"{}\" is not a valid number", start))?;
+    let end: u64 = end.trim().parse().map_err(|_| format!("\"{}\" is not a valid number", end))?;
+    if start > end {
+        return Err("start of the range must not be larger than its end".into());
+    }
+    Ok(NumberRange { start, end })
+}
+
+/// Picks the number appended to the username, either as a fixed amount of digits
+/// or as a value within a range, rerolling anything that looks like a year.
+fn random_number<R: Rng + ?Sized>(args: &UsernameArgs, rng: &mut R) -> Result<String, String> {
+    const MAX_ATTEMPTS: usize = 1000;
+
+    let chosen_number_amount = if let Some(user_number_amount) = args.numbers {
+        if user_number_amount > 65536 {
+            return Err(i18n::message("username-too-many-numbers", &[]));
+        }
+        user_number_amount
+    } else {
+        2 // Default number amount
+    };
+
+    for _ in 0..MAX_ATTEMPTS {
+        let number = if let Some(range) = args.number_range {
+            rng.random_range(range.start..=range.end).to_string()
+        } else {
+            (0..chosen_number_amount).map(|_| char::from(b'0' + rng.random_range(0..10))).collect()
+        };
+        if !args.avoid_years || !looks_like_year(&number) {
+            return Ok(number);
+        }
+    }
+    Err(i18n::message("username-no-number", &[]))
+}
+
+/// Whether any four digits in a row read like a year from 1900 to 2099.
+fn looks_like_year(number: &str) -> bool {
+    number.as_bytes()
+        .windows(4)
+        .any(|digits| digits.starts_with(b"19") || digits.starts_with(b"20"))
+}
@@ -0,0 +1,45 @@
+use clap::{Args, Subcommand};
+
+use crate::{i18n, keyring, porcelain};
+
+#[derive(Args)]
+pub struct VaultsArgs {
+    #[command(subcommand)]
+    command: VaultsCommands,
+}
+
+#[derive(Subcommand)]
+enum VaultsCommands {
+    /// List the secrets saved with --keyring.
+    List,
+    /// Print a saved secret.
+    Get(NameArgs),
+    /// Delete a saved secret.
+    Delete(NameArgs),
+}
+
+#[derive(Args)]
+struct NameArgs {
+    /// Name the secret was saved under.
+    name: String,
+}
+
+pub fn run(args: &VaultsArgs) -> Result<(), String> {
+    match &args.command {
+        VaultsCommands::List => list(),
+        VaultsCommands::Get(name_args) => keyring::get(&name_args.name).map(|secret| porcelain::secret("value", &secret, &secret)),
+        VaultsCommands::Delete(name_args) => keyring::delete(&name_args.name)
+            .map(|()| porcelain::human(i18n::message("vaults-deleted", &[("name", &name_args.name)]))),
+    }
+}
+
+fn list() -> Result<(), String> {
+    let names = keyring::list()?;
+    if names.is_empty() {
+        porcelain::human(i18n::message("vaults-empty", &[]));
+    }
+    for name in names {
+        println!("{}", name);
+    }
+    Ok(())
+}
@@ -0,0 +1,545 @@
+use clap::{Args, Subcommand};
+use once_cell::sync::Lazy;
+use rand::Rng;
+use rand::seq::IndexedRandom;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::{i18n, paths, policy, porcelain};
+
+const ADJECTIVE_LIST_RAW: &str = include_str!("../data/adjective.txt");
+const OBJECT_LIST_RAW: &str = include_str!("../data/object.txt");
+
+pub static ADJECTIVE_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    ADJECTIVE_LIST_RAW.lines().collect()
+});
+pub static OBJECT_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
+    OBJECT_LIST_RAW.lines().collect()
+});
+
+#[cfg(feature = "lang-de")]
+static GERMAN_ADJECTIVE_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/de/adjective.txt").lines().collect());
+#[cfg(feature = "lang-de")]
+static GERMAN_OBJECT_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/de/object.txt").lines().collect());
+#[cfg(feature = "lang-es")]
+static SPANISH_ADJECTIVE_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/es/adjective.txt").lines().collect());
+#[cfg(feature = "lang-es")]
+static SPANISH_OBJECT_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/es/object.txt").lines().collect());
+#[cfg(feature = "lang-fr")]
+static FRENCH_ADJECTIVE_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/fr/adjective.txt").lines().collect());
+#[cfg(feature = "lang-fr")]
+static FRENCH_OBJECT_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| include_str!("../data/fr/object.txt").lines().collect());
+
+/// The words usernames are made of in one language.
+pub struct UsernameWords {
+    pub adjectives: &'static Lazy<Vec<&'static str>>,
+    pub objects: &'static Lazy<Vec<&'static str>>,
+    /// Whether the object comes first, as adjectives follow the noun in Spanish and French.
+    pub object_first: bool,
+}
+
+/// Username words in `language`, English when there are none or the "lang-<code>" feature
+/// was left out of the build. The Spanish and French adjectives don't change with gender,
+/// the German ones are stems that read like compounds.
+pub fn username_words(language: Option<&str>) -> UsernameWords {
+    let words = |adjectives, objects, object_first| UsernameWords { adjectives, objects, object_first };
+    match language {
+        #[cfg(feature = "lang-de")]
+        Some("de") => words(&GERMAN_ADJECTIVE_LIST, &GERMAN_OBJECT_LIST, false),
+        #[cfg(feature = "lang-es")]
+        Some("es") => words(&SPANISH_ADJECTIVE_LIST, &SPANISH_OBJECT_LIST, true),
+        #[cfg(feature = "lang-fr")]
+        Some("fr") => words(&FRENCH_ADJECTIVE_LIST, &FRENCH_OBJECT_LIST, true),
+        Some(language) if language != "en" => {
+            log::info!("No username words in \"{}\" in this build, using English ones", language);
+            words(&ADJECTIVE_LIST, &OBJECT_LIST, false)
+        }
+        _ => words(&ADJECTIVE_LIST, &OBJECT_LIST, false),
+    }
+}
+
+/// Wordlists embedded in the binary, addressable by name like imported ones.
+const BUILT_IN_LISTS: [(&str, &Lazy<Vec<&'static str>>); 2] = [
+    ("adjective", &ADJECTIVE_LIST),
+    ("object", &OBJECT_LIST),
+];
+
+#[derive(Args)]
+pub struct WordlistArgs {
+    #[command(subcommand)]
+    command: WordlistCommands,
+}
+
+#[derive(Subcommand)]
+enum WordlistCommands {
+    /// Import a wordlist from a file containing one word per line.
+    Import(ImportArgs),
+    /// List all built-in and imported wordlists.
+    List,
+    /// Show statistics about a wordlist.
+    Show(ShowArgs),
+    /// Remove an imported wordlist.
+    Remove(NameArgs),
+    /// Check a wordlist for unnormalized, confusable or prefix-colliding words.
+    Verify(NameArgs),
+}
+
+#[derive(Args)]
+struct ImportArgs {
+    /// File to import, one word per line.
+    file: PathBuf,
+
+    /// Name the wordlist is stored under.
+    /// Only letters, digits, '-' and '_' are allowed.
+    #[arg(short = 'n', long = "name")]
+    name: String,
+
+    /// Replace an already imported wordlist with the same name.
+    #[arg(short = 'f', long = "force")]
+    force: bool,
+}
+
+#[derive(Args)]
+struct ShowArgs {
+    /// Name of the wordlist.
+    name: String,
+
+    /// Also print every word in the list.
+    #[arg(short = 'w', long = "words")]
+    print_words: bool,
+}
+
+#[derive(Args)]
+struct NameArgs {
+    /// Name of the wordlist.
+    name: String,
+}
+
+/// Result of cleaning up a raw wordlist before it is stored.
+struct NormalizedList {
+    words: Vec<String>,
+    duplicates: usize,
+    rejected_lines: Vec<usize>,
+}
+
+pub fn run(args: &WordlistArgs) -> Result<(), String> {
+    match &args.command {
+        WordlistCommands::Import(import_args) => import(import_args),
+        WordlistCommands::List => list(),
+        WordlistCommands::Show(show_args) => show(show_args),
+        WordlistCommands::Remove(name_args) => remove(&name_args.name),
+        WordlistCommands::Verify(name_args) => verify(&name_args.name),
+    }
+}
+
+fn import(args: &ImportArgs) -> Result<(), String> {
+    if !is_valid_name(&args.name) {
+        return Err(i18n::message("wordlist-invalid-name", &[("name", &args.name)]));
+    }
+    if is_built_in(&args.name) {
+        return Err(i18n::message("wordlist-built-in-replace", &[("name", &args.name)]));
+    }
+
+    let raw = fs::read_to_string(&args.file)
+        .map_err(|error| i18n::message("unable-to-read", &[("path", &args.file.display()), ("error", &error)]))?;
+    let normalized = normalize(&raw);
+
+    if normalized.words.len() < 2 {
+        return Err(i18n::message("wordlist-too-small", &[]));
+    }
+
+    let path = stored_path(&args.name)?;
+    if path.exists() && !args.force {
+        return Err(i18n::message("wordlist-exists", &[("name", &args.name)]));
+    }
+    save(&path, &normalized.words)?;
+
+    porcelain::human(i18n::message("wordlist-imported", &[("count", &normalized.words.len()), ("name", &args.name)]));
+    if normalized.duplicates > 0 {
+        porcelain::human(i18n::message("wordlist-duplicates", &[("count", &normalized.duplicates)]));
+    }
+    if !normalized.rejected_lines.is_empty() {
+        porcelain::human(i18n::message("wordlist-rejected", &[("count", &normalized.rejected_lines.len()), ("lines", &format_line_numbers(&normalized.rejected_lines))]));
+    }
+    porcelain::human(i18n::message("wordlist-entropy", &[("bits", &format!("{:.2}", entropy_per_word(normalized.words.len())))]));
+    Ok(())
+}
+
+fn list() -> Result<(), String> {
+    for (name, words) in BUILT_IN_LISTS {
+        println!("{}", i18n::message("wordlist-listed-built-in", &[("name", &name), ("count", &words.len())]));
+    }
+    for name in imported_names()? {
+        let words = load(&name)?;
+        println!("{}", i18n::message("wordlist-listed", &[("name", &name), ("count", &words.len())]));
+    }
+    Ok(())
+}
+
+fn show(args: &ShowArgs) -> Result<(), String> {
+    let words = load(&args.name)?;
+    let min_length = words.iter().map(|word| word.chars().count()).min().unwrap_or(0);
+    let max_length = words.iter().map(|word| word.chars().count()).max().unwrap_or(0);
+
+    println!("{}", i18n::message("wordlist-info-name", &[("name", &args.name)]));
+    println!("{}", i18n::message(if is_built_in(&args.name) { "wordlist-info-built-in" } else { "wordlist-info-imported" }, &[]));
+    println!("{}", i18n::message("wordlist-info-words", &[("count", &words.len())]));
+    println!("{}", i18n::message("wordlist-info-length", &[("min", &min_length), ("max", &max_length)]));
+    println!("{}", i18n::message("wordlist-info-entropy", &[("bits", &format!("{:.2}", entropy_per_word(words.len())))]));
+
+    if args.print_words {
+        println!();
+        for word in &words {
+            println!("{}", word);
+        }
+    }
+    Ok(())
+}
+
+fn remove(name: &str) -> Result<(), String> {
+    if is_built_in(name) {
+        return Err(i18n::message("wordlist-built-in-remove", &[("name", &name)]));
+    }
+
+    let path = stored_path(name)?;
+    if !path.exists() {
+        return Err(i18n::message("wordlist-not-imported", &[("name", &name)]));
+    }
+    fs::remove_file(&path).map_err(|error| i18n::message("unable-to-remove", &[("path", &path.display()), ("error", &error)]))?;
+
+    porcelain::human(i18n::message("wordlist-removed", &[("name", &name)]));
+    Ok(())
+}
+
+fn verify(name: &str) -> Result<(), String> {
+    let words = load(name)?;
+
+    let unnormalized: Vec<String> = {
+        let mut seen = HashSet::new();
+        words.iter()
+            .filter(|word| {
+                let normalized = word.trim().to_lowercase();
+                normalized != **word
+                    || normalized.chars().any(|c| c.is_whitespace() || c.is_control())
+                    || !seen.insert(normalized)
+            })
+            .cloned()
+            .collect()
+    };
+    let prefix_collisions = find_prefix_collisions(&words);
+    let confusable_groups = find_confusable_groups(&words);
+
+    println!("{}", i18n::message("wordlist-checked", &[("count", &words.len()), ("name", &name)]));
+    if unnormalized.is_empty() && prefix_collisions.is_empty() && confusable_groups.is_empty() {
+        println!("{}", i18n::message("wordlist-no-problems", &[]));
+        return Ok(());
+    }
+
+    if !unnormalized.is_empty() {
+        println!("{}", i18n::message("wordlist-unnormalized", &[("count", &unnormalized.len()), ("examples", &format_examples(&unnormalized))]));
+    }
+    if !prefix_collisions.is_empty() {
+        println!("{}", i18n::message("wordlist-prefixes", &[("count", &prefix_collisions.len()), ("examples", &format_examples(&prefix_collisions))]));
+    }
+    if !confusable_groups.is_empty() {
+        println!("{}", i18n::message("wordlist-confusable", &[("count", &confusable_groups.len()), ("examples", &format_examples(&confusable_groups))]));
+    }
+    Ok(())
+}
+
+/// Words that are a prefix of another word, like "sun" and "sunset".
+/// After sorting, a word prefixes some other word exactly when it prefixes its successor.
+fn find_prefix_collisions(words: &[String]) -> Vec<String> {
+    let mut sorted: Vec<&String> = words.iter().collect();
+    sorted.sort();
+    sorted.dedup();
+
+    sorted.windows(2)
+        .filter(|pair| pair[1].starts_with(pair[0].as_str()))
+        .map(|pair| format!("{}/{}", pair[0], pair[1]))
+        .collect()
+}
+
+/// Groups of distinct words that reduce to the same shape once easily confused
+/// glyphs ("rn" and "m", "0" and "o", "1" and "l") are folded together.
+fn find_confusable_groups(words: &[String]) -> Vec<String> {
+    let mut groups: HashMap<String, Vec<&String>> = HashMap::new();
+    for word in words {
+        groups.entry(confusable_skeleton(word)).or_default().push(word);
+    }
+
+    let mut confusable: Vec<String> = groups.into_values()
+        .filter_map(|mut group| {
+            group.sort();
+            group.dedup();
+            (group.len() > 1).then(|| group.iter().map(|word| word.as_str()).collect::<Vec<_>>().join("/"))
+        })
+        .collect();
+    confusable.sort();
+    confusable
+}
+
+fn confusable_skeleton(word: &str) -> String {
+    let folded: String = word.to_lowercase().chars()
+        .map(|c| match c {
+            '0' => 'o',
+            '1' | 'i' | '|' => 'l',
+            '5' => 's',
+            '2' => 'z',
+            '8' => 'b',
+            _ => c,
+        })
+        .collect();
+    folded.replace("rn", "m").replace("vv", "w").replace("cl", "d")
+}
+
+/// Trims and lowercases every line, dropping blanks, duplicates and words that
+/// contain whitespace or control characters. First occurrence order is kept.
+fn normalize(raw: &str) -> NormalizedList {
+    let mut seen = HashSet::new();
+    let mut normalized = NormalizedList { words: Vec::new(), duplicates: 0, rejected_lines: Vec::new() };
+
+    for (index, line) in raw.trim_start_matches('\u{feff}').lines().enumerate() {
+        let word = line.trim().to_lowercase();
+        if word.is_empty() {
+            continue;
+        }
+        if word.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            normalized.rejected_lines.push(index + 1);
+            continue;
+        }
+        if seen.insert(word.clone()) {
+            normalized.words.push(word);
+        } else {
+            normalized.duplicates += 1;
+        }
+    }
+
+    normalized
+}
+
+fn entropy_per_word(word_count: usize) -> f64 {
+    (word_count as f64).log2()
+}
+
+fn format_line_numbers(lines: &[usize]) -> String {
+    let formatted: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    format_examples(&formatted)
+}
+
+/// Joins the first few items of a potentially long list for display.
+fn format_examples(items: &[String]) -> String {
+    const SHOWN: usize = 10;
+    let mut formatted: Vec<&str> = items.iter().take(SHOWN).map(|item| item.as_str()).collect();
+    let remaining = i18n::message("wordlist-more", &[("count", &items.len().saturating_sub(SHOWN))]);
+    if items.len() > SHOWN {
+        formatted.push(&remaining);
+    }
+    formatted.join(", ")
+}
+
+fn is_built_in(name: &str) -> bool {
+    BUILT_IN_LISTS.iter().any(|(built_in_name, _)| *built_in_name == name)
+}
+
+/// Loads a built-in or imported wordlist by name.
+pub fn load(name: &str) -> Result<Vec<String>, String> {
+    if let Some((_, words)) = BUILT_IN_LISTS.iter().find(|(built_in_name, _)| *built_in_name == name) {
+        return Ok(words.iter().map(|word| word.to_string()).collect());
+    }
+    if !is_valid_name(name) {
+        return Err(i18n::message("wordlist-unknown", &[("name", &name)]));
+    }
+
+    let path = stored_path(name)?;
+    if !path.exists() {
+        return Err(i18n::message("wordlist-unknown-hint", &[("name", &name)]));
+    }
+    let contents = fs::read_to_string(&path)
+        .map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+    Ok(contents.lines().map(|word| word.to_string()).collect())
+}
+
+/// Words used for passphrases: the named list, or all built-in lists combined.
+pub fn passphrase_words(name: Option<&str>) -> Result<Vec<String>, String> {
+    if let Some(name) = name {
+        return load(name);
+    }
+
+    let mut seen = HashSet::new();
+    Ok(BUILT_IN_LISTS.iter()
+        .flat_map(|(_, words)| words.iter())
+        .map(|word| word.to_lowercase())
+        .filter(|word| seen.insert(word.clone()))
+        .collect())
+}
+
+/// Picks one word from each list. With a `max_length`, the words together with
+/// `fixed_length` extra characters (separators, digits) are kept within it by
+/// regenerating, and if that keeps failing, by only picking words short enough to fit.
+pub fn choose_words_within<R: Rng + ?Sized>(lists: &[&[&str]], fixed_length: usize, max_length: Option<usize>, rng: &mut R) -> Result<Vec<String>, String> {
+    choose_words_counted(lists, fixed_length, max_length, rng).map(|(words, _)| words)
+}
+
+/// Like `choose_words_within`, also returning the entropy of the words in bits. Leaving out
+/// the words that don't fit makes that less than the sizes of the lists suggest.
+pub fn choose_words_counted<R: Rng + ?Sized>(lists: &[&[&str]], fixed_length: usize, max_length: Option<usize>, rng: &mut R) -> Result<(Vec<String>, f64), String> {
+    const MAX_ATTEMPTS: usize = 1000;
+
+    if lists.iter().any(|list| list.is_empty()) {
+        return Err(i18n::message("wordlist-empty", &[]));
+    }
+
+    let choose_all = |rng: &mut R| -> Vec<&str> {
+        lists.iter().map(|list| *list.choose(rng).unwrap()).collect()
+    };
+    let Some(max_length) = max_length else {
+        let bits = lists.iter().map(|list| (list.len() as f64).log2()).sum();
+        return Ok((choose_all(rng).into_iter().map(String::from).collect(), bits));
+    };
+
+    let shortest_lengths: Vec<usize> = lists.iter()
+        .map(|list| list.iter().map(|word| word.chars().count()).min().unwrap())
+        .collect();
+    let shortest_total = fixed_length + shortest_lengths.iter().sum::<usize>();
+    if shortest_total > max_length {
+        return Err(i18n::message("wordlist-too-long", &[("max", &max_length), ("shortest", &shortest_total)]));
+    }
+
+    for _ in 0..MAX_ATTEMPTS {
+        let words = choose_all(rng);
+        let total = fixed_length + words.iter().map(|word| word.chars().count()).sum::<usize>();
+        if total <= max_length {
+            // Regenerating is uniform over the combinations that fit.
+            let bits = fitting_combinations(lists, max_length - fixed_length) / std::f64::consts::LN_2;
+            return Ok((words.into_iter().map(String::from).collect(), bits));
+        }
+    }
+
+    // Spread what is left evenly so the first words don't eat up the whole budget.
+    let mut remaining = max_length - fixed_length;
+    let mut words = Vec::with_capacity(lists.len());
+    let mut bits = 0.0;
+    for (index, list) in lists.iter().enumerate() {
+        let reserved: usize = shortest_lengths[index + 1..].iter().sum();
+        let fair_share = remaining / (lists.len() - index);
+        let allowed = fair_share.max(shortest_lengths[index]).min(remaining - reserved);
+        let fitting: Vec<&str> = list.iter()
+            .copied()
+            .filter(|word| word.chars().count() <= allowed)
+            .collect();
+        let word = *fitting.choose(rng).unwrap();
+        bits += (fitting.len() as f64).log2();
+        remaining -= word.chars().count();
+        words.push(word.to_string());
+    }
+    Ok((words, bits))
+}
+
+/// Natural log of how many combinations of one word from each list are at most `budget`
+/// characters long together.
+fn fitting_combinations(lists: &[&[&str]], budget: usize) -> f64 {
+    let longest: usize = lists.iter()
+        .map(|list| list.iter().map(|word| word.chars().count()).max().unwrap())
+        .sum();
+    if longest <= budget {
+        return lists.iter().map(|list| (list.len() as f64).ln()).sum();
+    }
+
+    // Combinations by their length so far, as natural logs.
+    let mut by_length = vec![f64::NEG_INFINITY; budget + 1];
+    by_length[0] = 0.0;
+    for list in lists {
+        let mut word_lengths: HashMap<usize, usize> = HashMap::new();
+        for word in list.iter() {
+            *word_lengths.entry(word.chars().count()).or_default() += 1;
+        }
+        by_length = (0..=budget)
+            .map(|length| policy::log_sum(word_lengths.iter()
+                .filter(|&(&word_length, _)| word_length <= length)
+                .map(|(&word_length, &count)| by_length[length - word_length] + (count as f64).ln())))
+            .collect();
+    }
+    policy::log_sum(by_length.into_iter())
+}
+
+/// Like `choose_words_within` for two lists, but the second word is only picked
+/// from words starting with the same letter as the first one.
+pub fn choose_alliterating_within<R: Rng + ?Sized>(first_list: &[&str], second_list: &[&str], fixed_length: usize, max_length: Option<usize>, rng: &mut R) -> Result<Vec<String>, String> {
+    let first_letter = |word: &str| word.chars().next().map(|c| c.to_lowercase().to_string());
+
+    let mut second_by_letter: HashMap<String, Vec<&str>> = HashMap::new();
+    for word in second_list {
+        if let Some(letter) = first_letter(word) {
+            second_by_letter.entry(letter).or_default().push(word);
+        }
+    }
+    let shortest_second = |letter: &str| -> usize {
+        second_by_letter[letter].iter().map(|word| word.chars().count()).min().unwrap()
+    };
+
+    let candidates: Vec<&str> = first_list.iter()
+        .copied()
+        .filter(|word| {
+            let Some(letter) = first_letter(word) else {
+                return false;
+            };
+            if !second_by_letter.contains_key(&letter) {
+                return false;
+            }
+            max_length.is_none_or(|max_length| fixed_length + word.chars().count() + shortest_second(&letter) <= max_length)
+        })
+        .collect();
+
+    let Some(first_word) = candidates.choose(rng) else {
+        return Err(match max_length {
+            Some(max_length) => i18n::message("wordlist-alliteration-too-long", &[("max", &max_length)]),
+            None => i18n::message("wordlist-no-alliteration", &[]),
+        });
+    };
+    let matching_second = &second_by_letter[&first_letter(first_word).unwrap()];
+    choose_words_within(&[&[first_word], matching_second], fixed_length, max_length, rng)
+}
+
+/// Names of all imported wordlists, sorted alphabetically.
+fn imported_names() -> Result<Vec<String>, String> {
+    let Some(dir) = paths::wordlist_dir() else {
+        return Ok(Vec::new());
+    };
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let path = entry.path();
+            let is_text_file = path.extension().is_some_and(|extension| extension == "txt");
+            let name = path.file_stem()?.to_str()?.to_string();
+            (is_text_file && is_valid_name(&name)).then_some(name)
+        })
+        .collect();
+    names.sort();
+    Ok(names)
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+fn stored_path(name: &str) -> Result<PathBuf, String> {
+    let dir = paths::wordlist_dir().ok_or_else(|| i18n::message("no-config-directory", &[]))?;
+    Ok(dir.join(format!("{}.txt", name)))
+}
+
+fn save(path: &Path, words: &[String]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|error| i18n::message("unable-to-create", &[("path", &dir.display()), ("error", &error)]))?;
+    }
+
+    let mut contents = words.join("\n");
+    contents.push('\n');
+    fs::write(path, contents).map_err(|error| i18n::message("unable-to-write", &[("path", &path.display()), ("error", &error)]))
+}
@@ -0,0 +1,375 @@
+//! Passwords made the way xkpasswd makes them: a few words, transformed in case, with a
+//! separator, padding digits and padding symbols around them. The settings use the names
+//! of xkpasswd, so a scheme from xkpasswd.net can be kept as it is, either as its
+//! exported JSON or in an "[xkpasswd.<SCHEME>]" table of the config file.
+
+use std::fs;
+use std::path::PathBuf;
+
+use clap::{Args, ValueEnum};
+use rand::Rng;
+use rand::seq::IndexedRandom;
+
+use crate::config::{Config, Value};
+use crate::i18n;
+use crate::json;
+use crate::wordlist;
+
+#[derive(Args)]
+pub struct XkpasswdArgs {
+    /// Preset of xkpasswd the settings start from.
+    /// Defaults to default.
+    #[arg(short = 'p', long = "preset", value_enum)]
+    preset: Option<Preset>,
+
+    /// Take the settings from an "[xkpasswd.<SCHEME>]" table in the config file.
+    /// Example usage: "--scheme work".
+    #[arg(short = 'S', long = "scheme")]
+    scheme: Option<String>,
+
+    /// Take the settings from a JSON file saved from xkpasswd.net.
+    /// Example usage: "--settings xkpasswd.json".
+    #[arg(short = 'f', long = "settings", conflicts_with = "scheme")]
+    settings: Option<PathBuf>,
+
+    /// Name of the wordlist to pick words from.
+    /// By default the built-in adjective and object lists are combined.
+    #[arg(short = 'W', long = "wordlist")]
+    wordlist: Option<String>,
+
+    /// Prints the estimated entropy of the password.
+    #[arg(short = 's', long = "show-strength")]
+    pub show_strength: bool,
+
+    /// Disables copying the password to clipboard.
+    #[arg(short = 'o', long = "no-copy", env = "PWGEN_NO_COPY")]
+    pub copy_disabled: bool,
+
+    /// Disables hiding the password generated.
+    #[arg(short = 'i', long = "no-hide", env = "PWGEN_NO_HIDE")]
+    pub hide_disabled: bool,
+}
+
+#[derive(ValueEnum, Clone, Copy)]
+pub enum Preset {
+    /// Three words in alternating case, two digits and two symbols on each end.
+    Default,
+    /// Four random case words separated by hyphens, like the comic.
+    Xkcd,
+    /// Four short words padded with symbols to 32 characters.
+    Web32,
+}
+
+#[derive(ValueEnum, Clone, Copy, PartialEq)]
+enum CaseTransform {
+    None,
+    Lower,
+    Upper,
+    Capitalise,
+    Invert,
+    Alternate,
+    Random,
+}
+
+#[derive(ValueEnum, Clone, Copy, PartialEq)]
+enum PaddingType {
+    None,
+    Fixed,
+    Adaptive,
+}
+
+/// A character setting, which is a character, "RANDOM" for one of its alphabet or "NONE".
+/// Padding can also be "SEPARATOR".
+#[derive(Clone, Copy, PartialEq)]
+enum Character {
+    None,
+    Fixed(char),
+    Random,
+    Separator,
+}
+
+struct Settings {
+    num_words: usize,
+    word_length_min: usize,
+    word_length_max: usize,
+    case_transform: CaseTransform,
+    separator_character: Character,
+    separator_alphabet: Vec<char>,
+    padding_digits_before: usize,
+    padding_digits_after: usize,
+    padding_type: PaddingType,
+    padding_character: Character,
+    padding_characters_before: usize,
+    padding_characters_after: usize,
+    pad_to_length: usize,
+    symbol_alphabet: Vec<char>,
+}
+
+const DEFAULT_ALPHABET: &str = "!@$%^&*-_+=:|~?/.;";
+
+impl Preset {
+    fn settings(self) -> Settings {
+        let default = Settings {
+            num_words: 3,
+            word_length_min: 4,
+            word_length_max: 8,
+            case_transform: CaseTransform::Alternate,
+            separator_character: Character::Random,
+            separator_alphabet: DEFAULT_ALPHABET.chars().collect(),
+            padding_digits_before: 2,
+            padding_digits_after: 2,
+            padding_type: PaddingType::Fixed,
+            padding_character: Character::Random,
+            padding_characters_before: 2,
+            padding_characters_after: 2,
+            pad_to_length: 0,
+            symbol_alphabet: DEFAULT_ALPHABET.chars().collect(),
+        };
+        match self {
+            Preset::Default => default,
+            Preset::Xkcd => Settings {
+                num_words: 4,
+                case_transform: CaseTransform::Random,
+                separator_character: Character::Fixed('-'),
+                padding_digits_before: 0,
+                padding_digits_after: 0,
+                padding_type: PaddingType::None,
+                ..default
+            },
+            Preset::Web32 => Settings {
+                num_words: 4,
+                word_length_max: 5,
+                separator_alphabet: "-+=.*_|~,".chars().collect(),
+                padding_type: PaddingType::Adaptive,
+                pad_to_length: 32,
+                symbol_alphabet: "!@$%^&*+=:|~?".chars().collect(),
+                ..default
+            },
+        }
+    }
+}
+
+impl Settings {
+    /// Replaces the settings that `config` has, with keys like "num_words".
+    fn update(&mut self, config: &Config) -> Result<(), String> {
+        let number = |key: &str| -> Result<Option<usize>, String> {
+            config.get_integer(key)?
+                .map(|number| usize::try_from(number).map_err(|_| i18n::message("xkpasswd-negative", &[("key", &key)])))
+                .transpose()
+        };
+        let choice = |key: &str| config.get_string(key).map(|text| text.map(str::to_lowercase));
+
+        for (key, value) in config.iter() {
+            match key {
+                "num_words" => self.num_words = number(key)?.unwrap(),
+                "word_length_min" => self.word_length_min = number(key)?.unwrap(),
+                "word_length_max" => self.word_length_max = number(key)?.unwrap(),
+                "padding_digits_before" => self.padding_digits_before = number(key)?.unwrap(),
+                "padding_digits_after" => self.padding_digits_after = number(key)?.unwrap(),
+                "padding_characters_before" => self.padding_characters_before = number(key)?.unwrap(),
+                "padding_characters_after" => self.padding_characters_after = number(key)?.unwrap(),
+                "pad_to_length" => self.pad_to_length = number(key)?.unwrap(),
+                "case_transform" => {
+                    let text = choice(key)?.unwrap_or_default();
+                    self.case_transform = CaseTransform::from_str(&text, true)
+                        .map_err(|_| i18n::message("xkpasswd-unknown-case", &[("value", &text)]))?;
+                }
+                "padding_type" => {
+                    let text = choice(key)?.unwrap_or_default();
+                    self.padding_type = PaddingType::from_str(&text, true)
+                        .map_err(|_| i18n::message("xkpasswd-unknown-padding", &[("value", &text)]))?;
+                }
+                "separator_character" => self.separator_character = character(key, value, false)?,
+                "padding_character" => self.padding_character = character(key, value, true)?,
+                "separator_alphabet" => self.separator_alphabet = alphabet(key, value)?,
+                "symbol_alphabet" => self.symbol_alphabet = alphabet(key, value)?,
+                // xkpasswd.net exports a few settings pwgen has no use for.
+                "allow_accents" | "character_substitutions" => {}
+                _ => return Err(i18n::message("xkpasswd-unknown-setting", &[("key", &key)])),
+            }
+        }
+        Ok(())
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if self.num_words == 0 || self.num_words > 100 {
+            return Err(i18n::message("xkpasswd-words-range", &[]));
+        }
+        if self.word_length_min == 0 || self.word_length_min > self.word_length_max {
+            return Err(i18n::message("xkpasswd-word-length", &[]));
+        }
+        if self.separator_character == Character::Random && self.separator_alphabet.is_empty() {
+            return Err(i18n::message("xkpasswd-separator-alphabet", &[]));
+        }
+        if self.padding_type != PaddingType::None {
+            if self.padding_character == Character::Random && self.symbol_alphabet.is_empty() {
+                return Err(i18n::message("xkpasswd-symbol-alphabet", &[]));
+            }
+            if self.padding_character == Character::Separator && self.separator_character == Character::None {
+                return Err(i18n::message("xkpasswd-no-separator", &[]));
+            }
+        }
+        if self.padding_type == PaddingType::Adaptive && self.pad_to_length == 0 {
+            return Err(i18n::message("xkpasswd-pad-to-length", &[]));
+        }
+        Ok(())
+    }
+}
+
+fn character(key: &str, value: &Value, padding: bool) -> Result<Character, String> {
+    let Value::String(text) = value else {
+        return Err(i18n::message("xkpasswd-character", &[("key", &key)]));
+    };
+    let mut chars = text.chars();
+    Ok(match (text.to_uppercase().as_str(), chars.next(), chars.next()) {
+        ("RANDOM", _, _) => Character::Random,
+        ("NONE", _, _) => Character::None,
+        ("SEPARATOR", _, _) if padding => Character::Separator,
+        (_, Some(c), None) => Character::Fixed(c),
+        _ => return Err(i18n::message("xkpasswd-character", &[("key", &key)])),
+    })
+}
+
+fn alphabet(key: &str, value: &Value) -> Result<Vec<char>, String> {
+    match value {
+        Value::String(text) => Ok(text.chars().collect()),
+        Value::Array(items) => items.iter().map(|item| match item {
+            Value::String(text) if text.chars().count() == 1 => Ok(text.chars().next().unwrap()),
+            _ => Err(i18n::message("xkpasswd-single-characters", &[("key", &key)])),
+        }).collect(),
+        _ => Err(i18n::message("xkpasswd-character-list", &[("key", &key)])),
+    }
+}
+
+/// The settings of a JSON file from xkpasswd.net, as the values of a config file.
+fn json_settings(path: &PathBuf) -> Result<Config, String> {
+    let text = fs::read_to_string(path).map_err(|error| i18n::message("unable-to-read", &[("path", &path.display()), ("error", &error)]))?;
+    let json::Value::Object(fields) = json::parse(&text)? else {
+        return Err(i18n::message("xkpasswd-not-object", &[("path", &path.display())]));
+    };
+    fn convert(value: &json::Value) -> Option<Value> {
+        Some(match value {
+            json::Value::String(text) => Value::String(text.clone()),
+            json::Value::Number(number) if number.fract() == 0.0 => Value::Integer(*number as i64),
+            json::Value::Boolean(boolean) => Value::Boolean(*boolean),
+            json::Value::Array(items) => Value::Array(items.iter().map(convert).collect::<Option<_>>()?),
+            _ => return None,
+        })
+    }
+    let mut config = Config::default();
+    for (key, value) in &fields {
+        let value = convert(value).ok_or_else(|| i18n::message("xkpasswd-unsupported-value", &[("key", &key)]))?;
+        config.set(&key.to_lowercase(), value);
+    }
+    Ok(config)
+}
+
+/// The settings of an "[xkpasswd.<SCHEME>]" table, without the table name in the keys.
+fn scheme_settings(scheme: &str) -> Result<Config, String> {
+    let config = Config::load()?;
+    let table = format!("xkpasswd.{}", scheme);
+    if !config.has_table(&table) {
+        return Err(i18n::message("xkpasswd-unknown-scheme", &[("scheme", &scheme)]));
+    }
+    let mut settings = Config::default();
+    for (key, value) in config.iter() {
+        if let Some(field) = key.strip_prefix(&format!("{}.", table)) {
+            settings.set(field, value.clone());
+        }
+    }
+    Ok(settings)
+}
+
+/// Generates a password with the settings, returning it with its entropy in bits.
+pub fn generate<R: Rng + ?Sized>(args: &XkpasswdArgs, rng: &mut R) -> Result<(String, f64), String> {
+    let mut settings = args.preset.unwrap_or(Preset::Default).settings();
+    if let Some(scheme) = &args.scheme {
+        settings.update(&scheme_settings(scheme)?)?;
+    }
+    if let Some(path) = &args.settings {
+        settings.update(&json_settings(path)?).map_err(|error| i18n::message("xkpasswd-invalid", &[("path", &path.display()), ("error", &error)]))?;
+    }
+    settings.check()?;
+
+    let words: Vec<String> = wordlist::passphrase_words(args.wordlist.as_deref())?.into_iter()
+        .filter(|word| (settings.word_length_min..=settings.word_length_max).contains(&word.chars().count()))
+        .collect();
+    if words.is_empty() {
+        return Err(i18n::message("xkpasswd-no-words", &[("min", &settings.word_length_min), ("max", &settings.word_length_max)]));
+    }
+    let mut bits = settings.num_words as f64 * (words.len() as f64).log2();
+
+    let words: Vec<String> = (0..settings.num_words).map(|index| {
+        let word = words.choose(rng).unwrap();
+        match settings.case_transform {
+            CaseTransform::None | CaseTransform::Lower => word.to_lowercase(),
+            CaseTransform::Upper => word.to_uppercase(),
+            CaseTransform::Capitalise => capitalise(word, false),
+            CaseTransform::Invert => capitalise(word, true),
+            CaseTransform::Alternate if index % 2 == 1 => word.to_uppercase(),
+            CaseTransform::Alternate => word.to_lowercase(),
+            CaseTransform::Random if rng.random_bool(0.5) => word.to_uppercase(),
+            CaseTransform::Random => word.to_lowercase(),
+        }
+    }).collect();
+    if settings.case_transform == CaseTransform::Random {
+        bits += settings.num_words as f64;
+    }
+
+    let separator = match settings.separator_character {
+        Character::Fixed(c) => Some(c),
+        Character::Random => {
+            bits += (settings.separator_alphabet.len() as f64).log2();
+            Some(*settings.separator_alphabet.choose(rng).unwrap())
+        }
+        Character::None | Character::Separator => None,
+    };
+    let separator = separator.map(String::from).unwrap_or_default();
+
+    let digits = |amount: usize, rng: &mut R| -> String { (0..amount).map(|_| char::from(b'0' + rng.random_range(0..10))).collect() };
+    let mut parts = Vec::new();
+    if settings.padding_digits_before > 0 {
+        parts.push(digits(settings.padding_digits_before, rng));
+    }
+    parts.extend(words);
+    if settings.padding_digits_after > 0 {
+        parts.push(digits(settings.padding_digits_after, rng));
+    }
+    bits += (settings.padding_digits_before + settings.padding_digits_after) as f64 * 10f64.log2();
+    let mut password = parts.join(&separator);
+
+    if settings.padding_type != PaddingType::None {
+        let padding = match settings.padding_character {
+            Character::Fixed(c) => c.to_string(),
+            Character::Random => {
+                bits += (settings.symbol_alphabet.len() as f64).log2();
+                settings.symbol_alphabet.choose(rng).unwrap().to_string()
+            }
+            Character::Separator => separator.clone(),
+            Character::None => String::new(),
+        };
+        if settings.padding_type == PaddingType::Fixed {
+            password = padding.repeat(settings.padding_characters_before) + &password + &padding.repeat(settings.padding_characters_after);
+        } else {
+            let length = password.chars().count();
+            if length < settings.pad_to_length {
+                password += &padding.repeat(settings.pad_to_length - length);
+            } else {
+                password = password.chars().take(settings.pad_to_length).collect();
+            }
+        }
+    }
+    Ok((password, bits))
+}
+
+fn capitalise(word: &str, inverted: bool) -> String {
+    let mut chars = word.chars();
+    let Some(first) = chars.next() else {
+        return String::new();
+    };
+    if inverted {
+        first.to_lowercase().chain(chars.as_str().to_uppercase().chars()).collect()
+    } else {
+        first.to_uppercase().chain(chars.as_str().to_lowercase().chars()).collect()
+    }
+}
@@ -0,0 +1,13 @@
+use std::process::{Command, Stdio};
+
+#[test]
+fn failing_commands_exit_with_1() {
+    let output = Command::new(env!("CARGO_BIN_EXE_pwgen"))
+        .args(["--quiet", "password", "--length", "70000", "--no-copy"])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("65536"));
+}